@@ -1,4 +1,4 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use deltachat_message_parser::parser::{
     is_emoji::get_first_emoji, parse_desktop_set, parse_markdown_text, parse_only_text,
     LinkDestination,
@@ -70,5 +70,44 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, criterion_benchmark);
+/// Inputs with many characters that could start an element, but don't.
+/// The parse time should grow linearly with the input length.
+pub fn adversarial_benchmark(c: &mut Criterion) {
+    // the pattern is repeated and followed by the suffix
+    let patterns = [
+        ("stars", "* ", ""),
+        ("underscores", "a_ ", ""),
+        ("tildes", "~a ", ""),
+        ("open_brackets", "[a ", ""),
+        ("labels_without_link", "[a]( ", ""),
+        ("angle_brackets", "<a ", ""),
+        ("hashes", "# ", ""),
+        ("dots", "a.a", ""),
+        ("words", "lorem ipsum ", ""),
+        // without spaces every character can be the start of an element
+        ("stars_without_spaces", "*", ""),
+        ("hashes_without_spaces", "#", ""),
+        ("email_local_part", "a", "@"),
+        ("scheme", "a", ":"),
+        ("angle_brackets_without_spaces", "<a", ">"),
+    ];
+    for (name, pattern, suffix) in patterns.iter() {
+        let mut group = c.benchmark_group(format!("adversarial_{}", name));
+        for length in [1_000, 4_000, 16_000] {
+            let input = pattern.repeat(length / pattern.len()) + suffix;
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new("markdown", length), &input, |b, input| {
+                b.iter(|| parse_markdown_text(black_box(input)))
+            });
+            group.bench_with_input(
+                BenchmarkId::new("desktop_set", length),
+                &input,
+                |b, input| b.iter(|| parse_desktop_set(black_box(input))),
+            );
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, criterion_benchmark, adversarial_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,7 @@
+#![no_main]
+use deltachat_message_parser_fuzz::check_emoji;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    check_emoji(input);
+});
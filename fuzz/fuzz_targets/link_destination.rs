@@ -0,0 +1,9 @@
+#![no_main]
+use deltachat_message_parser::parser::LinkDestination;
+use deltachat_message_parser_fuzz::check_link_destination;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    check_link_destination(input, LinkDestination::parse(input).ok());
+    check_link_destination(input, LinkDestination::parse_labelled(input).ok());
+});
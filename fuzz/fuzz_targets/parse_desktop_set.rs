@@ -0,0 +1,10 @@
+#![no_main]
+use deltachat_message_parser::parser::parse_desktop_set_with_sources;
+use deltachat_message_parser_fuzz::{check_sources, options};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for options in &options() {
+        check_sources(input, parse_desktop_set_with_sources(input, options));
+    }
+});
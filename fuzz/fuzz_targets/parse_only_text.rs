@@ -0,0 +1,12 @@
+#![no_main]
+use deltachat_message_parser::parser::parse_only_text_with_sources;
+use deltachat_message_parser_fuzz::{check_exact_coverage, check_sources, options};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for options in &options() {
+        let (elements, sources) = parse_only_text_with_sources(input, options);
+        check_exact_coverage(input, &elements);
+        check_sources(input, (elements, sources));
+    }
+});
@@ -0,0 +1,7 @@
+#![no_main]
+use deltachat_message_parser_fuzz::check_punycode;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|host: &str| {
+    check_punycode(host);
+});
@@ -0,0 +1,261 @@
+//! Invariants that the fuzz targets check, they panic when an invariant does not hold.
+
+use std::ops::Range;
+
+use deltachat_message_parser::parser::{
+    is_emoji::{
+        apply_skin_tone, canonical_base_emoji, classify_jumbo_emoji,
+        count_emoji_elements_if_only_emoji, count_emojis_if_only_contains_emoji, decompose_emoji,
+        emoji, emoji_qualification, emoji_segments, SegmentKind, SkinTone,
+    },
+    parse_only_text_with_options, punycode_decode_host, punycode_encode_host,
+    source::{verify_coverage, ElementSource},
+    Element, ElementKind, LinkDestination, ParserOptions,
+};
+
+/// The option combinations every input is parsed with
+pub fn options() -> [ParserOptions; 3] {
+    [
+        ParserOptions::default(),
+        ParserOptions {
+            emoji_elements: true,
+            bot_command_args: true,
+            ..Default::default()
+        },
+        ParserOptions {
+            enabled_elements: Some(vec![
+                ElementKind::Tag,
+                ElementKind::Link,
+                ElementKind::Italics,
+                ElementKind::LabeledLink,
+            ]),
+            allowed_schemes: Some(vec!["https".to_owned()]),
+            ..Default::default()
+        },
+    ]
+}
+
+/// Byte range of `slice` in `input`, panics if the slice is not part of the input
+pub fn range_in(input: &str, slice: &str) -> Range<usize> {
+    let input_start = input.as_ptr() as usize;
+    let start = slice.as_ptr() as usize;
+    assert!(
+        start >= input_start && start + slice.len() <= input_start + input.len(),
+        "{slice:?} is not part of the input {input:?}"
+    );
+    let start = start - input_start;
+    start..start + slice.len()
+}
+
+fn union(range: Option<Range<usize>>, other: Range<usize>) -> Option<Range<usize>> {
+    Some(match range {
+        Some(range) => range.start.min(other.start)..range.end.max(other.end),
+        None => other,
+    })
+}
+
+fn link_destination_span(input: &str, destination: &LinkDestination) -> Range<usize> {
+    let target = range_in(input, destination.target);
+    for part in destination.hostname.iter().chain(destination.scheme.iter()) {
+        range_in(input, part);
+    }
+    target
+}
+
+/// Byte range in `input` that contains all slices of the element and its children,
+/// `None` for elements without a slice like [Element::Linebreak]
+pub fn element_span(input: &str, element: &Element) -> Option<Range<usize>> {
+    match element {
+        Element::Text(slice)
+        | Element::Tag(slice)
+        | Element::EmailAddress(slice)
+        | Element::Emoji(slice)
+        | Element::InlineCode { content: slice } => Some(range_in(input, slice)),
+        Element::Linebreak => None,
+        Element::Link { destination } => Some(link_destination_span(input, destination)),
+        Element::BotCommandSuggestion {
+            content,
+            command,
+            target,
+            args,
+        } => [Some(content), Some(command), target.as_ref(), args.as_ref()]
+            .iter()
+            .flatten()
+            .fold(None, |span, slice| union(span, range_in(input, slice))),
+        Element::Bold(children) | Element::Italics(children) | Element::StrikeThrough(children) => {
+            children_span(input, children)
+        }
+        Element::LabeledLink { label, destination } => union(
+            children_span(input, label),
+            link_destination_span(input, destination),
+        ),
+        Element::CodeBlock { language, content } => language
+            .iter()
+            .fold(Some(range_in(input, content)), |span, language| {
+                union(span, range_in(input, language))
+            }),
+    }
+}
+
+fn children_span(input: &str, children: &[Element]) -> Option<Range<usize>> {
+    check_ordered(input, children);
+    children
+        .iter()
+        .filter_map(|child| element_span(input, child))
+        .fold(None, union)
+}
+
+/// Checks that all slices of the elements lie inside the input and that the elements follow each
+/// other without overlapping, the same for the children of each element.
+pub fn check_ordered(input: &str, elements: &[Element]) {
+    let mut end = 0;
+    for element in elements {
+        if let Some(span) = element_span(input, element) {
+            assert!(
+                span.start >= end,
+                "{element:?} overlaps the element before it in {input:?}"
+            );
+            end = span.end;
+        }
+    }
+}
+
+/// Checks [check_ordered] and that concatenating the source of all elements reproduces the input,
+/// this holds for the elements of `parse_only_text`, which contain no markdown delimiters
+pub fn check_exact_coverage(input: &str, elements: &[Element]) {
+    check_ordered(input, elements);
+    let mut position = 0;
+    for element in elements {
+        let span = match element_span(input, element) {
+            Some(span) => span,
+            None => {
+                assert_eq!(
+                    input.get(position..position + 1),
+                    Some("\n"),
+                    "linebreak at {position} is no newline in {input:?}"
+                );
+                position..position + 1
+            }
+        };
+        assert_eq!(
+            span.start, position,
+            "text before {element:?} is not covered in {input:?}"
+        );
+        position = span.end;
+    }
+    assert_eq!(position, input.len(), "end of {input:?} is not covered");
+}
+
+/// Checks [check_ordered], that the sources cover the input (see [verify_coverage]) and that
+/// each element and its children lie inside of their source
+pub fn check_sources(input: &str, (elements, sources): (Vec<Element>, Vec<ElementSource>)) {
+    check_ordered(input, &elements);
+    assert_eq!(verify_coverage(input, &sources), Ok(()), "{input:?}");
+    check_inside_sources(input, &elements, &sources);
+}
+
+fn check_inside_sources(input: &str, elements: &[Element], sources: &[ElementSource]) {
+    assert_eq!(elements.len(), sources.len(), "{elements:?} {sources:?}");
+    for (element, source) in elements.iter().zip(sources) {
+        if let Some(span) = element_span(input, element) {
+            assert!(
+                source.range.start <= span.start && span.end <= source.range.end,
+                "{element:?} is not inside of its source {source:?}"
+            );
+        }
+        match element {
+            Element::Bold(children)
+            | Element::Italics(children)
+            | Element::StrikeThrough(children)
+            | Element::LabeledLink {
+                label: children, ..
+            } => check_inside_sources(input, children, &source.children),
+            _ => assert!(source.children.is_empty(), "{source:?}"),
+        }
+    }
+}
+
+/// Checks the result of [LinkDestination::parse] or [LinkDestination::parse_labelled]:
+/// the link is a prefix of the input and the rest follows it
+pub fn check_link_destination(input: &str, result: Option<(&str, LinkDestination)>) {
+    if let Some((rest, destination)) = result {
+        let rest_range = range_in(input, rest);
+        assert_eq!(
+            rest_range.end,
+            input.len(),
+            "rest is no suffix of {input:?}"
+        );
+        let target = link_destination_span(input, &destination);
+        assert_eq!(target.start, 0, "{destination:?} does not start the input");
+        assert!(
+            !destination.target.is_empty(),
+            "empty link target in {input:?}"
+        );
+        assert!(
+            target.end <= rest_range.start,
+            "rest overlaps {destination:?}"
+        );
+        if let Some(warning) = &destination.punycode {
+            assert!(
+                warning.ascii_hostname.is_ascii(),
+                "{warning:?} has no ascii hostname"
+            );
+        }
+    }
+}
+
+/// Checks that encoding and decoding do not panic and that decoding an encoded host gives it back
+pub fn check_punycode(host: &str) {
+    punycode_decode_host(host);
+    let encoded = punycode_encode_host(host);
+    assert!(encoded.is_ascii(), "{host:?} encoded to {encoded:?}");
+    // hosts that already contain punycode are not encoded again, so they are not restored
+    let restorable = host.split('.').all(|label| !label.starts_with("xn--"));
+    if restorable && !encoded.contains("[punycode encode failed]") {
+        assert_eq!(punycode_decode_host(&encoded), host, "via {encoded:?}");
+    }
+}
+
+/// Checks that the emoji functions agree with each other
+pub fn check_emoji(input: &str) {
+    if let Ok((rest, found)) = emoji(input) {
+        assert!(!found.is_empty(), "empty emoji in {input:?}");
+        assert_eq!(range_in(input, found).start, 0);
+        assert_eq!(range_in(input, rest).end, input.len());
+        assert_eq!(found.len() + rest.len(), input.len());
+        assert!(emoji_qualification(found).is_some(), "{found:?}");
+        decompose_emoji(found);
+        canonical_base_emoji(found);
+        apply_skin_tone(found, SkinTone::Medium);
+    }
+
+    let mut position = 0;
+    let mut emoji_count: u32 = 0;
+    for (range, kind) in emoji_segments(input) {
+        assert_eq!(range.start, position, "segments of {input:?} have a gap");
+        let segment = input.get(range.clone()).expect("segment is no str slice");
+        if kind == SegmentKind::Emoji {
+            assert!(emoji_qualification(segment).is_some(), "{segment:?}");
+        }
+        if kind != SegmentKind::Text {
+            emoji_count += 1;
+        }
+        position = range.end;
+    }
+    assert_eq!(position, input.len(), "segments don't cover {input:?}");
+    let classification = classify_jumbo_emoji(input, 5);
+    assert_eq!(classification.emoji_count, emoji_count, "{input:?}");
+
+    let elements = parse_only_text_with_options(
+        input,
+        &ParserOptions {
+            emoji_elements: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        count_emoji_elements_if_only_emoji(&elements),
+        count_emojis_if_only_contains_emoji(input),
+        "{input:?} parsed to {elements:?}"
+    );
+}
@@ -0,0 +1,7 @@
+fn main() {
+    // `cc` needs the target to find the C compiler, it is only set for build scripts
+    println!(
+        "cargo:rustc-env=TARGET={}",
+        std::env::var("TARGET").unwrap_or_default()
+    );
+}
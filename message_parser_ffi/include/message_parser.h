@@ -0,0 +1,121 @@
+/* DO NOT EDIT, generated by `cargo xtask ffi-header` from message_parser_ffi/src/lib.rs. */
+
+#ifndef MESSAGE_PARSER_H
+#define MESSAGE_PARSER_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Parse markdown, like `parse_markdown_text`
+ */
+#define MP_MODE_MARKDOWN 0
+
+/**
+ * Parse only text elements, like `parse_only_text`
+ */
+#define MP_MODE_TEXT 1
+
+/**
+ * Parse text elements and labeled links, like `parse_desktop_set`
+ */
+#define MP_MODE_DESKTOP_SET 2
+
+/**
+ * Emit `Emoji` elements for all emoji
+ */
+#define MP_FLAG_EMOJI_ELEMENTS 1
+
+/**
+ * Parse the rest of the line after a bot command as its arguments
+ */
+#define MP_FLAG_BOT_COMMAND_ARGS (1 << 1)
+
+/**
+ * Return `{"schema_version": 1, "elements": [...]}` instead of only the elements
+ */
+#define MP_FLAG_VERSIONED (1 << 2)
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parses the text to the JSON of the elements
+ *
+ * `mode` is one of the `MP_MODE_*` constants and `flags` a combination of the `MP_FLAG_*`
+ * constants. Returns NULL if the text is not UTF-8 or the mode or a flag is unknown.
+ *
+ * # Safety
+ *
+ * `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+ * The result has to be freed with [mp_string_free].
+ */
+char *mp_parse(const char *text, size_t len, uint32_t mode, uint32_t flags);
+
+/**
+ * Frees a string that was returned by one of the `mp_*` functions, does nothing for NULL
+ *
+ * # Safety
+ *
+ * `string` has to be NULL or a string returned by this library that was not freed before.
+ */
+void mp_string_free(char *string);
+
+/**
+ * Returns the emoji at the start of the text, NULL if the text does not start with an emoji
+ *
+ * # Safety
+ *
+ * `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+ * The result has to be freed with [mp_string_free].
+ */
+char *mp_get_first_emoji(const char *text, size_t len);
+
+/**
+ * Returns the number of emoji if the text contains only emoji, otherwise -1
+ *
+ * # Safety
+ *
+ * `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+ */
+int64_t mp_count_emojis_if_only_contains_emoji(const char *text, size_t len);
+
+/**
+ * Encodes a host to punycode
+ *
+ * # Safety
+ *
+ * `host` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+ * The result has to be freed with [mp_string_free].
+ */
+char *mp_punycode_encode_host(const char *host, size_t len);
+
+/**
+ * Decodes a punycode encoded host to unicode
+ *
+ * # Safety
+ *
+ * `host` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+ * The result has to be freed with [mp_string_free].
+ */
+char *mp_punycode_decode_host(const char *host, size_t len);
+
+/**
+ * Returns true if the host contains characters that need to be encoded with punycode,
+ * false also if the host is not UTF-8
+ *
+ * # Safety
+ *
+ * `host` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+ */
+bool mp_is_puny(const char *host, size_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* MESSAGE_PARSER_H */
@@ -0,0 +1,176 @@
+//! C bindings of the message parser, for native clients.
+//!
+//! The header `include/message_parser.h` is generated from this file with
+//! `cargo xtask ffi-header`.
+//!
+//! All functions take the text as a pointer to UTF-8 and its length in bytes, it does not need
+//! to be NUL-terminated. The strings they return are NUL-terminated and have to be freed with
+//! [mp_string_free]. Functions that return a string return NULL if the text is not valid UTF-8
+//! or the arguments are invalid.
+
+use std::{
+    ffi::CString,
+    os::raw::c_char,
+    panic::{catch_unwind, UnwindSafe},
+    ptr, slice,
+};
+
+use deltachat_message_parser::parser::{
+    is_emoji, is_puny, punycode_decode_host, punycode_encode_host, versioned::VersionedAst,
+    ParseMode, ParserOptions,
+};
+
+/// Parse markdown, like `parse_markdown_text`
+pub const MP_MODE_MARKDOWN: u32 = 0;
+/// Parse only text elements, like `parse_only_text`
+pub const MP_MODE_TEXT: u32 = 1;
+/// Parse text elements and labeled links, like `parse_desktop_set`
+pub const MP_MODE_DESKTOP_SET: u32 = 2;
+
+/// Emit `Emoji` elements for all emoji
+pub const MP_FLAG_EMOJI_ELEMENTS: u32 = 1;
+/// Parse the rest of the line after a bot command as its arguments
+pub const MP_FLAG_BOT_COMMAND_ARGS: u32 = 1 << 1;
+/// Return `{"schema_version": 1, "elements": [...]}` instead of only the elements
+pub const MP_FLAG_VERSIONED: u32 = 1 << 2;
+
+const ALL_FLAGS: u32 = MP_FLAG_EMOJI_ELEMENTS | MP_FLAG_BOT_COMMAND_ARGS | MP_FLAG_VERSIONED;
+
+/// The text of a `text` and `len` argument, `None` if it is not UTF-8
+///
+/// # Safety
+///
+/// `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+unsafe fn input<'a>(text: *const c_char, len: usize) -> Option<&'a str> {
+    if text.is_null() {
+        return if len == 0 { Some("") } else { None };
+    }
+    std::str::from_utf8(slice::from_raw_parts(text.cast::<u8>(), len)).ok()
+}
+
+/// Converts the string to a C string for returning it, NULL for `None`, for strings that contain
+/// a NUL byte and if `string` panics, because panics must not unwind into C
+fn c_string(string: impl FnOnce() -> Option<String> + UnwindSafe) -> *mut c_char {
+    match catch_unwind(string).ok().flatten().map(CString::new) {
+        Some(Ok(string)) => string.into_raw(),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Parses the text to the JSON of the elements
+///
+/// `mode` is one of the `MP_MODE_*` constants and `flags` a combination of the `MP_FLAG_*`
+/// constants. Returns NULL if the text is not UTF-8 or the mode or a flag is unknown.
+///
+/// # Safety
+///
+/// `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+/// The result has to be freed with [mp_string_free].
+#[no_mangle]
+pub unsafe extern "C" fn mp_parse(
+    text: *const c_char,
+    len: usize,
+    mode: u32,
+    flags: u32,
+) -> *mut c_char {
+    let text = input(text, len);
+    c_string(move || {
+        let mode = match mode {
+            MP_MODE_MARKDOWN => ParseMode::Markdown,
+            MP_MODE_TEXT => ParseMode::Text,
+            MP_MODE_DESKTOP_SET => ParseMode::DesktopSet,
+            _ => return None,
+        };
+        if flags & !ALL_FLAGS != 0 {
+            return None;
+        }
+        let options = ParserOptions {
+            emoji_elements: flags & MP_FLAG_EMOJI_ELEMENTS != 0,
+            bot_command_args: flags & MP_FLAG_BOT_COMMAND_ARGS != 0,
+            ..Default::default()
+        };
+        let (elements, _) = mode.parse(text?, &options);
+        if flags & MP_FLAG_VERSIONED != 0 {
+            serde_json::to_string(&VersionedAst::new(elements)).ok()
+        } else {
+            serde_json::to_string(&elements).ok()
+        }
+    })
+}
+
+/// Frees a string that was returned by one of the `mp_*` functions, does nothing for NULL
+///
+/// # Safety
+///
+/// `string` has to be NULL or a string returned by this library that was not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn mp_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Returns the emoji at the start of the text, NULL if the text does not start with an emoji
+///
+/// # Safety
+///
+/// `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+/// The result has to be freed with [mp_string_free].
+#[no_mangle]
+pub unsafe extern "C" fn mp_get_first_emoji(text: *const c_char, len: usize) -> *mut c_char {
+    let text = input(text, len);
+    c_string(move || is_emoji::get_first_emoji(text?).map(str::to_owned))
+}
+
+/// Returns the number of emoji if the text contains only emoji, otherwise -1
+///
+/// # Safety
+///
+/// `text` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn mp_count_emojis_if_only_contains_emoji(
+    text: *const c_char,
+    len: usize,
+) -> i64 {
+    let text = input(text, len);
+    catch_unwind(move || is_emoji::count_emojis_if_only_contains_emoji(text?))
+        .ok()
+        .flatten()
+        .map_or(-1, i64::from)
+}
+
+/// Encodes a host to punycode
+///
+/// # Safety
+///
+/// `host` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+/// The result has to be freed with [mp_string_free].
+#[no_mangle]
+pub unsafe extern "C" fn mp_punycode_encode_host(host: *const c_char, len: usize) -> *mut c_char {
+    let host = input(host, len);
+    c_string(move || Some(punycode_encode_host(host?)))
+}
+
+/// Decodes a punycode encoded host to unicode
+///
+/// # Safety
+///
+/// `host` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+/// The result has to be freed with [mp_string_free].
+#[no_mangle]
+pub unsafe extern "C" fn mp_punycode_decode_host(host: *const c_char, len: usize) -> *mut c_char {
+    let host = input(host, len);
+    c_string(move || Some(punycode_decode_host(host?)))
+}
+
+/// Returns true if the host contains characters that need to be encoded with punycode,
+/// false also if the host is not UTF-8
+///
+/// # Safety
+///
+/// `host` has to point to `len` readable bytes, it can only be NULL if `len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn mp_is_puny(host: *const c_char, len: usize) -> bool {
+    let host = input(host, len);
+    catch_unwind(move || host.map_or(false, is_puny)).unwrap_or(false)
+}
@@ -0,0 +1,85 @@
+/* Uses the C API like a native client, built and run by tests/c_api.rs */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "message_parser.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                       \
+  do {                                                                         \
+    if (!(condition)) {                                                        \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,         \
+              #condition);                                                     \
+      failures++;                                                              \
+    }                                                                          \
+  } while (0)
+
+/* checks that the result is `expected` and frees it */
+static void check_string(const char *name, char *result, const char *expected) {
+  if (result == NULL || expected == NULL) {
+    if (result != expected) {
+      fprintf(stderr, "%s: expected %s, got %s\n", name,
+              expected ? expected : "NULL", result ? result : "NULL");
+      failures++;
+    }
+  } else if (strcmp(result, expected) != 0) {
+    fprintf(stderr, "%s: expected %s, got %s\n", name, expected, result);
+    failures++;
+  }
+  mp_string_free(result);
+}
+
+static char *parse(const char *text, uint32_t mode, uint32_t flags) {
+  return mp_parse(text, strlen(text), mode, flags);
+}
+
+int main(void) {
+  check_string("markdown", parse("**hi** #tag", MP_MODE_MARKDOWN, 0),
+               "[{\"t\":\"Bold\",\"c\":[{\"t\":\"Text\",\"c\":\"hi\"}]},"
+               "{\"t\":\"Text\",\"c\":\" \"},{\"t\":\"Tag\",\"c\":\"#tag\"}]");
+  check_string("text", parse("**hi**", MP_MODE_TEXT, 0),
+               "[{\"t\":\"Text\",\"c\":\"**hi**\"}]");
+  check_string("desktop set", parse("[a](https://delta.chat)", MP_MODE_DESKTOP_SET, 0),
+               "[{\"t\":\"LabeledLink\",\"c\":{\"label\":[{\"t\":\"Text\",\"c\":\"a\"}],"
+               "\"destination\":{\"target\":\"https://delta.chat\",\"hostname\":\"delta.chat\","
+               "\"punycode\":null,\"scheme\":\"https\",\"payment_request\":null,"
+               "\"magnet\":null}}}]");
+  check_string("emoji elements", parse("hi 👋", MP_MODE_TEXT, MP_FLAG_EMOJI_ELEMENTS),
+               "[{\"t\":\"Text\",\"c\":\"hi \"},{\"t\":\"Emoji\",\"c\":\"👋\"}]");
+  check_string("versioned", parse("", MP_MODE_MARKDOWN, MP_FLAG_VERSIONED),
+               "{\"schema_version\":1,\"elements\":[]}");
+  /* the text does not need to be NUL-terminated */
+  check_string("length", mp_parse("#tag and more", 4, MP_MODE_TEXT, 0),
+               "[{\"t\":\"Tag\",\"c\":\"#tag\"}]");
+  check_string("empty", mp_parse(NULL, 0, MP_MODE_TEXT, 0), "[]");
+
+  check_string("invalid UTF-8", parse("\xff", MP_MODE_TEXT, 0), NULL);
+  check_string("NULL text", mp_parse(NULL, 1, MP_MODE_TEXT, 0), NULL);
+  check_string("unknown mode", parse("hi", 3, 0), NULL);
+  check_string("unknown flag", parse("hi", MP_MODE_TEXT, 1 << 3), NULL);
+
+  check_string("first emoji", mp_get_first_emoji("👋🏽 hi", strlen("👋🏽 hi")), "👋🏽");
+  check_string("no first emoji", mp_get_first_emoji("hi", 2), NULL);
+  CHECK(mp_count_emojis_if_only_contains_emoji("👋🏽😀", strlen("👋🏽😀")) == 2);
+  CHECK(mp_count_emojis_if_only_contains_emoji("hi 😀", strlen("hi 😀")) == -1);
+  CHECK(mp_count_emojis_if_only_contains_emoji("\xff", 1) == -1);
+
+  check_string("punycode encode",
+               mp_punycode_encode_host("münchen.de", strlen("münchen.de")),
+               "xn--mnchen-3ya.de");
+  check_string("punycode decode",
+               mp_punycode_decode_host("xn--mnchen-3ya.de", strlen("xn--mnchen-3ya.de")),
+               "münchen.de");
+  CHECK(mp_is_puny("münchen.de", strlen("münchen.de")));
+  CHECK(!mp_is_puny("delta.chat", strlen("delta.chat")));
+
+  mp_string_free(NULL);
+
+  if (failures > 0) {
+    fprintf(stderr, "%d checks failed\n", failures);
+    return 1;
+  }
+  return 0;
+}
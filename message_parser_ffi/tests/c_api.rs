@@ -0,0 +1,43 @@
+//! Builds `tests/c/test.c` against the shared library and the generated header and runs it
+
+#![cfg(unix)]
+
+use std::{env, path::Path, process::Command};
+
+#[test]
+fn c_test_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // cargo builds the library for the tests next to the test binary in `target/<profile>/deps/`
+    let exe = env::current_exe().unwrap();
+    let library_dir = exe.parent().unwrap();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("message_parser_c_test");
+
+    let compiler = cc::Build::new()
+        .target(env!("TARGET"))
+        .host(env!("TARGET"))
+        .opt_level(0)
+        .cargo_metadata(false)
+        .warnings_into_errors(true)
+        .get_compiler();
+    let status = compiler
+        .to_command()
+        .arg(manifest_dir.join("tests/c/test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(library_dir)
+        .arg("-lmessage_parser_ffi")
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "compiling test.c failed");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "test.c failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,36 @@
+# Types of the module written in rust (src/lib.rs), maturin includes them in the package.
+# The elements are dicts in the shape of their JSON, see schema/ast.v1.schema.json.
+
+from typing import Any, Dict, List, Optional
+
+Element = Dict[str, Any]
+
+def parse_markdown_text(
+    text: str, *, emoji_elements: bool = False, bot_command_args: bool = False
+) -> List[Element]:
+    """Parses text with markdown to a list of elements"""
+
+def parse_only_text(
+    text: str, *, emoji_elements: bool = False, bot_command_args: bool = False
+) -> List[Element]:
+    """Parses text without markdown to a list of elements"""
+
+def parse_desktop_set(
+    text: str, *, emoji_elements: bool = False, bot_command_args: bool = False
+) -> List[Element]:
+    """Parses text to a list of text elements and labeled links, like the desktop client"""
+
+def get_first_emoji(text: str) -> Optional[str]:
+    """Returns the emoji at the start of the text, None if the text does not start with an emoji"""
+
+def count_emojis_if_only_contains_emoji(text: str) -> Optional[int]:
+    """Returns the number of emoji if the text contains only emoji, otherwise None"""
+
+def punycode_encode_host(host: str) -> str:
+    """Encodes a host to punycode"""
+
+def punycode_decode_host(host: str) -> str:
+    """Decodes a punycode encoded host to unicode"""
+
+def is_puny(host: str) -> bool:
+    """Returns True if the host contains characters that need to be encoded with punycode"""
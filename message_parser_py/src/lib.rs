@@ -0,0 +1,249 @@
+//! Python bindings of the message parser, for bots.
+//!
+//! The parse functions return the elements as lists and dicts in the shape of their JSON (see
+//! `schema/ast.v1.schema.json`), like `json.loads` of the JSON would. The package is built with
+//! maturin, see `pyproject.toml`.
+
+use deltachat_message_parser::parser::{self, is_emoji, Element, ParseMode, ParserOptions};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use serde_json::Value;
+
+/// Converts JSON to the Python objects that `json.loads` returns for it
+fn to_python(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_py(py),
+        Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(number), _) => number.into_py(py),
+            (None, Some(number)) => number.into_py(py),
+            (None, None) => number.as_f64().unwrap_or(f64::NAN).into_py(py),
+        },
+        Value::String(string) => string.into_py(py),
+        Value::Array(values) => PyList::new_bound(
+            py,
+            values
+                .iter()
+                .map(|value| to_python(py, value))
+                .collect::<PyResult<Vec<_>>>()?,
+        )
+        .into_py(py),
+        Value::Object(fields) => {
+            let dict = PyDict::new_bound(py);
+            for (key, value) in fields {
+                dict.set_item(key, to_python(py, value)?)?;
+            }
+            dict.into_py(py)
+        }
+    })
+}
+
+/// The elements as a list of dicts in the shape of their JSON
+fn elements_to_python(py: Python<'_>, elements: &[Element]) -> PyResult<PyObject> {
+    let json =
+        serde_json::to_value(elements).map_err(|error| PyValueError::new_err(error.to_string()))?;
+    to_python(py, &json)
+}
+
+fn parse(
+    py: Python<'_>,
+    mode: ParseMode,
+    text: &str,
+    emoji_elements: bool,
+    bot_command_args: bool,
+) -> PyResult<PyObject> {
+    let options = ParserOptions {
+        emoji_elements,
+        bot_command_args,
+        ..Default::default()
+    };
+    let (elements, _) = mode.parse(text, &options);
+    elements_to_python(py, &elements)
+}
+
+/// Parses text with markdown to a list of elements
+#[pyfunction]
+#[pyo3(signature = (text, *, emoji_elements = false, bot_command_args = false))]
+fn parse_markdown_text(
+    py: Python<'_>,
+    text: &str,
+    emoji_elements: bool,
+    bot_command_args: bool,
+) -> PyResult<PyObject> {
+    parse(
+        py,
+        ParseMode::Markdown,
+        text,
+        emoji_elements,
+        bot_command_args,
+    )
+}
+
+/// Parses text without markdown to a list of elements
+#[pyfunction]
+#[pyo3(signature = (text, *, emoji_elements = false, bot_command_args = false))]
+fn parse_only_text(
+    py: Python<'_>,
+    text: &str,
+    emoji_elements: bool,
+    bot_command_args: bool,
+) -> PyResult<PyObject> {
+    parse(py, ParseMode::Text, text, emoji_elements, bot_command_args)
+}
+
+/// Parses text to a list of text elements and labeled links, like the desktop client
+#[pyfunction]
+#[pyo3(signature = (text, *, emoji_elements = false, bot_command_args = false))]
+fn parse_desktop_set(
+    py: Python<'_>,
+    text: &str,
+    emoji_elements: bool,
+    bot_command_args: bool,
+) -> PyResult<PyObject> {
+    parse(
+        py,
+        ParseMode::DesktopSet,
+        text,
+        emoji_elements,
+        bot_command_args,
+    )
+}
+
+/// Returns the emoji at the start of the text, None if the text does not start with an emoji
+#[pyfunction]
+fn get_first_emoji(text: &str) -> Option<&str> {
+    is_emoji::get_first_emoji(text)
+}
+
+/// Returns the number of emoji if the text contains only emoji, otherwise None
+#[pyfunction]
+fn count_emojis_if_only_contains_emoji(text: &str) -> Option<u32> {
+    is_emoji::count_emojis_if_only_contains_emoji(text)
+}
+
+/// Encodes a host to punycode
+#[pyfunction]
+fn punycode_encode_host(host: &str) -> String {
+    parser::punycode_encode_host(host)
+}
+
+/// Decodes a punycode encoded host to unicode
+#[pyfunction]
+fn punycode_decode_host(host: &str) -> String {
+    parser::punycode_decode_host(host)
+}
+
+/// Returns True if the host contains characters that need to be encoded with punycode
+#[pyfunction]
+fn is_puny(host: &str) -> bool {
+    parser::is_puny(host)
+}
+
+/// Parsing of links, email addresses, hashtags, bot commands, simple markdown and more in
+/// Delta Chat messages
+#[pymodule]
+#[pyo3(name = "deltachat_message_parser")]
+fn message_parser(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse_markdown_text, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_only_text, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_desktop_set, m)?)?;
+    m.add_function(wrap_pyfunction!(get_first_emoji, m)?)?;
+    m.add_function(wrap_pyfunction!(count_emojis_if_only_contains_emoji, m)?)?;
+    m.add_function(wrap_pyfunction!(punycode_encode_host, m)?)?;
+    m.add_function(wrap_pyfunction!(punycode_decode_host, m)?)?;
+    m.add_function(wrap_pyfunction!(is_puny, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::elements_to_python;
+    use deltachat_message_parser::parser::{parse_markdown_text_with_options, ParserOptions};
+    use pyo3::{prelude::*, types::PyDict, wrap_pymodule};
+
+    /// Runs python code with the module as `mp`
+    fn run(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals
+                .set_item("mp", wrap_pymodule!(super::message_parser)(py))
+                .unwrap();
+            if let Err(error) = py.run_bound(code, None, Some(&locals)) {
+                panic!("{}", error);
+            }
+        });
+    }
+
+    #[test]
+    fn elements_convert_like_json_loads() {
+        let input = "**bold _italics_** [label](https://münchen.de) #tag /help@bot.example args
+```rust
+code
+``` bitcoin:1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2?amount=0.01
+magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&xl=10826029&dn=name 👋🏽 ~~x~~ `inline`
+hello@delta.chat";
+        let elements = parse_markdown_text_with_options(
+            input,
+            &ParserOptions {
+                emoji_elements: true,
+                bot_command_args: true,
+                ..Default::default()
+            },
+        );
+        let json = serde_json::to_string(&elements).unwrap();
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let converted = elements_to_python(py, &elements).unwrap();
+            let locals = PyDict::new_bound(py);
+            locals.set_item("converted", converted).unwrap();
+            locals.set_item("json_text", json).unwrap();
+            let code = "
+import json
+expected = json.loads(json_text)
+assert converted == expected, (converted, expected)
+# the keys are also in the order of the JSON
+assert json.dumps(converted, ensure_ascii=False, separators=(',', ':')) == json_text
+";
+            if let Err(error) = py.run_bound(code, None, Some(&locals)) {
+                panic!("{}", error);
+            }
+        });
+    }
+
+    #[test]
+    fn parse_functions() {
+        run(r##"
+assert mp.parse_markdown_text("**hi**") == [{"t": "Bold", "c": [{"t": "Text", "c": "hi"}]}]
+assert mp.parse_only_text("**hi** #tag") == [
+    {"t": "Text", "c": "**hi** "},
+    {"t": "Tag", "c": "#tag"},
+]
+[link] = mp.parse_desktop_set("[a](https://delta.chat)")
+assert link["t"] == "LabeledLink"
+assert link["c"]["destination"]["hostname"] == "delta.chat"
+assert link["c"]["destination"]["punycode"] is None
+assert mp.parse_only_text("hi 👋", emoji_elements=True)[1] == {"t": "Emoji", "c": "👋"}
+[command] = mp.parse_only_text("/echo hello", bot_command_args=True)
+assert command["c"]["args"] == "hello"
+assert mp.parse_only_text("/echo hello")[0]["c"]["args"] is None
+"##);
+    }
+
+    #[test]
+    fn helpers() {
+        run(r##"
+assert mp.get_first_emoji("👋🏽 hi") == "👋🏽"
+assert mp.get_first_emoji("hi") is None
+assert mp.count_emojis_if_only_contains_emoji("👋🏽😀") == 2
+assert mp.count_emojis_if_only_contains_emoji("hi 😀") is None
+assert mp.punycode_encode_host("münchen.de") == "xn--mnchen-3ya.de"
+assert mp.punycode_decode_host("xn--mnchen-3ya.de") == "münchen.de"
+assert mp.is_puny("münchen.de")
+assert not mp.is_puny("delta.chat")
+"##);
+    }
+}
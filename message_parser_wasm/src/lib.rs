@@ -1,5 +1,11 @@
+mod typings;
 mod utils;
 
+use deltachat_message_parser::parser::{
+    is_emoji::SegmentKind, source::ElementSource, Element, ElementKind, LimitsReport, ParseMode,
+    ParserOptions,
+};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -30,35 +36,175 @@ pub fn parse_desktop_set(s: &str) -> JsValue {
         .expect("Element converts to JsValue")
 }
 
+// the types of the AST are generated in `typings.rs`, the types of this crate are written here
+/// The options of [parse], `ParseOptions` in TypeScript
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct ParseOptions {
+    mode: ParseMode,
+    elements: Option<Vec<ElementKind>>,
+    emoji: bool,
+    bot_command_args: bool,
+    known_bot_commands: Option<Vec<String>>,
+    allowed_schemes: Option<Vec<String>>,
+    max_nesting_depth: Option<usize>,
+    max_elements: Option<usize>,
+    max_markdown_bytes: Option<usize>,
+    spans: bool,
+}
+
+impl ParseOptions {
+    fn parser_options(self) -> ParserOptions {
+        ParserOptions {
+            emoji_elements: self.emoji,
+            bot_command_args: self.bot_command_args,
+            known_bot_commands: self.known_bot_commands,
+            max_nesting_depth: self.max_nesting_depth,
+            max_elements: self.max_elements,
+            max_markdown_bytes: self.max_markdown_bytes,
+            enabled_elements: self.elements,
+            allowed_schemes: self.allowed_schemes,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ParseResult<'a> {
+    elements: Vec<Element<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spans: Option<Vec<ElementSpan>>,
+    limits: LimitsReport,
+}
+
+#[derive(Serialize)]
+struct ElementSpan {
+    start: usize,
+    end: usize,
+    children: Vec<ElementSpan>,
+}
+
+/// Converts byte offsets of the input to UTF-16 offsets, the offsets should be asked for in
+/// increasing order, otherwise it starts again from the beginning of the input
+struct Utf16Offsets<'a> {
+    input: &'a str,
+    byte: usize,
+    utf16: usize,
+}
+
+impl Utf16Offsets<'_> {
+    fn get(&mut self, byte: usize) -> usize {
+        if byte < self.byte {
+            self.byte = 0;
+            self.utf16 = 0;
+        }
+        self.utf16 += self
+            .input
+            .get(self.byte..byte)
+            .unwrap_or_default()
+            .encode_utf16()
+            .count();
+        self.byte = byte;
+        self.utf16
+    }
+
+    fn spans(&mut self, sources: &[ElementSource]) -> Vec<ElementSpan> {
+        sources
+            .iter()
+            .map(|source| {
+                let start = self.get(source.range.start);
+                let children = self.spans(&source.children);
+                ElementSpan {
+                    start,
+                    end: self.get(source.range.end),
+                    children,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parses text to json AST with options (`ParseOptions`), returns a `ParseResult`
+///
+/// Throws if the options are invalid.
+#[wasm_bindgen]
+pub fn parse(s: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: ParseOptions = if options.is_undefined() || options.is_null() {
+        ParseOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+    let mode = options.mode;
+    let spans = options.spans;
+    let parser_options = options.parser_options();
+    let result = if spans {
+        let (elements, sources, limits) = mode.parse_with_sources(s, &parser_options);
+        let mut offsets = Utf16Offsets {
+            input: s,
+            byte: 0,
+            utf16: 0,
+        };
+        ParseResult {
+            elements,
+            spans: Some(offsets.spans(&sources)),
+            limits,
+        }
+    } else {
+        let (elements, limits) = mode.parse(s, &parser_options);
+        ParseResult {
+            elements,
+            spans: None,
+            limits,
+        }
+    };
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+}
+
 #[wasm_bindgen(typescript_custom_section)]
-const TS_APPEND_CONTENT: &'static str = r#"
-export type PunycodeWarning = {
-  original_hostname: string;
-  ascii_hostname: string;
-  punycode_encoded_url: string;
+const TS_WASM_TYPES: &'static str = r#"
+export type ParseOptions = {
+  /** which elements are parsed, like `parse_text` with and without markdown or `parse_desktop_set`, default "Markdown" */
+  mode?: ParseMode;
+  /** the kinds of elements that are parsed, all if not set, the others are kept as text */
+  elements?: ElementKind[];
+  /** emit `Emoji` elements for all emoji */
+  emoji?: boolean;
+  /** parse the rest of the line after a bot command as its arguments */
+  bot_command_args?: boolean;
+  /** only parse these bot commands, with or without `/` */
+  known_bot_commands?: string[];
+  /** the schemes of the links that are parsed, like "https" or "mailto", all if not set */
+  allowed_schemes?: string[];
+  /** how deep markdown elements can be nested, deeper markup is kept as text */
+  max_nesting_depth?: number;
+  /** maximum number of elements, the rest of the text is kept as one text element */
+  max_elements?: number;
+  /** longer texts (in UTF-8 bytes) are parsed without markdown */
+  max_markdown_bytes?: number;
+  /** also return the span of every element */
+  spans?: boolean;
 };
-export type LinkDestination = {
-  target: string;
-  hostname: null | string;
-  punycode: null | PunycodeWarning;
-  scheme: null | string;
+export type ElementSpan = {
+  /** UTF-16 offset, like the indices of JavaScript strings */
+  start: number;
+  /** UTF-16 offset, exclusive */
+  end: number;
+  /** the spans of the children of `Bold`, `Italics`, `StrikeThrough` and of the label of `LabeledLink` */
+  children: ElementSpan[];
+};
+export type ParseResult = {
+  elements: ParsedElement[];
+  /** the spans of the elements in the same order, including their delimiters like the `**` of bold text, only with the `spans` option */
+  spans?: ElementSpan[];
+  /** which limits of the options were hit */
+  limits: LimitsReport;
+};
+export type EmojiSegment = {
+  /** UTF-16 offset, like the indices of JavaScript strings */
+  start: number;
+  /** UTF-16 offset, exclusive */
+  end: number;
+  kind: SegmentKind;
 };
-export type ParsedElement =
-  | { t: "Text"; c: string }
-  | { t: "Tag"; c: string }
-  | { t: "Linebreak" }
-  | { t: "Bold"; c: ParsedElement[] }
-  | { t: "Italics"; c: ParsedElement[] }
-  | { t: "StrikeThrough"; c: ParsedElement[] }
-  | { t: "InlineCode"; c: { content: string } }
-  | { t: "CodeBlock"; c: { language: null | string; content: string } }
-  | { t: "EmailAddress"; c: string }
-  | { t: "BotCommandSuggestion"; c: string }
-  | { t: "Link"; c: { destination: LinkDestination } }
-  | {
-      t: "LabeledLink";
-      c: { label: ParsedElement[]; destination: LinkDestination };
-    };
 "#;
 
 /// returns first emoji from text if text begins with an emoji
@@ -73,6 +219,50 @@ pub fn count_emojis_if_only_contains_emoji(input: &str) -> Option<u32> {
     deltachat_message_parser::parser::is_emoji::count_emojis_if_only_contains_emoji(input)
 }
 
+#[derive(Serialize)]
+struct EmojiSegment {
+    start: usize,
+    end: usize,
+    kind: SegmentKind,
+}
+
+/// Splits text into emoji and text segments (`EmojiSegment[]`),
+/// the offsets are in UTF-16 code units like the indices of JavaScript strings
+#[wasm_bindgen]
+pub fn emoji_segments(input: &str) -> JsValue {
+    let mut offset: usize = 0;
+    let segments: Vec<EmojiSegment> =
+        deltachat_message_parser::parser::is_emoji::emoji_segments(input)
+            .map(|(range, kind)| {
+                let start = offset;
+                offset += input.get(range).unwrap_or_default().encode_utf16().count();
+                EmojiSegment {
+                    start,
+                    end: offset,
+                    kind,
+                }
+            })
+            .collect();
+    serde_wasm_bindgen::to_value(&segments).expect("EmojiSegment converts to JsValue")
+}
+
+/// Classifies text for showing it with big emoji (`JumboEmojiClassification`), `jumbo` is set
+/// if the text contains only emoji and whitespace and not more than `max_emojis` emoji
+#[wasm_bindgen]
+pub fn classify_jumbo_emoji(input: &str, max_emojis: u32) -> JsValue {
+    serde_wasm_bindgen::to_value(
+        &deltachat_message_parser::parser::is_emoji::classify_jumbo_emoji(input, max_emojis),
+    )
+    .expect("JumboEmojiClassification converts to JsValue")
+}
+
+/// Returns the normalized form of a hashtag (without `#`, NFKC, case folded, without emoji presentation selectors),
+/// that can be used for comparing, searching and indexing
+#[wasm_bindgen]
+pub fn normalize_hashtag(tag: &str) -> String {
+    deltachat_message_parser::parser::hashtag::normalize_hashtag(tag)
+}
+
 /// encode a host to punycode encoded string
 #[wasm_bindgen]
 pub fn punycode_encode_host(host: &str) -> String {
@@ -90,3 +280,29 @@ pub fn punycode_decode_host(host: &str) -> String {
 pub fn is_puny(host: &str) -> bool {
     deltachat_message_parser::parser::is_puny(host)
 }
+
+#[cfg(test)]
+mod test {
+    use super::Utf16Offsets;
+    use deltachat_message_parser::parser::{ParseMode, ParserOptions};
+
+    #[test]
+    fn spans_use_utf16_offsets() {
+        let input = "👋🏽 **a [😀](https://delta.chat)**";
+        let (_, sources, _) =
+            ParseMode::Markdown.parse_with_sources(input, &ParserOptions::default());
+        let mut offsets = Utf16Offsets {
+            input,
+            byte: 0,
+            utf16: 0,
+        };
+        let spans = offsets.spans(&sources);
+        let ranges: Vec<_> = spans.iter().map(|span| span.start..span.end).collect();
+        assert_eq!(ranges, vec![0..5, 5..35]);
+        let bold = &spans[1].children;
+        assert_eq!(bold[1].start..bold[1].end, 9..33);
+        assert_eq!(bold[1].children[0].start..bold[1].children[0].end, 10..12);
+        // asking for an earlier offset starts again
+        assert_eq!(offsets.get(4), 2);
+    }
+}
@@ -0,0 +1,128 @@
+// DO NOT EDIT, generated by `cargo xtask typescript` from the Rust types.
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_APPEND_CONTENT: &'static str = r#"
+export type VersionedAst = {
+  /** always [AST_SCHEMA_VERSION], deserializing fails for other versions */
+  schema_version: 1;
+  elements: ParsedElement[];
+};
+export type ParsedElement =
+  | { t: "Text"; c: string }
+  | { t: "Tag"; c: string }
+  | { t: "Linebreak" }
+  | { t: "Link"; c: { destination: LinkDestination } }
+  | { t: "EmailAddress"; c: string }
+  | { t: "BotCommandSuggestion"; c: { content: string; command: string; target: null | string; args: null | string } }
+  | { t: "Emoji"; c: string }
+  | { t: "Bold"; c: ParsedElement[] }
+  | { t: "Italics"; c: ParsedElement[] }
+  | { t: "StrikeThrough"; c: ParsedElement[] }
+  | { t: "LabeledLink"; c: { label: ParsedElement[]; destination: LinkDestination } }
+  | { t: "InlineCode"; c: { content: string } }
+  | { t: "CodeBlock"; c: { language: null | string; content: string } };
+export type LinkDestination = {
+  target: string;
+  /** hostname if it was found */
+  hostname: null | string;
+  /** contains data for the punycode warning if punycode was detected (the host part contains non ascii unicode characters) */
+  punycode: null | PunycodeWarning;
+  /** scheme */
+  scheme: null | string;
+  /** contains the parsed payment data for `bitcoin:`, `bitcoincash:`, `ethereum:` and `eth:` links */
+  payment_request: null | PaymentRequest;
+  /** contains the parsed hashes, name, size and trackers for `magnet:` links */
+  magnet: null | MagnetLink;
+};
+export type PunycodeWarning = {
+  original_hostname: string;
+  ascii_hostname: string;
+  punycode_encoded_url: string;
+};
+export type PaymentRequest = {
+  currency: PaymentCurrency;
+  /** address of the receiver, for EIP-681 this can also be an ENS name */
+  address: string;
+  /** result of the checksum validation of [PaymentRequest::address] */
+  address_checksum: AddressChecksum;
+  /**
+   * amount as written in the link, without any conversion
+   *
+   * - BIP-21: decimal amount in the main unit (BTC or BCH) - EIP-681: the `value` parameter in wei, may use scientific notation like `2.014e18`
+   */
+  amount: null | string;
+  /** BIP-21 `label`, percent decoded */
+  label: null | string;
+  /** BIP-21 `message`, percent decoded */
+  message: null | string;
+  /** EIP-681 chain id (`@1` is ethereum mainnet) */
+  chain_id: null | number;
+  /** EIP-681 contract function that should be called, for example `transfer` */
+  function_name: null | string;
+  /** all other parameters in the order they appear in the link, percent decoded */
+  parameters: [string, string][];
+};
+export type PaymentCurrency = "Bitcoin" | "BitcoinCash" | "Ethereum";
+export type AddressChecksum =
+  | "Valid"
+  | "Invalid"
+  | "NotChecksummed"
+  | "Unknown";
+export type MagnetLink = {
+  /** `xt` - the hashes of the content */
+  exact_topics: ExactTopic[];
+  /** `dn` - file name to display, percent decoded */
+  display_name: null | string;
+  /** `xl` - size of the content in bytes */
+  exact_length: null | number;
+  /** `tr` - tracker urls, percent decoded */
+  trackers: string[];
+  /** `ws` - web seed urls, percent decoded */
+  web_seeds: string[];
+};
+export type ExactTopic =
+  | { t: "BitTorrentInfoHash"; c: string }
+  | { t: "BitTorrentInfoHashV2"; c: string }
+  | { t: "Other"; c: string };
+export type JumboEmojiClassification = {
+  /** the text contains at least one emoji and otherwise only whitespace */
+  emoji_only: boolean;
+  /** number of emoji, an emoji sequence that is not RGI (like `👍🏽‍🔥`) counts as one emoji */
+  emoji_count: number;
+  /** number of extended grapheme clusters of the whole text, including whitespace */
+  grapheme_count: number;
+  /** whether there is an emoji sequence that is not RGI, fonts may show it as multiple emoji */
+  contains_non_rgi_sequence: boolean;
+  /** `emoji_only` and not more than the maximum number of emoji */
+  jumbo: boolean;
+};
+export type SegmentKind =
+  | "Emoji"
+  | "UnknownEmojiSequence"
+  | "Text";
+export type ParseMode =
+  | "Markdown"
+  | "Text"
+  | "DesktopSet";
+export type ElementKind =
+  | "Tag"
+  | "Link"
+  | "EmailAddress"
+  | "BotCommandSuggestion"
+  | "Bold"
+  | "Italics"
+  | "StrikeThrough"
+  | "LabeledLink"
+  | "InlineCode"
+  | "CodeBlock";
+export type LimitsReport = {
+  /** markup nested deeper than [ParserOptions::max_nesting_depth] was kept as text */
+  max_nesting_depth_exceeded: boolean;
+  /** there were more than [ParserOptions::max_elements] elements */
+  max_elements_exceeded: boolean;
+  /** the text was longer than [ParserOptions::max_markdown_bytes] and parsed without markdown */
+  max_markdown_bytes_exceeded: boolean;
+};
+"#;
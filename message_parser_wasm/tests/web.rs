@@ -25,3 +25,56 @@ fn test_parse() {
         JsValue::from_str(r#"[{"t":"Bold","c":[{"t":"InlineCode","c":{"content":"Block"}}]}]"#)
     ); // this test needs somekind of deep equal because the order of the properties is not fixed
 }
+
+#[wasm_bindgen_test]
+fn test_emoji_segments_use_utf16_offsets() {
+    assert_eq!(
+        stringify(&emoji_segments("Hi 👋🏽!")),
+        JsValue::from_str(
+            r#"[{"start":0,"end":3,"kind":"Text"},{"start":3,"end":7,"kind":"Emoji"},{"start":7,"end":8,"kind":"Text"}]"#
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_classify_jumbo_emoji() {
+    assert_eq!(
+        stringify(&classify_jumbo_emoji("👍🏽 ❤️", 3)),
+        JsValue::from_str(
+            r#"{"emoji_only":true,"emoji_count":2,"grapheme_count":3,"contains_non_rgi_sequence":false,"jumbo":true}"#
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_with_options() {
+    let options = serde_wasm_bindgen::to_value(&json!({
+        "mode": "Text",
+        "elements": ["Link"],
+        "allowed_schemes": ["https"],
+        "spans": true,
+    }))
+    .unwrap();
+    assert_eq!(
+        stringify(&parse("👋 #tag https://delta.chat", options).unwrap()),
+        JsValue::from_str(
+            r#"{"elements":[{"t":"Text","c":"👋 "},{"t":"Text","c":"#tag"},{"t":"Text","c":" "},{"t":"Link","c":{"destination":{"target":"https://delta.chat","hostname":"delta.chat","scheme":"https"}}}],"spans":[{"start":0,"end":3,"children":[]},{"start":3,"end":7,"children":[]},{"start":7,"end":8,"children":[]},{"start":8,"end":26,"children":[]}],"limits":{"max_nesting_depth_exceeded":false,"max_elements_exceeded":false,"max_markdown_bytes_exceeded":false}}"#
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_without_options() {
+    assert_eq!(
+        stringify(&parse("**bold**", JsValue::UNDEFINED).unwrap()),
+        JsValue::from_str(
+            r#"{"elements":[{"t":"Bold","c":[{"t":"Text","c":"bold"}]}],"limits":{"max_nesting_depth_exceeded":false,"max_elements_exceeded":false,"max_markdown_bytes_exceeded":false}}"#
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_parse_rejects_unknown_options() {
+    let options = serde_wasm_bindgen::to_value(&json!({ "markdown": true })).unwrap();
+    assert!(parse("text", options).is_err());
+}
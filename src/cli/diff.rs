@@ -0,0 +1,189 @@
+//! The `diff` subcommand, which compares the elements of two parser configurations or of a
+//! baseline for the messages of a corpus
+
+use std::io::{self, Write};
+
+use deltachat_message_parser::parser::{
+    diff::{diff, ElementDiff},
+    OwnedElement, ParseMode, ParserOptions,
+};
+use serde_derive::Serialize;
+
+use super::{jsonl_text, output_error, parse_mode, read, Command};
+
+/// A parse mode with options, written like `text+emoji`
+#[derive(Debug)]
+struct Config {
+    mode: ParseMode,
+    options: ParserOptions,
+}
+
+#[derive(Debug)]
+pub(super) struct DiffArgs {
+    a: Config,
+    b: Config,
+    baseline: Option<String>,
+    files: Vec<String>,
+}
+
+/// One line of the output
+#[derive(Serialize)]
+struct MessageDiff<'a> {
+    file: &'a str,
+    line: usize,
+    text: &'a str,
+    diffs: Vec<ElementDiff>,
+}
+
+fn parse_config(config: Option<&str>) -> Result<Config, String> {
+    let config = config.ok_or("missing configuration")?;
+    let mut parts = config.split('+');
+    let mut parsed = Config {
+        mode: parse_mode(parts.next())?,
+        options: ParserOptions::default(),
+    };
+    for option in parts {
+        match option {
+            "emoji" => parsed.options.emoji_elements = true,
+            "bot-command-args" => parsed.options.bot_command_args = true,
+            other => return Err(format!("unknown option {other:?} in {config:?}")),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Parses the arguments after `diff`
+pub(super) fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut diff_args = DiffArgs {
+        a: parse_config(Some("markdown"))?,
+        b: parse_config(Some("markdown"))?,
+        baseline: None,
+        files: Vec::new(),
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--a" => diff_args.a = parse_config(args.next().map(String::as_str))?,
+            "--b" => diff_args.b = parse_config(args.next().map(String::as_str))?,
+            "--baseline" => {
+                diff_args.baseline = Some(args.next().ok_or("missing baseline file")?.clone())
+            }
+            option if option.starts_with("--") => return Err(format!("unknown option {option}")),
+            file => diff_args.files.push(file.to_owned()),
+        }
+    }
+    if diff_args.files.is_empty() {
+        diff_args.files.push("-".to_owned());
+    }
+    Ok(Command::Diff(diff_args))
+}
+
+/// Prints the messages that differ, returns `false` if a message differs or is invalid
+pub(super) fn run(out: &mut impl Write, args: &DiffArgs) -> Result<bool, String> {
+    let baseline = args.baseline.as_deref().map(read).transpose()?;
+    let mut baseline_lines = baseline.as_deref().map(str::lines);
+    let mut messages: usize = 0;
+    let mut differences: usize = 0;
+    let mut invalid: usize = 0;
+    for file in &args.files {
+        let input = read(file)?;
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index.saturating_add(1);
+            // the baseline has one line for every line of the files, also for empty lines
+            let baseline_line = match &mut baseline_lines {
+                Some(lines) => Some(lines.next().ok_or("the baseline has fewer lines")?),
+                None => None,
+            };
+            let text = match jsonl_text(line) {
+                Ok(text) => text,
+                Err(None) => continue,
+                Err(Some(error)) => {
+                    eprintln!("{file}:{line_number}: {error}");
+                    invalid = invalid.saturating_add(1);
+                    continue;
+                }
+            };
+            messages = messages.saturating_add(1);
+            let (b, _) = args.b.mode.parse(&text, &args.b.options);
+            let diffs = match baseline_line {
+                Some(baseline_line) => {
+                    match serde_json::from_str::<Option<Vec<OwnedElement>>>(baseline_line) {
+                        Ok(Some(a)) => {
+                            let a: Vec<_> = a.iter().map(OwnedElement::as_element).collect();
+                            diff(&a, &b)
+                        }
+                        Ok(None) => {
+                            eprintln!("{file}:{line_number}: the baseline has no elements");
+                            invalid = invalid.saturating_add(1);
+                            continue;
+                        }
+                        Err(error) => {
+                            eprintln!("{file}:{line_number}: invalid baseline: {error}");
+                            invalid = invalid.saturating_add(1);
+                            continue;
+                        }
+                    }
+                }
+                None => diff(&args.a.mode.parse(&text, &args.a.options).0, &b),
+            };
+            if diffs.is_empty() {
+                continue;
+            }
+            differences = differences.saturating_add(1);
+            let message = MessageDiff {
+                file,
+                line: line_number,
+                text: &text,
+                diffs,
+            };
+            serde_json::to_writer(&mut *out, &message)
+                .map_err(io::Error::from)
+                .and_then(|()| writeln!(out))
+                .map_err(output_error)?;
+        }
+    }
+    eprintln!("{differences} of {messages} messages are parsed differently");
+    if invalid > 0 {
+        eprintln!("{invalid} lines are invalid");
+    }
+    Ok(differences == 0 && invalid == 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_args, parse_config};
+    use crate::cli::Command;
+    use deltachat_message_parser::parser::ParseMode;
+
+    #[test]
+    fn configurations() {
+        let config = parse_config(Some("text+emoji+bot-command-args")).unwrap();
+        assert_eq!(config.mode, ParseMode::Text);
+        assert!(config.options.emoji_elements);
+        assert!(config.options.bot_command_args);
+        let config = parse_config(Some("desktop")).unwrap();
+        assert_eq!(config.mode, ParseMode::DesktopSet);
+        assert!(!config.options.emoji_elements);
+        assert!(parse_config(Some("markdown+html")).is_err());
+        assert!(parse_config(Some("")).is_err());
+        assert!(parse_config(None).is_err());
+    }
+
+    #[test]
+    fn arguments() {
+        let args: Vec<String> = ["--a", "desktop", "--baseline", "old.jsonl", "corpus.jsonl"]
+            .iter()
+            .map(|arg| (*arg).to_owned())
+            .collect();
+        let command = parse_args(&args).unwrap();
+        let Command::Diff(diff) = command else {
+            panic!("{:?}", command)
+        };
+        assert_eq!(diff.a.mode, ParseMode::DesktopSet);
+        assert_eq!(diff.b.mode, ParseMode::Markdown);
+        assert_eq!(diff.baseline.as_deref(), Some("old.jsonl"));
+        assert_eq!(diff.files, vec!["corpus.jsonl".to_owned()]);
+        assert!(parse_args(&["--baseline".to_owned()]).is_err());
+    }
+}
@@ -0,0 +1,387 @@
+//! Arguments and commands of the command line tool
+
+mod diff;
+mod render;
+
+use std::{
+    fs,
+    io::{self, BufWriter, Read, Write},
+};
+
+use deltachat_message_parser::parser::{
+    is_emoji::{
+        canonical_base_emoji, classify_jumbo_emoji, count_emojis_if_only_contains_emoji,
+        emoji_qualification, emoji_segments, get_first_emoji, SegmentKind,
+    },
+    is_puny, punycode_decode_host, punycode_encode_host, Element, ParseMode, ParserOptions,
+};
+use serde_json::Value;
+
+const HELP: &str = "\
+usage: deltachat_message_parser [options] [file...]
+       deltachat_message_parser emoji [text...]
+       deltachat_message_parser punycode <host>...
+       deltachat_message_parser diff [--a <config>] [--b <config>] [--baseline <file>] [file...]
+
+Parses each file as one message and prints the elements, reads stdin without files or for `-`.
+
+options:
+  --mode <mode>         markdown (default), text (without markdown) or desktop (text elements
+                        and labeled links, like the desktop client)
+  --format <format>     debug (default), json, html, markdown or tree
+  --jsonl               parse every line of the files as one message, each line is a JSON string
+                        or an object with a \"text\" string, like in message corpora.
+                        With `--format json` the output has one line for every line of the input,
+                        `null` for empty lines and lines that are no message.
+  --emoji               parse emoji to Emoji elements
+  --bot-command-args    parse the rest of the line after a bot command as its arguments
+  -h, --help            show this help
+
+subcommands:
+  emoji       shows the emoji segments, the first emoji and the emoji count of the texts
+              (or of stdin without texts)
+  punycode    shows whether the hosts need punycode and their encoded and decoded form
+  diff        parses the messages of JSONL files (like with --jsonl) with two configurations
+              and prints a JSON line for each message that is parsed differently, with the
+              parts that differ: {\"file\", \"line\", \"text\", \"diffs\": [{\"path\", \"a\", \"b\"}]}.
+              The exit code is 1 if a message differs.
+
+diff options:
+  --a <config>, --b <config>
+                        the configurations to compare, a mode with options like
+                        `text+emoji+bot-command-args`, `markdown` by default
+  --baseline <file>     compare the elements in the file with --b instead of parsing with --a,
+                        the file is the output of `--jsonl --format json` for the same files,
+                        for example of an older version of the parser
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Debug,
+    Json,
+    Html,
+    Markdown,
+    Tree,
+}
+
+#[derive(Debug)]
+struct ParseArgs {
+    mode: ParseMode,
+    format: Format,
+    jsonl: bool,
+    options: ParserOptions,
+    files: Vec<String>,
+}
+
+#[derive(Debug)]
+enum Command {
+    Help,
+    Parse(ParseArgs),
+    Emoji(Vec<String>),
+    Punycode(Vec<String>),
+    Diff(diff::DiffArgs),
+}
+
+fn parse_mode(mode: Option<&str>) -> Result<ParseMode, String> {
+    match mode {
+        Some("markdown") => Ok(ParseMode::Markdown),
+        Some("text") => Ok(ParseMode::Text),
+        Some("desktop") => Ok(ParseMode::DesktopSet),
+        other => Err(format!("unknown mode {other:?}")),
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("emoji") => return Ok(Command::Emoji(args.iter().skip(1).cloned().collect())),
+        Some("punycode") if args.len() > 1 => {
+            return Ok(Command::Punycode(args.iter().skip(1).cloned().collect()))
+        }
+        Some("punycode") => return Err("punycode needs at least one host".to_owned()),
+        Some("diff") => return diff::parse_args(args.get(1..).unwrap_or_default()),
+        _ => {}
+    }
+    let mut parse_args = ParseArgs {
+        mode: ParseMode::Markdown,
+        format: Format::Debug,
+        jsonl: false,
+        options: ParserOptions::default(),
+        files: Vec::new(),
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--mode" => parse_args.mode = parse_mode(args.next().map(String::as_str))?,
+            "--format" => {
+                parse_args.format = match args.next().map(String::as_str) {
+                    Some("debug") => Format::Debug,
+                    Some("json") => Format::Json,
+                    Some("html") => Format::Html,
+                    Some("markdown") => Format::Markdown,
+                    Some("tree") => Format::Tree,
+                    other => return Err(format!("unknown format {other:?}")),
+                }
+            }
+            "--jsonl" => parse_args.jsonl = true,
+            "--emoji" => parse_args.options.emoji_elements = true,
+            "--bot-command-args" => parse_args.options.bot_command_args = true,
+            option if option.starts_with("--") => return Err(format!("unknown option {option}")),
+            file => parse_args.files.push(file.to_owned()),
+        }
+    }
+    if parse_args.files.is_empty() {
+        parse_args.files.push("-".to_owned());
+    }
+    Ok(Command::Parse(parse_args))
+}
+
+/// Runs the command of the arguments, returns `false` if some messages could not be parsed
+pub(crate) fn run(args: &[String]) -> Result<bool, String> {
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let success = match parse_args(args)? {
+        Command::Help => {
+            out.write_all(HELP.as_bytes()).map_err(output_error)?;
+            true
+        }
+        Command::Parse(args) => parse(&mut out, &args)?,
+        Command::Diff(args) => diff::run(&mut out, &args)?,
+        Command::Emoji(texts) if texts.is_empty() => {
+            emoji(&mut out, &read("-")?).map_err(output_error)?;
+            true
+        }
+        Command::Emoji(texts) => {
+            for (index, text) in texts.iter().enumerate() {
+                if index > 0 {
+                    writeln!(out).map_err(output_error)?;
+                }
+                emoji(&mut out, text).map_err(output_error)?;
+            }
+            true
+        }
+        Command::Punycode(hosts) => {
+            for (index, host) in hosts.iter().enumerate() {
+                if index > 0 {
+                    writeln!(out).map_err(output_error)?;
+                }
+                punycode(&mut out, host).map_err(output_error)?;
+            }
+            true
+        }
+    };
+    out.flush().map_err(output_error)?;
+    Ok(success)
+}
+
+fn output_error(error: io::Error) -> String {
+    format!("writing the output: {error}")
+}
+
+fn read(file: &str) -> Result<String, String> {
+    if file == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|error| format!("reading stdin: {error}"))?;
+        Ok(input)
+    } else {
+        fs::read_to_string(file).map_err(|error| format!("reading {file}: {error}"))
+    }
+}
+
+/// Parses and prints the files, returns `false` if some lines of JSONL files are no messages
+fn parse(out: &mut impl Write, args: &ParseArgs) -> Result<bool, String> {
+    let mut success = true;
+    for file in &args.files {
+        let input = read(file)?;
+        if !args.jsonl {
+            if args.files.len() > 1 {
+                writeln!(out, "==> {file} <==").map_err(output_error)?;
+            }
+            let (elements, _) = args.mode.parse(&input, &args.options);
+            print_elements(out, args.format, &elements, false).map_err(output_error)?;
+            continue;
+        }
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index.saturating_add(1);
+            let text = match jsonl_text(line) {
+                Ok(text) => text,
+                Err(error) => {
+                    if let Some(error) = error {
+                        eprintln!("{file}:{line_number}: {error}");
+                        success = false;
+                    }
+                    if args.format == Format::Json {
+                        writeln!(out, "null").map_err(output_error)?;
+                    }
+                    continue;
+                }
+            };
+            if args.format != Format::Json {
+                writeln!(out, "==> {file}:{line_number} <==").map_err(output_error)?;
+            }
+            let (elements, _) = args.mode.parse(&text, &args.options);
+            print_elements(out, args.format, &elements, true).map_err(output_error)?;
+        }
+    }
+    Ok(success)
+}
+
+/// The message of a line of a JSONL file, `Err(None)` for empty lines
+fn jsonl_text(line: &str) -> Result<String, Option<String>> {
+    if line.trim().is_empty() {
+        return Err(None);
+    }
+    match serde_json::from_str(line).map_err(|error| Some(error.to_string()))? {
+        Value::String(text) => Ok(text),
+        Value::Object(mut fields) => match fields.remove("text") {
+            Some(Value::String(text)) => Ok(text),
+            _ => Err(Some("the object has no \"text\" string".to_owned())),
+        },
+        _ => Err(Some("the line is no JSON string or object".to_owned())),
+    }
+}
+
+/// Prints the elements in the format, `compact` JSON is written on one line
+fn print_elements(
+    out: &mut impl Write,
+    format: Format,
+    elements: &[Element],
+    compact: bool,
+) -> io::Result<()> {
+    match format {
+        Format::Debug => writeln!(out, "{elements:#?}"),
+        Format::Json => {
+            if compact {
+                serde_json::to_writer(&mut *out, elements)?;
+            } else {
+                serde_json::to_writer_pretty(&mut *out, elements)?;
+            }
+            writeln!(out)
+        }
+        Format::Html => writeln!(out, "{}", render::html(elements)),
+        Format::Markdown => writeln!(out, "{}", render::markdown(elements)),
+        Format::Tree => out.write_all(render::tree(elements).as_bytes()),
+    }
+}
+
+fn emoji(out: &mut impl Write, text: &str) -> io::Result<()> {
+    writeln!(out, "text: {text:?}")?;
+    writeln!(out, "first emoji: {:?}", get_first_emoji(text))?;
+    writeln!(
+        out,
+        "emoji count if only emoji: {:?}",
+        count_emojis_if_only_contains_emoji(text)
+    )?;
+    let classification = classify_jumbo_emoji(text, u32::MAX);
+    writeln!(
+        out,
+        "emoji and whitespace only: {}, emoji: {}, graphemes: {}, non-RGI sequences: {}",
+        classification.emoji_only,
+        classification.emoji_count,
+        classification.grapheme_count,
+        classification.contains_non_rgi_sequence
+    )?;
+    writeln!(out, "segments:")?;
+    for (range, kind) in emoji_segments(text) {
+        let segment = text.get(range.clone()).unwrap_or_default();
+        write!(out, "  {}..{} {kind:?} {segment:?}", range.start, range.end)?;
+        if kind == SegmentKind::Emoji {
+            if let Some(qualification) = emoji_qualification(segment) {
+                write!(out, " {qualification:?}")?;
+            }
+            if let Some(base) = canonical_base_emoji(segment).filter(|base| base != segment) {
+                write!(out, " base {base:?}")?;
+            }
+            #[cfg(feature = "emoji-metadata")]
+            if let Some(metadata) =
+                deltachat_message_parser::parser::is_emoji::emoji_metadata(segment)
+            {
+                write!(out, " {:?}", metadata.name)?;
+            }
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn punycode(out: &mut impl Write, host: &str) -> io::Result<()> {
+    writeln!(out, "host: {host}")?;
+    writeln!(out, "needs punycode: {}", is_puny(host))?;
+    writeln!(out, "encoded: {}", punycode_encode_host(host))?;
+    writeln!(out, "decoded: {}", punycode_decode_host(host))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{jsonl_text, parse_args, Command, Format};
+    use deltachat_message_parser::parser::ParseMode;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| (*arg).to_owned()).collect()
+    }
+
+    #[test]
+    fn arguments() {
+        let command = parse_args(&args(&[
+            "--mode", "desktop", "--format", "tree", "--jsonl", "--emoji", "a.jsonl", "-",
+        ]))
+        .unwrap();
+        let Command::Parse(parse) = command else {
+            panic!("{:?}", command)
+        };
+        assert_eq!(parse.mode, ParseMode::DesktopSet);
+        assert_eq!(parse.format, Format::Tree);
+        assert!(parse.jsonl);
+        assert!(parse.options.emoji_elements);
+        assert!(!parse.options.bot_command_args);
+        assert_eq!(parse.files, args(&["a.jsonl", "-"]));
+
+        let Ok(Command::Parse(parse)) = parse_args(&[]) else {
+            panic!("no arguments parse stdin")
+        };
+        assert_eq!(parse.mode, ParseMode::Markdown);
+        assert_eq!(parse.format, Format::Debug);
+        assert_eq!(parse.files, args(&["-"]));
+
+        assert!(matches!(parse_args(&args(&["-h"])), Ok(Command::Help)));
+        assert!(matches!(
+            parse_args(&args(&["punycode", "a", "b"])),
+            Ok(Command::Punycode(hosts)) if hosts == args(&["a", "b"])
+        ));
+        assert!(matches!(
+            parse_args(&args(&["emoji"])),
+            Ok(Command::Emoji(texts)) if texts.is_empty()
+        ));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        for invalid in [
+            &["--mode", "html"][..],
+            &["--mode"],
+            &["--format", "yaml"],
+            &["--verbose"],
+            &["punycode"],
+        ] {
+            assert!(parse_args(&args(invalid)).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn jsonl_lines() {
+        assert_eq!(
+            jsonl_text(r#""hi\n**there**""#),
+            Ok("hi\n**there**".to_owned())
+        );
+        assert_eq!(
+            jsonl_text(r#"{"id": 1, "text": "hi"}"#),
+            Ok("hi".to_owned())
+        );
+        assert_eq!(jsonl_text("  "), Err(None));
+        assert!(matches!(jsonl_text(r#"{"id": 1}"#), Err(Some(_))));
+        assert!(matches!(jsonl_text("[1]"), Err(Some(_))));
+        assert!(matches!(jsonl_text("hi"), Err(Some(_))));
+    }
+}
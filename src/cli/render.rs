@@ -0,0 +1,311 @@
+//! The html, markdown and tree output formats of the elements
+
+use std::fmt::Write;
+
+use deltachat_message_parser::parser::{Element, LinkDestination};
+
+/// Renders the elements as html, links to hosts with punycode warnings use the punycode url
+pub(crate) fn html(elements: &[Element]) -> String {
+    let mut html = String::new();
+    for element in elements {
+        push_html(&mut html, element);
+    }
+    html
+}
+
+fn push_html(html: &mut String, element: &Element) {
+    match element {
+        Element::Text(text) => push_escaped(html, text),
+        Element::Tag(tag) => push_span(html, "tag", tag),
+        Element::Linebreak => html.push_str("<br>\n"),
+        Element::Link { destination } => {
+            push_link_start(html, destination);
+            push_escaped(html, destination.target);
+            html.push_str("</a>");
+        }
+        Element::EmailAddress(address) => {
+            html.push_str("<a href=\"mailto:");
+            push_escaped(html, address);
+            html.push_str("\">");
+            push_escaped(html, address);
+            html.push_str("</a>");
+        }
+        Element::BotCommandSuggestion { content, .. } => push_span(html, "bot-command", content),
+        Element::Emoji(emoji) => push_span(html, "emoji", emoji),
+        Element::Bold(children) => push_html_tag(html, "b", children),
+        Element::Italics(children) => push_html_tag(html, "i", children),
+        Element::StrikeThrough(children) => push_html_tag(html, "s", children),
+        Element::LabeledLink { label, destination } => {
+            push_link_start(html, destination);
+            for child in label {
+                push_html(html, child);
+            }
+            html.push_str("</a>");
+        }
+        Element::InlineCode { content } => {
+            html.push_str("<code>");
+            push_escaped(html, content);
+            html.push_str("</code>");
+        }
+        Element::CodeBlock { language, content } => {
+            html.push_str("<pre><code");
+            if let Some(language) = language {
+                html.push_str(" class=\"language-");
+                push_escaped(html, language);
+                html.push('"');
+            }
+            html.push('>');
+            push_escaped(html, content);
+            html.push_str("</code></pre>");
+        }
+    }
+}
+
+fn push_escaped(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+}
+
+fn push_span(html: &mut String, class: &str, text: &str) {
+    let _ = write!(html, "<span class=\"{class}\">");
+    push_escaped(html, text);
+    html.push_str("</span>");
+}
+
+fn push_html_tag(html: &mut String, tag: &str, children: &[Element]) {
+    let _ = write!(html, "<{tag}>");
+    for child in children {
+        push_html(html, child);
+    }
+    let _ = write!(html, "</{tag}>");
+}
+
+fn push_link_start(html: &mut String, destination: &LinkDestination) {
+    html.push_str("<a href=\"");
+    match &destination.punycode {
+        Some(warning) => push_escaped(html, &warning.punycode_encoded_url),
+        None => push_escaped(html, destination.target),
+    }
+    html.push_str("\">");
+}
+
+/// Writes the elements as markdown again, the text is not escaped
+pub(crate) fn markdown(elements: &[Element]) -> String {
+    let mut markdown = String::new();
+    for element in elements {
+        push_markdown(&mut markdown, element);
+    }
+    markdown
+}
+
+fn push_markdown(markdown: &mut String, element: &Element) {
+    match element {
+        Element::Text(text)
+        | Element::Tag(text)
+        | Element::EmailAddress(text)
+        | Element::Emoji(text)
+        | Element::BotCommandSuggestion { content: text, .. } => markdown.push_str(text),
+        Element::Linebreak => markdown.push('\n'),
+        Element::Link { destination } => markdown.push_str(destination.target),
+        Element::Bold(children) => push_markdown_delimited(markdown, "**", children),
+        Element::Italics(children) => push_markdown_delimited(markdown, "_", children),
+        Element::StrikeThrough(children) => push_markdown_delimited(markdown, "~~", children),
+        Element::LabeledLink { label, destination } => {
+            markdown.push('[');
+            for child in label {
+                push_markdown(markdown, child);
+            }
+            let _ = write!(markdown, "]({})", destination.target);
+        }
+        Element::InlineCode { content } => {
+            let _ = write!(markdown, "`{content}`");
+        }
+        Element::CodeBlock { language, content } => {
+            let _ = write!(
+                markdown,
+                "```{}\n{content}\n```",
+                language.unwrap_or_default()
+            );
+        }
+    }
+}
+
+fn push_markdown_delimited(markdown: &mut String, delimiter: &str, children: &[Element]) {
+    markdown.push_str(delimiter);
+    for child in children {
+        push_markdown(markdown, child);
+    }
+    markdown.push_str(delimiter);
+}
+
+/// One line for each element, the children are indented below their parent
+pub(crate) fn tree(elements: &[Element]) -> String {
+    let mut tree = String::new();
+    push_tree(&mut tree, elements, 0);
+    tree
+}
+
+fn push_tree(tree: &mut String, elements: &[Element], depth: usize) {
+    for element in elements {
+        for _ in 0..depth {
+            tree.push_str("  ");
+        }
+        let children: &[Element] = match element {
+            Element::Text(text) => {
+                let _ = write!(tree, "Text {text:?}");
+                &[]
+            }
+            Element::Tag(tag) => {
+                let _ = write!(tree, "Tag {tag:?}");
+                &[]
+            }
+            Element::Linebreak => {
+                tree.push_str("Linebreak");
+                &[]
+            }
+            Element::Link { destination } => {
+                tree.push_str("Link");
+                push_tree_destination(tree, destination);
+                &[]
+            }
+            Element::EmailAddress(address) => {
+                let _ = write!(tree, "EmailAddress {address:?}");
+                &[]
+            }
+            Element::BotCommandSuggestion {
+                content,
+                command,
+                target,
+                args,
+            } => {
+                let _ = write!(tree, "BotCommandSuggestion {content:?} command {command:?}");
+                if let Some(target) = target {
+                    let _ = write!(tree, " target {target:?}");
+                }
+                if let Some(args) = args {
+                    let _ = write!(tree, " args {args:?}");
+                }
+                &[]
+            }
+            Element::Emoji(emoji) => {
+                let _ = write!(tree, "Emoji {emoji:?}");
+                &[]
+            }
+            Element::Bold(children) => {
+                tree.push_str("Bold");
+                children
+            }
+            Element::Italics(children) => {
+                tree.push_str("Italics");
+                children
+            }
+            Element::StrikeThrough(children) => {
+                tree.push_str("StrikeThrough");
+                children
+            }
+            Element::LabeledLink { label, destination } => {
+                tree.push_str("LabeledLink");
+                push_tree_destination(tree, destination);
+                label
+            }
+            Element::InlineCode { content } => {
+                let _ = write!(tree, "InlineCode {content:?}");
+                &[]
+            }
+            Element::CodeBlock { language, content } => {
+                let _ = write!(tree, "CodeBlock {content:?}");
+                if let Some(language) = language {
+                    let _ = write!(tree, " language {language:?}");
+                }
+                &[]
+            }
+        };
+        tree.push('\n');
+        push_tree(tree, children, depth.saturating_add(1));
+    }
+}
+
+fn push_tree_destination(tree: &mut String, destination: &LinkDestination) {
+    let _ = write!(tree, " {:?}", destination.target);
+    if let Some(warning) = &destination.punycode {
+        let _ = write!(tree, " punycode {:?}", warning.ascii_hostname);
+    }
+    if let Some(payment_request) = &destination.payment_request {
+        let _ = write!(
+            tree,
+            " payment {:?} {:?}",
+            payment_request.currency, payment_request.address
+        );
+    }
+    if destination.magnet.is_some() {
+        tree.push_str(" magnet");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{html, markdown, tree};
+    use deltachat_message_parser::parser::{parse_markdown_text, parse_only_text};
+
+    #[test]
+    fn html_is_escaped() {
+        assert_eq!(
+            html(&parse_markdown_text(
+                "**<b>** [\"x\"](https://delta.chat/?a=1&b=2) #tag\n`a<b`"
+            )),
+            "<b>&lt;b&gt;</b> <a href=\"https://delta.chat/?a=1&amp;b=2\">&quot;x&quot;</a> \
+            <span class=\"tag\">#tag</span><br>\n<code>a&lt;b</code>"
+        );
+        assert_eq!(
+            html(&parse_markdown_text("```rust\nfn main() {}\n```")),
+            "<pre><code class=\"language-rust\">fn main() {}</code></pre>"
+        );
+    }
+
+    #[test]
+    fn html_uses_the_punycode_url() {
+        assert_eq!(
+            html(&parse_only_text("https://münchen.de")),
+            "<a href=\"https://xn--mnchen-3ya.de\">https://münchen.de</a>"
+        );
+    }
+
+    #[test]
+    fn markdown_parses_to_the_same_elements() {
+        for input in [
+            "**bold _italics_ ~~strike~~** [label **bold**](https://delta.chat)",
+            "`code` #tag hi@delta.chat /help\n```rust\nfn main() {}\n```",
+        ] {
+            let elements = parse_markdown_text(input);
+            let written = markdown(&elements);
+            assert_eq!(parse_markdown_text(&written), elements, "{}", written);
+        }
+    }
+
+    #[test]
+    fn tree_indents_children() {
+        assert_eq!(
+            tree(&parse_markdown_text(
+                "**a _b_**\n[c](https://münchen.de) /help@bot"
+            )),
+            r#"Bold
+  Text "a "
+  Italics
+    Text "b"
+Linebreak
+LabeledLink "https://münchen.de" punycode "xn--mnchen-3ya.de"
+  Text "c"
+Text " "
+BotCommandSuggestion "/help@bot" command "help" target "bot"
+"#
+        );
+    }
+}
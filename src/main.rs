@@ -1,22 +1,17 @@
-use std::io::{self, Read, Write};
+//! Command line tool to debug the parser with real messages, see `--help`
 
-use parser::parse_markdown_text;
-#[allow(dead_code)]
-mod parser;
-extern crate nom;
-#[macro_use]
-extern crate serde_derive;
+mod cli;
 
-fn main() -> io::Result<()> {
-    let mut buffer = String::new();
-    io::stdin().read_to_string(&mut buffer)?;
+use std::{env, process::ExitCode};
 
-    //println!("input: {:?}", buffer);
-
-    let output = parse_markdown_text(&buffer);
-
-    io::stdout().write_all(format!("output: {:?}", output).as_bytes())?;
-
-    //println!("output: {:?}", output);
-    Ok(())
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match cli::run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,116 @@
+//! Structured differences between two parse results of the same text, to find the messages that
+//! are parsed differently by two parser configurations or by two versions of the parser.
+//!
+//! ```
+//! use deltachat_message_parser::parser::{
+//!     diff::{diff, ElementDiff},
+//!     parse_desktop_set, parse_markdown_text, OwnedElement,
+//! };
+//!
+//! let text = "**Hello** #world";
+//! assert_eq!(
+//!     diff(&parse_desktop_set(text), &parse_markdown_text(text)),
+//!     vec![ElementDiff {
+//!         path: vec![0],
+//!         a: vec![OwnedElement::Text("**Hello** ".to_owned())],
+//!         b: vec![
+//!             OwnedElement::Bold(vec![OwnedElement::Text("Hello".to_owned())]),
+//!             OwnedElement::Text(" ".to_owned()),
+//!         ],
+//!     }]
+//! );
+//! ```
+
+use std::iter::once;
+
+use super::{Element, OwnedElement};
+
+/// A range of elements that differs between two parse results, see [diff]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElementDiff {
+    /// where the range starts: the last index is the index of its first element, the indices
+    /// before it are the indices of the parents, the children of a
+    /// [LabeledLink](Element::LabeledLink) are the elements of its label.
+    /// The elements before the range are the same in both results, so the indices are also the same.
+    pub path: Vec<usize>,
+    /// the elements of the range in the first result, empty if the second result has additional
+    /// elements there
+    pub a: Vec<OwnedElement>,
+    /// the elements of the range in the second result
+    pub b: Vec<OwnedElement>,
+}
+
+/// Returns the ranges of elements that differ between `a` and `b`, empty if they are the same
+///
+/// The common elements at the start and the end of each list of elements are skipped, what is
+/// left becomes one [ElementDiff], except if it is one markdown element with the same delimiters
+/// in both results (like bold text in both), then the children of the element are compared.
+pub fn diff(a: &[Element], b: &[Element]) -> Vec<ElementDiff> {
+    let mut diffs = Vec::new();
+    push_diffs(&mut diffs, &mut Vec::new(), a, b);
+    diffs
+}
+
+fn push_diffs(diffs: &mut Vec<ElementDiff>, path: &mut Vec<usize>, a: &[Element], b: &[Element]) {
+    let prefix = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+    let a = a.get(prefix..).unwrap_or_default();
+    let b = b.get(prefix..).unwrap_or_default();
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = a.get(..a.len().saturating_sub(suffix)).unwrap_or_default();
+    let b = b.get(..b.len().saturating_sub(suffix)).unwrap_or_default();
+    match (a, b) {
+        ([], []) => {}
+        ([a_element], [b_element]) => match same_container(a_element, b_element) {
+            Some((a_children, b_children)) => {
+                path.push(prefix);
+                push_diffs(diffs, path, a_children, b_children);
+                path.pop();
+            }
+            None => push_diff(diffs, path, prefix, a, b),
+        },
+        _ => push_diff(diffs, path, prefix, a, b),
+    }
+}
+
+fn push_diff(
+    diffs: &mut Vec<ElementDiff>,
+    path: &[usize],
+    index: usize,
+    a: &[Element],
+    b: &[Element],
+) {
+    diffs.push(ElementDiff {
+        path: path.iter().copied().chain(once(index)).collect(),
+        a: a.iter().cloned().map(Element::into_owned).collect(),
+        b: b.iter().cloned().map(Element::into_owned).collect(),
+    });
+}
+
+/// The children of two elements of the same kind, to compare the children instead of the whole
+/// elements
+fn same_container<'e, 'a>(
+    a: &'e Element<'a>,
+    b: &'e Element<'a>,
+) -> Option<(&'e [Element<'a>], &'e [Element<'a>])> {
+    match (a, b) {
+        (Element::Bold(a), Element::Bold(b))
+        | (Element::Italics(a), Element::Italics(b))
+        | (Element::StrikeThrough(a), Element::StrikeThrough(b)) => Some((a, b)),
+        (
+            Element::LabeledLink {
+                label: a,
+                destination: a_destination,
+            },
+            Element::LabeledLink {
+                label: b,
+                destination: b_destination,
+            },
+        ) if a_destination == b_destination => Some((a, b)),
+        _ => None,
+    }
+}
@@ -0,0 +1,96 @@
+//! Helpers for searching and indexing hashtags
+//!
+//! `#Foo`, `#FOO` and `#ｆｏｏ` are the same hashtag for a human, so they need to be compared in
+//! their normalized form.
+
+use std::collections::HashSet;
+
+use caseless::default_case_fold_str;
+use unicode_normalization::UnicodeNormalization;
+
+use super::Element;
+
+fn is_text_or_emoji_presentation_selector(c: char) -> bool {
+    matches!(c, '\u{fe0e}' | '\u{fe0f}')
+}
+
+/// Returns the normalized form of a hashtag, that can be used for comparing, searching and indexing
+///
+/// - NFKC normalization, so compatibility characters like fullwidth letters match their normal form
+/// - Unicode case folding
+/// - the leading `#` is removed
+/// - the emoji and text presentation selectors (`U+FE0F`, `U+FE0E`) are removed, so `#❤️` and `#❤` are the same
+pub fn normalize_hashtag(tag: &str) -> String {
+    let normalized: String = tag.nfkc().collect();
+    let folded = default_case_fold_str(&normalized);
+    // case folding can produce decomposed characters again
+    folded
+        .nfkc()
+        .filter(|c| !is_text_or_emoji_presentation_selector(*c))
+        .collect::<String>()
+        .trim_start_matches('#')
+        .to_owned()
+}
+
+/// Returns the unique normalized hashtags (see [normalize_hashtag]) of parsed elements, in order of their first appearance
+///
+/// Hashtags inside of other elements (like `**#bold_tag**`) are included as well.
+pub fn get_hashtags(elements: &[Element]) -> Vec<String> {
+    let mut hashtags = Vec::new();
+    collect_hashtags(elements, &mut hashtags, &mut HashSet::new());
+    hashtags
+}
+
+/// `seen` contains the same tags as `hashtags`, for checking in constant time if a tag is new
+fn collect_hashtags(elements: &[Element], hashtags: &mut Vec<String>, seen: &mut HashSet<String>) {
+    for element in elements {
+        match element {
+            Element::Tag(tag) => {
+                let tag = normalize_hashtag(tag);
+                if !tag.is_empty() && seen.insert(tag.clone()) {
+                    hashtags.push(tag);
+                }
+            }
+            Element::Bold(children)
+            | Element::Italics(children)
+            | Element::StrikeThrough(children)
+            | Element::LabeledLink {
+                label: children, ..
+            } => collect_hashtags(children, hashtags, seen),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::normalize_hashtag;
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(normalize_hashtag("#Foo"), "foo");
+        assert_eq!(normalize_hashtag("#FOO"), "foo");
+        assert_eq!(normalize_hashtag("#foo"), "foo");
+        // case folding is more than lowercase
+        assert_eq!(normalize_hashtag("#Straße"), "strasse");
+        assert_eq!(normalize_hashtag("#STRASSE"), "strasse");
+    }
+
+    #[test]
+    fn compatibility_characters() {
+        assert_eq!(normalize_hashtag("#ｆｏｏ"), "foo");
+        assert_eq!(normalize_hashtag("＃foo"), "foo");
+        assert_eq!(normalize_hashtag("#ﬁle"), "file");
+        // composed and decomposed umlauts
+        assert_eq!(normalize_hashtag("#M\u{fc}nchen"), "m\u{fc}nchen");
+        assert_eq!(normalize_hashtag("#Mu\u{308}nchen"), "m\u{fc}nchen");
+    }
+
+    #[test]
+    fn emoji() {
+        assert_eq!(normalize_hashtag("#❤️"), "❤");
+        assert_eq!(normalize_hashtag("#❤"), "❤");
+        // skin tones are kept, they change the meaning
+        assert_eq!(normalize_hashtag("#👍🏽"), "👍🏽");
+    }
+}
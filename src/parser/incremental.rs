@@ -0,0 +1,582 @@
+//! Incremental markdown parsing for the live preview of a draft, so that after an edit only
+//! the edited part is parsed again instead of the whole text.
+//!
+//! The parser continues at every top level element without knowing what came before, so the
+//! elements after the edit can be reused as soon as the new parse reaches one of their boundaries.
+//! Elements before the edit are reused up to the start of the line of the edit, unless a markdown
+//! delimiter before it searched for its closing delimiter inside or after the edit. A delimiter
+//! without any closing delimiter after it, like the `_` in `my_file.txt`, only needs to be parsed
+//! again if the edit inserts one, then the parse restarts at the line of that delimiter.
+
+use std::ops::Range;
+
+use super::{
+    parse_from_text::{
+        parse_markdown, parse_next_markdown_element, scan_cache::ScanCache, Context, LimitsState,
+    },
+    utils::is_white_space,
+    Element, LinkDestination, ParserOptions,
+};
+
+/// Replacement of `range` (byte offsets in the previous text) with `replacement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit<'e> {
+    pub range: Range<usize>,
+    pub replacement: &'e str,
+}
+
+/// Markdown text parsed like [parse_markdown_text](super::parse_markdown_text), that can be
+/// parsed again after an edit with [ParsedMarkdown::reparse]
+#[derive(Debug)]
+pub struct ParsedMarkdown<'a> {
+    text: &'a str,
+    options: ParserOptions,
+    elements: Vec<Element<'a>>,
+    /// byte ranges of the elements in `text`
+    ranges: Vec<Range<usize>>,
+}
+
+/// Result of [ParsedMarkdown::reparse]
+#[derive(Debug)]
+pub struct Reparsed<'a> {
+    pub parsed: ParsedMarkdown<'a>,
+    /// byte range of the new text that was parsed again
+    pub changed_text: Range<usize>,
+    /// the new elements (indices in `parsed.elements()`), that replace `replaced_elements`
+    pub changed_elements: Range<usize>,
+    /// the indices of the replaced elements in the previous result
+    pub replaced_elements: Range<usize>,
+}
+
+/// Markdown delimiters whose parsers search for the closing delimiter without a limit
+const DELIMITERS: [(char, char); 6] = [
+    ('*', '*'),
+    ('_', '_'),
+    ('~', '~'),
+    ('`', '`'),
+    ('[', ']'),
+    ('<', '>'),
+];
+
+impl<'a> ParsedMarkdown<'a> {
+    pub fn parse(text: &'a str, options: &ParserOptions) -> ParsedMarkdown<'a> {
+        let (elements, ranges) = if has_text_wide_limits(options) {
+            // without ranges every edit parses the whole text again
+            (parse_markdown(text, options, false).elements, Vec::new())
+        } else {
+            parse_from(text, 0, options, |_| false)
+        };
+        ParsedMarkdown {
+            text,
+            options: options.clone(),
+            elements,
+            ranges,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn elements(&self) -> &[Element<'a>] {
+        &self.elements
+    }
+
+    pub fn into_elements(self) -> Vec<Element<'a>> {
+        self.elements
+    }
+
+    /// Parses `new_text`, which is the previous text with `edit` applied, reusing the elements
+    /// that are not affected by the edit
+    ///
+    /// The result is always the same as parsing `new_text` from scratch.
+    /// If `edit` does not describe the change from the previous to the new text,
+    /// the whole new text is parsed again.
+    /// An edit that adds a closing delimiter for an unclosed one on an earlier line, like
+    /// typing `_` after `my_file.txt`, is parsed again from the line of the unclosed delimiter.
+    ///
+    /// ```
+    /// use deltachat_message_parser::parser::{
+    ///     incremental::{ParsedMarkdown, TextEdit},
+    ///     parse_markdown_text, ParserOptions,
+    /// };
+    ///
+    /// let parsed = ParsedMarkdown::parse("**Hello**\nWorl", &ParserOptions::default());
+    /// let new_text = "**Hello**\nWorld";
+    /// let reparsed = parsed.reparse(new_text, &TextEdit { range: 14..14, replacement: "d" });
+    /// assert_eq!(reparsed.parsed.elements(), parse_markdown_text(new_text).as_slice());
+    /// // only the last line was parsed again
+    /// assert_eq!(reparsed.changed_text, 10..15);
+    /// ```
+    pub fn reparse<'b>(&self, new_text: &'b str, edit: &TextEdit) -> Reparsed<'b> {
+        self.try_reparse(new_text, edit).unwrap_or_else(|| {
+            let parsed = ParsedMarkdown::parse(new_text, &self.options);
+            Reparsed {
+                changed_text: 0..new_text.len(),
+                changed_elements: 0..parsed.elements.len(),
+                replaced_elements: 0..self.elements.len(),
+                parsed,
+            }
+        })
+    }
+
+    fn try_reparse<'b>(&self, new_text: &'b str, edit: &TextEdit) -> Option<Reparsed<'b>> {
+        if has_text_wide_limits(&self.options) {
+            return None;
+        }
+        let edit_start = edit.range.start;
+        let old_end = edit.range.end;
+        let new_end = edit_start.checked_add(edit.replacement.len())?;
+        let edit_matches = edit_start <= old_end
+            && self.text.get(..edit_start)? == new_text.get(..edit_start)?
+            && new_text.get(edit_start..new_end)? == edit.replacement
+            && self.text.get(old_end..)? == new_text.get(new_end..)?;
+        if !edit_matches {
+            return None;
+        }
+
+        let first_changed = self.restart_index(edit_start, edit.replacement);
+        let restart = self
+            .ranges
+            .get(first_changed)
+            .map_or(self.text.len(), |range| range.start);
+
+        // parse until an element of the previous text after the edit starts at the same position
+        let mut first_reused = self.elements.len();
+        let (new_elements, new_ranges) = parse_from(new_text, restart, &self.options, |position| {
+            let Some(old_position) = position
+                .checked_sub(new_end)
+                .and_then(|offset| offset.checked_add(old_end))
+            else {
+                return false;
+            };
+            match self
+                .ranges
+                .binary_search_by(|range| range.start.cmp(&old_position))
+            {
+                Ok(index) => {
+                    first_reused = index;
+                    true
+                }
+                Err(_) => false,
+            }
+        });
+        let changed_end = new_ranges.last().map_or(restart, |range| range.end);
+
+        let mut elements = Vec::with_capacity(
+            first_changed
+                .saturating_add(new_elements.len())
+                .saturating_add(self.elements.len().saturating_sub(first_reused)),
+        );
+        let mut ranges = Vec::with_capacity(elements.capacity());
+        for (element, range) in self
+            .elements
+            .iter()
+            .zip(self.ranges.iter())
+            .take(first_changed)
+        {
+            elements.push(self.rebase(element, new_text, &Some)?);
+            ranges.push(range.clone());
+        }
+        let changed_elements = elements.len()..elements.len().saturating_add(new_elements.len());
+        elements.extend(new_elements);
+        ranges.extend(new_ranges);
+        let shift = |position: usize| position.checked_sub(old_end)?.checked_add(new_end);
+        for (element, range) in self
+            .elements
+            .iter()
+            .zip(self.ranges.iter())
+            .skip(first_reused)
+        {
+            elements.push(self.rebase(element, new_text, &shift)?);
+            ranges.push(shift(range.start)?..shift(range.end)?);
+        }
+
+        Some(Reparsed {
+            parsed: ParsedMarkdown {
+                text: new_text,
+                options: self.options.clone(),
+                elements,
+                ranges,
+            },
+            changed_text: restart..changed_end,
+            changed_elements,
+            replaced_elements: first_changed..first_reused,
+        })
+    }
+
+    /// Index of the first element that needs to be parsed again for an edit at `edit_start` that
+    /// inserts `replacement`
+    fn restart_index(&self, edit_start: usize, replacement: &str) -> usize {
+        let last_candidate = self
+            .ranges
+            .partition_point(|range| range.start <= edit_start);
+        (1..=last_candidate)
+            .rev()
+            .find(|&index| {
+                let follows_linebreak = matches!(
+                    index.checked_sub(1).and_then(|i| self.elements.get(i)),
+                    Some(Element::Linebreak)
+                );
+                let restart = self
+                    .ranges
+                    .get(index)
+                    .map_or(self.text.len(), |range| range.start);
+                follows_linebreak
+                    && restart <= edit_start
+                    && self.prefix_is_independent(index, edit_start, replacement)
+            })
+            .unwrap_or(0)
+    }
+
+    /// Whether parsing the elements before `index` did not look at the text from `edit_start` on
+    ///
+    /// Text elements end at whitespace, but markdown delimiters search for their closing delimiter,
+    /// for example `**` for the first `*` after it. If there is no closing delimiter, the parser
+    /// failed and only works after an edit that inserts one.
+    ///
+    /// The last parser that found a closing delimiter looked further than the ones before it.
+    fn prefix_is_independent(&self, index: usize, edit_start: usize, replacement: &str) -> bool {
+        DELIMITERS.iter().all(|&(open, close)| {
+            let last_close = self.text.rfind(close);
+            let mut failed_run_start = None;
+            for position in self.parse_attempts(index, open) {
+                // the delimiters of a run search from the same position
+                let in_failed_run = failed_run_start == position.checked_add(open.len_utf8());
+                let close_position = last_close
+                    .filter(|&last_close| last_close > position && !in_failed_run)
+                    .and_then(|_| closing_delimiter(self.text, position, open, close));
+                match close_position {
+                    Some(close_position) => {
+                        return lookahead_end(self.text, close_position, open)
+                            .is_some_and(|end| end <= edit_start)
+                    }
+                    None if replacement.contains(close) => return false,
+                    None => failed_run_start = Some(position),
+                }
+            }
+            true
+        })
+    }
+
+    /// The positions before the element at `index` where the parser tried to parse an element
+    /// starting with `open`, from the last to the first, that are the starts of elements and any
+    /// position inside of text
+    fn parse_attempts(&self, index: usize, open: char) -> impl Iterator<Item = usize> + '_ {
+        self.elements
+            .iter()
+            .zip(self.ranges.iter())
+            .take(index)
+            .rev()
+            .flat_map(move |(element, range)| {
+                let content = self.text.get(range.clone()).unwrap_or_default();
+                let offsets: Vec<usize> = match element {
+                    Element::Text(_) => content.rmatch_indices(open).map(|(i, _)| i).collect(),
+                    _ if content.starts_with(open) => vec![0],
+                    _ => Vec::new(),
+                };
+                offsets
+                    .into_iter()
+                    .filter_map(move |offset| range.start.checked_add(offset))
+            })
+    }
+
+    /// The same element, but with slices of `new_text`, the slices are moved by `shift`
+    fn rebase<'b>(
+        &self,
+        element: &Element<'a>,
+        new_text: &'b str,
+        shift: &dyn Fn(usize) -> Option<usize>,
+    ) -> Option<Element<'b>> {
+        let slice = |slice: &str| -> Option<&'b str> {
+            let start = (slice.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)?;
+            let end = start.checked_add(slice.len())?;
+            if self.text.get(start..end)? != slice {
+                return None;
+            }
+            new_text.get(shift(start)?..shift(end)?)
+        };
+        let optional_slice = |optional: Option<&str>| -> Option<Option<&'b str>> {
+            match optional {
+                Some(s) => Some(Some(slice(s)?)),
+                None => Some(None),
+            }
+        };
+        let children = |children: &[Element<'a>]| -> Option<Vec<Element<'b>>> {
+            children
+                .iter()
+                .map(|child| self.rebase(child, new_text, shift))
+                .collect()
+        };
+        let destination = |destination: &LinkDestination<'a>| -> Option<LinkDestination<'b>> {
+            Some(LinkDestination {
+                target: slice(destination.target)?,
+                hostname: optional_slice(destination.hostname)?,
+                punycode: destination.punycode.clone(),
+                scheme: optional_slice(destination.scheme)?,
+                payment_request: destination.payment_request.clone(),
+                magnet: destination.magnet.clone(),
+            })
+        };
+        Some(match element {
+            Element::Text(text) => Element::Text(slice(text)?),
+            Element::Tag(tag) => Element::Tag(slice(tag)?),
+            Element::Linebreak => Element::Linebreak,
+            Element::Link { destination: d } => Element::Link {
+                destination: destination(d)?,
+            },
+            Element::EmailAddress(address) => Element::EmailAddress(slice(address)?),
+            Element::BotCommandSuggestion {
+                content,
+                command,
+                target,
+                args,
+            } => Element::BotCommandSuggestion {
+                content: slice(content)?,
+                command: slice(command)?,
+                target: optional_slice(*target)?,
+                args: optional_slice(*args)?,
+            },
+            Element::Emoji(emoji) => Element::Emoji(slice(emoji)?),
+            Element::Bold(c) => Element::Bold(children(c)?),
+            Element::Italics(c) => Element::Italics(children(c)?),
+            Element::StrikeThrough(c) => Element::StrikeThrough(children(c)?),
+            Element::LabeledLink {
+                label,
+                destination: d,
+            } => Element::LabeledLink {
+                label: children(label)?,
+                destination: destination(d)?,
+            },
+            Element::InlineCode { content } => Element::InlineCode {
+                content: slice(content)?,
+            },
+            Element::CodeBlock { language, content } => Element::CodeBlock {
+                language: optional_slice(*language)?,
+                content: slice(content)?,
+            },
+        })
+    }
+}
+
+/// The element and markdown bytes limits depend on the whole text, so elements can't be reused
+fn has_text_wide_limits(options: &ParserOptions) -> bool {
+    options.max_elements.is_some() || options.max_markdown_bytes.is_some()
+}
+
+type ElementsWithRanges<'a> = (Vec<Element<'a>>, Vec<Range<usize>>);
+
+/// Parses `text` from `start` until the end or until `stop` returns true for the end of an element
+fn parse_from<'a>(
+    text: &'a str,
+    start: usize,
+    options: &ParserOptions,
+    mut stop: impl FnMut(usize) -> bool,
+) -> ElementsWithRanges<'a> {
+    let mut elements = Vec::new();
+    let mut ranges = Vec::new();
+    let mut position = start;
+    let mut remaining = text.get(start..).unwrap_or_default();
+    let limits = LimitsState::default();
+    let scan = ScanCache::new(text);
+    let context = Context::new(&limits, &scan, None);
+    while !remaining.is_empty() {
+        let (rest, element) = parse_next_markdown_element(remaining, options, context);
+        let end = text.len().saturating_sub(rest.len());
+        elements.push(element);
+        ranges.push(position..end);
+        position = end;
+        remaining = rest;
+        if stop(position) {
+            break;
+        }
+    }
+    (elements, ranges)
+}
+
+/// Position of the first closing delimiter that the parsers starting at or before the delimiter at
+/// `position` find
+fn closing_delimiter(text: &str, position: usize, open: char, close: char) -> Option<usize> {
+    // parsers for longer delimiters like `**` start to search after all of them
+    let search_start = position.checked_add(
+        text.get(position..)?
+            .find(|c| c != open)
+            .unwrap_or(text.len().saturating_sub(position)),
+    )?;
+    search_start.checked_add(text.get(search_start..)?.find(close)?)
+}
+
+/// End of the text that the parsers looked at, when they found the closing delimiter at
+/// `close_position`
+fn lookahead_end(text: &str, close_position: usize, open: char) -> Option<usize> {
+    if matches!(open, '[' | '<') {
+        // links follow, they end at whitespace
+        let whitespace =
+            close_position.checked_add(text.get(close_position..)?.find(is_white_space)?)?;
+        whitespace.checked_add(1)
+    } else {
+        // the longest closing delimiter is ```
+        close_position.checked_add(3)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParsedMarkdown, TextEdit};
+    use crate::parser::{parse_markdown_text, parse_markdown_text_with_options, ParserOptions};
+
+    /// applies the edit and checks that the result is the same as parsing from scratch
+    fn check_reparse(text: &str, range: std::ops::Range<usize>, replacement: &str) -> String {
+        let new_text = format!(
+            "{}{}{}",
+            text.get(..range.start).unwrap_or_default(),
+            replacement,
+            text.get(range.end..).unwrap_or_default()
+        );
+        let parsed = ParsedMarkdown::parse(text, &ParserOptions::default());
+        assert_eq!(parsed.elements(), parse_markdown_text(text).as_slice());
+        let reparsed = parsed.reparse(&new_text, &TextEdit { range, replacement });
+        assert_eq!(
+            reparsed.parsed.elements(),
+            parse_markdown_text(&new_text).as_slice(),
+            "reparse of {new_text:?}"
+        );
+        // replaced elements of the previous text + unchanged elements = elements of the new text
+        assert_eq!(
+            parsed
+                .elements()
+                .len()
+                .saturating_sub(reparsed.replaced_elements.len())
+                .saturating_add(reparsed.changed_elements.len()),
+            reparsed.parsed.elements().len()
+        );
+        new_text
+            .get(reparsed.changed_text)
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    #[test]
+    fn only_the_edited_line_is_parsed() {
+        let text = "first line with https://delta.chat\n**second** line\nthird #line";
+        // the elements after the edited one are reused
+        assert_eq!(check_reparse(text, 40..40, "!"), "**sec!ond**");
+        assert_eq!(check_reparse(text, 0..5, "1st"), "1st line with ");
+        assert_eq!(check_reparse(text, 62..62, "s"), "third #lines");
+        // the link ends at the edit
+        assert_eq!(
+            check_reparse(text, 34..34, "/blog"),
+            "first line with https://delta.chat/blog"
+        );
+    }
+
+    #[test]
+    fn typing_at_the_end() {
+        let mut text = String::new();
+        for c in "Hi **there**, see https://example.com\n`code` [label](https://x.org) ~~x~~ /cmd"
+            .chars()
+        {
+            let end = text.len();
+            check_reparse(&text, end..end, &c.to_string());
+            text.push(c);
+        }
+    }
+
+    #[test]
+    fn deleting_everything() {
+        let text = "a **b**\nc";
+        assert_eq!(check_reparse(text, 0..text.len(), ""), "");
+    }
+
+    #[test]
+    fn closing_delimiter_on_later_line() {
+        // closing the code block changes the elements of the previous lines
+        let text = "```\nlet a = 1;\n\n";
+        check_reparse(text, 17..17, "```");
+        check_reparse("**bold\n\nstill bold?", 19..19, "**");
+        check_reparse("[label\nsomething", 16..16, "](https://delta.chat)");
+        check_reparse("<https://delta.chat\n", 20..20, ">");
+    }
+
+    #[test]
+    fn unclosed_delimiter() {
+        let text = "see my_file.txt and *notes\nsecond line\nthird line";
+        // nothing can close the `_` and `*`, so the earlier lines are reused
+        assert_eq!(check_reparse(text, 49..49, "!"), "third line!");
+        assert_eq!(check_reparse(text, 33..33, "ish"), "secondish line");
+        // a closing delimiter restarts the parse at the line of the unclosed one
+        let changed = check_reparse(text, 49..49, "_");
+        assert!(changed.starts_with("see my_file.txt"), "{:?}", changed);
+        let changed = check_reparse(text, 33..33, "*");
+        assert!(changed.starts_with("see my_file.txt"), "{:?}", changed);
+        let text = "first line\nsee [a](https://x.org/[q\nlast line";
+        assert_eq!(check_reparse(text, 45..45, "s"), "last lines");
+        check_reparse(text, 35..35, ")");
+        check_reparse(text, 45..45, "]");
+    }
+
+    #[test]
+    fn removing_linebreaks() {
+        check_reparse("*a\n*b\n", 2..3, "");
+        check_reparse("a\nb\nc", 1..2, " ");
+        check_reparse("https://delta.chat\n/path", 18..19, "");
+    }
+
+    #[test]
+    fn edits_that_do_not_match() {
+        let parsed = ParsedMarkdown::parse("some text", &ParserOptions::default());
+        let reparsed = parsed.reparse(
+            "other text",
+            &TextEdit {
+                range: 0..4,
+                replacement: "more",
+            },
+        );
+        assert_eq!(
+            reparsed.parsed.elements(),
+            parse_markdown_text("other text").as_slice()
+        );
+        assert_eq!(reparsed.changed_text, 0..10);
+        // out of bounds
+        let reparsed = parsed.reparse(
+            "some text",
+            &TextEdit {
+                range: 5..100,
+                replacement: "",
+            },
+        );
+        assert_eq!(reparsed.changed_text, 0..9);
+    }
+
+    #[test]
+    fn limits() {
+        let text = "**a _b_**\n#c #d";
+        let edit = TextEdit {
+            range: 15..15,
+            replacement: "e",
+        };
+        let new_text = "**a _b_**\n#c #de";
+        let options = ParserOptions {
+            max_nesting_depth: Some(1),
+            ..Default::default()
+        };
+        let reparsed = ParsedMarkdown::parse(text, &options).reparse(new_text, &edit);
+        assert_eq!(
+            reparsed.parsed.elements(),
+            parse_markdown_text_with_options(new_text, &options).as_slice()
+        );
+        assert_eq!(reparsed.changed_text, 10..16);
+
+        // the element limit needs the whole text
+        let options = ParserOptions {
+            max_elements: Some(4),
+            ..Default::default()
+        };
+        let reparsed = ParsedMarkdown::parse(text, &options).reparse(new_text, &edit);
+        assert_eq!(
+            reparsed.parsed.elements(),
+            parse_markdown_text_with_options(new_text, &options).as_slice()
+        );
+        assert_eq!(reparsed.changed_text, 0..16);
+    }
+}
@@ -0,0 +1,132 @@
+//! Deciding whether a message is shown with big emoji ("jumbomoji"), so that all clients use the same rule
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::segments::{grapheme_kind, SegmentKind};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct JumboEmojiClassification {
+    /// the text contains at least one emoji and otherwise only whitespace
+    pub emoji_only: bool,
+    /// number of emoji, an emoji sequence that is not RGI (like `👍🏽‍🔥`) counts as one emoji
+    pub emoji_count: u32,
+    /// number of extended grapheme clusters of the whole text, including whitespace
+    pub grapheme_count: u32,
+    /// whether there is an emoji sequence that is not RGI, fonts may show it as multiple emoji
+    pub contains_non_rgi_sequence: bool,
+    /// `emoji_only` and not more than the maximum number of emoji
+    pub jumbo: bool,
+}
+
+/// Classifies `text` for showing it with big emoji, `jumbo` is set if the text contains only emoji
+/// and whitespace and not more than `max_emojis` emoji
+///
+/// Unlike [count_emojis_if_only_contains_emoji](super::count_emojis_if_only_contains_emoji)
+/// whitespace between the emoji is allowed.
+/// This function goes through the whole text, so check the length of long texts beforehand.
+///
+/// ```
+/// use deltachat_message_parser::parser::is_emoji::classify_jumbo_emoji;
+///
+/// assert!(classify_jumbo_emoji("🎉 🎂 🥳", 3).jumbo);
+/// assert!(!classify_jumbo_emoji("🎉🎂🥳🎁", 3).jumbo);
+/// assert!(!classify_jumbo_emoji("happy birthday 🎂", 3).jumbo);
+/// ```
+pub fn classify_jumbo_emoji(text: &str, max_emojis: u32) -> JumboEmojiClassification {
+    let mut emoji_count: u32 = 0;
+    let mut grapheme_count: u32 = 0;
+    let mut contains_text = false;
+    let mut contains_non_rgi_sequence = false;
+    for grapheme in text.graphemes(true) {
+        grapheme_count = grapheme_count.saturating_add(1);
+        match grapheme_kind(grapheme) {
+            SegmentKind::Emoji => emoji_count = emoji_count.saturating_add(1),
+            SegmentKind::UnknownEmojiSequence => {
+                emoji_count = emoji_count.saturating_add(1);
+                contains_non_rgi_sequence = true;
+            }
+            SegmentKind::Text => {
+                if !grapheme.chars().all(char::is_whitespace) {
+                    contains_text = true;
+                }
+            }
+        }
+    }
+    let emoji_only = !contains_text && emoji_count > 0;
+    JumboEmojiClassification {
+        emoji_only,
+        emoji_count,
+        grapheme_count,
+        contains_non_rgi_sequence,
+        jumbo: emoji_only && emoji_count <= max_emojis,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{classify_jumbo_emoji, JumboEmojiClassification};
+
+    #[test]
+    fn emoji_with_whitespace() {
+        assert_eq!(
+            classify_jumbo_emoji(" 👍🏽 ❤️\n🇩🇪 ", 3),
+            JumboEmojiClassification {
+                emoji_only: true,
+                emoji_count: 3,
+                grapheme_count: 7,
+                contains_non_rgi_sequence: false,
+                jumbo: true,
+            }
+        );
+    }
+
+    #[test]
+    fn maximum() {
+        assert!(classify_jumbo_emoji("😀😀", 2).jumbo);
+        let too_many = classify_jumbo_emoji("😀😀😀", 2);
+        assert!(too_many.emoji_only);
+        assert!(!too_many.jumbo);
+        assert!(!classify_jumbo_emoji("😀", 0).jumbo);
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            classify_jumbo_emoji("ok 👍", 5),
+            JumboEmojiClassification {
+                emoji_only: false,
+                emoji_count: 1,
+                grapheme_count: 4,
+                contains_non_rgi_sequence: false,
+                jumbo: false,
+            }
+        );
+        // a keycap base alone is no emoji
+        assert!(!classify_jumbo_emoji("1", 5).emoji_only);
+    }
+
+    #[test]
+    fn empty_and_whitespace_only() {
+        for text in ["", "  ", "\n"] {
+            let classification = classify_jumbo_emoji(text, 5);
+            assert!(!classification.emoji_only);
+            assert!(!classification.jumbo);
+            assert_eq!(classification.emoji_count, 0);
+        }
+    }
+
+    #[test]
+    fn non_rgi_sequence() {
+        assert_eq!(
+            classify_jumbo_emoji("👍🏽\u{200d}🔥 👨‍👩‍👧‍👦", 5),
+            JumboEmojiClassification {
+                emoji_only: true,
+                emoji_count: 2,
+                grapheme_count: 3,
+                contains_non_rgi_sequence: true,
+                jumbo: true,
+            }
+        );
+    }
+}
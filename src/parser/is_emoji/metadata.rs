@@ -0,0 +1,133 @@
+//! Names, groups and versions of emoji for emoji pickers and accessibility labels,
+//! only available with the `emoji-metadata` feature
+
+use super::variants::fully_qualified;
+use crate::parser::unicode_tables::emoji_metadata::{
+    EMOJI_GROUPS, EMOJI_METADATA, EMOJI_SUBGROUPS,
+};
+
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{fe0f}';
+
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+pub struct EmojiMetadata {
+    /// CLDR short name, like `thumbs up: medium skin tone`
+    pub name: &'static str,
+    /// like `People & Body`
+    pub group: &'static str,
+    /// like `hand-fingers-closed`
+    pub subgroup: &'static str,
+    /// the emoji version that introduced the emoji, like `(0, 6)` for `E0.6`
+    pub emoji_version: (u8, u8),
+}
+
+fn lookup(emoji: &str) -> Option<EmojiMetadata> {
+    let index = EMOJI_METADATA
+        .binary_search_by(|(sequence, ..)| (*sequence).cmp(emoji))
+        .ok()?;
+    let (_, name, subgroup, emoji_version) = EMOJI_METADATA.get(index)?;
+    let (subgroup, group) = EMOJI_SUBGROUPS.get(usize::from(*subgroup))?;
+    Some(EmojiMetadata {
+        name,
+        group: EMOJI_GROUPS.get(usize::from(*group))?,
+        subgroup,
+        emoji_version: *emoji_version,
+    })
+}
+
+/// Returns the metadata of `emoji` if it is exactly one emoji (including components like skin tones)
+///
+/// Unqualified and minimally-qualified emoji like `❤` have the metadata of their fully-qualified form,
+/// so the results of [get_first_emoji](super::get_first_emoji) can be looked up.
+///
+/// ```
+/// use deltachat_message_parser::parser::is_emoji::{emoji_metadata, get_first_emoji};
+///
+/// let emoji = get_first_emoji("👍🏽 sounds good").unwrap();
+/// assert_eq!(emoji_metadata(emoji).unwrap().name, "thumbs up: medium skin tone");
+/// ```
+pub fn emoji_metadata(emoji: &str) -> Option<EmojiMetadata> {
+    lookup(emoji).or_else(|| {
+        let without_selectors = emoji.replace(EMOJI_PRESENTATION_SELECTOR, "");
+        lookup(&fully_qualified(&without_selectors))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{emoji_metadata, EmojiMetadata};
+
+    #[test]
+    fn thumbs_up() {
+        assert_eq!(
+            emoji_metadata("👍"),
+            Some(EmojiMetadata {
+                name: "thumbs up",
+                group: "People & Body",
+                subgroup: "hand-fingers-closed",
+                emoji_version: (0, 6),
+            })
+        );
+        assert_eq!(
+            emoji_metadata("👍🏽").map(|metadata| metadata.name),
+            Some("thumbs up: medium skin tone")
+        );
+        assert_eq!(
+            emoji_metadata("👍🏽").map(|metadata| metadata.emoji_version),
+            Some((1, 0))
+        );
+    }
+
+    #[test]
+    fn not_fully_qualified() {
+        let red_heart = emoji_metadata("❤️").map(|metadata| metadata.name);
+        assert_eq!(red_heart, Some("red heart"));
+        assert_eq!(emoji_metadata("❤").map(|metadata| metadata.name), red_heart);
+        // minimally-qualified
+        assert_eq!(
+            emoji_metadata("👁‍🗨").map(|metadata| metadata.name),
+            Some("eye in speech bubble")
+        );
+    }
+
+    #[test]
+    fn components_flags_and_keycaps() {
+        assert_eq!(
+            emoji_metadata("🏽"),
+            Some(EmojiMetadata {
+                name: "medium skin tone",
+                group: "Component",
+                subgroup: "skin-tone",
+                emoji_version: (1, 0),
+            })
+        );
+        assert_eq!(
+            emoji_metadata("🇨🇮").map(|metadata| metadata.name),
+            Some("flag: Côte d’Ivoire")
+        );
+        assert_eq!(
+            emoji_metadata("#️⃣").map(|metadata| metadata.subgroup),
+            Some("keycap")
+        );
+    }
+
+    #[test]
+    fn unicode_16() {
+        assert_eq!(
+            emoji_metadata("🫩"),
+            Some(EmojiMetadata {
+                name: "face with bags under eyes",
+                group: "Smileys & Emotion",
+                subgroup: "face-sleepy",
+                emoji_version: (16, 0),
+            })
+        );
+    }
+
+    #[test]
+    fn not_an_emoji() {
+        assert_eq!(emoji_metadata(""), None);
+        assert_eq!(emoji_metadata("a"), None);
+        assert_eq!(emoji_metadata("👍👍"), None);
+        assert_eq!(emoji_metadata("👍🏽\u{200d}🔥"), None);
+    }
+}
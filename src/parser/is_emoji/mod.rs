@@ -0,0 +1,297 @@
+//! Emoji detection based on the emoji sequences of
+//! [emoji-test.txt](https://www.unicode.org/Public/emoji/latest/emoji-test.txt)
+//!
+//! Recognized are the RGI emoji (recommended for general interchange) including keycaps,
+//! flags, tag sequences and ZWJ sequences, plus their forms that miss an emoji presentation
+//! selector (`U+FE0F`) and the emoji components like skin tones. Other sequences are not
+//! recognized as one emoji, for example `👍🏽‍🔥` is only recognized up to `👍🏽`.
+//!
+//! The RGI sequences are the fully-qualified entries of emoji-test.txt, which lists the same
+//! sequences as emoji-sequences.txt and emoji-zwj-sequences.txt, so those files are not used.
+
+use std::convert::TryFrom;
+
+use nom::{
+    error::{Error, ErrorKind},
+    IResult,
+};
+
+mod jumbo;
+#[cfg(feature = "emoji-metadata")]
+mod metadata;
+mod segments;
+mod variants;
+
+pub use jumbo::{classify_jumbo_emoji, JumboEmojiClassification};
+#[cfg(feature = "emoji-metadata")]
+pub use metadata::{emoji_metadata, EmojiMetadata};
+pub use segments::{emoji_segments, EmojiSegments, SegmentKind};
+pub use variants::{
+    apply_skin_tone, canonical_base_emoji, decompose_emoji, DecomposedEmoji, Gender, HairStyle,
+    SkinTone,
+};
+
+use super::{
+    unicode_tables::emoji::{EMOJI_SEQUENCES, EMOJI_START_RANGES, MAX_EMOJI_SEQUENCE_LENGTH},
+    utils::is_in_one_of_ranges,
+    Element,
+};
+
+/// Status of an emoji sequence, see <https://www.unicode.org/reports/tr51/#def_qualified_emoji_character>
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+pub enum EmojiQualification {
+    /// RGI emoji, like `❤️` (with `U+FE0F`)
+    FullyQualified,
+    /// RGI emoji that misses an emoji presentation selector, but not the first one, like `👁‍🗨`
+    MinimallyQualified,
+    /// RGI emoji that misses the first emoji presentation selector, like `❤`
+    Unqualified,
+    /// emoji component that is shown as emoji on its own, like the skin tone `🏽`
+    Component,
+}
+
+/// Finds the longest emoji sequence at the start of `input`
+///
+/// The sequences are the ones of emoji-test.txt, see the [module documentation](self)
+fn longest_emoji_sequence(input: &str) -> Option<(&str, EmojiQualification)> {
+    let first = input.chars().next()?;
+    if !is_in_one_of_ranges(first as u32, EMOJI_START_RANGES) {
+        return None;
+    }
+    let candidates = input
+        .char_indices()
+        .map(|(index, c)| index.saturating_add(c.len_utf8()))
+        .take(MAX_EMOJI_SEQUENCE_LENGTH)
+        .collect::<Vec<usize>>();
+    candidates.iter().rev().find_map(|&end| {
+        let candidate = input.get(..end)?;
+        let index = EMOJI_SEQUENCES
+            .binary_search_by(|(sequence, _)| (*sequence).cmp(candidate))
+            .ok()?;
+        let (_, qualification) = EMOJI_SEQUENCES.get(index)?;
+        Some((candidate, *qualification))
+    })
+}
+
+/// nom parser that eats one emoji
+pub fn emoji(input: &str) -> IResult<&str, &str> {
+    match longest_emoji_sequence(input) {
+        Some((emoji, _)) => Ok((input.get(emoji.len()..).unwrap_or_default(), emoji)),
+        None => Err(nom::Err::Error(Error::new(input, ErrorKind::Verify))),
+    }
+}
+
+/// Returns the qualification status if `input` is exactly one emoji, `None` otherwise
+pub fn emoji_qualification(input: &str) -> Option<EmojiQualification> {
+    longest_emoji_sequence(input)
+        .filter(|(emoji, _)| emoji.len() == input.len())
+        .map(|(_, qualification)| qualification)
+}
+
+/// returns first emoji from text if text begins with an emoji
+pub fn get_first_emoji(text: &str) -> Option<&str> {
+    if let Ok((_, emoji)) = emoji(text) {
+        Some(emoji)
+    } else {
+        None
+    }
+}
+
+/// If string contains only emojis count the emojis otherwise retuns None
+pub fn count_emojis_if_only_contains_emoji(input: &str) -> Option<u32> {
+    let mut remainder = input;
+    let mut count: u32 = 0;
+
+    while let Ok((new_remainder, _)) = emoji(remainder) {
+        remainder = new_remainder;
+        count = count.saturating_add(1);
+    }
+
+    if !remainder.is_empty() {
+        // string contains not only emojis
+        return None;
+    }
+
+    if count == 0 {
+        None
+    } else {
+        Some(count)
+    }
+}
+
+/// If the parsed elements are only [Element::Emoji] count them, otherwise returns None
+///
+/// For elements of [parse_only_text_with_options](crate::parser::parse_only_text_with_options)
+/// with [ParserOptions::emoji_elements](crate::parser::ParserOptions::emoji_elements) this is the same
+/// as [count_emojis_if_only_contains_emoji] of the text, so it can be used without parsing the text again.
+pub fn count_emoji_elements_if_only_emoji(elements: &[Element]) -> Option<u32> {
+    if elements.is_empty() || !elements.iter().all(|e| matches!(e, Element::Emoji(_))) {
+        return None;
+    }
+    u32::try_from(elements.len()).ok()
+}
+
+#[cfg(test)]
+mod emoji_test {
+    mod emoji_char {
+        use crate::parser::is_emoji::emoji;
+
+        #[test]
+        fn some_emojis() {
+            assert!(emoji("🔥").is_ok());
+        }
+
+        #[test]
+        fn not_emoji() {
+            // println!("{:?}", emoji("A"));
+            assert!(emoji("A").is_err());
+        }
+
+        #[test]
+        fn keycap() {
+            // keycap emojis
+            assert!(emoji("#️⃣").is_ok());
+        }
+
+        #[test]
+        fn flag() {
+            // flag emojis
+            assert!(emoji("🇦🇨").is_ok());
+        }
+
+        #[test]
+        fn mahjong() {
+            // mahjongTiles
+            assert!(emoji("🀄").is_ok());
+        }
+
+        #[test]
+        fn playing_card() {
+            // playingCard
+            assert!(emoji("🃏").is_ok());
+        }
+
+        #[test]
+        fn supplemental_arrows() {
+            // supplementalArrows
+            assert!(emoji("⤴").is_ok());
+            assert!(emoji("⤵").is_ok());
+        }
+
+        #[test]
+        fn unicode_16() {
+            // face with bags under eyes
+            assert!(emoji("🫩").is_ok());
+        }
+
+        #[test]
+        fn tag_sequence() {
+            // flag: Scotland
+            assert_eq!(emoji("🏴󠁧󠁢󠁳󠁣󠁴󠁿 scotland"), Ok((" scotland", "🏴󠁧󠁢󠁳󠁣󠁴󠁿")));
+            // not an RGI subdivision flag, only the black flag is an emoji
+            assert_eq!(
+                emoji("🏴\u{e0061}\u{e0061}\u{e007f}"),
+                Ok(("\u{e0061}\u{e0061}\u{e007f}", "🏴"))
+            );
+        }
+
+        #[test]
+        fn only_rgi_sequences() {
+            // a zwj sequence that is not RGI is not one emoji
+            assert_eq!(emoji("👍🏽\u{200d}🔥"), Ok(("\u{200d}🔥", "👍🏽")));
+            // a single regional indicator is no flag
+            assert!(emoji("🇦").is_err());
+            assert_eq!(emoji("🇦🇨🇦"), Ok(("🇦", "🇦🇨")));
+            // keycap base without keycap
+            assert!(emoji("1").is_err());
+            assert!(emoji("1️").is_err());
+            assert_eq!(emoji("1️⃣2"), Ok(("2", "1️⃣")));
+        }
+
+        #[test]
+        fn qualification() {
+            use crate::parser::is_emoji::{emoji_qualification, EmojiQualification};
+            assert_eq!(
+                emoji_qualification("❤️"),
+                Some(EmojiQualification::FullyQualified)
+            );
+            assert_eq!(
+                emoji_qualification("❤"),
+                Some(EmojiQualification::Unqualified)
+            );
+            assert_eq!(
+                emoji_qualification("👁️‍🗨"),
+                Some(EmojiQualification::MinimallyQualified)
+            );
+            assert_eq!(
+                emoji_qualification("🏽"),
+                Some(EmojiQualification::Component)
+            );
+            assert_eq!(
+                emoji_qualification("👨‍👩‍👧‍👦"),
+                Some(EmojiQualification::FullyQualified)
+            );
+            assert_eq!(emoji_qualification("❤️❤️"), None);
+            assert_eq!(emoji_qualification("A"), None);
+            assert_eq!(emoji_qualification(""), None);
+        }
+
+        #[test]
+        fn test_variant_emoji() {
+            assert!(emoji("🏋️‍♀️").is_ok());
+            assert!(emoji("🤹🏽").is_ok());
+            assert!(emoji("🛌🏿").is_ok());
+        }
+        // composite with zero width joiner
+        #[test]
+        fn test_composite_emoji() {
+            assert!(emoji("❤️‍🔥").is_ok());
+            assert!(emoji("🐕‍🦺").is_ok());
+            assert!(emoji("👩‍👩‍👧").is_ok());
+            assert!(emoji("🧑🏿‍🤝‍🧑🏿").is_ok());
+            assert!(emoji("👩🏽‍❤️‍👨🏽").is_ok());
+        }
+    }
+
+    mod exported_methods {
+        use crate::parser::is_emoji::{count_emojis_if_only_contains_emoji, get_first_emoji};
+
+        #[test]
+        fn test_get_first_emoji() {
+            assert_eq!(get_first_emoji("#️⃣ Hashtag"), Some("#️⃣"));
+            assert_eq!(get_first_emoji("#️⃣Hashtag"), Some("#️⃣"));
+            assert_eq!(get_first_emoji("#️⃣🃏Hashtag"), Some("#️⃣"));
+            assert_eq!(get_first_emoji("Hashtag #️⃣"), None);
+            assert_eq!(get_first_emoji("'#️⃣"), None);
+            assert_eq!(get_first_emoji("❤️‍🔥Hashtag"), Some("❤️‍🔥"));
+            assert_eq!(get_first_emoji("👩🏽‍❤️‍👨🏽Hashtag"), Some("👩🏽‍❤️‍👨🏽"));
+            assert_eq!(get_first_emoji("🇪🇸🚧"), Some("🇪🇸"));
+        }
+
+        #[test]
+        fn test_string_contains_only_emojis_and_count() {
+            assert_eq!(count_emojis_if_only_contains_emoji("#️⃣"), Some(1));
+            assert_eq!(
+                count_emojis_if_only_contains_emoji("👩🏽‍❤️‍👨🏽Hashtag"),
+                None
+            );
+            assert_eq!(count_emojis_if_only_contains_emoji("❤️‍🔥"), Some(1));
+            assert_eq!(count_emojis_if_only_contains_emoji("👩🏽‍❤️‍👨🏽"), Some(1));
+            assert_eq!(
+                count_emojis_if_only_contains_emoji("👩🏽‍❤️‍👨🏽👩🏽‍❤️‍👨🏽"),
+                Some(2)
+            );
+            assert_eq!(
+                count_emojis_if_only_contains_emoji("👩🏽‍❤️‍👨🏽❤️‍🔥👩🏽‍❤️‍👨🏽"),
+                Some(3)
+            );
+            // hair color
+            assert_eq!(count_emojis_if_only_contains_emoji("👨‍🦰"), Some(1));
+            assert_eq!(count_emojis_if_only_contains_emoji("👨‍🦳"), Some(1));
+            assert_eq!(
+                count_emojis_if_only_contains_emoji("🇪🇸🚧🚧🚧🚧🚧🚧🚧"),
+                Some(8)
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Splitting text into emoji and non-emoji segments, for example to show emojis with an emoji
+//! font or to find the emojis for a reaction picker.
+
+use std::ops::Range;
+
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+use super::emoji;
+
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum SegmentKind {
+    /// exactly one emoji, as recognized by [emoji]
+    Emoji,
+    /// a grapheme cluster that starts with an emoji, but is no known emoji sequence,
+    /// like a ZWJ sequence that is not RGI (`👍🏽‍🔥`). Fonts usually show them as multiple emojis.
+    UnknownEmojiSequence,
+    /// everything else, consecutive text is returned as one segment
+    Text,
+}
+
+/// Iterator over the emoji and text segments of a string, see [emoji_segments]
+pub struct EmojiSegments<'a> {
+    graphemes: std::iter::Peekable<GraphemeIndices<'a>>,
+}
+
+/// Returns the segments of `text` as byte ranges, a segment never splits an extended grapheme cluster
+///
+/// ```
+/// use deltachat_message_parser::parser::is_emoji::{emoji_segments, SegmentKind};
+///
+/// let segments: Vec<_> = emoji_segments("Hi 👋🏽!").collect();
+/// assert_eq!(
+///     segments,
+///     vec![
+///         (0..3, SegmentKind::Text),
+///         (3..11, SegmentKind::Emoji),
+///         (11..12, SegmentKind::Text),
+///     ]
+/// );
+/// ```
+pub fn emoji_segments(text: &str) -> EmojiSegments<'_> {
+    EmojiSegments {
+        graphemes: text.grapheme_indices(true).peekable(),
+    }
+}
+
+pub(super) fn grapheme_kind(grapheme: &str) -> SegmentKind {
+    match emoji(grapheme) {
+        Ok(("", _)) => SegmentKind::Emoji,
+        Ok(_) => SegmentKind::UnknownEmojiSequence,
+        Err(_) => SegmentKind::Text,
+    }
+}
+
+impl<'a> Iterator for EmojiSegments<'a> {
+    type Item = (Range<usize>, SegmentKind);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, grapheme) = self.graphemes.next()?;
+        let mut end = start.saturating_add(grapheme.len());
+        let kind = grapheme_kind(grapheme);
+        if kind == SegmentKind::Text {
+            while let Some((_, grapheme)) = self
+                .graphemes
+                .next_if(|(_, grapheme)| grapheme_kind(grapheme) == SegmentKind::Text)
+            {
+                end = end.saturating_add(grapheme.len());
+            }
+        }
+        Some((start..end, kind))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{emoji_segments, SegmentKind};
+
+    fn segments(text: &str) -> Vec<(&str, SegmentKind)> {
+        emoji_segments(text)
+            .map(|(range, kind)| (text.get(range).unwrap_or_default(), kind))
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(segments(""), vec![]);
+        assert_eq!(
+            segments("just text"),
+            vec![("just text", SegmentKind::Text)]
+        );
+    }
+
+    #[test]
+    fn emojis_in_text() {
+        assert_eq!(
+            segments("I ❤️ 🇩🇪!🏴󠁧󠁢󠁳󠁣󠁴󠁿🏴󠁧󠁢󠁳󠁣󠁴󠁿"),
+            vec![
+                ("I ", SegmentKind::Text),
+                ("❤️", SegmentKind::Emoji),
+                (" ", SegmentKind::Text),
+                ("🇩🇪", SegmentKind::Emoji),
+                ("!", SegmentKind::Text),
+                ("🏴󠁧󠁢󠁳󠁣󠁴󠁿", SegmentKind::Emoji),
+                ("🏴󠁧󠁢󠁳󠁣󠁴󠁿", SegmentKind::Emoji),
+            ]
+        );
+    }
+
+    #[test]
+    fn zwj_sequences() {
+        assert_eq!(
+            segments("👨‍👩‍👧‍👦👍🏽‍🔥"),
+            vec![
+                ("👨‍👩‍👧‍👦", SegmentKind::Emoji),
+                ("👍🏽‍🔥", SegmentKind::UnknownEmojiSequence),
+            ]
+        );
+    }
+
+    #[test]
+    fn keycaps_and_digits() {
+        assert_eq!(
+            segments("1️⃣23"),
+            vec![("1️⃣", SegmentKind::Emoji), ("23", SegmentKind::Text)]
+        );
+    }
+
+    #[test]
+    fn combining_characters_stay_with_their_base() {
+        // the combining enclosing keycap belongs to the `a` and not to the text after it
+        assert_eq!(
+            segments("a\u{20e3}😀e\u{301}"),
+            vec![
+                ("a\u{20e3}", SegmentKind::Text),
+                ("😀", SegmentKind::Emoji),
+                ("e\u{301}", SegmentKind::Text),
+            ]
+        );
+    }
+}
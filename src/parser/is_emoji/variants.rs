@@ -0,0 +1,364 @@
+//! Decomposing emoji into a base emoji and its modifiers, for example to group the reactions
+//! `👍`, `👍🏽` and `👍🏿` together.
+
+use super::{emoji_qualification, EmojiQualification};
+use crate::parser::unicode_tables::emoji::{
+    FULLY_QUALIFIED_FORMS, SKIN_TONE_BASES, SKIN_TONE_VARIANTS,
+};
+
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{fe0f}';
+
+/// Skin tone of an emoji, the Fitzpatrick scale emoji modifiers `🏻` to `🏿`
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+pub enum SkinTone {
+    /// `U+1F3FB` 🏻
+    Light,
+    /// `U+1F3FC` 🏼
+    MediumLight,
+    /// `U+1F3FD` 🏽
+    Medium,
+    /// `U+1F3FE` 🏾
+    MediumDark,
+    /// `U+1F3FF` 🏿
+    Dark,
+}
+
+impl SkinTone {
+    const ALL: [SkinTone; 5] = [
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+
+    /// The emoji modifier character of this skin tone
+    pub fn modifier(self) -> char {
+        match self {
+            SkinTone::Light => '🏻',
+            SkinTone::MediumLight => '🏼',
+            SkinTone::Medium => '🏽',
+            SkinTone::MediumDark => '🏾',
+            SkinTone::Dark => '🏿',
+        }
+    }
+
+    pub fn from_modifier(c: char) -> Option<SkinTone> {
+        SkinTone::ALL
+            .iter()
+            .copied()
+            .find(|tone| tone.modifier() == c)
+    }
+}
+
+fn emoji_modifier(c: char) -> bool {
+    SkinTone::from_modifier(c).is_some()
+}
+
+/// Hair style emoji component, joined with a ZWJ to a person like `👩‍🦰`
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+pub enum HairStyle {
+    /// `U+1F9B0` 🦰
+    Red,
+    /// `U+1F9B1` 🦱
+    Curly,
+    /// `U+1F9B2` 🦲
+    Bald,
+    /// `U+1F9B3` 🦳
+    White,
+}
+
+impl HairStyle {
+    pub fn component(self) -> char {
+        match self {
+            HairStyle::Red => '🦰',
+            HairStyle::Curly => '🦱',
+            HairStyle::Bald => '🦲',
+            HairStyle::White => '🦳',
+        }
+    }
+
+    pub fn from_component(c: char) -> Option<HairStyle> {
+        [
+            HairStyle::Red,
+            HairStyle::Curly,
+            HairStyle::Bald,
+            HairStyle::White,
+        ]
+        .iter()
+        .copied()
+        .find(|hair_style| hair_style.component() == c)
+    }
+}
+
+/// Gender sign that is joined with a ZWJ to a person like `🏃‍♀️`
+///
+/// Emoji that are gendered by their base emoji, like `👩` or `👨‍👩‍👧`, are not decomposed.
+#[derive(Debug, PartialEq, Eq, Serialize, Clone, Copy)]
+pub enum Gender {
+    /// `U+2640` ♀
+    Female,
+    /// `U+2642` ♂
+    Male,
+}
+
+impl Gender {
+    pub fn sign(self) -> char {
+        match self {
+            Gender::Female => '♀',
+            Gender::Male => '♂',
+        }
+    }
+
+    pub fn from_sign(c: char) -> Option<Gender> {
+        [Gender::Female, Gender::Male]
+            .iter()
+            .copied()
+            .find(|gender| gender.sign() == c)
+    }
+}
+
+/// An emoji split into its base emoji and modifiers, see [decompose_emoji]
+#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+pub struct DecomposedEmoji {
+    /// the fully-qualified emoji without skin tones, hair style and gender sign
+    pub base: String,
+    /// skin tones in order of appearance, emoji with multiple persons like `🧑🏻‍🤝‍🧑🏿` can have more than one
+    pub skin_tones: Vec<SkinTone>,
+    pub hair_style: Option<HairStyle>,
+    pub gender: Option<Gender>,
+    /// whether the emoji contains an emoji presentation selector (`U+FE0F`, VS16)
+    pub emoji_presentation_selector: bool,
+}
+
+/// Returns the fully-qualified form of an emoji without emoji presentation selectors
+pub(super) fn fully_qualified(emoji: &str) -> String {
+    lookup(FULLY_QUALIFIED_FORMS, emoji)
+        .unwrap_or(emoji)
+        .to_owned()
+}
+
+fn lookup<T: Copy>(table: &[(&str, T)], key: &str) -> Option<T> {
+    let index = table.binary_search_by(|(k, _)| (*k).cmp(key)).ok()?;
+    table.get(index).map(|(_, value)| *value)
+}
+
+/// The fully-qualified emoji without skin tones, but with the other modifiers
+fn without_skin_tones(emoji: &str) -> String {
+    let toneless: String = emoji
+        .chars()
+        .filter(|c| !emoji_modifier(*c) && *c != EMOJI_PRESENTATION_SELECTOR)
+        .collect();
+    match lookup(SKIN_TONE_BASES, &toneless) {
+        // like the handshake `🫱🏻‍🫲🏿`, whose base is `🤝`
+        Some(base) => base.to_owned(),
+        None => fully_qualified(&toneless),
+    }
+}
+
+/// Splits `emoji` into its base emoji and modifiers, returns `None` if it is not exactly one emoji
+///
+/// ```
+/// use deltachat_message_parser::parser::is_emoji::{decompose_emoji, Gender, SkinTone};
+///
+/// let decomposed = decompose_emoji("🏃🏽‍♀️").unwrap();
+/// assert_eq!(decomposed.base, "🏃");
+/// assert_eq!(decomposed.skin_tones, vec![SkinTone::Medium]);
+/// assert_eq!(decomposed.gender, Some(Gender::Female));
+/// ```
+pub fn decompose_emoji(emoji: &str) -> Option<DecomposedEmoji> {
+    let qualification = emoji_qualification(emoji)?;
+    let emoji_presentation_selector = emoji.contains(EMOJI_PRESENTATION_SELECTOR);
+    if qualification == EmojiQualification::Component {
+        // a skin tone or hair style on its own
+        return Some(DecomposedEmoji {
+            base: emoji.to_owned(),
+            skin_tones: Vec::new(),
+            hair_style: None,
+            gender: None,
+            emoji_presentation_selector,
+        });
+    }
+
+    let skin_tones = emoji.chars().filter_map(SkinTone::from_modifier).collect();
+    let mut decomposed = DecomposedEmoji {
+        base: without_skin_tones(emoji),
+        skin_tones,
+        hair_style: None,
+        gender: None,
+        emoji_presentation_selector,
+    };
+
+    let mut hair_style = None;
+    let mut gender = None;
+    let mut parts = Vec::new();
+    for (index, part) in decomposed.base.split(ZERO_WIDTH_JOINER).enumerate() {
+        let part = part.trim_end_matches(EMOJI_PRESENTATION_SELECTOR);
+        let mut chars = part.chars();
+        let single_char = match (chars.next(), chars.next()) {
+            (Some(c), None) if index > 0 => Some(c),
+            _ => None,
+        };
+        if let Some(style) = single_char.and_then(HairStyle::from_component) {
+            hair_style = Some(style);
+        } else if let Some(sign) = single_char.and_then(Gender::from_sign) {
+            gender = Some(sign);
+        } else {
+            parts.push(part);
+        }
+    }
+    if hair_style.is_some() || gender.is_some() {
+        let base = fully_qualified(&parts.join(&ZERO_WIDTH_JOINER.to_string()));
+        // only if the emoji without them still exists
+        if emoji_qualification(&base) == Some(EmojiQualification::FullyQualified) {
+            decomposed.base = base;
+            decomposed.hair_style = hair_style;
+            decomposed.gender = gender;
+        }
+    }
+    Some(decomposed)
+}
+
+/// Returns the fully-qualified base emoji without skin tone, hair style and gender sign,
+/// so `👍`, `👍🏽` and `👍🏿` all become `👍`. Returns `None` if `emoji` is not exactly one emoji.
+pub fn canonical_base_emoji(emoji: &str) -> Option<String> {
+    decompose_emoji(emoji).map(|decomposed| decomposed.base)
+}
+
+/// Returns `emoji` with the given skin tone instead of its current skin tones,
+/// the hair style and gender sign are kept
+///
+/// Returns `None` if `emoji` is not exactly one emoji or the emoji has no skin tone variants,
+/// like `👨‍👩‍👧` or `🐶`.
+pub fn apply_skin_tone(emoji: &str, skin_tone: SkinTone) -> Option<String> {
+    emoji_qualification(emoji)?;
+    let variants = lookup(SKIN_TONE_VARIANTS, &without_skin_tones(emoji))?;
+    variants
+        .get(skin_tone as usize)
+        .map(|variant| (*variant).to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        apply_skin_tone, canonical_base_emoji, decompose_emoji, DecomposedEmoji, Gender, HairStyle,
+        SkinTone,
+    };
+
+    #[test]
+    fn group_reactions() {
+        assert_eq!(canonical_base_emoji("👍"), Some("👍".to_owned()));
+        assert_eq!(canonical_base_emoji("👍🏽"), Some("👍".to_owned()));
+        assert_eq!(canonical_base_emoji("👍🏿"), Some("👍".to_owned()));
+        // unqualified forms become fully-qualified
+        assert_eq!(canonical_base_emoji("❤"), Some("❤️".to_owned()));
+        assert_eq!(canonical_base_emoji("🖐🏽"), Some("🖐️".to_owned()));
+    }
+
+    #[test]
+    fn not_one_emoji() {
+        assert_eq!(canonical_base_emoji(""), None);
+        assert_eq!(canonical_base_emoji("a"), None);
+        assert_eq!(canonical_base_emoji("👍👍"), None);
+        assert_eq!(canonical_base_emoji("👍 "), None);
+    }
+
+    #[test]
+    fn gender_and_hair_style() {
+        assert_eq!(
+            decompose_emoji("🏃🏽‍♀️"),
+            Some(DecomposedEmoji {
+                base: "🏃".to_owned(),
+                skin_tones: vec![SkinTone::Medium],
+                hair_style: None,
+                gender: Some(Gender::Female),
+                emoji_presentation_selector: true,
+            })
+        );
+        assert_eq!(
+            decompose_emoji("👩🏽‍🦰"),
+            Some(DecomposedEmoji {
+                base: "👩".to_owned(),
+                skin_tones: vec![SkinTone::Medium],
+                hair_style: Some(HairStyle::Red),
+                gender: None,
+                emoji_presentation_selector: false,
+            })
+        );
+        assert_eq!(canonical_base_emoji("🧑‍🦲"), Some("🧑".to_owned()));
+        // gendered base emoji are kept
+        assert_eq!(canonical_base_emoji("👨‍👩‍👧"), Some("👨‍👩‍👧".to_owned()));
+        // other zwj sequences are kept
+        assert_eq!(canonical_base_emoji("🧑🏾‍⚕️"), Some("🧑‍⚕️".to_owned()));
+    }
+
+    #[test]
+    fn multiple_skin_tones() {
+        assert_eq!(
+            decompose_emoji("🫱🏻‍🫲🏿"),
+            Some(DecomposedEmoji {
+                base: "🤝".to_owned(),
+                skin_tones: vec![SkinTone::Light, SkinTone::Dark],
+                hair_style: None,
+                gender: None,
+                emoji_presentation_selector: false,
+            })
+        );
+        assert_eq!(canonical_base_emoji("👩🏻‍🤝‍👨🏿"), Some("👫".to_owned()));
+        assert_eq!(
+            canonical_base_emoji("🧑🏻‍🤝‍🧑🏼"),
+            Some("🧑‍🤝‍🧑".to_owned())
+        );
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(canonical_base_emoji("🏽"), Some("🏽".to_owned()));
+        assert_eq!(canonical_base_emoji("🦰"), Some("🦰".to_owned()));
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(
+            apply_skin_tone("👍", SkinTone::Light),
+            Some("👍🏻".to_owned())
+        );
+        assert_eq!(
+            apply_skin_tone("👍🏿", SkinTone::Light),
+            Some("👍🏻".to_owned())
+        );
+        assert_eq!(
+            apply_skin_tone("🏃‍♂️", SkinTone::Medium),
+            Some("🏃🏽‍♂️".to_owned())
+        );
+        assert_eq!(
+            apply_skin_tone("🖐", SkinTone::MediumDark),
+            Some("🖐🏾".to_owned())
+        );
+        assert_eq!(
+            apply_skin_tone("🧑‍🤝‍🧑", SkinTone::Dark),
+            Some("🧑🏿‍🤝‍🧑🏿".to_owned())
+        );
+        assert_eq!(
+            apply_skin_tone("🫱🏻‍🫲🏿", SkinTone::Medium),
+            Some("🤝🏽".to_owned())
+        );
+    }
+
+    #[test]
+    fn apply_unsupported() {
+        assert_eq!(apply_skin_tone("👨‍👩‍👧", SkinTone::Dark), None);
+        assert_eq!(apply_skin_tone("🐶", SkinTone::Dark), None);
+        assert_eq!(apply_skin_tone("🏽", SkinTone::Dark), None);
+        assert_eq!(apply_skin_tone("a", SkinTone::Dark), None);
+    }
+
+    #[test]
+    fn modifiers() {
+        for tone in SkinTone::ALL.iter().copied() {
+            assert_eq!(SkinTone::from_modifier(tone.modifier()), Some(tone));
+        }
+        assert_eq!(SkinTone::from_modifier('a'), None);
+    }
+}
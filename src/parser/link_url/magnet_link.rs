@@ -0,0 +1,206 @@
+//! Structured data for `magnet:` links, so clients can show a preview with name and size
+//!
+//! see <https://en.wikipedia.org/wiki/Magnet_URI_scheme> and
+//! [BEP 9](https://www.bittorrent.org/beps/bep_0009.html) / [BEP 52](https://www.bittorrent.org/beps/bep_0052.html)
+
+use std::convert::TryFrom;
+
+use super::{query_parameters::parse_query, JS_MAX_SAFE_INTEGER};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(tag = "t", content = "c")]
+pub enum ExactTopic {
+    /// `urn:btih:` BitTorrent v1 info hash, normalized to 40 lowercase hex digits
+    BitTorrentInfoHash(String),
+    /// `urn:btmh:` BitTorrent v2 info hash, the sha256 multihash as lowercase hex digits
+    BitTorrentInfoHashV2(String),
+    /// any other urn, like `urn:sha1:` or `urn:ed2k:`
+    Other(String),
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct MagnetLink {
+    /// `xt` - the hashes of the content
+    pub exact_topics: Vec<ExactTopic>,
+    /// `dn` - file name to display, percent decoded
+    pub display_name: Option<String>,
+    /// `xl` - size of the content in bytes
+    pub exact_length: Option<u64>,
+    /// `tr` - tracker urls, percent decoded
+    pub trackers: Vec<String>,
+    /// `ws` - web seed urls, percent decoded
+    pub web_seeds: Vec<String>,
+}
+
+impl MagnetLink {
+    /// Parses the part after `magnet:`, returns `None` if there is no query
+    pub(crate) fn parse(content: &str) -> Option<MagnetLink> {
+        let query = content.strip_prefix('?')?;
+        let mut magnet_link = MagnetLink {
+            exact_topics: Vec::new(),
+            display_name: None,
+            exact_length: None,
+            trackers: Vec::new(),
+            web_seeds: Vec::new(),
+        };
+        for (key, value) in parse_query(query) {
+            // multiple values of the same parameter can be numbered like `xt.1`, `xt.2`
+            let key = match key.split_once('.') {
+                Some((key, index)) if index.chars().all(|c| c.is_ascii_digit()) => key,
+                _ => &key,
+            };
+            match key {
+                "xt" => {
+                    if let Some(topic) = parse_exact_topic(&value) {
+                        magnet_link.exact_topics.push(topic);
+                    }
+                }
+                "dn" => magnet_link.display_name = Some(value),
+                "xl" => {
+                    magnet_link.exact_length = value
+                        .parse()
+                        .ok()
+                        .filter(|length| *length <= JS_MAX_SAFE_INTEGER)
+                }
+                "tr" => magnet_link.trackers.push(value),
+                "ws" => magnet_link.web_seeds.push(value),
+                _ => {}
+            }
+        }
+        Some(magnet_link)
+    }
+}
+
+fn parse_exact_topic(urn: &str) -> Option<ExactTopic> {
+    if let Some(hash) = strip_prefix_ignore_case(urn, "urn:btih:") {
+        let hash = match hash.len() {
+            40 if hash.chars().all(|c| c.is_ascii_hexdigit()) => hash.to_ascii_lowercase(),
+            32 => to_hex(&base32_decode(hash)?),
+            _ => return None,
+        };
+        Some(ExactTopic::BitTorrentInfoHash(hash))
+    } else if let Some(hash) = strip_prefix_ignore_case(urn, "urn:btmh:") {
+        // multihash of sha256: 0x12 (sha2-256), 0x20 (32 bytes), digest
+        let is_sha256_multihash = hash.len() == 68
+            && hash.chars().all(|c| c.is_ascii_hexdigit())
+            && hash.get(..4) == Some("1220");
+        if !is_sha256_multihash {
+            return None;
+        }
+        Some(ExactTopic::BitTorrentInfoHashV2(hash.to_ascii_lowercase()))
+    } else if strip_prefix_ignore_case(urn, "urn:").is_some() {
+        Some(ExactTopic::Other(urn.to_owned()))
+    } else {
+        None
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let start = input.get(..prefix.len())?;
+    if start.eq_ignore_ascii_case(prefix) {
+        input.get(prefix.len()..)
+    } else {
+        None
+    }
+}
+
+/// RFC 4648 base32 without padding
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut bytes = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    for c in input.chars() {
+        let value = ALPHABET.find(c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | u32::try_from(value).ok()?;
+        bits = bits.saturating_add(5);
+        if bits >= 8 {
+            bits = bits.saturating_sub(8);
+            bytes.push(buffer.checked_shr(bits)?.to_le_bytes().first().copied()?);
+            buffer &= 1u32.checked_shl(bits)?.wrapping_sub(1);
+        }
+    }
+    Some(bytes)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ExactTopic, MagnetLink};
+
+    #[test]
+    fn full_magnet_link() {
+        assert_eq!(
+            MagnetLink::parse("?xt=urn:btih:C12FE1C06BBA254A9DC9F519B335AA7C1367A88A&dn=Ubuntu%2024.04%20Desktop.iso&xl=6114656256&tr=udp%3A%2F%2Ftracker.example.org%3A1337&tr=https://tracker.example.com/announce&ws=https%3A%2F%2Fexample.com%2Fubuntu.iso"),
+            Some(MagnetLink {
+                exact_topics: vec![ExactTopic::BitTorrentInfoHash(
+                    "c12fe1c06bba254a9dc9f519b335aa7c1367a88a".to_owned()
+                )],
+                display_name: Some("Ubuntu 24.04 Desktop.iso".to_owned()),
+                exact_length: Some(6_114_656_256),
+                trackers: vec![
+                    "udp://tracker.example.org:1337".to_owned(),
+                    "https://tracker.example.com/announce".to_owned()
+                ],
+                web_seeds: vec!["https://example.com/ubuntu.iso".to_owned()],
+            })
+        );
+    }
+
+    #[test]
+    fn base32_info_hash() {
+        assert_eq!(
+            MagnetLink::parse("?xt=urn:btih:YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKEK")
+                .map(|m| m.exact_topics),
+            Some(vec![ExactTopic::BitTorrentInfoHash(
+                "c12fe1c06bba254a9dc9f519b335aa7c1367a88a".to_owned()
+            )])
+        );
+    }
+
+    #[test]
+    fn hybrid_torrent() {
+        assert_eq!(
+            MagnetLink::parse("?xt=urn:btih:631a31dd0a46257d5078c0dee4e66e26f73e42ac&xt=urn:btmh:1220d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb&dn=bittorrent-v1-v2-hybrid-test").map(|m| m.exact_topics),
+            Some(vec![
+                ExactTopic::BitTorrentInfoHash(
+                    "631a31dd0a46257d5078c0dee4e66e26f73e42ac".to_owned()
+                ),
+                ExactTopic::BitTorrentInfoHashV2(
+                    "1220d8dd32ac93357c368556af3ac1d95c9d76bd0dff6fa9833ecdac3d53134efabb"
+                        .to_owned()
+                )
+            ])
+        );
+    }
+
+    #[test]
+    fn numbered_and_other_parameters() {
+        assert_eq!(
+            MagnetLink::parse("?xt.1=urn:sha1:YNCKHTQCWBTRNJIV4WNAE52SJUQCZO5C&xt.2=urn:btih:invalid&tr.1=udp://a.example&tr.2=udp://b.example&so=0-3&xl=big"),
+            Some(MagnetLink {
+                exact_topics: vec![ExactTopic::Other(
+                    "urn:sha1:YNCKHTQCWBTRNJIV4WNAE52SJUQCZO5C".to_owned()
+                )],
+                display_name: None,
+                exact_length: None,
+                trackers: vec!["udp://a.example".to_owned(), "udp://b.example".to_owned()],
+                web_seeds: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn no_query() {
+        assert_eq!(MagnetLink::parse("xt=urn:btih:abc"), None);
+    }
+}
@@ -1,16 +1,30 @@
 mod allowed_tlds;
 mod ip;
+mod magnet_link;
 mod parenthesis_counter;
 mod parse_link;
+mod payment_request;
 pub(crate) mod punycode_warning;
+mod query_parameters;
 
+pub use magnet_link::{ExactTopic, MagnetLink};
 use nom::{
     error::{ErrorKind, ParseError},
     IResult, Slice,
 };
+pub use payment_request::{AddressChecksum, PaymentCurrency, PaymentRequest};
 pub use punycode_warning::PunycodeWarning;
 
 use crate::parser::{link_url::parse_link::parse_link, parse_from_text::base_parsers::CustomError};
+pub(crate) use allowed_tlds::check_if_tld_is_allowed;
+pub(crate) use parse_link::{
+    is_allowed_generic_scheme, is_ireg_name_not_pct_encoded, is_scheme, is_scheme_start,
+    MAX_GENERIC_SCHEME_LENGTH,
+};
+
+/// biggest integer that javascript can represent exactly (`Number.MAX_SAFE_INTEGER`),
+/// numbers parsed from links are limited to it so they survive the conversion to JSON
+pub(crate) const JS_MAX_SAFE_INTEGER: u64 = 9_007_199_254_740_991;
 
 /* Parsing / Validation of URLs
  *
@@ -25,16 +39,23 @@ use crate::parser::{link_url::parse_link::parse_link, parse_from_text::base_pars
  * [1] RFC1738(Section 3.1), RFC3987, RFC3988 --Farooq
  */
 
-#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LinkDestination<'a> {
     pub target: &'a str,
     /// hostname if it was found
+    #[serde(borrow)]
     pub hostname: Option<&'a str>,
     /// contains data for the punycode warning if punycode was detected
     /// (the host part contains non ascii unicode characters)
     pub punycode: Option<PunycodeWarning>,
     /// scheme
+    #[serde(borrow)]
     pub scheme: Option<&'a str>,
+    /// contains the parsed payment data for `bitcoin:`, `bitcoincash:`, `ethereum:` and `eth:` links
+    pub payment_request: Option<PaymentRequest>,
+    /// contains the parsed hashes, name, size and trackers for `magnet:` links
+    pub magnet: Option<MagnetLink>,
 }
 
 impl LinkDestination<'_> {
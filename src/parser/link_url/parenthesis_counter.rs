@@ -22,7 +22,7 @@ macro_rules! adjust_balance {
     };
 }
 
-/// finds unbalanced closing parenthesesis and returns distance to it.
+/// finds unbalanced closing parenthesesis and returns distance to it in bytes.
 /// unbalanced means it was closed but not opened before in the given string
 pub(super) fn count_chars_in_complete_parenthesis(input: &str) -> Option<usize> {
     let mut parenthes = 0usize; // ()
@@ -30,7 +30,7 @@ pub(super) fn count_chars_in_complete_parenthesis(input: &str) -> Option<usize>
     let mut bracket = 0usize; // []
     let mut angle = 0usize; // <>
 
-    for (i, ch) in input.chars().enumerate() {
+    for (i, ch) in input.char_indices() {
         match ch {
             '(' => {
                 adjust_balance!(parenthes, ')', i, input);
@@ -79,3 +79,9 @@ fn test_count_different_types_invalid() {
 fn test_count_different_types_invalid2() {
     assert_eq!(count_chars_in_complete_parenthesis("}(({(})))"), Some(0));
 }
+
+#[test]
+fn test_count_parenthesis_after_multibyte_characters() {
+    assert_eq!(count_chars_in_complete_parenthesis("ä)"), Some(2));
+    assert_eq!(count_chars_in_complete_parenthesis("🇩🇪(a"), Some(8));
+}
@@ -1,5 +1,3 @@
-use std::ops::RangeInclusive;
-
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_while, take_while1, take_while_m_n},
@@ -16,6 +14,7 @@ use crate::parser::{
         LinkDestination,
     },
     parse_from_text::base_parsers::CustomError,
+    unicode_tables::rfc3987::{IPRIVATE_RANGES, UCSCHAR_RANGES},
     utils::{
         is_alpha, is_digit, is_hex_digit, is_in_one_of_ranges, is_not_white_space, is_sub_delim,
         is_unreserved,
@@ -23,13 +22,16 @@ use crate::parser::{
 };
 
 use super::{
-    allowed_tlds::check_if_tld_is_allowed,
-    parenthesis_counter::count_chars_in_complete_parenthesis,
+    allowed_tlds::check_if_tld_is_allowed, magnet_link::MagnetLink,
+    parenthesis_counter::count_chars_in_complete_parenthesis, payment_request::PaymentRequest,
     punycode_warning::get_puny_code_warning,
 };
 
+/// length of the longest scheme of [is_allowed_generic_scheme]
+pub(crate) const MAX_GENERIC_SCHEME_LENGTH: usize = "bitcoincash".len();
+
 /// determines which generic schemes (without '://') get linkifyed
-fn is_allowed_generic_scheme(scheme: &str) -> bool {
+pub(crate) fn is_allowed_generic_scheme(scheme: &str) -> bool {
     matches!(
         scheme.to_ascii_lowercase().as_ref(),
         "mailto"
@@ -47,29 +49,8 @@ fn is_allowed_generic_scheme(scheme: &str) -> bool {
     )
 }
 
-// These ranges have been extracted from RFC3987, Page 8.
-const UCSCHAR_RANGES: [RangeInclusive<u32>; 17] = [
-    0xa0..=0xd7ff,
-    0xF900..=0xFDCF,
-    0xFDF0..=0xFFEF,
-    0x10000..=0x1FFFD,
-    0x20000..=0x2FFFD,
-    0x30000..=0x3FFFD,
-    0x40000..=0x4FFFD,
-    0x50000..=0x5FFFD,
-    0x60000..=0x6FFFD,
-    0x70000..=0x7FFFD,
-    0x80000..=0x8FFFD,
-    0x90000..=0x9FFFD,
-    0xA0000..=0xAFFFD,
-    0xB0000..=0xBFFFD,
-    0xC0000..=0xCFFFD,
-    0xD0000..=0xDFFFD,
-    0xE1000..=0xEFFFD,
-];
-
 fn is_ucschar(c: char) -> bool {
-    is_in_one_of_ranges(c as u32, &UCSCHAR_RANGES[..])
+    is_in_one_of_ranges(c as u32, UCSCHAR_RANGES)
 }
 
 fn is_iunreserved(c: char) -> bool {
@@ -78,10 +59,14 @@ fn is_iunreserved(c: char) -> bool {
 
 // Here again, order is important. As URLs/IRIs have letters in them
 // most of the time and less digits or other characters. --Farooq
-fn is_scheme(c: char) -> bool {
+pub(crate) fn is_scheme(c: char) -> bool {
     is_alpha(c) || is_digit(c) || is_other_scheme(c)
 }
 
+pub(crate) fn is_scheme_start(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
 fn is_other_scheme(c: char) -> bool {
     matches!(c, '+' | '-' | '.')
 }
@@ -89,7 +74,7 @@ fn is_other_scheme(c: char) -> bool {
 /**
  * allowed chars in host names (except for pct encoded)
  */
-fn is_ireg_name_not_pct_encoded(c: char) -> bool {
+pub(crate) fn is_ireg_name_not_pct_encoded(c: char) -> bool {
     is_iunreserved(c)
 }
 
@@ -181,11 +166,8 @@ fn take_while_ipchar1(input: &str) -> IResult<&str, &str, CustomError<&str>> {
     ))))(input)
 }
 
-const IPRIVATE_RANGES: [RangeInclusive<u32>; 3] =
-    [0xe000..=0xf8ff, 0xf0000..=0xffffd, 0x100000..=0x10fffd];
-
 fn is_iprivate(c: char) -> bool {
-    is_in_one_of_ranges(c as u32, &IPRIVATE_RANGES[..])
+    is_in_one_of_ranges(c as u32, IPRIVATE_RANGES)
 }
 
 fn is_iquery_not_pct_encoded(c: char) -> bool {
@@ -209,10 +191,12 @@ fn take_while_ifragment(input: &str) -> IResult<&str, &str, CustomError<&str>> {
 /// # Description
 /// This function as it can be seen, consumes exactly one alpha and as many
 /// scheme characters as there are. then it gets a slice of input(as cloned to i)
+///
+/// The first character is an ascii letter, like in RFC3986
 fn scheme_and_separator(input: &str) -> IResult<&str, (&str, &str), CustomError<&str>> {
     let _input = <&str>::clone(&input);
     let (input, (_first, second)) =
-        pair(take_while_m_n(1, 1, is_alpha), take_while(is_scheme))(input)?;
+        pair(take_while_m_n(1, 1, is_scheme_start), take_while(is_scheme))(input)?;
     // "1" is for the first, its length is always 1
     let len = 1usize.saturating_add(second.len());
     if let Some(scheme) = _input.get(0..len) {
@@ -283,7 +267,7 @@ fn parse_iri(input: &str) -> IResult<&str, LinkDestination, CustomError<&str>> {
 
     // host is actually part of authority but we need it separately
     // see iauthority function description for more information
-    let (input, (authority, mut host, is_ipv6_or_future)) = iauthority(input)?;
+    let (input, (authority, host, is_ipv6_or_future)) = iauthority(input)?;
 
     // now with host, if we dont have a scheme we need to check it for TLD
     if scheme.is_empty() {
@@ -334,12 +318,8 @@ fn parse_iri(input: &str) -> IResult<&str, LinkDestination, CustomError<&str>> {
         .saturating_add(fragment.len());
     if let Some(link) = input_.get(0..len) {
         if link.ends_with([':', ';', '.', ',', '!']) {
+            // the host can't end with one of these characters, only an empty port or the path
             len = len.saturating_sub(1);
-            if path.is_empty() && query.is_empty() && fragment.is_empty() {
-                host = input_.slice(
-                    scheme.len().saturating_add(separator.len())..input_.len().saturating_sub(1),
-                );
-            }
         }
         len = count_chars_in_complete_parenthesis(link).unwrap_or(len);
         let link = input_.slice(0..len);
@@ -360,6 +340,8 @@ fn parse_iri(input: &str) -> IResult<&str, LinkDestination, CustomError<&str>> {
                 } else {
                     Some(scheme)
                 },
+                payment_request: None,
+                magnet: None,
             },
         ));
     }
@@ -370,13 +352,16 @@ fn parse_iri(input: &str) -> IResult<&str, LinkDestination, CustomError<&str>> {
 fn parse_generic(input: &str) -> IResult<&str, LinkDestination, CustomError<&str>> {
     let i = <&str>::clone(&input);
     let (input, scheme_parts) = opt(scheme_and_separator)(input)?;
-    let (scheme, _separator) = scheme_parts.unwrap_or(("", ""));
+    let (scheme, separator) = scheme_parts.unwrap_or(("", ""));
     if !is_allowed_generic_scheme(scheme) {
         return Err(nom::Err::Error(CustomError::InvalidLink));
     }
 
     let (input, rest) = take_while1(is_not_white_space)(input)?;
-    let len = scheme.len().saturating_add(1).saturating_add(rest.len());
+    let len = scheme
+        .len()
+        .saturating_add(separator.len())
+        .saturating_add(rest.len());
     if let Some(target) = i.get(0..len) {
         return Ok((
             input,
@@ -385,6 +370,12 @@ fn parse_generic(input: &str) -> IResult<&str, LinkDestination, CustomError<&str
                 target,
                 hostname: None,
                 punycode: None,
+                payment_request: PaymentRequest::parse(scheme, rest),
+                magnet: if scheme.eq_ignore_ascii_case("magnet") {
+                    MagnetLink::parse(rest)
+                } else {
+                    None
+                },
             },
         ));
     }
@@ -0,0 +1,87 @@
+//! Structured data for cryptocurrency payment links
+//!
+//! - `bitcoin:` and `bitcoincash:` according to [BIP-21](https://github.com/bitcoin/bips/blob/master/bip-0021.mediawiki)
+//! - `ethereum:` and `eth:` according to [EIP-681](https://eips.ethereum.org/EIPS/eip-681)
+//!
+//! The parsed data is meant for showing a confirmation before the link is handed to a wallet,
+//! so the address checksum is checked to catch typos and tampered addresses.
+
+mod address_checksum;
+mod bip21;
+mod eip681;
+
+pub use address_checksum::AddressChecksum;
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum PaymentCurrency {
+    Bitcoin,
+    BitcoinCash,
+    Ethereum,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct PaymentRequest {
+    pub currency: PaymentCurrency,
+    /// address of the receiver, for EIP-681 this can also be an ENS name
+    pub address: String,
+    /// result of the checksum validation of [PaymentRequest::address]
+    pub address_checksum: AddressChecksum,
+    /// amount as written in the link, without any conversion
+    ///
+    /// - BIP-21: decimal amount in the main unit (BTC or BCH)
+    /// - EIP-681: the `value` parameter in wei, may use scientific notation like `2.014e18`
+    pub amount: Option<String>,
+    /// BIP-21 `label`, percent decoded
+    pub label: Option<String>,
+    /// BIP-21 `message`, percent decoded
+    pub message: Option<String>,
+    /// EIP-681 chain id (`@1` is ethereum mainnet)
+    pub chain_id: Option<u64>,
+    /// EIP-681 contract function that should be called, for example `transfer`
+    pub function_name: Option<String>,
+    /// all other parameters in the order they appear in the link, percent decoded
+    pub parameters: Vec<(String, String)>,
+}
+
+impl PaymentRequest {
+    /// Parses the part after the `scheme:` of a payment link.
+    ///
+    /// Returns `None` if the scheme is no payment scheme or if the link is invalid
+    /// according to its specification.
+    pub(crate) fn parse(scheme: &str, content: &str) -> Option<PaymentRequest> {
+        match scheme.to_ascii_lowercase().as_ref() {
+            "bitcoin" => bip21::parse(PaymentCurrency::Bitcoin, content),
+            "bitcoincash" => bip21::parse(PaymentCurrency::BitcoinCash, content),
+            "ethereum" | "eth" => eip681::parse(content),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AddressChecksum, PaymentCurrency, PaymentRequest};
+
+    #[test]
+    fn not_a_payment_scheme() {
+        assert_eq!(PaymentRequest::parse("mailto", "hello@delta.chat"), None);
+        assert_eq!(PaymentRequest::parse("magnet", "?xt=urn:btih:abc"), None);
+    }
+
+    #[test]
+    fn scheme_is_case_insensitive() {
+        let request = PaymentRequest::parse("BITCOIN", "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+        assert_eq!(
+            request.map(|r| (r.currency, r.address_checksum)),
+            Some((PaymentCurrency::Bitcoin, AddressChecksum::Valid))
+        );
+
+        let request = PaymentRequest::parse("eth", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(
+            request.map(|r| (r.currency, r.address_checksum)),
+            Some((PaymentCurrency::Ethereum, AddressChecksum::Valid))
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Checksum validation for the address formats used in payment links
+//!
+//! - base58check for legacy bitcoin addresses
+//! - bech32 / bech32m ([BIP-173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki), [BIP-350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)) for segwit addresses
+//! - cashaddr for bitcoin cash addresses
+//! - mixed case checksum ([EIP-55](https://eips.ethereum.org/EIPS/eip-55)) for ethereum addresses
+
+use std::convert::TryFrom;
+
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AddressChecksum {
+    /// address contains a checksum and it matches
+    Valid,
+    /// address contains a checksum, but it does not match - likely a typo or a tampered address
+    Invalid,
+    /// address format has no checksum, for example an all lowercase ethereum address
+    NotChecksummed,
+    /// address format is unknown, so nothing could be checked (for example an ENS name)
+    Unknown,
+}
+
+impl From<bool> for AddressChecksum {
+    fn from(valid: bool) -> Self {
+        if valid {
+            AddressChecksum::Valid
+        } else {
+            AddressChecksum::Invalid
+        }
+    }
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// 25 bytes (version, 160 bit hash and checksum) are at most 35 base58 characters,
+/// longer addresses are not decoded, because decoding base58 takes quadratic time
+const MAX_BASE58_ADDRESS_LENGTH: usize = 35;
+/// maximum length of a bech32 string including the human readable part, see BIP-173
+const MAX_BECH32_ADDRESS_LENGTH: usize = 90;
+/// version byte + 512 bit hash + 40 bit checksum, the largest hash size of cashaddr
+const MAX_CASHADDR_LENGTH: usize = 112;
+
+/// bitcoin address, either base58check or bech32/bech32m
+pub(super) fn bitcoin(address: &str) -> AddressChecksum {
+    let lowercase = address.to_ascii_lowercase();
+    if ["bc1", "tb1", "bcrt1"]
+        .iter()
+        .any(|hrp| lowercase.starts_with(hrp))
+    {
+        segwit(address)
+    } else if address.len() > MAX_BASE58_ADDRESS_LENGTH {
+        AddressChecksum::Unknown
+    } else if let Some(payload) = base58_decode(address) {
+        if payload.len() == 25 {
+            base58check(&payload).into()
+        } else {
+            AddressChecksum::Unknown
+        }
+    } else {
+        AddressChecksum::Unknown
+    }
+}
+
+fn base58_decode(input: &str) -> Option<Vec<u8>> {
+    if input.is_empty() {
+        return None;
+    }
+    // little endian while decoding
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in input.chars() {
+        let mut carry = u32::try_from(BASE58_ALPHABET.find(c)?).ok()?;
+        for byte in bytes.iter_mut() {
+            carry = u32::from(*byte).checked_mul(58)?.checked_add(carry)?;
+            *byte = carry.to_le_bytes().first().copied()?;
+            carry = carry.checked_shr(8)?;
+        }
+        while carry > 0 {
+            bytes.push(carry.to_le_bytes().first().copied()?);
+            carry = carry.checked_shr(8)?;
+        }
+    }
+    // leading '1's encode leading zero bytes
+    let leading_zeros = input.chars().take_while(|c| *c == '1').count();
+    bytes.extend(std::iter::repeat(0).take(leading_zeros));
+    bytes.reverse();
+    Some(bytes)
+}
+
+/// last 4 bytes are the start of the double sha256 of the rest
+fn base58check(payload: &[u8]) -> bool {
+    let (data, checksum) = payload.split_at(payload.len().saturating_sub(4));
+    let hash = Sha256::digest(Sha256::digest(data));
+    hash.get(..4) == Some(checksum)
+}
+
+fn segwit(address: &str) -> AddressChecksum {
+    if address.len() > MAX_BECH32_ADDRESS_LENGTH {
+        return AddressChecksum::Invalid;
+    }
+    // mixed case is not allowed
+    if address.to_ascii_lowercase() != address && address.to_ascii_uppercase() != address {
+        return AddressChecksum::Invalid;
+    }
+    let address = address.to_ascii_lowercase();
+    let Some((hrp, data)) = address.rsplit_once('1') else {
+        return AddressChecksum::Unknown;
+    };
+    let Some(values) = bech32_values(data) else {
+        return AddressChecksum::Invalid;
+    };
+    if values.len() < 7 {
+        return AddressChecksum::Invalid;
+    }
+    let witness_version = values.first().copied().unwrap_or_default();
+    let residue = bech32_polymod(bech32_hrp_expand(hrp).chain(values.iter().copied()));
+    // segwit version 0 uses bech32, all later versions use bech32m
+    let expected_residue = if witness_version == 0 { 1 } else { 0x2bc8_30a3 };
+    (residue == expected_residue).into()
+}
+
+fn bech32_values(data: &str) -> Option<Vec<u8>> {
+    data.chars()
+        .map(|c| BECH32_CHARSET.find(c).and_then(|v| u8::try_from(v).ok()))
+        .collect()
+}
+
+fn bech32_hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes()
+        .map(|b| b >> 5)
+        .chain(std::iter::once(0))
+        .chain(hrp.bytes().map(|b| b & 0x1f))
+}
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut checksum: u32 = 1;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(value);
+        for (bit, generator) in GENERATOR.iter().enumerate() {
+            if top.checked_shr(bit as u32).unwrap_or_default() & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// bitcoin cash address without the `prefix:`, which is part of the checksum nonetheless
+pub(super) fn cashaddr(prefix: &str, address: &str) -> AddressChecksum {
+    if address.len() > MAX_CASHADDR_LENGTH {
+        return AddressChecksum::Unknown;
+    }
+    if address.to_ascii_lowercase() != address && address.to_ascii_uppercase() != address {
+        return AddressChecksum::Invalid;
+    }
+    let address = address.to_ascii_lowercase();
+    let Some(values) = bech32_values(&address) else {
+        return AddressChecksum::Unknown;
+    };
+    // version byte + 160 bit hash + 40 bit checksum
+    if values.len() < 42 {
+        return AddressChecksum::Unknown;
+    }
+    let prefix_values = prefix.bytes().map(|b| b & 0x1f).chain(std::iter::once(0));
+    (cashaddr_polymod(prefix_values.chain(values.iter().copied())) == 0).into()
+}
+
+fn cashaddr_polymod(values: impl Iterator<Item = u8>) -> u64 {
+    const GENERATOR: [u64; 5] = [
+        0x98_f2bc_8e61,
+        0x79_b76d_99e2,
+        0xf3_3e5f_b3c4,
+        0xae_2eab_e2a8,
+        0x1e_4f43_e470,
+    ];
+    let mut checksum: u64 = 1;
+    for value in values {
+        let top = checksum >> 35;
+        checksum = ((checksum & 0x07_ffff_ffff) << 5) ^ u64::from(value);
+        for (bit, generator) in GENERATOR.iter().enumerate() {
+            if top.checked_shr(bit as u32).unwrap_or_default() & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum ^ 1
+}
+
+/// `0x` + 40 hex digits, mixed case addresses carry the EIP-55 checksum
+pub(super) fn ethereum(address: &str) -> AddressChecksum {
+    let Some(hex) = address.strip_prefix("0x") else {
+        return AddressChecksum::Unknown;
+    };
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return AddressChecksum::Unknown;
+    }
+    if hex.to_ascii_lowercase() == hex || hex.to_ascii_uppercase() == hex {
+        return AddressChecksum::NotChecksummed;
+    }
+    let hash = Keccak256::digest(hex.to_ascii_lowercase().as_bytes());
+    // every hex digit of the address has a corresponding nibble in the hash,
+    // letters must be uppercase if that nibble is 8 or higher
+    let nibbles = hash.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]);
+    hex.chars()
+        .zip(nibbles)
+        .all(|(c, nibble)| !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8))
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bitcoin, cashaddr, ethereum, AddressChecksum};
+
+    #[test]
+    fn base58check() {
+        assert_eq!(
+            bitcoin("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"),
+            AddressChecksum::Valid
+        );
+        assert_eq!(
+            bitcoin("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+            AddressChecksum::Valid
+        );
+        assert_eq!(
+            bitcoin("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"),
+            AddressChecksum::Invalid
+        );
+        // 0, O, I and l are not part of the alphabet
+        assert_eq!(
+            bitcoin("1A1zP1eP5QGefi2DMPTfTL5SLmv7Divf0a"),
+            AddressChecksum::Unknown
+        );
+    }
+
+    #[test]
+    fn bech32() {
+        assert_eq!(
+            bitcoin("BC1QT3XHFVWMDQVXKK089TLLVVTZQS8TS06U3U6QKA"),
+            AddressChecksum::Valid
+        );
+        assert_eq!(
+            bitcoin("tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7"),
+            AddressChecksum::Valid
+        );
+        assert_eq!(
+            bitcoin("bc1qt3xhfvwmdqvxkk089tllvvtzqs8ts06u3u6qkb"),
+            AddressChecksum::Invalid
+        );
+        assert_eq!(
+            bitcoin("bc1Qt3xhfvwmdqvxkk089tllvvtzqs8ts06u3u6qka"),
+            AddressChecksum::Invalid
+        );
+    }
+
+    #[test]
+    fn bech32m() {
+        assert_eq!(
+            bitcoin("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"),
+            AddressChecksum::Valid
+        );
+        // version 1 with a bech32 instead of a bech32m checksum
+        assert_eq!(
+            bitcoin("bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7k7grplx"),
+            AddressChecksum::Invalid
+        );
+    }
+
+    #[test]
+    fn bitcoin_cash() {
+        assert_eq!(
+            cashaddr("bitcoincash", "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"),
+            AddressChecksum::Valid
+        );
+        assert_eq!(
+            cashaddr("bitcoincash", "QPM2QSZNHKS23Z7629MMS6S4CWEF74VCWVY22GDX6A"),
+            AddressChecksum::Valid
+        );
+        assert_eq!(
+            cashaddr("bchtest", "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"),
+            AddressChecksum::Invalid
+        );
+    }
+
+    #[test]
+    fn too_long_addresses() {
+        // a valid address with an extra character
+        assert_eq!(
+            bitcoin("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNaa1"),
+            AddressChecksum::Unknown
+        );
+        assert_eq!(bitcoin(&"z".repeat(64_000)), AddressChecksum::Unknown);
+        assert_eq!(
+            bitcoin(&format!("bc1{}", "q".repeat(88))),
+            AddressChecksum::Invalid
+        );
+        assert_eq!(
+            cashaddr("bitcoincash", &"q".repeat(113)),
+            AddressChecksum::Unknown
+        );
+    }
+
+    #[test]
+    fn eip55() {
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            assert_eq!(ethereum(address), AddressChecksum::Valid, "{address}");
+        }
+        assert_eq!(
+            ethereum("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            AddressChecksum::Invalid
+        );
+        assert_eq!(
+            ethereum("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            AddressChecksum::NotChecksummed
+        );
+        assert_eq!(ethereum("delta.eth"), AddressChecksum::Unknown);
+        assert_eq!(ethereum("0x5aaeb6053f3e"), AddressChecksum::Unknown);
+    }
+}
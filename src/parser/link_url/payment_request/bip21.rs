@@ -0,0 +1,182 @@
+use crate::parser::link_url::query_parameters::parse_query;
+
+use super::{address_checksum, AddressChecksum, PaymentCurrency, PaymentRequest};
+
+/// `bitcoin:<address>[?amount=<amount>][?label=<label>][?message=<message>]`
+pub(super) fn parse(currency: PaymentCurrency, content: &str) -> Option<PaymentRequest> {
+    let (address, query) = content.split_once('?').unwrap_or((content, ""));
+    if address.is_empty() {
+        return None;
+    }
+
+    let mut request = PaymentRequest {
+        currency,
+        address: address.to_owned(),
+        address_checksum: check_address(currency, address),
+        amount: None,
+        label: None,
+        message: None,
+        chain_id: None,
+        function_name: None,
+        parameters: Vec::new(),
+    };
+
+    for (key, value) in parse_query(query) {
+        match key.as_ref() {
+            "amount" => {
+                if !is_valid_amount(&value) {
+                    return None;
+                }
+                request.amount = Some(value);
+            }
+            "label" => request.label = Some(value),
+            "message" => request.message = Some(value),
+            // "If a required parameter is not understood, the entire URI is invalid" - BIP-21
+            _ if key.starts_with("req-") => return None,
+            _ => request.parameters.push((key, value)),
+        }
+    }
+    Some(request)
+}
+
+fn check_address(currency: PaymentCurrency, address: &str) -> AddressChecksum {
+    let checksum = address_checksum::bitcoin(address);
+    if currency == PaymentCurrency::BitcoinCash && checksum == AddressChecksum::Unknown {
+        address_checksum::cashaddr("bitcoincash", address)
+    } else {
+        checksum
+    }
+}
+
+/// decimal number with `.` as separator, like `20.3` or `.5`
+fn is_valid_amount(amount: &str) -> bool {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    !(whole.is_empty() && fraction.is_empty())
+        && whole.chars().all(|c| c.is_ascii_digit())
+        && fraction.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::parse;
+    use crate::parser::link_url::payment_request::{
+        AddressChecksum, PaymentCurrency, PaymentRequest,
+    };
+
+    #[test]
+    fn address_only() {
+        assert_eq!(
+            parse(
+                PaymentCurrency::Bitcoin,
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"
+            ),
+            Some(PaymentRequest {
+                currency: PaymentCurrency::Bitcoin,
+                address: "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_owned(),
+                address_checksum: AddressChecksum::Valid,
+                amount: None,
+                label: None,
+                message: None,
+                chain_id: None,
+                function_name: None,
+                parameters: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn all_parameters() {
+        assert_eq!(
+            parse(
+                PaymentCurrency::Bitcoin,
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=20.3&label=Luke-Jr&message=Donation%20for%20project%20xyz&somethingyoudontunderstand=50"
+            ),
+            Some(PaymentRequest {
+                currency: PaymentCurrency::Bitcoin,
+                address: "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_owned(),
+                address_checksum: AddressChecksum::Valid,
+                amount: Some("20.3".to_owned()),
+                label: Some("Luke-Jr".to_owned()),
+                message: Some("Donation for project xyz".to_owned()),
+                chain_id: None,
+                function_name: None,
+                parameters: vec![("somethingyoudontunderstand".to_owned(), "50".to_owned())],
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_links() {
+        // unknown required parameter
+        assert_eq!(
+            parse(
+                PaymentCurrency::Bitcoin,
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?req-somethingyoudontunderstand=50"
+            ),
+            None
+        );
+        // amount with thousands separator
+        assert_eq!(
+            parse(
+                PaymentCurrency::Bitcoin,
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=1,000"
+            ),
+            None
+        );
+        assert_eq!(parse(PaymentCurrency::Bitcoin, "?amount=1"), None);
+    }
+
+    #[test]
+    fn checksums() {
+        let checksum = |currency, content| parse(currency, content).map(|r| r.address_checksum);
+        assert_eq!(
+            checksum(
+                PaymentCurrency::Bitcoin,
+                "bc1qt3xhfvwmdqvxkk089tllvvtzqs8ts06u3u6qka"
+            ),
+            Some(AddressChecksum::Valid)
+        );
+        // typo in the address
+        assert_eq!(
+            checksum(
+                PaymentCurrency::Bitcoin,
+                "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb"
+            ),
+            Some(AddressChecksum::Invalid)
+        );
+        assert_eq!(
+            checksum(
+                PaymentCurrency::BitcoinCash,
+                "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a?amount=0.1"
+            ),
+            Some(AddressChecksum::Valid)
+        );
+        assert_eq!(
+            checksum(
+                PaymentCurrency::BitcoinCash,
+                "qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6c"
+            ),
+            Some(AddressChecksum::Invalid)
+        );
+        assert_eq!(
+            checksum(PaymentCurrency::Bitcoin, "not-an-address"),
+            Some(AddressChecksum::Unknown)
+        );
+    }
+
+    #[test]
+    fn long_address_is_parsed_quickly() {
+        // base58 decoding takes quadratic time, so long addresses are not decoded
+        let address = "z".repeat(64_000);
+        let start = Instant::now();
+        for currency in [PaymentCurrency::Bitcoin, PaymentCurrency::BitcoinCash] {
+            assert_eq!(
+                parse(currency, &address).map(|r| r.address_checksum),
+                Some(AddressChecksum::Unknown)
+            );
+        }
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::parser::link_url::{query_parameters::parse_query, JS_MAX_SAFE_INTEGER};
+
+use super::{address_checksum, PaymentCurrency, PaymentRequest};
+
+/// `ethereum:[pay-]<address>[@<chain_id>][/<function_name>][?<parameters>]`
+pub(super) fn parse(content: &str) -> Option<PaymentRequest> {
+    let (target, query) = content.split_once('?').unwrap_or((content, ""));
+    let target = target.strip_prefix("pay-").unwrap_or(target);
+    let (target, function_name) = match target.split_once('/') {
+        Some((target, function_name)) => (target, Some(function_name)),
+        None => (target, None),
+    };
+    let (address, chain_id) = match target.split_once('@') {
+        Some((address, chain_id)) => (
+            address,
+            Some(
+                chain_id
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|id| *id <= JS_MAX_SAFE_INTEGER)?,
+            ),
+        ),
+        None => (target, None),
+    };
+    if address.is_empty() || function_name == Some("") {
+        return None;
+    }
+
+    let mut request = PaymentRequest {
+        currency: PaymentCurrency::Ethereum,
+        address: address.to_owned(),
+        address_checksum: address_checksum::ethereum(address),
+        amount: None,
+        label: None,
+        message: None,
+        chain_id,
+        function_name: function_name.map(|name| name.to_owned()),
+        parameters: Vec::new(),
+    };
+
+    for (key, value) in parse_query(query) {
+        if key == "value" {
+            request.amount = Some(value);
+        } else {
+            request.parameters.push((key, value));
+        }
+    }
+    Some(request)
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse;
+    use crate::parser::link_url::payment_request::{
+        AddressChecksum, PaymentCurrency, PaymentRequest,
+    };
+
+    #[test]
+    fn simple_payment() {
+        assert_eq!(
+            parse("0xfb6916095ca1df60bb79Ce92ce3Ea74c37c5d359?value=2.014e18"),
+            Some(PaymentRequest {
+                currency: PaymentCurrency::Ethereum,
+                address: "0xfb6916095ca1df60bb79Ce92ce3Ea74c37c5d359".to_owned(),
+                address_checksum: AddressChecksum::Invalid,
+                amount: Some("2.014e18".to_owned()),
+                label: None,
+                message: None,
+                chain_id: None,
+                function_name: None,
+                parameters: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn token_transfer() {
+        assert_eq!(
+            parse("pay-0x89205A3A3b2A69De6Dbf7f01ED13B2108B2c43e7@1/transfer?address=0x8e23ee67d1332ad560396262c48ffbb01f93d052&uint256=1"),
+            Some(PaymentRequest {
+                currency: PaymentCurrency::Ethereum,
+                address: "0x89205A3A3b2A69De6Dbf7f01ED13B2108B2c43e7".to_owned(),
+                address_checksum: AddressChecksum::Valid,
+                amount: None,
+                label: None,
+                message: None,
+                chain_id: Some(1),
+                function_name: Some("transfer".to_owned()),
+                parameters: vec![
+                    (
+                        "address".to_owned(),
+                        "0x8e23ee67d1332ad560396262c48ffbb01f93d052".to_owned()
+                    ),
+                    ("uint256".to_owned(), "1".to_owned())
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn ens_name() {
+        let request = parse("pay-delta.eth@10?value=1e16");
+        assert_eq!(
+            request.as_ref().map(|r| r.address.as_str()),
+            Some("delta.eth")
+        );
+        assert_eq!(
+            request.as_ref().map(|r| r.address_checksum),
+            Some(AddressChecksum::Unknown)
+        );
+        assert_eq!(request.and_then(|r| r.chain_id), Some(10));
+    }
+
+    #[test]
+    fn invalid_links() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("pay-"), None);
+        assert_eq!(
+            parse("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359@mainnet"),
+            None
+        );
+        assert_eq!(parse("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359/"), None);
+        assert_eq!(
+            parse("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359@9007199254740992"),
+            None
+        );
+    }
+}
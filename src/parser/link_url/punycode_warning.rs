@@ -1,6 +1,7 @@
 // this is to protect against https://en.wikipedia.org/wiki/IDN_homograph_attack
 
-#[derive(Debug, PartialEq, Eq, Serialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PunycodeWarning {
     pub original_hostname: String,
     pub ascii_hostname: String,
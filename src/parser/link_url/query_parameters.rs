@@ -0,0 +1,76 @@
+//! Helpers for reading the query part of generic scheme links like `bitcoin:` or `magnet:`
+
+use crate::parser::utils::is_hex_digit;
+
+/// Decodes `%XX` escapes, invalid escapes are kept as they are.
+///
+/// The result is interpreted as UTF-8, invalid sequences are replaced with `U+FFFD`.
+/// Unlike form encoding, `+` stays a `+`.
+pub(crate) fn percent_decode(input: &str) -> String {
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    let mut remaining = input;
+    while let Some(index) = remaining.find('%') {
+        let (before, escape) = remaining.split_at(index);
+        bytes.extend_from_slice(before.as_bytes());
+        let hex = escape.get(1..3).filter(|hex| hex.chars().all(is_hex_digit));
+        match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+            Some(byte) => {
+                bytes.push(byte);
+                remaining = escape.get(3..).unwrap_or_default();
+            }
+            None => {
+                bytes.push(b'%');
+                remaining = escape.get(1..).unwrap_or_default();
+            }
+        }
+    }
+    bytes.extend_from_slice(remaining.as_bytes());
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Splits a query (without the leading `?`) into percent decoded key value pairs.
+///
+/// Keys are kept in their original case, empty pairs (`&&`) are skipped.
+pub(crate) fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_query, percent_decode};
+
+    #[test]
+    fn decode() {
+        assert_eq!(percent_decode("Luke-Jr"), "Luke-Jr");
+        assert_eq!(
+            percent_decode("Donation%20for%20project%20xyz"),
+            "Donation for project xyz"
+        );
+        assert_eq!(percent_decode("a+b"), "a+b");
+        assert_eq!(percent_decode("M%C3%BCnchen"), "München");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+        assert_eq!(percent_decode("%FF"), "\u{fffd}");
+    }
+
+    #[test]
+    fn query() {
+        assert_eq!(
+            parse_query("amount=50&label=Luke-Jr&&message=Donation%20for%20project%20xyz&flag"),
+            vec![
+                ("amount".to_owned(), "50".to_owned()),
+                ("label".to_owned(), "Luke-Jr".to_owned()),
+                ("message".to_owned(), "Donation for project xyz".to_owned()),
+                ("flag".to_owned(), "".to_owned()),
+            ]
+        );
+        assert_eq!(parse_query(""), vec![]);
+    }
+}
@@ -1,17 +1,34 @@
 // mod email;
+pub mod diff;
+pub mod hashtag;
+pub mod incremental;
 pub mod is_emoji;
 pub mod link_url;
+mod options;
+pub mod owned;
 pub mod parse_from_text;
+pub mod source;
+pub(crate) mod unicode_tables;
 pub mod utils;
+pub mod versioned;
 
-#[allow(unused_imports)]
 pub use crate::parser::link_url::punycode_warning::{
     is_puny, punycode_decode_host, punycode_encode_host,
 };
 pub use crate::parser::link_url::LinkDestination;
+pub use crate::parser::options::{ElementKind, LimitsReport, ParserOptions};
+pub use crate::parser::owned::{OwnedElement, OwnedLinkDestination};
+pub use crate::parser::unicode_tables::UNICODE_VERSION;
+pub use crate::parser::versioned::AST_SCHEMA_VERSION;
+
+use source::ElementSource;
 
 /// The representation of Elements for the Abstract Syntax Tree
-#[derive(Debug, PartialEq, Eq, Serialize)]
+///
+/// Deserializing borrows the text from the input, which fails for JSON strings with escape
+/// sequences like `\n`, use [OwnedElement] for those.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(tag = "t", content = "c")]
 pub enum Element<'a> {
     /*
@@ -24,6 +41,7 @@ pub enum Element<'a> {
     /// Represents a linebreak - \n
     Linebreak,
     Link {
+        #[serde(borrow)]
         destination: LinkDestination<'a>,
     },
     EmailAddress(&'a str),
@@ -32,24 +50,40 @@ pub enum Element<'a> {
     //     internal_id: &str
     // },
     /// On click, the command gets prefilled as the draft, so it can be easily send.
-    BotCommandSuggestion(&'a str),
+    BotCommandSuggestion {
+        /// the command as written, like `/command@bot@example.org`, use this to prefill the draft
+        content: &'a str,
+        /// the command name without `/` and target, like `command`
+        command: &'a str,
+        /// the bot the command is meant for, like `bot@example.org`
+        #[serde(borrow)]
+        target: Option<&'a str>,
+        /// the rest of the line after the command, only parsed with [ParserOptions::bot_command_args]
+        #[serde(borrow)]
+        args: Option<&'a str>,
+    },
+    /// An emoji, only emitted with [ParserOptions::emoji_elements]
+    Emoji(&'a str),
 
     /*
     All markdown elements.
     These elements are converted to html when sent out and converted back to the AST format when displaying the message.
     */
-    Bold(Vec<Element<'a>>),
-    Italics(Vec<Element<'a>>),
-    StrikeThrough(Vec<Element<'a>>),
+    Bold(#[serde(borrow)] Vec<Element<'a>>),
+    Italics(#[serde(borrow)] Vec<Element<'a>>),
+    StrikeThrough(#[serde(borrow)] Vec<Element<'a>>),
 
     LabeledLink {
+        #[serde(borrow)]
         label: Vec<Element<'a>>,
+        #[serde(borrow)]
         destination: LinkDestination<'a>,
     },
     InlineCode {
         content: &'a str,
     },
     CodeBlock {
+        #[serde(borrow)]
         language: Option<&'a str>,
         content: &'a str,
     },
@@ -61,15 +95,151 @@ pub enum Element<'a> {
 
 /// parses all kinds of elements, including markdown
 pub fn parse_markdown_text(input: &str) -> std::vec::Vec<Element> {
-    parse_from_text::parse_all(input)
+    parse_from_text::parse_markdown(input, &ParserOptions::default(), false).elements
 }
 
 /// parses text elements such as links and email addresses, excluding markdown
 pub fn parse_only_text(input: &str) -> std::vec::Vec<Element> {
-    parse_from_text::parse_only_text(input)
+    parse_from_text::parse_only_text(input, &ParserOptions::default(), false).elements
 }
 
 /// parses text and delimited/labled link elements to replicate current desktop elements
 pub fn parse_desktop_set(input: &str) -> std::vec::Vec<Element> {
-    parse_from_text::parse_desktop_set(input)
+    parse_from_text::parse_desktop_set(input, &ParserOptions::default(), false).elements
+}
+
+/// like [parse_markdown_text], with [ParserOptions]
+pub fn parse_markdown_text_with_options<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> std::vec::Vec<Element<'a>> {
+    parse_from_text::parse_markdown(input, options, false).elements
+}
+
+/// like [parse_only_text], with [ParserOptions]
+pub fn parse_only_text_with_options<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> std::vec::Vec<Element<'a>> {
+    parse_from_text::parse_only_text(input, options, false).elements
+}
+
+/// like [parse_desktop_set], with [ParserOptions]
+pub fn parse_desktop_set_with_options<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> std::vec::Vec<Element<'a>> {
+    parse_from_text::parse_desktop_set(input, options, false).elements
+}
+
+/// like [parse_markdown_text_with_options], also returns which limits of the options were hit
+pub fn parse_markdown_text_with_report<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> (std::vec::Vec<Element<'a>>, LimitsReport) {
+    let parsed = parse_from_text::parse_markdown(input, options, false);
+    (parsed.elements, parsed.report)
+}
+
+/// like [parse_only_text_with_options], also returns which limits of the options were hit
+pub fn parse_only_text_with_report<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> (std::vec::Vec<Element<'a>>, LimitsReport) {
+    let parsed = parse_from_text::parse_only_text(input, options, false);
+    (parsed.elements, parsed.report)
+}
+
+/// like [parse_desktop_set_with_options], also returns which limits of the options were hit
+pub fn parse_desktop_set_with_report<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> (std::vec::Vec<Element<'a>>, LimitsReport) {
+    let parsed = parse_from_text::parse_desktop_set(input, options, false);
+    (parsed.elements, parsed.report)
+}
+
+/// like [parse_markdown_text_with_options], also returns the part of the input that each element
+/// was parsed from, see [source]
+pub fn parse_markdown_text_with_sources<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> (std::vec::Vec<Element<'a>>, std::vec::Vec<ElementSource<'a>>) {
+    let parsed = parse_from_text::parse_markdown(input, options, true);
+    (parsed.elements, parsed.sources)
+}
+
+/// like [parse_only_text_with_options], also returns the part of the input that each element
+/// was parsed from, see [source]
+pub fn parse_only_text_with_sources<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> (std::vec::Vec<Element<'a>>, std::vec::Vec<ElementSource<'a>>) {
+    let parsed = parse_from_text::parse_only_text(input, options, true);
+    (parsed.elements, parsed.sources)
+}
+
+/// like [parse_desktop_set_with_options], also returns the part of the input that each element
+/// was parsed from, see [source]
+pub fn parse_desktop_set_with_sources<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> (std::vec::Vec<Element<'a>>, std::vec::Vec<ElementSource<'a>>) {
+    let parsed = parse_from_text::parse_desktop_set(input, options, true);
+    (parsed.elements, parsed.sources)
+}
+
+/// Which parse function to use, for callers that choose it at runtime, like the bindings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum ParseMode {
+    /// [parse_markdown_text]
+    #[default]
+    Markdown,
+    /// [parse_only_text]
+    Text,
+    /// [parse_desktop_set]
+    DesktopSet,
+}
+
+impl ParseMode {
+    fn parsed<'a>(
+        self,
+        input: &'a str,
+        options: &ParserOptions,
+        with_sources: bool,
+    ) -> parse_from_text::Parsed<'a> {
+        match self {
+            ParseMode::Markdown => parse_from_text::parse_markdown(input, options, with_sources),
+            ParseMode::Text => parse_from_text::parse_only_text(input, options, with_sources),
+            ParseMode::DesktopSet => {
+                parse_from_text::parse_desktop_set(input, options, with_sources)
+            }
+        }
+    }
+
+    /// parses with the parse function of the mode, like [parse_markdown_text_with_report]
+    pub fn parse<'a>(
+        self,
+        input: &'a str,
+        options: &ParserOptions,
+    ) -> (std::vec::Vec<Element<'a>>, LimitsReport) {
+        let parsed = self.parsed(input, options, false);
+        (parsed.elements, parsed.report)
+    }
+
+    /// like [ParseMode::parse], also returns the part of the input that each element was parsed
+    /// from, see [source]
+    pub fn parse_with_sources<'a>(
+        self,
+        input: &'a str,
+        options: &ParserOptions,
+    ) -> (
+        std::vec::Vec<Element<'a>>,
+        std::vec::Vec<ElementSource<'a>>,
+        LimitsReport,
+    ) {
+        let parsed = self.parsed(input, options, true);
+        (parsed.elements, parsed.sources, parsed.report)
+    }
 }
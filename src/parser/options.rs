@@ -0,0 +1,134 @@
+use super::{Element, LinkDestination};
+
+/// Options for the `*_with_options` parse functions
+///
+/// The default options give the same result as the functions without options,
+/// so set only what you need: `ParserOptions { emoji_elements: true, ..Default::default() }`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// Emit [Element::Emoji] for every emoji (see [super::is_emoji::emoji]),
+    /// instead of keeping them inside of [Element::Text]
+    pub emoji_elements: bool,
+    /// Parse the rest of the line after a bot command as its arguments into
+    /// [Element::BotCommandSuggestion], for bot frameworks.
+    ///
+    /// The arguments are not parsed further, so they can't contain other elements like links or markdown.
+    pub bot_command_args: bool,
+    /// The commands that the bots of the chat advertise, with or without `/`.
+    ///
+    /// If set, only these commands are parsed as [Element::BotCommandSuggestion], also when
+    /// they are followed by `_` and arguments,
+    /// so with `help` and `move` known `/help`, `/move_a5_a6` and `/help@bot` are commands,
+    /// but `/s`, `/dev` and `/helpful` are text. If not set, every `/command` is parsed as command.
+    pub known_bot_commands: Option<Vec<String>>,
+    /// How deep markdown elements can be nested in each other, deeper markup is kept as text.
+    ///
+    /// With `1` bold text can't contain italics and with `0` no bold, italics, strikethrough
+    /// or labeled links are parsed.
+    pub max_nesting_depth: Option<usize>,
+    /// The maximum number of elements (including nested ones), the rest of the text after them
+    /// is kept as one [Element::Text].
+    ///
+    /// The parser stops as soon as the limit is exceeded, also inside of a large element.
+    pub max_elements: Option<usize>,
+    /// Longer texts are parsed without markdown, like with
+    /// [parse_only_text](super::parse_only_text), by the markdown parse functions.
+    pub max_markdown_bytes: Option<usize>,
+    /// The kinds of elements that are parsed, all if not set.
+    ///
+    /// The other elements are kept as text: the whole text of links, email addresses, hashtags,
+    /// bot commands and code, but only the delimiters of bold, italics, strikethrough and labeled
+    /// links, their content is parsed like the text around them.
+    /// [Element::Text] and [Element::Linebreak] are always parsed and
+    /// [Element::Emoji] only with [ParserOptions::emoji_elements].
+    pub enabled_elements: Option<Vec<ElementKind>>,
+    /// The schemes of the links that are parsed, like `https` or `mailto`, all if not set.
+    ///
+    /// Links with other schemes are kept as text, also the ones of labeled links.
+    /// Links without scheme, like `delta.chat`, are opened as `https` links, so they are parsed if
+    /// `https` is allowed. The comparison ignores case.
+    pub allowed_schemes: Option<Vec<String>>,
+}
+
+/// The kinds of elements that can be turned off with [ParserOptions::enabled_elements]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum ElementKind {
+    Tag,
+    Link,
+    EmailAddress,
+    BotCommandSuggestion,
+    Bold,
+    Italics,
+    StrikeThrough,
+    LabeledLink,
+    InlineCode,
+    CodeBlock,
+}
+
+impl ParserOptions {
+    /// whether the element is parsed with these options, see [ParserOptions::enabled_elements]
+    /// and [ParserOptions::allowed_schemes]
+    pub(crate) fn allows(&self, element: &Element) -> bool {
+        let kind = match element {
+            Element::Text(_) | Element::Linebreak | Element::Emoji(_) => return true,
+            Element::Tag(_) => ElementKind::Tag,
+            Element::Link { destination } => {
+                if !self.allows_scheme(destination) {
+                    return false;
+                }
+                ElementKind::Link
+            }
+            Element::EmailAddress(_) => ElementKind::EmailAddress,
+            Element::BotCommandSuggestion { .. } => ElementKind::BotCommandSuggestion,
+            Element::Bold(_) => ElementKind::Bold,
+            Element::Italics(_) => ElementKind::Italics,
+            Element::StrikeThrough(_) => ElementKind::StrikeThrough,
+            Element::LabeledLink { destination, .. } => {
+                if !self.allows_scheme(destination) {
+                    return false;
+                }
+                ElementKind::LabeledLink
+            }
+            Element::InlineCode { .. } => ElementKind::InlineCode,
+            Element::CodeBlock { .. } => ElementKind::CodeBlock,
+        };
+        self.allows_kind(kind)
+    }
+
+    pub(crate) fn allows_kind(&self, kind: ElementKind) -> bool {
+        self.enabled_elements
+            .as_ref()
+            .map_or(true, |enabled| enabled.contains(&kind))
+    }
+
+    pub(crate) fn allows_scheme(&self, destination: &LinkDestination) -> bool {
+        let scheme = destination.scheme.unwrap_or("https");
+        self.allowed_schemes.as_ref().map_or(true, |allowed| {
+            allowed
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+        })
+    }
+}
+
+/// Which limits of [ParserOptions] were hit, returned by the `*_with_report` parse functions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LimitsReport {
+    /// markup nested deeper than [ParserOptions::max_nesting_depth] was kept as text
+    pub max_nesting_depth_exceeded: bool,
+    /// there were more than [ParserOptions::max_elements] elements
+    pub max_elements_exceeded: bool,
+    /// the text was longer than [ParserOptions::max_markdown_bytes] and parsed without markdown
+    pub max_markdown_bytes_exceeded: bool,
+}
+
+impl LimitsReport {
+    /// true if any of the limits was hit, so the result is not the same as without limits
+    pub fn any(&self) -> bool {
+        self.max_nesting_depth_exceeded
+            || self.max_elements_exceeded
+            || self.max_markdown_bytes_exceeded
+    }
+}
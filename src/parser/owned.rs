@@ -0,0 +1,199 @@
+//! Versions of [Element] and [LinkDestination] that own their text instead of borrowing it from
+//! the input, so parse results can be cached, sent to other threads and stored as JSON.
+//!
+//! They serialize to the same JSON as the borrowed types.
+//!
+//! ```
+//! use deltachat_message_parser::parser::{parse_markdown_text, OwnedElement};
+//!
+//! let elements: Vec<OwnedElement> = {
+//!     let text = String::from("**Hello** https://delta.chat");
+//!     parse_markdown_text(&text)
+//!         .into_iter()
+//!         .map(|element| element.into_owned())
+//!         .collect()
+//! };
+//! // the text is dropped, but the elements can still be used
+//! assert_eq!(
+//!     elements[0],
+//!     OwnedElement::Bold(vec![OwnedElement::Text("Hello".to_owned())])
+//! );
+//! ```
+
+use super::{
+    link_url::{MagnetLink, PaymentRequest, PunycodeWarning},
+    Element, LinkDestination,
+};
+
+/// Owned version of [Element]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+pub enum OwnedElement {
+    Text(String),
+    Tag(String),
+    Linebreak,
+    Link {
+        destination: OwnedLinkDestination,
+    },
+    EmailAddress(String),
+    BotCommandSuggestion {
+        content: String,
+        command: String,
+        target: Option<String>,
+        args: Option<String>,
+    },
+    Emoji(String),
+    Bold(Vec<OwnedElement>),
+    Italics(Vec<OwnedElement>),
+    StrikeThrough(Vec<OwnedElement>),
+    LabeledLink {
+        label: Vec<OwnedElement>,
+        destination: OwnedLinkDestination,
+    },
+    InlineCode {
+        content: String,
+    },
+    CodeBlock {
+        language: Option<String>,
+        content: String,
+    },
+}
+
+/// Owned version of [LinkDestination]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedLinkDestination {
+    pub target: String,
+    pub hostname: Option<String>,
+    pub punycode: Option<PunycodeWarning>,
+    pub scheme: Option<String>,
+    pub payment_request: Option<PaymentRequest>,
+    pub magnet: Option<MagnetLink>,
+}
+
+fn into_owned(elements: Vec<Element>) -> Vec<OwnedElement> {
+    elements.into_iter().map(Element::into_owned).collect()
+}
+
+fn as_elements(elements: &[OwnedElement]) -> Vec<Element<'_>> {
+    elements.iter().map(OwnedElement::as_element).collect()
+}
+
+impl Element<'_> {
+    /// Copies the text of the element and its children, so it no longer borrows the input
+    pub fn into_owned(self) -> OwnedElement {
+        match self {
+            Element::Text(text) => OwnedElement::Text(text.to_owned()),
+            Element::Tag(tag) => OwnedElement::Tag(tag.to_owned()),
+            Element::Linebreak => OwnedElement::Linebreak,
+            Element::Link { destination } => OwnedElement::Link {
+                destination: destination.into_owned(),
+            },
+            Element::EmailAddress(address) => OwnedElement::EmailAddress(address.to_owned()),
+            Element::BotCommandSuggestion {
+                content,
+                command,
+                target,
+                args,
+            } => OwnedElement::BotCommandSuggestion {
+                content: content.to_owned(),
+                command: command.to_owned(),
+                target: target.map(str::to_owned),
+                args: args.map(str::to_owned),
+            },
+            Element::Emoji(emoji) => OwnedElement::Emoji(emoji.to_owned()),
+            Element::Bold(children) => OwnedElement::Bold(into_owned(children)),
+            Element::Italics(children) => OwnedElement::Italics(into_owned(children)),
+            Element::StrikeThrough(children) => OwnedElement::StrikeThrough(into_owned(children)),
+            Element::LabeledLink { label, destination } => OwnedElement::LabeledLink {
+                label: into_owned(label),
+                destination: destination.into_owned(),
+            },
+            Element::InlineCode { content } => OwnedElement::InlineCode {
+                content: content.to_owned(),
+            },
+            Element::CodeBlock { language, content } => OwnedElement::CodeBlock {
+                language: language.map(str::to_owned),
+                content: content.to_owned(),
+            },
+        }
+    }
+}
+
+impl OwnedElement {
+    /// Borrows the element as [Element], to use it with the functions that take elements
+    pub fn as_element(&self) -> Element<'_> {
+        match self {
+            OwnedElement::Text(text) => Element::Text(text),
+            OwnedElement::Tag(tag) => Element::Tag(tag),
+            OwnedElement::Linebreak => Element::Linebreak,
+            OwnedElement::Link { destination } => Element::Link {
+                destination: destination.as_link_destination(),
+            },
+            OwnedElement::EmailAddress(address) => Element::EmailAddress(address),
+            OwnedElement::BotCommandSuggestion {
+                content,
+                command,
+                target,
+                args,
+            } => Element::BotCommandSuggestion {
+                content,
+                command,
+                target: target.as_deref(),
+                args: args.as_deref(),
+            },
+            OwnedElement::Emoji(emoji) => Element::Emoji(emoji),
+            OwnedElement::Bold(children) => Element::Bold(as_elements(children)),
+            OwnedElement::Italics(children) => Element::Italics(as_elements(children)),
+            OwnedElement::StrikeThrough(children) => Element::StrikeThrough(as_elements(children)),
+            OwnedElement::LabeledLink { label, destination } => Element::LabeledLink {
+                label: as_elements(label),
+                destination: destination.as_link_destination(),
+            },
+            OwnedElement::InlineCode { content } => Element::InlineCode { content },
+            OwnedElement::CodeBlock { language, content } => Element::CodeBlock {
+                language: language.as_deref(),
+                content,
+            },
+        }
+    }
+}
+
+impl<'a> From<Element<'a>> for OwnedElement {
+    fn from(element: Element<'a>) -> Self {
+        element.into_owned()
+    }
+}
+
+impl LinkDestination<'_> {
+    /// Copies the text of the link, so it no longer borrows the input
+    pub fn into_owned(self) -> OwnedLinkDestination {
+        OwnedLinkDestination {
+            target: self.target.to_owned(),
+            hostname: self.hostname.map(str::to_owned),
+            punycode: self.punycode,
+            scheme: self.scheme.map(str::to_owned),
+            payment_request: self.payment_request,
+            magnet: self.magnet,
+        }
+    }
+}
+
+impl OwnedLinkDestination {
+    /// Borrows the link as [LinkDestination]
+    pub fn as_link_destination(&self) -> LinkDestination<'_> {
+        LinkDestination {
+            target: &self.target,
+            hostname: self.hostname.as_deref(),
+            punycode: self.punycode.clone(),
+            scheme: self.scheme.as_deref(),
+            payment_request: self.payment_request.clone(),
+            magnet: self.magnet.clone(),
+        }
+    }
+}
+
+impl<'a> From<LinkDestination<'a>> for OwnedLinkDestination {
+    fn from(destination: LinkDestination<'a>) -> Self {
+        destination.into_owned()
+    }
+}
@@ -22,6 +22,8 @@ pub enum CustomError<I> {
     UnexpectedContent,
     PrecedingWhitespaceMissing,
     OptionIsUnexpectedNone,
+    NestingTooDeep,
+    DisabledElement,
     UnxepectedError(String),
 }
 
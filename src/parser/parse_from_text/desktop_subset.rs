@@ -1,22 +1,30 @@
 //! desktop subset of markdown, becase this way we can already use the punycode detection of this crate
 //! and also we can keep delimited and labled links in desktop
 use nom::{
-    bytes::complete::{is_not, tag, take},
+    bytes::complete::tag,
     combinator::{peek, recognize},
-    sequence::{delimited, tuple},
-    IResult,
+    sequence::tuple,
+    IResult, Slice,
 };
 
-use crate::parser::LinkDestination;
+use crate::parser::{ElementKind, LinkDestination, ParserOptions};
 
 use super::base_parsers::CustomError;
-use super::markdown_elements::{delimited_email_address, delimited_link};
+use super::element_starts::{ElementSet, ElementStarts};
+use super::markdown_elements::{delimited_email_address, delimited_link, raw_label};
 use super::text_elements::parse_text_element;
-use super::Element;
+use super::{allowed_or_text, Context, Element};
 
 // [labeled](https://link)
-pub(crate) fn labeled_link(input: &str) -> IResult<&str, Element, CustomError<&str>> {
-    let (input, raw_label) = delimited(tag("["), is_not("]"), tag("]"))(input)?;
+pub(crate) fn labeled_link<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    if !context.scan().may_be_labeled_link(input) {
+        return Err(nom::Err::Error(CustomError::NoElement));
+    }
+    let (input, raw_label) = raw_label(input)?;
     if raw_label.is_empty() {
         return Err(nom::Err::Error(CustomError::NoContent));
     }
@@ -26,6 +34,10 @@ pub(crate) fn labeled_link(input: &str) -> IResult<&str, Element, CustomError<&s
 
     let (input, (_, destination, _)) =
         tuple((tag("("), LinkDestination::parse_labelled, tag(")")))(input)?;
+    if !options.allows_kind(ElementKind::LabeledLink) || !options.allows_scheme(&destination) {
+        return Err(nom::Err::Error(CustomError::DisabledElement));
+    }
+    context.record_source(context.source_mark(), raw_label);
 
     Ok((input, Element::LabeledLink { label, destination }))
 }
@@ -33,43 +45,71 @@ pub(crate) fn labeled_link(input: &str) -> IResult<&str, Element, CustomError<&s
 /// consumes all text until [parse_element] works again, this method is only for internal use by [desktopset_text]
 ///
 /// its output is not useable on its own, always combinate this with [nom::combinator::recognize]
-fn eat_desktopset_text(input: &str) -> IResult<&str, (), CustomError<&str>> {
-    let mut remaining = input;
-    while !remaining.is_empty() {
-        // take 1, because other parsers didn't work (text is always the last used parser)
-        let (remainder, taken) = take(1usize)(remaining)?;
-        remaining = remainder;
+fn eat_desktopset_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, (), CustomError<&'a str>> {
+    // skip the first character, because other parsers didn't work (text is always the last used parser)
+    for (position, prev_char) in ElementStarts::new(
+        input,
+        ElementSet::DesktopSet,
+        options.emoji_elements,
+        context.scan(),
+    ) {
+        let remaining = input.slice(position..);
         // peek if there is an element
-        if peek(|input| parse_element(input, taken.chars().next()))(remaining).is_ok() {
-            break;
+        let element = context.peek(|| {
+            peek(|input| parse_element(input, Some(prev_char), options, context))(remaining)
+        });
+        if element.is_ok() {
+            return Ok((remaining, ()));
         }
     }
-    Ok((remaining, ()))
+    Ok((input.slice(input.len()..), ()))
 }
 
 /// Consumes text until another parser of [parse_element] works again
 ///
 /// used as last parser, if the others do not consume the input it consumes the input until another parser works again
 /// (uses whitespace seperation to make the parsing faster)
-pub(crate) fn desktopset_text(input: &str) -> IResult<&str, Element, CustomError<&str>> {
-    let (rest, content) = recognize(eat_desktopset_text)(input)?;
+pub(crate) fn desktopset_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    let (rest, content) = recognize(|input| eat_desktopset_text(input, options, context))(input)?;
     Ok((rest, Element::Text(content)))
 }
 
-pub(crate) fn parse_element(
-    input: &str,
+/// parses the next element, elements that the options don't allow are returned as text or, for
+/// labeled links, not parsed
+pub(crate) fn parse_element<'a>(
+    input: &'a str,
+    prev_char: Option<char>,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    desktop_set_element(input, prev_char, options, context)
+        .map(|parsed| allowed_or_text(input, parsed, options))
+}
+
+fn desktop_set_element<'a>(
+    input: &'a str,
     prev_char: Option<char>,
-) -> IResult<&str, Element, CustomError<&str>> {
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
     // the order is important
     // generaly more specific parsers that fail/return fast should be in the front
     // But keep in mind that the order can also change how and if the parser works as intended
-    if let Ok((i, elm)) = labeled_link(input) {
+    if let Ok((i, elm)) = labeled_link(input, options, context) {
         Ok((i, elm))
-    } else if let Ok((i, elm)) = delimited_email_address(input) {
+    } else if let Ok((i, elm)) = delimited_email_address(input, context) {
         Ok((i, elm))
-    } else if let Ok((i, elm)) = delimited_link(input) {
+    } else if let Ok((i, elm)) = delimited_link(input, context) {
         Ok((i, elm))
     } else {
-        parse_text_element(input, prev_char)
+        parse_text_element(input, prev_char, options, context)
     }
 }
@@ -0,0 +1,202 @@
+//! Fast scan for the positions where an element might start.
+//!
+//! The text parsers consume text until an element parser works again. Trying every element parser
+//! after each character makes parsing long messages slow, so they only try the positions this
+//! scanner returns. A position that is not returned can never be the start of an element, so the
+//! parse result stays the same.
+use nom::Slice;
+
+use super::scan_cache::ScanCache;
+use crate::parser::{unicode_tables::emoji::EMOJI_START_RANGES, utils::is_in_one_of_ranges};
+
+/// The elements that are parsed in the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ElementSet {
+    /// the elements of [super::text_elements::parse_text_element]
+    Text,
+    /// the elements of [super::desktop_subset::parse_element]
+    DesktopSet,
+    /// the elements of [super::markdown_elements::parse_element]
+    Markdown,
+    /// the elements of [super::markdown_elements::label_elements::parse_markdown_label_element]
+    MarkdownLabel,
+}
+
+/// Iterator over the byte offsets after the first character of `input` where an element of the
+/// [ElementSet] might start, together with the character before that offset.
+pub(crate) struct ElementStarts<'a> {
+    input: &'a str,
+    set: ElementSet,
+    emoji: bool,
+    /// links, email addresses and delimited elements are looked up in the scans of the parse
+    scan: &'a ScanCache<'a>,
+    position: usize,
+}
+
+impl<'a> ElementStarts<'a> {
+    pub(crate) fn new(
+        input: &'a str,
+        set: ElementSet,
+        emoji: bool,
+        scan: &'a ScanCache<'a>,
+    ) -> Self {
+        // the first character was already tried by the caller
+        let position = input.chars().next().map_or(0, char::len_utf8);
+        ElementStarts {
+            input,
+            set,
+            emoji,
+            scan,
+            position,
+        }
+    }
+
+    fn is_element_start(&self, position: usize, byte: u8) -> bool {
+        let prev_byte = self
+            .input
+            .as_bytes()
+            .get(position.saturating_sub(1))
+            .copied();
+        let rest = self.input.slice(position..);
+        let markdown = matches!(self.set, ElementSet::Markdown | ElementSet::MarkdownLabel);
+        let text = matches!(
+            self.set,
+            ElementSet::Text | ElementSet::DesktopSet | ElementSet::Markdown
+        );
+        let delimited = matches!(self.set, ElementSet::DesktopSet | ElementSet::Markdown);
+        let is_trigger = match byte {
+            b'*' | b'_' | b'~' | b'`' => markdown,
+            b'<' => {
+                delimited
+                    && (self.scan.may_be_delimited_email_address(rest)
+                        || self.scan.may_be_delimited_link(rest))
+            }
+            b'[' => delimited && self.scan.may_be_labeled_link(rest),
+            b'#' | b'@' | b'\n' => text,
+            // bot commands need a space in front of them
+            b'/' => text && prev_byte == Some(b' '),
+            _ => false,
+        };
+        is_trigger
+            || (text && (self.scan.may_be_email_address(rest) || self.scan.may_be_link(rest)))
+            || (self.emoji && self.may_be_emoji(position, byte))
+    }
+
+    fn may_be_emoji(&self, position: usize, byte: u8) -> bool {
+        if byte.is_ascii() {
+            return is_in_one_of_ranges(u32::from(byte), EMOJI_START_RANGES);
+        }
+        self.input
+            .slice(position..)
+            .chars()
+            .next()
+            .map_or(false, |c| is_in_one_of_ranges(c as u32, EMOJI_START_RANGES))
+    }
+}
+
+impl<'a> Iterator for ElementStarts<'a> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let position = self.position;
+            let byte = *self.input.as_bytes().get(position)?;
+            self.position = position.saturating_add(1);
+            if !self.input.is_char_boundary(position) {
+                continue;
+            }
+            if self.is_element_start(position, byte) {
+                let prev_char = self.input.slice(..position).chars().next_back()?;
+                return Some((position, prev_char));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElementSet, ElementStarts, ScanCache};
+
+    fn starts(input: &str, set: ElementSet) -> Vec<usize> {
+        let scan = ScanCache::new(input);
+        ElementStarts::new(input, set, false, &scan)
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    #[test]
+    fn plain_words_have_no_element_starts() {
+        assert_eq!(
+            starts("hello world, how are you", ElementSet::Markdown),
+            Vec::<usize>::new()
+        );
+    }
+
+    #[test]
+    fn trigger_characters() {
+        assert_eq!(starts("a #b\nc", ElementSet::Text), vec![2, 4]);
+        assert_eq!(starts("a *b* _c_", ElementSet::Text), Vec::<usize>::new());
+        assert_eq!(starts("a *b* _c_", ElementSet::Markdown), vec![2, 4, 6, 8]);
+        // bot commands need a space before them
+        assert_eq!(starts("a /b a/b", ElementSet::Text), vec![2]);
+    }
+
+    #[test]
+    fn words_with_link_or_email_characters() {
+        // every position up to the last `.` might be the start of a link
+        assert_eq!(starts("ab a.de cd", ElementSet::Text), vec![3, 4]);
+        assert_eq!(starts("xa@b c", ElementSet::Text), vec![1, 2]);
+        // only the scheme can start a link with scheme
+        assert_eq!(starts("xa:b c", ElementSet::Text), vec![1]);
+        assert_eq!(starts("xa@ b: c%", ElementSet::Text), vec![2, 4]);
+    }
+
+    #[test]
+    fn links_without_scheme_need_allowed_tld() {
+        assert_eq!(
+            starts("ab a.b.c.d.e cd", ElementSet::Text),
+            Vec::<usize>::new()
+        );
+        assert_eq!(starts("x1.2.3.4", ElementSet::Text), Vec::<usize>::new());
+        assert_eq!(starts("xa.de.b/a.b", ElementSet::Text), Vec::<usize>::new());
+        assert_eq!(starts("x[v1.b]", ElementSet::Text), Vec::<usize>::new());
+        assert_eq!(starts("xa.b/a.com", ElementSet::Text), vec![5, 6]);
+    }
+
+    #[test]
+    fn delimited_links_need_closing_angle_bracket() {
+        assert_eq!(
+            starts("a <b <c@d> <> d>", ElementSet::Markdown),
+            vec![5, 6, 7]
+        );
+        assert_eq!(
+            starts("a <b <c> <> d>", ElementSet::Markdown),
+            Vec::<usize>::new()
+        );
+    }
+
+    #[test]
+    fn labeled_link_needs_label_and_destination() {
+        assert_eq!(starts("a [b](x:y)", ElementSet::Markdown), vec![2, 6]);
+        assert_eq!(starts("a [b](c", ElementSet::Markdown), Vec::<usize>::new());
+        assert_eq!(
+            starts("a [b] [] [c", ElementSet::Markdown),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            starts("a [[[[b](x:y)", ElementSet::DesktopSet),
+            vec![2, 3, 4, 5, 9]
+        );
+        assert_eq!(starts("a [b](c", ElementSet::Text), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn emoji_starts() {
+        let scan = ScanCache::new("a🍏b c");
+        let emoji_starts: Vec<usize> = ElementStarts::new("a🍏b c", ElementSet::Text, true, &scan)
+            .map(|(position, _)| position)
+            .collect();
+        assert_eq!(emoji_starts, vec![1]);
+        assert_eq!(starts("a🍏b c", ElementSet::Text), Vec::<usize>::new());
+    }
+}
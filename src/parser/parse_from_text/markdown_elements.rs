@@ -1,12 +1,17 @@
 use nom::{
-    bytes::complete::{is_not, tag, take, take_while},
+    bytes::complete::{is_not, tag, take_while},
     character::complete::alphanumeric1,
     combinator::{opt, peek, recognize},
     sequence::{delimited, tuple},
-    IResult,
+    IResult, Slice,
 };
 
-use super::{base_parsers::*, parse_all};
+use super::{
+    allowed_or_text,
+    base_parsers::*,
+    element_starts::{ElementSet, ElementStarts},
+    parse_all, Context,
+};
 use crate::parser::{
     link_url::LinkDestination,
     parse_from_text::{
@@ -15,9 +20,10 @@ use crate::parser::{
         Element,
     },
     utils::{is_white_space, is_white_space_but_not_linebreak},
+    ElementKind, ParserOptions,
 };
 
-mod label_elements;
+pub(crate) mod label_elements;
 use label_elements::parse_label_elements;
 
 pub(crate) fn inline_code(input: &str) -> IResult<&str, &str, CustomError<&str>> {
@@ -83,7 +89,13 @@ pub(crate) fn code_block(input: &str) -> IResult<&str, Element, CustomError<&str
 }
 
 // <hello@delta.chat>
-pub(crate) fn delimited_email_address(input: &str) -> IResult<&str, Element, CustomError<&str>> {
+pub(crate) fn delimited_email_address<'a>(
+    input: &'a str,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    if !context.scan().may_be_delimited_email_address(input) {
+        return Err(nom::Err::Error(CustomError::NoElement));
+    }
     let (input, content): (&str, &str) = delimited(tag("<"), is_not(">"), tag(">"))(input)?;
     if content.is_empty() {
         return Err(nom::Err::Error(CustomError::NoContent));
@@ -96,84 +108,163 @@ pub(crate) fn delimited_email_address(input: &str) -> IResult<&str, Element, Cus
 }
 
 // <https://link>
-pub(crate) fn delimited_link(input: &str) -> IResult<&str, Element, CustomError<&str>> {
+pub(crate) fn delimited_link<'a>(
+    input: &'a str,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    if !context.scan().may_be_delimited_link(input) {
+        return Err(nom::Err::Error(CustomError::NoElement));
+    }
     let (input, (_, destination, _)): (&str, (&str, LinkDestination, &str)) =
         tuple((tag("<"), LinkDestination::parse_labelled, tag(">")))(input)?;
     Ok((input, Element::Link { destination }))
 }
 
 // [labeled](https://link)
-pub(crate) fn labeled_link(input: &str) -> IResult<&str, Element, CustomError<&str>> {
-    let (input, raw_label): (&str, &str) = delimited(tag("["), is_not("]"), tag("]"))(input)?;
+/// the label of a labeled link, the text between `[` and the first `]`
+pub(crate) fn raw_label(input: &str) -> IResult<&str, &str, CustomError<&str>> {
+    delimited(tag("["), is_not("]"), tag("]"))(input)
+}
+
+pub(crate) fn labeled_link<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    if !context.scan().may_be_labeled_link(input) {
+        return Err(nom::Err::Error(CustomError::NoElement));
+    }
+    let (input, raw_label) = raw_label(input)?;
     if raw_label.is_empty() {
         return Err(nom::Err::Error(CustomError::NoContent));
     }
-    // the list of elements that can appear inside of a label is restricted
-    // clickable elements make no sense there.
-    let label = parse_label_elements(raw_label);
-
     let (input, (_, destination, _)) =
         tuple((tag("("), LinkDestination::parse_labelled, tag(")")))(input)?;
+    if !options.allows_kind(ElementKind::LabeledLink) || !options.allows_scheme(&destination) {
+        return Err(nom::Err::Error(CustomError::DisabledElement));
+    }
+
+    // the list of elements that can appear inside of a label is restricted
+    // clickable elements make no sense there.
+    // parsed after the destination, so text with many `[` does not parse the same label again and again
+    let context = context
+        .children(options)
+        .ok_or(nom::Err::Error(CustomError::NestingTooDeep))?;
+    let label = parse_label_elements(raw_label, options, context);
 
     Ok((input, Element::LabeledLink { label, destination }))
 }
 
-pub(crate) fn parse_element(
+type MarkupElement<'a> = fn(Vec<Element<'a>>) -> Element<'a>;
+
+/// bold, italics and strikethrough, returns the element type and the content
+pub(crate) fn delimited_markup(
     input: &str,
-    prev_char: Option<char>,
-) -> IResult<&str, Element, CustomError<&str>> {
-    // the order is important
-    // generaly more specific parsers that fail/return fast should be in the front
-    // But keep in mind that the order can also change how and if the parser works as intended
+) -> IResult<&str, (MarkupElement, &str), CustomError<&str>> {
     if let Ok((i, b)) = direct_delimited(input, "**") {
-        Ok((i, Element::Bold(parse_all(b))))
+        Ok((i, (Element::Bold, b)))
     } else if let Ok((i, b)) = direct_delimited(input, "__") {
-        Ok((i, Element::Bold(parse_all(b))))
+        Ok((i, (Element::Bold, b)))
     } else if let Ok((i, b)) = direct_delimited(input, "_") {
-        Ok((i, Element::Italics(parse_all(b))))
+        Ok((i, (Element::Italics, b)))
     } else if let Ok((i, b)) = direct_delimited(input, "*") {
-        Ok((i, Element::Italics(parse_all(b))))
+        Ok((i, (Element::Italics, b)))
     } else if let Ok((i, b)) = direct_delimited(input, "~~") {
-        Ok((i, Element::StrikeThrough(parse_all(b))))
+        Ok((i, (Element::StrikeThrough, b)))
+    } else {
+        Err(nom::Err::Error(CustomError::NoElement))
+    }
+}
+
+fn markup_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    let (input, (element, content)) = delimited_markup(input)?;
+    if !options.allows(&element(Vec::new())) {
+        return Err(nom::Err::Error(CustomError::DisabledElement));
+    }
+    let context = context
+        .children(options)
+        .ok_or(nom::Err::Error(CustomError::NestingTooDeep))?;
+    Ok((input, element(parse_all(content, options, context))))
+}
+
+/// parses the next element, elements that the options don't allow are returned as text or, for
+/// elements with children, not parsed
+pub(crate) fn parse_element<'a>(
+    input: &'a str,
+    prev_char: Option<char>,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    markdown_element(input, prev_char, options, context)
+        .map(|parsed| allowed_or_text(input, parsed, options))
+}
+
+fn markdown_element<'a>(
+    input: &'a str,
+    prev_char: Option<char>,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    // the order is important
+    // generaly more specific parsers that fail/return fast should be in the front
+    // But keep in mind that the order can also change how and if the parser works as intended
+    if let Ok((i, elm)) = markup_element(input, options, context) {
+        Ok((i, elm))
     } else if let Ok((i, elm)) = code_block(input) {
         Ok((i, elm))
     } else if let Ok((i, b)) = inline_code(input) {
         Ok((i, Element::InlineCode { content: b }))
-    } else if let Ok((i, elm)) = labeled_link(input) {
+    } else if let Ok((i, elm)) = labeled_link(input, options, context) {
         Ok((i, elm))
-    } else if let Ok((i, elm)) = delimited_email_address(input) {
+    } else if let Ok((i, elm)) = delimited_email_address(input, context) {
         Ok((i, elm))
-    } else if let Ok((i, elm)) = delimited_link(input) {
+    } else if let Ok((i, elm)) = delimited_link(input, context) {
         Ok((i, elm))
     } else {
-        parse_text_element(input, prev_char)
+        parse_text_element(input, prev_char, options, context)
     }
 }
 
 /// consumes all text until [parse_element] works again, this method is only for internal use by [markdown_text]
 ///
 /// its output is not useable on its own, always combinate this with [nom::combinator::recognize]
-fn eat_markdown_text(input: &str) -> IResult<&str, (), CustomError<&str>> {
-    let mut remaining = input;
-    while !remaining.is_empty() {
-        // take 1, because other parsers didn't work (text is always the last used parser)
-        let (remainder, taken) = take(1usize)(remaining)?;
-        remaining = remainder;
+fn eat_markdown_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, (), CustomError<&'a str>> {
+    // skip the first character, because other parsers didn't work (text is always the last used parser)
+    for (position, prev_char) in ElementStarts::new(
+        input,
+        ElementSet::Markdown,
+        options.emoji_elements,
+        context.scan(),
+    ) {
+        let remaining = input.slice(position..);
         // peek if there is an element
-        if peek(|input| parse_element(input, taken.chars().next()))(remaining).is_ok() {
-            break;
+        let element = context.peek(|| {
+            peek(|input| parse_element(input, Some(prev_char), options, context))(remaining)
+        });
+        if element.is_ok() {
+            return Ok((remaining, ()));
         }
-        // take until whitespace
-        //remaining = take_while(|c| not_blank_space(c))(remaining)?.0;
     }
-    Ok((remaining, ()))
+    Ok((input.slice(input.len()..), ()))
 }
 
 /// Consumes text until another parser of [parse_element] works again
 ///
 /// used as last parser, if the others do not consume the input it consumes the input until another parser works again
 /// (uses whitespace seperation to make the parsing faster)
-pub(crate) fn markdown_text(input: &str) -> IResult<&str, Element, CustomError<&str>> {
-    let (rest, content) = recognize(eat_markdown_text)(input)?;
+pub(crate) fn markdown_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    let (rest, content) = recognize(|input| eat_markdown_text(input, options, context))(input)?;
     Ok((rest, Element::Text(content)))
 }
@@ -1,88 +1,140 @@
 use nom::{
-    bytes::complete::take,
     combinator::{peek, recognize},
-    IResult,
+    IResult, Slice,
 };
 
 use crate::parser::{
     parse_from_text::{
-        base_parsers::{direct_delimited, CustomError},
-        markdown_elements::inline_code,
+        allowed_or_text,
+        base_parsers::CustomError,
+        consumed,
+        element_starts::{ElementSet, ElementStarts},
+        markdown_elements::{delimited_markup, inline_code},
+        text_elements::emoji_element,
+        Context,
     },
-    Element,
+    Element, ParserOptions,
 };
 
 /// Parsers for label in labelled links and later also labeled hashtags
 /// parse elements inside of label in markdown set
-pub(crate) fn parse_label_elements(input: &str) -> Vec<Element> {
+pub(crate) fn parse_label_elements<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> Vec<Element<'a>> {
     let mut result = Vec::new();
     let mut remaining = input;
-    // println!("p-{}", input);
-    while !remaining.is_empty() {
-        // println!("r-{}", remaining);
-        if let Ok((rest, element)) = parse_markdown_label_element(remaining) {
-            // println!("e-{:?} - {}", element, remaining);
-            remaining = rest;
-            result.push(element);
-        } else if let Ok((rest, element)) = markdown_label_text(remaining) {
-            // println!("e-{:?} - {}", element, remaining);
-            result.push(element);
-            remaining = rest;
-        } else {
-            // println!("e-textDefault-{}", remaining);
-            result.push(Element::Text(remaining));
-            break;
-        }
+    // after the limit the top level element is kept as text, so its other children are not needed
+    while !remaining.is_empty() && !context.elements_exceeded(options) {
+        let mark = context.source_mark();
+        let (rest, element) = next_label_element(remaining, options, context);
+        context.count_element();
+        context.record_source(mark, consumed(remaining, rest));
+        result.push(element);
+        remaining = rest;
     }
     result
 }
 
-pub(crate) fn parse_markdown_label_element(
-    input: &str,
-) -> IResult<&str, Element, CustomError<&str>> {
+/// parses the next element of [parse_label_elements], returns the remaining input and the element
+pub(crate) fn next_label_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> (&'a str, Element<'a>) {
+    if let Ok((rest, element)) = parse_markdown_label_element(input, options, context) {
+        (rest, element)
+    } else if let Ok((rest, element)) = markdown_label_text(input, options, context) {
+        (rest, element)
+    } else {
+        ("", Element::Text(input))
+    }
+}
+
+/// parses the next element of a label, elements that the options don't allow are returned as
+/// text or, for elements with children, not parsed
+pub(crate) fn parse_markdown_label_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    label_element(input, options, context).map(|parsed| allowed_or_text(input, parsed, options))
+}
+
+fn label_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
     // the order is important
     // generaly more specific parsers that fail/return fast should be in the front
     // But keep in mind that the order can also change how and if the parser works as intended
-    if let Ok((i, b)) = direct_delimited(input, "**") {
-        Ok((i, Element::Bold(parse_label_elements(b))))
-    } else if let Ok((i, b)) = direct_delimited(input, "__") {
-        Ok((i, Element::Bold(parse_label_elements(b))))
-    } else if let Ok((i, b)) = direct_delimited(input, "_") {
-        Ok((i, Element::Italics(parse_label_elements(b))))
-    } else if let Ok((i, b)) = direct_delimited(input, "*") {
-        Ok((i, Element::Italics(parse_label_elements(b))))
-    } else if let Ok((i, b)) = direct_delimited(input, "~~") {
-        Ok((i, Element::StrikeThrough(parse_label_elements(b))))
+    if let Ok((i, elm)) = label_markup_element(input, options, context) {
+        Ok((i, elm))
     } else if let Ok((i, b)) = inline_code(input) {
         Ok((i, Element::InlineCode { content: b }))
+    } else if options.emoji_elements {
+        emoji_element(input)
     } else {
         Err(nom::Err::Error(CustomError::NoElement))
     }
 }
+fn label_markup_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    let (input, (element, content)) = delimited_markup(input)?;
+    if !options.allows(&element(Vec::new())) {
+        return Err(nom::Err::Error(CustomError::DisabledElement));
+    }
+    let context = context
+        .children(options)
+        .ok_or(nom::Err::Error(CustomError::NestingTooDeep))?;
+    Ok((
+        input,
+        element(parse_label_elements(content, options, context)),
+    ))
+}
+
 /// consumes all text until [parse_label_elements] works again, this method is only for internal use by [markdown_label_text]
 ///
 /// its output is not useable on its own, always combinate this with [nom::combinator::recognize]
-fn eat_markdown_label_text(input: &str) -> IResult<&str, (), CustomError<&str>> {
-    let mut remaining = input;
-    while !remaining.is_empty() {
-        // take 1, because other parsers didn't work (text is always the last used parser)
-        let (remainder, _taken) = take(1usize)(remaining)?;
-        remaining = remainder;
+fn eat_markdown_label_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, (), CustomError<&'a str>> {
+    // skip the first character, because other parsers didn't work (text is always the last used parser)
+    for (position, _) in ElementStarts::new(
+        input,
+        ElementSet::MarkdownLabel,
+        options.emoji_elements,
+        context.scan(),
+    ) {
+        let remaining = input.slice(position..);
         // peek if there is an element
-        if peek(|input| parse_markdown_label_element(input))(remaining).is_ok() {
-            break;
+        let element = context.peek(|| {
+            peek(|input| parse_markdown_label_element(input, options, context))(remaining)
+        });
+        if element.is_ok() {
+            return Ok((remaining, ()));
         }
-        // take until whitespace
-        //remaining = take_while(|c| not_blank_space(c))(remaining)?.0;
     }
-    Ok((remaining, ()))
+    Ok((input.slice(input.len()..), ()))
 }
 
 /// Consumes text until another parser of [parse_markdown_label_element] works again
 ///
 /// used as last parser, if the others do not consume the input it consumes the input until another parser works again
 /// (uses whitespace seperation to make the parsing faster)
-fn markdown_label_text(input: &str) -> IResult<&str, Element, CustomError<&str>> {
-    let (rest, content) = recognize(eat_markdown_label_text)(input)?;
+fn markdown_label_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    let (rest, content) =
+        recognize(|input| eat_markdown_label_text(input, options, context))(input)?;
     Ok((rest, Element::Text(content)))
 }
@@ -1,80 +1,341 @@
-use super::Element;
+use std::cell::Cell;
+
+use nom::Slice;
+
+use super::{source::ElementSource, Element, LimitsReport, ParserOptions};
+use scan_cache::ScanCache;
+use sources::SourceRecorder;
 
 pub(crate) mod base_parsers;
 mod desktop_subset;
+pub(crate) mod element_starts;
 pub mod find_range;
 pub mod hashtag_content_char_ranges;
-mod markdown_elements;
+pub(crate) mod markdown_elements;
+pub(crate) mod scan_cache;
+pub(crate) mod sources;
 mod text_elements;
 
-/// parses text elements such as links and email addresses, excluding markdown
-pub(crate) fn parse_only_text(input: &str) -> std::vec::Vec<Element> {
-    let mut result = Vec::new();
-    let mut remaining = input;
-    // println!("p-{}", input);
-    while !remaining.is_empty() {
-        // println!("r-{}", remaining);
-        if let Ok((rest, element)) = text_elements::parse_text_element(remaining, None) {
-            // println!("e-{:?} - {}", element, remaining);
-            remaining = rest;
-            result.push(element);
-        } else if let Ok((rest, element)) = text_elements::text(remaining) {
-            // println!("e-{:?} - {}", element, remaining);
-            result.push(element);
-            remaining = rest;
-        } else {
-            // println!("e-textDefault-{}", remaining);
-            result.push(Element::Text(remaining));
-            break;
+/// The limits of one parse, shared by the parsers of all elements
+#[derive(Debug, Default)]
+pub(crate) struct LimitsState {
+    /// set when markup was kept as text because of the nesting depth
+    nesting_exceeded: Cell<bool>,
+    /// number of the parsed elements including the nested ones, see [ParserOptions::max_elements]
+    element_count: Cell<usize>,
+}
+
+/// Passed to the parsers of nested elements, to check the limits of [ParserOptions], to share
+/// what the parsers scanned and to record the sources of the elements
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Context<'r> {
+    /// how deep the parser is in nested markdown elements, see [ParserOptions::max_nesting_depth]
+    depth: usize,
+    limits: &'r LimitsState,
+    scan: &'r ScanCache<'r>,
+    /// only set if the sources of the elements are returned
+    sources: Option<&'r SourceRecorder<'r>>,
+}
+
+impl<'r> Context<'r> {
+    pub(crate) fn new(
+        limits: &'r LimitsState,
+        scan: &'r ScanCache<'r>,
+        sources: Option<&'r SourceRecorder<'r>>,
+    ) -> Self {
+        Context {
+            depth: 0,
+            limits,
+            scan,
+            sources,
+        }
+    }
+
+    /// What the parsers scanned in this parse, to not scan the same text again
+    pub(crate) fn scan(self) -> &'r ScanCache<'r> {
+        self.scan
+    }
+
+    /// Context of the children of an element, `None` if they would be nested too deep
+    pub(crate) fn children(self, options: &ParserOptions) -> Option<Self> {
+        if options
+            .max_nesting_depth
+            .map_or(false, |max_depth| self.depth >= max_depth)
+        {
+            self.limits.nesting_exceeded.set(true);
+            return None;
+        }
+        Some(Context {
+            depth: self.depth.saturating_add(1),
+            ..self
+        })
+    }
+
+    /// Counts a parsed child element, its own children were counted by the parser of its children
+    pub(crate) fn count_element(self) {
+        let count = self.limits.element_count.get();
+        self.limits.element_count.set(count.saturating_add(1));
+    }
+
+    /// Whether more elements were parsed than [ParserOptions::max_elements] allows, then the
+    /// parsers of nested elements stop and the top level element is kept as text
+    pub(crate) fn elements_exceeded(self, options: &ParserOptions) -> bool {
+        options.max_elements.map_or(false, |max_elements| {
+            self.limits.element_count.get() > max_elements
+        })
+    }
+
+    /// Runs a parser that only looks if there is an element, without counting its elements or
+    /// recording their sources
+    pub(crate) fn peek<T>(self, parser: impl FnOnce() -> T) -> T {
+        let element_count = self.limits.element_count.get();
+        let mark = self.source_mark();
+        let result = parser();
+        self.limits.element_count.set(element_count);
+        self.discard_sources(mark);
+        result
+    }
+
+    /// Take it before an element is parsed, for [Context::record_source]
+    pub(crate) fn source_mark(self) -> usize {
+        self.sources.map_or(0, SourceRecorder::mark)
+    }
+
+    /// Records that an element was parsed from `text`, the sources recorded after `mark` are the
+    /// sources of its children
+    pub(crate) fn record_source(self, mark: usize, text: &str) {
+        if let Some(sources) = self.sources {
+            sources.record(mark, text);
+        }
+    }
+
+    fn discard_sources(self, mark: usize) {
+        if let Some(sources) = self.sources {
+            sources.truncate(mark);
         }
     }
-    result
 }
 
-/// parses all kinds of elements, including markdown
-pub(crate) fn parse_all(input: &str) -> std::vec::Vec<Element> {
-    let mut result = Vec::new();
+/// number of elements including the nested ones
+fn element_count(element: &Element) -> usize {
+    let children = match element {
+        Element::Bold(children)
+        | Element::Italics(children)
+        | Element::StrikeThrough(children)
+        | Element::LabeledLink {
+            label: children, ..
+        } => children.iter().map(element_count).sum(),
+        _ => 0,
+    };
+    children.saturating_add(1)
+}
+
+/// Elements of the top level parsers
+pub(crate) struct Parsed<'a> {
+    pub(crate) elements: Vec<Element<'a>>,
+    /// the part of the input that each element was parsed from, empty if they were not recorded
+    pub(crate) sources: Vec<ElementSource<'a>>,
+    pub(crate) report: LimitsReport,
+}
+
+/// Collects the elements returned by `next_element` until the end of the input or until
+/// [ParserOptions::max_elements] is exceeded, and their sources if `with_sources` is set
+fn parse_with_element_limit<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    with_sources: bool,
+    mut next_element: impl FnMut(&'a str, Context) -> (&'a str, Element<'a>),
+) -> Parsed<'a> {
+    let limits = LimitsState::default();
+    let scan = ScanCache::new(input);
+    let recorder = with_sources.then(|| SourceRecorder::new(input));
+    let context = Context::new(&limits, &scan, recorder.as_ref());
+    let mut elements = Vec::new();
+    let mut max_elements_exceeded = false;
     let mut remaining = input;
-    // println!("p-{}", input);
     while !remaining.is_empty() {
-        // println!("r-{}", remaining);
-        if let Ok((rest, element)) = markdown_elements::parse_element(remaining, None) {
-            // println!("e-{:?} - {}", element, remaining);
-            remaining = rest;
-            result.push(element);
-        } else if let Ok((rest, element)) = markdown_elements::markdown_text(remaining) {
-            // println!("e-{:?} - {}", element, remaining);
-            result.push(element);
-            remaining = rest;
-        } else {
-            // println!("e-textDefault-{}", remaining);
-            result.push(Element::Text(remaining));
+        let count = limits.element_count.get();
+        let mark = context.source_mark();
+        let (rest, element) = next_element(remaining, context);
+        // the nested markdown elements were already counted while they were parsed, but not the
+        // label of labeled links in the desktop set
+        limits
+            .element_count
+            .set(count.saturating_add(element_count(&element)));
+        if context.elements_exceeded(options) {
+            context.discard_sources(mark);
+            context.record_source(mark, remaining);
+            elements.push(Element::Text(remaining));
+            max_elements_exceeded = true;
             break;
         }
+        context.record_source(mark, consumed(remaining, rest));
+        elements.push(element);
+        remaining = rest;
+    }
+    Parsed {
+        elements,
+        sources: recorder
+            .map(|recorder| recorder.into_sources(input))
+            .unwrap_or_default(),
+        report: LimitsReport {
+            max_nesting_depth_exceeded: limits.nesting_exceeded.get(),
+            max_elements_exceeded,
+            ..Default::default()
+        },
     }
-    result
 }
 
-/// parses delimited and labled links additional to the text elements
-pub(crate) fn parse_desktop_set(input: &str) -> std::vec::Vec<Element> {
+/// the part of `input` before `rest`, which is the remaining input after a parser
+pub(crate) fn consumed<'a>(input: &'a str, rest: &str) -> &'a str {
+    input.slice(..input.len().saturating_sub(rest.len()))
+}
+
+/// Keeps an element that the options don't allow as the text it was parsed from, `input` is the
+/// input of the parser that returned `rest` and `element`
+pub(crate) fn allowed_or_text<'a>(
+    input: &'a str,
+    (rest, element): (&'a str, Element<'a>),
+    options: &ParserOptions,
+) -> (&'a str, Element<'a>) {
+    if options.allows(&element) {
+        (rest, element)
+    } else {
+        (rest, Element::Text(consumed(input, rest)))
+    }
+}
+
+/// parses text elements such as links and email addresses, excluding markdown
+pub(crate) fn parse_only_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    with_sources: bool,
+) -> Parsed<'a> {
+    parse_with_element_limit(input, options, with_sources, |input, context| {
+        next_text_element(input, options, context)
+    })
+}
+
+fn next_text_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> (&'a str, Element<'a>) {
+    if let Ok((rest, element)) = text_elements::parse_text_element(input, None, options, context) {
+        (rest, element)
+    } else if let Ok((rest, element)) = text_elements::text(input, options, context) {
+        (rest, element)
+    } else {
+        ("", Element::Text(input))
+    }
+}
+
+/// parses all kinds of elements, including markdown, with the limits of [ParserOptions]
+pub(crate) fn parse_markdown<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    with_sources: bool,
+) -> Parsed<'a> {
+    if options
+        .max_markdown_bytes
+        .map_or(false, |max_bytes| input.len() > max_bytes)
+    {
+        let parsed = parse_only_text(input, options, with_sources);
+        return Parsed {
+            report: LimitsReport {
+                max_markdown_bytes_exceeded: true,
+                ..parsed.report
+            },
+            ..parsed
+        };
+    }
+    parse_with_element_limit(input, options, with_sources, |input, context| {
+        parse_next_markdown_element(input, options, context)
+    })
+}
+
+/// parses all kinds of elements, including markdown, used for the content of nested elements
+pub(crate) fn parse_all<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> Vec<Element<'a>> {
     let mut result = Vec::new();
     let mut remaining = input;
-    // println!("p-{}", input);
-    while !remaining.is_empty() {
-        // println!("r-{}", remaining);
-        if let Ok((rest, element)) = desktop_subset::parse_element(remaining, None) {
-            // println!("e-{:?} - {}", element, remaining);
-            remaining = rest;
-            result.push(element);
-        } else if let Ok((rest, element)) = desktop_subset::desktopset_text(remaining) {
-            // println!("e-{:?} - {}", element, remaining);
-            result.push(element);
-            remaining = rest;
-        } else {
-            // println!("e-textDefault-{}", remaining);
-            result.push(Element::Text(remaining));
-            break;
-        }
+    // after the limit the top level element is kept as text, so its other children are not needed
+    while !remaining.is_empty() && !context.elements_exceeded(options) {
+        let mark = context.source_mark();
+        let (rest, element) = parse_next_markdown_element(remaining, options, context);
+        context.count_element();
+        context.record_source(mark, consumed(remaining, rest));
+        result.push(element);
+        remaining = rest;
     }
     result
 }
+
+/// parses the next top level element of [parse_all], returns the remaining input and the element
+///
+/// The result only depends on `input`, so parsing can continue at any element boundary.
+pub(crate) fn parse_next_markdown_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> (&'a str, Element<'a>) {
+    if let Ok((rest, element)) = markdown_elements::parse_element(input, None, options, context) {
+        (rest, element)
+    } else if let Ok((rest, element)) = markdown_elements::markdown_text(input, options, context) {
+        (rest, element)
+    } else {
+        ("", Element::Text(input))
+    }
+}
+
+/// parses delimited and labled links additional to the text elements
+pub(crate) fn parse_desktop_set<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    with_sources: bool,
+) -> Parsed<'a> {
+    parse_with_element_limit(input, options, with_sources, |input, context| {
+        next_desktop_set_element(input, options, context)
+    })
+}
+
+fn next_desktop_set_element<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> (&'a str, Element<'a>) {
+    if let Ok((rest, element)) = desktop_subset::parse_element(input, None, options, context) {
+        (rest, element)
+    } else if let Ok((rest, element)) = desktop_subset::desktopset_text(input, options, context) {
+        (rest, element)
+    } else {
+        ("", Element::Text(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_all, Context, LimitsState, ScanCache};
+    use crate::parser::{Element, ParserOptions};
+
+    #[test]
+    fn nested_parsers_stop_at_the_element_limit() {
+        let options = ParserOptions {
+            max_elements: Some(2),
+            ..Default::default()
+        };
+        let input = "#a #b #c #d _e_";
+        let limits = LimitsState::default();
+        let scan = ScanCache::new(input);
+        let children = parse_all(input, &options, Context::new(&limits, &scan, None));
+        // the third element exceeds the limit, the rest of the text is not parsed
+        assert_eq!(
+            children,
+            vec![Element::Tag("#a"), Element::Text(" "), Element::Tag("#b")]
+        );
+        assert_eq!(limits.element_count.get(), 3);
+    }
+}
@@ -0,0 +1,385 @@
+//! What the parsers of email addresses, links and delimited elements scanned, shared by all parsers
+//! of one parse.
+//!
+//! These parsers scan the text after their start, often until the end of the word, before they
+//! know that there is no element. In a long word without spaces every position might be a start,
+//! so scanning again from each of them makes parsing quadratic. A run of characters that a parser
+//! consumes ends at the same position for every start inside of it, so the runs are scanned once
+//! and the parsers are only tried where the cached scans say that they can work.
+//!
+//! The positions are byte offsets in the whole input, so the cache can be shared by the parsers
+//! of nested elements. Their input ends before the end of the whole input, so the runs are cut
+//! at the end of the text that a parser gets.
+use std::cell::Cell;
+
+use nom::{bytes::complete::tag, sequence::tuple, Slice};
+
+use super::text_elements::email_address_part_char;
+use crate::parser::{
+    link_url::{
+        check_if_tld_is_allowed, is_allowed_generic_scheme, is_ireg_name_not_pct_encoded,
+        is_scheme, is_scheme_start, LinkDestination, MAX_GENERIC_SCHEME_LENGTH,
+    },
+    utils::is_hex_digit,
+};
+
+/// Characters from `start` until `end`, the first position after `start` where the run stops
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    end: usize,
+}
+
+/// Length of the part at the start of the text that continues a run, `None` if the run stops there
+type RunPart = fn(&str) -> Option<usize>;
+
+/// The first `byte` at or after `from`
+#[derive(Debug, Clone, Copy)]
+struct NextByte {
+    from: usize,
+    found: Option<usize>,
+}
+
+/// The host name of a link without scheme that ends at `end`
+#[derive(Debug, Clone, Copy)]
+struct HostName {
+    end: usize,
+    /// start of the search for the last dot, the host name can start later
+    searched_from: usize,
+    /// offset of the last `.` that is not at the end, the host needs a top level domain
+    last_dot: Option<usize>,
+    tld_is_allowed: bool,
+    /// value of [ScanCache::host_name_uses] when it was last looked up
+    used: usize,
+}
+
+/// Host names cached at the same time
+///
+/// The nested elements that end inside a host name look up other ends than the text around them,
+/// the least recently used host name is replaced so the ends of the enclosing texts stay cached.
+const HOST_NAME_ENTRIES: usize = 4;
+
+/// Cache of the scans of one parse, see the [module documentation](self)
+#[derive(Debug)]
+pub(crate) struct ScanCache<'i> {
+    input: &'i str,
+    email_address_part: Cell<Option<Run>>,
+    /// the part after the `@`, cached separately because it is looked up for every start before it
+    email_domain_part: Cell<Option<Run>>,
+    scheme: Cell<Option<Run>>,
+    host_name_part: Cell<Option<Run>>,
+    pct_encoded: Cell<Option<Run>>,
+    host_names: [Cell<Option<HostName>>; HOST_NAME_ENTRIES],
+    host_name_uses: Cell<usize>,
+    closing_angle: Cell<Option<NextByte>>,
+    closing_bracket: Cell<Option<NextByte>>,
+    /// scheme end and input end of a link whose part after the scheme is no link
+    failed_link_after_scheme: Cell<Option<(usize, usize)>>,
+    /// `]` position, input end and whether a link destination in `()` follows
+    label_destination: Cell<Option<(usize, usize, bool)>>,
+}
+
+impl<'i> ScanCache<'i> {
+    pub(crate) fn new(input: &'i str) -> Self {
+        ScanCache {
+            input,
+            email_address_part: Cell::new(None),
+            email_domain_part: Cell::new(None),
+            scheme: Cell::new(None),
+            host_name_part: Cell::new(None),
+            pct_encoded: Cell::new(None),
+            host_names: Default::default(),
+            host_name_uses: Cell::new(0),
+            closing_angle: Cell::new(None),
+            closing_bracket: Cell::new(None),
+            failed_link_after_scheme: Cell::new(None),
+            label_destination: Cell::new(None),
+        }
+    }
+
+    /// Whether [super::text_elements::email_address] can parse an email address at the start of
+    /// `text`: email address characters followed by `@` and more of them
+    pub(crate) fn may_be_email_address(&self, text: &str) -> bool {
+        let Some((start, end)) = self.range(text) else {
+            return true;
+        };
+        self.email_address_end(start, end).is_some()
+    }
+
+    /// Whether [LinkDestination::parse] can parse a link at the start of `text`
+    ///
+    /// Call [ScanCache::link_failed] if it doesn't, so it is not tried again for the same scheme.
+    pub(crate) fn may_be_link(&self, text: &str) -> bool {
+        let Some((start, end)) = self.range(text) else {
+            return true;
+        };
+        match self.scheme_end(start, end) {
+            Some(scheme_end) => {
+                let scheme = self.input.get(start..scheme_end).unwrap_or_default();
+                // the part after the scheme is the same for all starts before the scheme end,
+                // only the scheme decides if it can be a link with a generic scheme
+                (scheme.len() <= MAX_GENERIC_SCHEME_LENGTH && is_allowed_generic_scheme(scheme))
+                    || self.failed_link_after_scheme.get() != Some((scheme_end, end))
+            }
+            None => self.is_link_without_scheme(start, end),
+        }
+    }
+
+    /// Records that [LinkDestination::parse] found no link at the start of `text`
+    pub(crate) fn link_failed(&self, text: &str) {
+        if let Some((start, end)) = self.range(text) {
+            if let Some(scheme_end) = self.scheme_end(start, end) {
+                self.failed_link_after_scheme.set(Some((scheme_end, end)));
+            }
+        }
+    }
+
+    /// Whether `text` starts with `<` and an email address that ends at the next `>`, see
+    /// [super::markdown_elements::delimited_email_address]
+    pub(crate) fn may_be_delimited_email_address(&self, text: &str) -> bool {
+        let Some((start, end)) = self.range(text) else {
+            return true;
+        };
+        if !text.starts_with('<') {
+            return false;
+        }
+        let content_start = start.saturating_add(1);
+        let Some(closing_angle) = self
+            .next_byte(&self.closing_angle, b'>', content_start)
+            .filter(|&closing_angle| closing_angle < end)
+        else {
+            return false;
+        };
+        // the email address has to end at `>`, but not with a dot, that is not part of it
+        self.email_address_end(content_start, closing_angle) == Some(closing_angle)
+            && self.byte(closing_angle.saturating_sub(1)) != Some(b'.')
+    }
+
+    /// Whether `text` starts with `<` and can be a link followed by `>`, see
+    /// [super::markdown_elements::delimited_link]
+    pub(crate) fn may_be_delimited_link(&self, text: &str) -> bool {
+        let Some((start, end)) = self.range(text) else {
+            return true;
+        };
+        text.starts_with('<')
+            && self
+                .next_byte(&self.closing_angle, b'>', start)
+                .map_or(false, |closing_angle| closing_angle < end)
+            && self.may_be_link(text.slice(1..))
+    }
+
+    /// Whether `text` starts with a non empty label in `[]` that is followed by a link in `()`,
+    /// see [super::markdown_elements::raw_label]
+    pub(crate) fn may_be_labeled_link(&self, text: &str) -> bool {
+        let Some((start, end)) = self.range(text) else {
+            return true;
+        };
+        if !text.starts_with('[') {
+            return false;
+        }
+        let Some(closing_bracket) = self
+            .next_byte(&self.closing_bracket, b']', start)
+            .filter(|&closing_bracket| closing_bracket < end)
+        else {
+            return false;
+        };
+        if closing_bracket <= start.saturating_add(1) {
+            return false;
+        }
+        // the destination is the same for all labels that end at this `]`
+        match self.label_destination.get() {
+            Some((cached_bracket, cached_end, has_destination))
+                if cached_bracket == closing_bracket && cached_end == end =>
+            {
+                has_destination
+            }
+            _ => {
+                let after_label = self
+                    .input
+                    .get(closing_bracket.saturating_add(1)..end)
+                    .unwrap_or_default();
+                let has_destination =
+                    tuple((tag("("), LinkDestination::parse_labelled, tag(")")))(after_label)
+                        .is_ok();
+                self.label_destination
+                    .set(Some((closing_bracket, end, has_destination)));
+                has_destination
+            }
+        }
+    }
+
+    /// the byte range of `text` in the input, `None` if it is not a part of it
+    fn range(&self, text: &str) -> Option<(usize, usize)> {
+        let start = (text.as_ptr() as usize).checked_sub(self.input.as_ptr() as usize)?;
+        let end = start.checked_add(text.len())?;
+        (end <= self.input.len()).then_some((start, end))
+    }
+
+    fn byte(&self, position: usize) -> Option<u8> {
+        self.input.as_bytes().get(position).copied()
+    }
+
+    /// The end of the run from `position`, the same as from every other position of a cached run
+    fn run_end(&self, cache: &Cell<Option<Run>>, part: RunPart, position: usize) -> usize {
+        let cached = cache.get();
+        if let Some(run) = cached {
+            if run.start <= position && position <= run.end {
+                return run.end;
+            }
+        }
+        let mut end = position;
+        loop {
+            if let Some(run) = cached.filter(|run| run.start == end) {
+                end = run.end;
+                break;
+            }
+            match self.input.get(end..).and_then(part) {
+                Some(length) => end = end.saturating_add(length),
+                None => break,
+            }
+        }
+        cache.set(Some(Run {
+            start: position,
+            end,
+        }));
+        end
+    }
+
+    fn next_byte(&self, cache: &Cell<Option<NextByte>>, byte: u8, from: usize) -> Option<usize> {
+        if let Some(next) = cache.get() {
+            if next.from <= from && next.found.map_or(true, |found| from <= found) {
+                return next.found;
+            }
+        }
+        let found = self
+            .input
+            .as_bytes()
+            .get(from..)
+            .unwrap_or_default()
+            .iter()
+            .position(|&other| other == byte)
+            .map(|offset| from.saturating_add(offset));
+        cache.set(Some(NextByte { from, found }));
+        found
+    }
+
+    /// The end of the email address that starts at `start`, it is cut at `end`
+    fn email_address_end(&self, start: usize, end: usize) -> Option<usize> {
+        let at = self
+            .run_end(&self.email_address_part, email_address_part, start)
+            .min(end);
+        if at <= start || self.byte(at) != Some(b'@') || at >= end {
+            return None;
+        }
+        let domain_start = at.saturating_add(1);
+        let domain_end = self
+            .run_end(&self.email_domain_part, email_address_part, domain_start)
+            .min(end);
+        (domain_end > domain_start).then_some(domain_end)
+    }
+
+    /// The end of the scheme of a link at `start` if it is followed by `:`
+    fn scheme_end(&self, start: usize, end: usize) -> Option<usize> {
+        let first = self.input.get(start..end)?.chars().next()?;
+        if !is_scheme_start(first) {
+            return None;
+        }
+        let scheme_end = self.run_end(&self.scheme, scheme_part, start).min(end);
+        (scheme_end < end && self.byte(scheme_end) == Some(b':')).then_some(scheme_end)
+    }
+
+    /// A link without scheme needs a host name with an allowed top level domain after the
+    /// optional user info, ip addresses and literals have no allowed top level domain
+    fn is_link_without_scheme(&self, start: usize, end: usize) -> bool {
+        let user_info_end = self
+            .run_end(&self.pct_encoded, pct_encoded_part, start)
+            .min(end);
+        let host_start = if user_info_end < end && self.byte(user_info_end) == Some(b'@') {
+            user_info_end.saturating_add(1)
+        } else {
+            start
+        };
+        if self.byte(host_start) == Some(b'[') {
+            return false;
+        }
+        let host_end = self
+            .run_end(&self.host_name_part, host_name_part, host_start)
+            .min(end);
+        if host_end <= host_start {
+            return false;
+        }
+        let used = self.host_name_uses.get().saturating_add(1);
+        self.host_name_uses.set(used);
+        let cached = self.host_names.iter().position(|entry| {
+            entry
+                .get()
+                .map_or(false, |host_name| host_name.end == host_end)
+        });
+        let host_name = match cached.and_then(|index| self.host_names.get(index)?.get()) {
+            Some(host_name)
+                if host_name.last_dot.is_some() || host_name.searched_from <= host_start =>
+            {
+                HostName { used, ..host_name }
+            }
+            _ => {
+                let host = self
+                    .input
+                    .get(host_start..host_end)
+                    .unwrap_or_default()
+                    .trim_end_matches('.');
+                HostName {
+                    end: host_end,
+                    searched_from: host_start,
+                    last_dot: host
+                        .rfind('.')
+                        .map(|offset| host_start.saturating_add(offset)),
+                    tld_is_allowed: host
+                        .rsplit('.')
+                        .next()
+                        .map_or(false, check_if_tld_is_allowed),
+                    used,
+                }
+            }
+        };
+        let replaced = cached.or_else(|| {
+            self.host_names
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, entry)| entry.get().map(|host_name| host_name.used))
+                .map(|(index, _)| index)
+        });
+        if let Some(entry) = replaced.and_then(|index| self.host_names.get(index)) {
+            entry.set(Some(host_name));
+        }
+        host_name.tld_is_allowed
+            && host_name
+                .last_dot
+                .map_or(false, |last_dot| last_dot >= host_start)
+    }
+}
+
+fn char_part(text: &str, is_part: fn(char) -> bool) -> Option<usize> {
+    text.chars()
+        .next()
+        .filter(|&c| is_part(c))
+        .map(char::len_utf8)
+}
+
+fn email_address_part(text: &str) -> Option<usize> {
+    char_part(text, email_address_part_char)
+}
+
+fn scheme_part(text: &str) -> Option<usize> {
+    char_part(text, is_scheme)
+}
+
+/// `%` and two hex digits
+fn pct_encoded_part(text: &str) -> Option<usize> {
+    let mut chars = text.chars();
+    (chars.next() == Some('%') && chars.take(2).filter(|&c| is_hex_digit(c)).count() == 2)
+        .then_some(3)
+}
+
+fn host_name_part(text: &str) -> Option<usize> {
+    pct_encoded_part(text).or_else(|| char_part(text, is_ireg_name_not_pct_encoded))
+}
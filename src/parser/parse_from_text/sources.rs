@@ -0,0 +1,82 @@
+//! Records the parts of the input that the elements were parsed from while they are parsed, for
+//! the `*_with_sources` parse functions.
+use std::{cell::RefCell, ops::Range};
+
+use nom::Offset;
+
+use crate::parser::source::ElementSource;
+
+/// The byte range of an element in the input and the ranges of its children
+#[derive(Debug)]
+struct RecordedSource {
+    range: Range<usize>,
+    children: Vec<RecordedSource>,
+}
+
+/// The sources of the elements of one parse
+///
+/// The children of an element are parsed before the element is complete, so their sources are
+/// recorded first. The parsers take a [SourceRecorder::mark] before they parse an element, the
+/// sources recorded after the mark become the children of the element.
+#[derive(Debug)]
+pub(crate) struct SourceRecorder<'r> {
+    /// the whole input, the ranges are byte offsets in it
+    input: &'r str,
+    /// the sources of the elements whose parent is not parsed yet
+    pending: RefCell<Vec<RecordedSource>>,
+}
+
+impl<'r> SourceRecorder<'r> {
+    pub(crate) fn new(input: &'r str) -> Self {
+        SourceRecorder {
+            input,
+            pending: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// the position of the next recorded source
+    pub(crate) fn mark(&self) -> usize {
+        self.pending.borrow().len()
+    }
+
+    /// Records the source of an element that was parsed from `text`, the sources recorded after
+    /// `mark` are the sources of its children
+    pub(crate) fn record(&self, mark: usize, text: &str) {
+        let mut pending = self.pending.borrow_mut();
+        let mark = mark.min(pending.len());
+        let children = pending.split_off(mark);
+        let start = self.input.offset(text);
+        pending.push(RecordedSource {
+            range: start..start.saturating_add(text.len()),
+            children,
+        });
+    }
+
+    /// Removes the sources recorded after `mark`, of elements that are not part of the result
+    pub(crate) fn truncate(&self, mark: usize) {
+        self.pending.borrow_mut().truncate(mark);
+    }
+
+    /// The sources of the top level elements, `input` is the input of the parse
+    pub(crate) fn into_sources(self, input: &str) -> Vec<ElementSource> {
+        self.pending
+            .into_inner()
+            .into_iter()
+            .map(|source| source.into_source(input))
+            .collect()
+    }
+}
+
+impl RecordedSource {
+    fn into_source(self, input: &str) -> ElementSource {
+        ElementSource {
+            text: input.get(self.range.clone()).unwrap_or_default(),
+            range: self.range,
+            children: self
+                .children
+                .into_iter()
+                .map(|child| child.into_source(input))
+                .collect(),
+        }
+    }
+}
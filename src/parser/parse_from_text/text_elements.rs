@@ -1,8 +1,8 @@
 /// nom parsers for text elements
-use crate::parser::link_url::LinkDestination;
+use crate::parser::{is_emoji::emoji, link_url::LinkDestination, ParserOptions};
 
 use super::hashtag_content_char_ranges::hashtag_content_char;
-use super::Element;
+use super::{allowed_or_text, Context, Element};
 use nom::{
     bytes::{
         complete::{tag, take, take_while, take_while1},
@@ -15,6 +15,7 @@ use nom::{
 };
 
 use super::base_parsers::CustomError;
+use super::element_starts::{ElementSet, ElementStarts};
 
 fn linebreak(input: &str) -> IResult<&str, char, CustomError<&str>> {
     char('\n')(input)
@@ -26,6 +27,13 @@ fn hashtag(input: &str) -> IResult<&str, Element, CustomError<&str>> {
     Ok((input, Element::Tag(content)))
 }
 
+pub(crate) fn emoji_element(input: &str) -> IResult<&str, Element, CustomError<&str>> {
+    match emoji(input) {
+        Ok((input, content)) => Ok((input, Element::Emoji(content))),
+        Err(_) => Err(nom::Err::Error(CustomError::NoElement)),
+    }
+}
+
 fn not_email_address_part_char(c: char) -> bool {
     matches!(
         c,
@@ -48,7 +56,7 @@ fn not_email_address_part_char(c: char) -> bool {
     )
 }
 
-fn email_address_part_char(c: char) -> bool {
+pub(crate) fn email_address_part_char(c: char) -> bool {
     !not_email_address_part_char(c)
 }
 
@@ -102,7 +110,10 @@ fn is_allowed_bot_cmd_suggestion_char(char: char) -> bool {
 }
 
 /// Bot command suggestion
-fn bot_command_suggestion(input: &str) -> IResult<&str, Element, CustomError<&str>> {
+fn bot_command_suggestion<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
     // dc-android's: regex /(?<=^|\\s)/[a-zA-Z][a-zA-Z@\\d_/.-]{0,254}/
 
     let (input, content) = recognize(tuple((
@@ -115,16 +126,76 @@ fn bot_command_suggestion(input: &str) -> IResult<&str, Element, CustomError<&st
         }),
     )))(input)?;
     if content.slice(1..).contains('/') {
-        Ok((input, Element::Text(content)))
+        return Ok((input, Element::Text(content)));
+    }
+    let (command, target) = match content.slice(1..).split_once('@') {
+        Some((command, target)) => (command, Some(target).filter(|t| !t.is_empty())),
+        None => (content.slice(1..), None),
+    };
+    if !is_known_bot_command(command, options) {
+        return Ok((input, Element::Text(content)));
+    }
+    let (input, args) = if options.bot_command_args {
+        bot_command_args(input)
     } else {
-        Ok((input, Element::BotCommandSuggestion(content)))
+        (input, None)
+    };
+    Ok((
+        input,
+        Element::BotCommandSuggestion {
+            content,
+            command,
+            target,
+            args,
+        },
+    ))
+}
+
+fn is_known_bot_command(command: &str, options: &ParserOptions) -> bool {
+    match &options.known_bot_commands {
+        Some(known_commands) => known_commands
+            .iter()
+            .map(|known| known.trim_start_matches('/'))
+            .filter(|known| !known.is_empty())
+            .any(|known| match command.strip_prefix(known) {
+                // the arguments of `/move_a5_a6` are separated with `_`
+                Some(rest) => rest.is_empty() || rest.starts_with('_'),
+                None => false,
+            }),
+        None => true,
     }
 }
 
-pub(crate) fn parse_text_element(
-    input: &str,
+/// The arguments after a bot command, everything after the space until the end of the line
+fn bot_command_args(input: &str) -> (&str, Option<&str>) {
+    let Some(rest) = input.strip_prefix(' ') else {
+        return (input, None);
+    };
+    let line_end = rest.find('\n').unwrap_or(rest.len());
+    let args = rest.slice(..line_end).trim_end();
+    if args.trim_start().is_empty() {
+        return (input, None);
+    }
+    (rest.slice(args.len()..), Some(args))
+}
+
+/// parses the next text element, elements that the options don't allow are returned as text
+pub(crate) fn parse_text_element<'a>(
+    input: &'a str,
+    prev_char: Option<char>,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    text_element(input, prev_char, options, context)
+        .map(|parsed| allowed_or_text(input, parsed, options))
+}
+
+fn text_element<'a>(
+    input: &'a str,
     prev_char: Option<char>,
-) -> IResult<&str, Element, CustomError<&str>> {
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
     // the order is important
     // generaly more specific parsers that fail/return fast should be in the front
     // But keep in mind that the order can also change how and if the parser works as intended
@@ -132,11 +203,17 @@ pub(crate) fn parse_text_element(
     // Also as this is the text element parser,
     // text elements parsers MUST NOT call the parser for markdown elements internally
 
+    if options.emoji_elements {
+        if let Ok((i, elm)) = emoji_element(input) {
+            return Ok((i, elm));
+        }
+    }
+
     if let Ok((i, elm)) = hashtag(input) {
         Ok((i, elm))
     } else if let Ok((i, elm)) = {
         if prev_char == Some(' ') || prev_char.is_none() {
-            bot_command_suggestion(input)
+            bot_command_suggestion(input, options)
         } else {
             Err(nom::Err::Error(
                 CustomError::<&str>::PrecedingWhitespaceMissing,
@@ -146,9 +223,9 @@ pub(crate) fn parse_text_element(
         Ok((i, elm))
     } else if let Ok((i, elm)) = fediverse_address_as_text(input) {
         Ok((i, elm))
-    } else if let Ok((i, elm)) = email_address(input) {
+    } else if let Ok((i, elm)) = text_email_address(input, context) {
         Ok((i, elm))
-    } else if let Ok((i, destination)) = LinkDestination::parse(input) {
+    } else if let Ok((i, destination)) = text_link(input, context) {
         Ok((i, Element::Link { destination }))
     } else if let Ok((i, _)) = linebreak(input) {
         Ok((i, Element::Linebreak))
@@ -157,30 +234,66 @@ pub(crate) fn parse_text_element(
     }
 }
 
+/// [email_address], only tried where the scans of the parse say that there can be one
+fn text_email_address<'a>(
+    input: &'a str,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    if !context.scan().may_be_email_address(input) {
+        return Err(nom::Err::Error(CustomError::NoElement));
+    }
+    email_address(input)
+}
+
+/// [LinkDestination::parse], only tried where the scans of the parse say that there can be a link
+fn text_link<'a>(
+    input: &'a str,
+    context: Context,
+) -> IResult<&'a str, LinkDestination<'a>, CustomError<&'a str>> {
+    if !context.scan().may_be_link(input) {
+        return Err(nom::Err::Error(CustomError::InvalidLink));
+    }
+    LinkDestination::parse(input).map_err(|error| {
+        context.scan().link_failed(input);
+        error
+    })
+}
+
 /// consumes all text until [parse_text_element] works again, this method is only for internal use by [text]
 ///
 /// its output is not useable on its own, always combinate this with [nom::combinator::recognize]
-fn eat_text(input: &str) -> IResult<&str, (), CustomError<&str>> {
-    let mut remaining = input;
-    while !remaining.is_empty() {
-        // take 1, because other parsers didn't work (text is always the last used parser)
-        let (remainder, taken) = take(1usize)(remaining)?;
-        remaining = remainder;
+fn eat_text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, (), CustomError<&'a str>> {
+    // skip the first character, because other parsers didn't work (text is always the last used parser)
+    for (position, prev_char) in ElementStarts::new(
+        input,
+        ElementSet::Text,
+        options.emoji_elements,
+        context.scan(),
+    ) {
+        let remaining = input.slice(position..);
         // peek if there is an element
-        if peek(|input| parse_text_element(input, taken.chars().next()))(remaining).is_ok() {
-            break;
+        if peek(|input| parse_text_element(input, Some(prev_char), options, context))(remaining)
+            .is_ok()
+        {
+            return Ok((remaining, ()));
         }
-        // take until whitespace
-        //remaining = take_while(|c| not_blank_space(c))(remaining)?.0;
     }
-    Ok((remaining, ()))
+    Ok((input.slice(input.len()..), ()))
 }
 
 /// Consumes text until another parser of [parse_text_element] works again
 ///
 /// used as last parser, if the others do not consume the input it consumes the input until another parser works again
 /// (uses whitespace seperation to make the parsing faster)
-pub(crate) fn text(input: &str) -> IResult<&str, Element, CustomError<&str>> {
-    let (rest, content) = recognize(eat_text)(input)?;
+pub(crate) fn text<'a>(
+    input: &'a str,
+    options: &ParserOptions,
+    context: Context,
+) -> IResult<&'a str, Element<'a>, CustomError<&'a str>> {
+    let (rest, content) = recognize(|input| eat_text(input, options, context))(input)?;
     Ok((rest, Element::Text(content)))
 }
@@ -0,0 +1,86 @@
+//! The parts of the input that elements were parsed from, returned by the `*_with_sources`
+//! parse functions
+//!
+//! The elements don't contain their delimiters (like the `**` around bold text or the `[`, `](`
+//! and `)` of a labeled link) and some of them change their text (code blocks without the
+//! whitespace around the code), the sources contain the whole text of each element.
+//! Concatenating the sources of all top level elements gives the input again.
+
+use std::ops::Range;
+
+/// The part of the input that an element was parsed from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementSource<'a> {
+    /// the text of the element including its delimiters, like `**bold**` for bold text
+    pub text: &'a str,
+    /// byte range of `text` in the input
+    pub range: Range<usize>,
+    /// the sources of the children of [Element::Bold](super::Element::Bold),
+    /// [Element::Italics](super::Element::Italics), [Element::StrikeThrough](super::Element::StrikeThrough)
+    /// and of the label of [Element::LabeledLink](super::Element::LabeledLink), in the same order
+    /// as the children
+    pub children: Vec<ElementSource<'a>>,
+}
+
+/// Why the sources don't cover the input, returned by [verify_coverage]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoverageError {
+    /// this byte range of the input is not part of any source
+    Uncovered(Range<usize>),
+    /// the source with this range overlaps the source before it
+    Overlap(Range<usize>),
+    /// the text of the source with this range is not the text of the input in this range
+    TextMismatch(Range<usize>),
+    /// the child source with this range is not inside of the source of its parent
+    ChildOutsideParent(Range<usize>),
+}
+
+/// Checks that the top level `sources` follow each other without gaps or overlaps and cover the
+/// whole `input`, and that the sources of the children lie in order inside their parent
+///
+/// ```
+/// use deltachat_message_parser::parser::{
+///     parse_markdown_text_with_sources, source::verify_coverage, ParserOptions,
+/// };
+///
+/// let input = "**Hello** [world](https://delta.chat)";
+/// let (_elements, sources) = parse_markdown_text_with_sources(input, &ParserOptions::default());
+/// assert_eq!(verify_coverage(input, &sources), Ok(()));
+/// assert_eq!(sources[0].text, "**Hello**");
+/// ```
+pub fn verify_coverage(input: &str, sources: &[ElementSource]) -> Result<(), CoverageError> {
+    let mut position = 0;
+    for source in sources {
+        if source.range.start > position {
+            return Err(CoverageError::Uncovered(position..source.range.start));
+        }
+        if source.range.start < position {
+            return Err(CoverageError::Overlap(source.range.clone()));
+        }
+        verify_source(input, source)?;
+        position = source.range.end;
+    }
+    if position < input.len() {
+        return Err(CoverageError::Uncovered(position..input.len()));
+    }
+    Ok(())
+}
+
+fn verify_source(input: &str, source: &ElementSource) -> Result<(), CoverageError> {
+    if input.get(source.range.clone()) != Some(source.text) {
+        return Err(CoverageError::TextMismatch(source.range.clone()));
+    }
+    // the delimiters of the parent are not part of a child, so there can be gaps around the children
+    let mut position = source.range.start;
+    for child in &source.children {
+        if child.range.start < source.range.start || child.range.end > source.range.end {
+            return Err(CoverageError::ChildOutsideParent(child.range.clone()));
+        }
+        if child.range.start < position {
+            return Err(CoverageError::Overlap(child.range.clone()));
+        }
+        verify_source(input, child)?;
+        position = child.range.end;
+    }
+    Ok(())
+}
@@ -0,0 +1,6712 @@
+// DO NOT EDIT, generated by `cargo xtask generate-unicode-tables` from unicode/emoji-test.txt.
+
+use std::ops::RangeInclusive;
+
+use crate::parser::is_emoji::EmojiQualification::{self, *};
+
+/// First characters of the sequences in [EMOJI_SEQUENCES]
+pub(crate) const EMOJI_START_RANGES: &[RangeInclusive<u32>] = &[
+    0x23..=0x23,
+    0x2a..=0x2a,
+    0x30..=0x39,
+    0xa9..=0xa9,
+    0xae..=0xae,
+    0x203c..=0x203c,
+    0x2049..=0x2049,
+    0x2122..=0x2122,
+    0x2139..=0x2139,
+    0x2194..=0x2199,
+    0x21a9..=0x21aa,
+    0x231a..=0x231b,
+    0x2328..=0x2328,
+    0x23cf..=0x23cf,
+    0x23e9..=0x23f3,
+    0x23f8..=0x23fa,
+    0x24c2..=0x24c2,
+    0x25aa..=0x25ab,
+    0x25b6..=0x25b6,
+    0x25c0..=0x25c0,
+    0x25fb..=0x25fe,
+    0x2600..=0x2604,
+    0x260e..=0x260e,
+    0x2611..=0x2611,
+    0x2614..=0x2615,
+    0x2618..=0x2618,
+    0x261d..=0x261d,
+    0x2620..=0x2620,
+    0x2622..=0x2623,
+    0x2626..=0x2626,
+    0x262a..=0x262a,
+    0x262e..=0x262f,
+    0x2638..=0x263a,
+    0x2640..=0x2640,
+    0x2642..=0x2642,
+    0x2648..=0x2653,
+    0x265f..=0x2660,
+    0x2663..=0x2663,
+    0x2665..=0x2666,
+    0x2668..=0x2668,
+    0x267b..=0x267b,
+    0x267e..=0x267f,
+    0x2692..=0x2697,
+    0x2699..=0x2699,
+    0x269b..=0x269c,
+    0x26a0..=0x26a1,
+    0x26a7..=0x26a7,
+    0x26aa..=0x26ab,
+    0x26b0..=0x26b1,
+    0x26bd..=0x26be,
+    0x26c4..=0x26c5,
+    0x26c8..=0x26c8,
+    0x26ce..=0x26cf,
+    0x26d1..=0x26d1,
+    0x26d3..=0x26d4,
+    0x26e9..=0x26ea,
+    0x26f0..=0x26f5,
+    0x26f7..=0x26fa,
+    0x26fd..=0x26fd,
+    0x2702..=0x2702,
+    0x2705..=0x2705,
+    0x2708..=0x270d,
+    0x270f..=0x270f,
+    0x2712..=0x2712,
+    0x2714..=0x2714,
+    0x2716..=0x2716,
+    0x271d..=0x271d,
+    0x2721..=0x2721,
+    0x2728..=0x2728,
+    0x2733..=0x2734,
+    0x2744..=0x2744,
+    0x2747..=0x2747,
+    0x274c..=0x274c,
+    0x274e..=0x274e,
+    0x2753..=0x2755,
+    0x2757..=0x2757,
+    0x2763..=0x2764,
+    0x2795..=0x2797,
+    0x27a1..=0x27a1,
+    0x27b0..=0x27b0,
+    0x27bf..=0x27bf,
+    0x2934..=0x2935,
+    0x2b05..=0x2b07,
+    0x2b1b..=0x2b1c,
+    0x2b50..=0x2b50,
+    0x2b55..=0x2b55,
+    0x3030..=0x3030,
+    0x303d..=0x303d,
+    0x3297..=0x3297,
+    0x3299..=0x3299,
+    0x1f004..=0x1f004,
+    0x1f0cf..=0x1f0cf,
+    0x1f170..=0x1f171,
+    0x1f17e..=0x1f17f,
+    0x1f18e..=0x1f18e,
+    0x1f191..=0x1f19a,
+    0x1f1e6..=0x1f1ff,
+    0x1f201..=0x1f202,
+    0x1f21a..=0x1f21a,
+    0x1f22f..=0x1f22f,
+    0x1f232..=0x1f23a,
+    0x1f250..=0x1f251,
+    0x1f300..=0x1f321,
+    0x1f324..=0x1f393,
+    0x1f396..=0x1f397,
+    0x1f399..=0x1f39b,
+    0x1f39e..=0x1f3f0,
+    0x1f3f3..=0x1f3f5,
+    0x1f3f7..=0x1f4fd,
+    0x1f4ff..=0x1f53d,
+    0x1f549..=0x1f54e,
+    0x1f550..=0x1f567,
+    0x1f56f..=0x1f570,
+    0x1f573..=0x1f57a,
+    0x1f587..=0x1f587,
+    0x1f58a..=0x1f58d,
+    0x1f590..=0x1f590,
+    0x1f595..=0x1f596,
+    0x1f5a4..=0x1f5a5,
+    0x1f5a8..=0x1f5a8,
+    0x1f5b1..=0x1f5b2,
+    0x1f5bc..=0x1f5bc,
+    0x1f5c2..=0x1f5c4,
+    0x1f5d1..=0x1f5d3,
+    0x1f5dc..=0x1f5de,
+    0x1f5e1..=0x1f5e1,
+    0x1f5e3..=0x1f5e3,
+    0x1f5e8..=0x1f5e8,
+    0x1f5ef..=0x1f5ef,
+    0x1f5f3..=0x1f5f3,
+    0x1f5fa..=0x1f64f,
+    0x1f680..=0x1f6c5,
+    0x1f6cb..=0x1f6d2,
+    0x1f6d5..=0x1f6d7,
+    0x1f6dc..=0x1f6e5,
+    0x1f6e9..=0x1f6e9,
+    0x1f6eb..=0x1f6ec,
+    0x1f6f0..=0x1f6f0,
+    0x1f6f3..=0x1f6fc,
+    0x1f7e0..=0x1f7eb,
+    0x1f7f0..=0x1f7f0,
+    0x1f90c..=0x1f93a,
+    0x1f93c..=0x1f945,
+    0x1f947..=0x1f9ff,
+    0x1fa70..=0x1fa7c,
+    0x1fa80..=0x1fa89,
+    0x1fa8f..=0x1fac6,
+    0x1face..=0x1fadc,
+    0x1fadf..=0x1fae9,
+    0x1faf0..=0x1faf8,
+];
+
+/// Maximum number of characters of the sequences in [EMOJI_SEQUENCES]
+pub(crate) const MAX_EMOJI_SEQUENCE_LENGTH: usize = 10;
+
+/// All sequences of emoji-test.txt, sorted for binary search
+pub(crate) const EMOJI_SEQUENCES: &[(&str, EmojiQualification)] = &[
+    ("\u{23}\u{20e3}", Unqualified),
+    ("\u{23}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{2a}\u{20e3}", Unqualified),
+    ("\u{2a}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{30}\u{20e3}", Unqualified),
+    ("\u{30}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{31}\u{20e3}", Unqualified),
+    ("\u{31}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{32}\u{20e3}", Unqualified),
+    ("\u{32}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{33}\u{20e3}", Unqualified),
+    ("\u{33}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{34}\u{20e3}", Unqualified),
+    ("\u{34}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{35}\u{20e3}", Unqualified),
+    ("\u{35}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{36}\u{20e3}", Unqualified),
+    ("\u{36}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{37}\u{20e3}", Unqualified),
+    ("\u{37}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{38}\u{20e3}", Unqualified),
+    ("\u{38}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{39}\u{20e3}", Unqualified),
+    ("\u{39}\u{fe0f}\u{20e3}", FullyQualified),
+    ("\u{a9}", Unqualified),
+    ("\u{a9}\u{fe0f}", FullyQualified),
+    ("\u{ae}", Unqualified),
+    ("\u{ae}\u{fe0f}", FullyQualified),
+    ("\u{203c}", Unqualified),
+    ("\u{203c}\u{fe0f}", FullyQualified),
+    ("\u{2049}", Unqualified),
+    ("\u{2049}\u{fe0f}", FullyQualified),
+    ("\u{2122}", Unqualified),
+    ("\u{2122}\u{fe0f}", FullyQualified),
+    ("\u{2139}", Unqualified),
+    ("\u{2139}\u{fe0f}", FullyQualified),
+    ("\u{2194}", Unqualified),
+    ("\u{2194}\u{fe0f}", FullyQualified),
+    ("\u{2195}", Unqualified),
+    ("\u{2195}\u{fe0f}", FullyQualified),
+    ("\u{2196}", Unqualified),
+    ("\u{2196}\u{fe0f}", FullyQualified),
+    ("\u{2197}", Unqualified),
+    ("\u{2197}\u{fe0f}", FullyQualified),
+    ("\u{2198}", Unqualified),
+    ("\u{2198}\u{fe0f}", FullyQualified),
+    ("\u{2199}", Unqualified),
+    ("\u{2199}\u{fe0f}", FullyQualified),
+    ("\u{21a9}", Unqualified),
+    ("\u{21a9}\u{fe0f}", FullyQualified),
+    ("\u{21aa}", Unqualified),
+    ("\u{21aa}\u{fe0f}", FullyQualified),
+    ("\u{231a}", FullyQualified),
+    ("\u{231b}", FullyQualified),
+    ("\u{2328}", Unqualified),
+    ("\u{2328}\u{fe0f}", FullyQualified),
+    ("\u{23cf}", Unqualified),
+    ("\u{23cf}\u{fe0f}", FullyQualified),
+    ("\u{23e9}", FullyQualified),
+    ("\u{23ea}", FullyQualified),
+    ("\u{23eb}", FullyQualified),
+    ("\u{23ec}", FullyQualified),
+    ("\u{23ed}", Unqualified),
+    ("\u{23ed}\u{fe0f}", FullyQualified),
+    ("\u{23ee}", Unqualified),
+    ("\u{23ee}\u{fe0f}", FullyQualified),
+    ("\u{23ef}", Unqualified),
+    ("\u{23ef}\u{fe0f}", FullyQualified),
+    ("\u{23f0}", FullyQualified),
+    ("\u{23f1}", Unqualified),
+    ("\u{23f1}\u{fe0f}", FullyQualified),
+    ("\u{23f2}", Unqualified),
+    ("\u{23f2}\u{fe0f}", FullyQualified),
+    ("\u{23f3}", FullyQualified),
+    ("\u{23f8}", Unqualified),
+    ("\u{23f8}\u{fe0f}", FullyQualified),
+    ("\u{23f9}", Unqualified),
+    ("\u{23f9}\u{fe0f}", FullyQualified),
+    ("\u{23fa}", Unqualified),
+    ("\u{23fa}\u{fe0f}", FullyQualified),
+    ("\u{24c2}", Unqualified),
+    ("\u{24c2}\u{fe0f}", FullyQualified),
+    ("\u{25aa}", Unqualified),
+    ("\u{25aa}\u{fe0f}", FullyQualified),
+    ("\u{25ab}", Unqualified),
+    ("\u{25ab}\u{fe0f}", FullyQualified),
+    ("\u{25b6}", Unqualified),
+    ("\u{25b6}\u{fe0f}", FullyQualified),
+    ("\u{25c0}", Unqualified),
+    ("\u{25c0}\u{fe0f}", FullyQualified),
+    ("\u{25fb}", Unqualified),
+    ("\u{25fb}\u{fe0f}", FullyQualified),
+    ("\u{25fc}", Unqualified),
+    ("\u{25fc}\u{fe0f}", FullyQualified),
+    ("\u{25fd}", FullyQualified),
+    ("\u{25fe}", FullyQualified),
+    ("\u{2600}", Unqualified),
+    ("\u{2600}\u{fe0f}", FullyQualified),
+    ("\u{2601}", Unqualified),
+    ("\u{2601}\u{fe0f}", FullyQualified),
+    ("\u{2602}", Unqualified),
+    ("\u{2602}\u{fe0f}", FullyQualified),
+    ("\u{2603}", Unqualified),
+    ("\u{2603}\u{fe0f}", FullyQualified),
+    ("\u{2604}", Unqualified),
+    ("\u{2604}\u{fe0f}", FullyQualified),
+    ("\u{260e}", Unqualified),
+    ("\u{260e}\u{fe0f}", FullyQualified),
+    ("\u{2611}", Unqualified),
+    ("\u{2611}\u{fe0f}", FullyQualified),
+    ("\u{2614}", FullyQualified),
+    ("\u{2615}", FullyQualified),
+    ("\u{2618}", Unqualified),
+    ("\u{2618}\u{fe0f}", FullyQualified),
+    ("\u{261d}", Unqualified),
+    ("\u{261d}\u{fe0f}", FullyQualified),
+    ("\u{261d}\u{1f3fb}", FullyQualified),
+    ("\u{261d}\u{1f3fc}", FullyQualified),
+    ("\u{261d}\u{1f3fd}", FullyQualified),
+    ("\u{261d}\u{1f3fe}", FullyQualified),
+    ("\u{261d}\u{1f3ff}", FullyQualified),
+    ("\u{2620}", Unqualified),
+    ("\u{2620}\u{fe0f}", FullyQualified),
+    ("\u{2622}", Unqualified),
+    ("\u{2622}\u{fe0f}", FullyQualified),
+    ("\u{2623}", Unqualified),
+    ("\u{2623}\u{fe0f}", FullyQualified),
+    ("\u{2626}", Unqualified),
+    ("\u{2626}\u{fe0f}", FullyQualified),
+    ("\u{262a}", Unqualified),
+    ("\u{262a}\u{fe0f}", FullyQualified),
+    ("\u{262e}", Unqualified),
+    ("\u{262e}\u{fe0f}", FullyQualified),
+    ("\u{262f}", Unqualified),
+    ("\u{262f}\u{fe0f}", FullyQualified),
+    ("\u{2638}", Unqualified),
+    ("\u{2638}\u{fe0f}", FullyQualified),
+    ("\u{2639}", Unqualified),
+    ("\u{2639}\u{fe0f}", FullyQualified),
+    ("\u{263a}", Unqualified),
+    ("\u{263a}\u{fe0f}", FullyQualified),
+    ("\u{2640}", Unqualified),
+    ("\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{2642}", Unqualified),
+    ("\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{2648}", FullyQualified),
+    ("\u{2649}", FullyQualified),
+    ("\u{264a}", FullyQualified),
+    ("\u{264b}", FullyQualified),
+    ("\u{264c}", FullyQualified),
+    ("\u{264d}", FullyQualified),
+    ("\u{264e}", FullyQualified),
+    ("\u{264f}", FullyQualified),
+    ("\u{2650}", FullyQualified),
+    ("\u{2651}", FullyQualified),
+    ("\u{2652}", FullyQualified),
+    ("\u{2653}", FullyQualified),
+    ("\u{265f}", Unqualified),
+    ("\u{265f}\u{fe0f}", FullyQualified),
+    ("\u{2660}", Unqualified),
+    ("\u{2660}\u{fe0f}", FullyQualified),
+    ("\u{2663}", Unqualified),
+    ("\u{2663}\u{fe0f}", FullyQualified),
+    ("\u{2665}", Unqualified),
+    ("\u{2665}\u{fe0f}", FullyQualified),
+    ("\u{2666}", Unqualified),
+    ("\u{2666}\u{fe0f}", FullyQualified),
+    ("\u{2668}", Unqualified),
+    ("\u{2668}\u{fe0f}", FullyQualified),
+    ("\u{267b}", Unqualified),
+    ("\u{267b}\u{fe0f}", FullyQualified),
+    ("\u{267e}", Unqualified),
+    ("\u{267e}\u{fe0f}", FullyQualified),
+    ("\u{267f}", FullyQualified),
+    ("\u{2692}", Unqualified),
+    ("\u{2692}\u{fe0f}", FullyQualified),
+    ("\u{2693}", FullyQualified),
+    ("\u{2694}", Unqualified),
+    ("\u{2694}\u{fe0f}", FullyQualified),
+    ("\u{2695}", Unqualified),
+    ("\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{2696}", Unqualified),
+    ("\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{2697}", Unqualified),
+    ("\u{2697}\u{fe0f}", FullyQualified),
+    ("\u{2699}", Unqualified),
+    ("\u{2699}\u{fe0f}", FullyQualified),
+    ("\u{269b}", Unqualified),
+    ("\u{269b}\u{fe0f}", FullyQualified),
+    ("\u{269c}", Unqualified),
+    ("\u{269c}\u{fe0f}", FullyQualified),
+    ("\u{26a0}", Unqualified),
+    ("\u{26a0}\u{fe0f}", FullyQualified),
+    ("\u{26a1}", FullyQualified),
+    ("\u{26a7}", Unqualified),
+    ("\u{26a7}\u{fe0f}", FullyQualified),
+    ("\u{26aa}", FullyQualified),
+    ("\u{26ab}", FullyQualified),
+    ("\u{26b0}", Unqualified),
+    ("\u{26b0}\u{fe0f}", FullyQualified),
+    ("\u{26b1}", Unqualified),
+    ("\u{26b1}\u{fe0f}", FullyQualified),
+    ("\u{26bd}", FullyQualified),
+    ("\u{26be}", FullyQualified),
+    ("\u{26c4}", FullyQualified),
+    ("\u{26c5}", FullyQualified),
+    ("\u{26c8}", Unqualified),
+    ("\u{26c8}\u{fe0f}", FullyQualified),
+    ("\u{26ce}", FullyQualified),
+    ("\u{26cf}", Unqualified),
+    ("\u{26cf}\u{fe0f}", FullyQualified),
+    ("\u{26d1}", Unqualified),
+    ("\u{26d1}\u{fe0f}", FullyQualified),
+    ("\u{26d3}", Unqualified),
+    ("\u{26d3}\u{200d}\u{1f4a5}", Unqualified),
+    ("\u{26d3}\u{fe0f}", FullyQualified),
+    ("\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}", FullyQualified),
+    ("\u{26d4}", FullyQualified),
+    ("\u{26e9}", Unqualified),
+    ("\u{26e9}\u{fe0f}", FullyQualified),
+    ("\u{26ea}", FullyQualified),
+    ("\u{26f0}", Unqualified),
+    ("\u{26f0}\u{fe0f}", FullyQualified),
+    ("\u{26f1}", Unqualified),
+    ("\u{26f1}\u{fe0f}", FullyQualified),
+    ("\u{26f2}", FullyQualified),
+    ("\u{26f3}", FullyQualified),
+    ("\u{26f4}", Unqualified),
+    ("\u{26f4}\u{fe0f}", FullyQualified),
+    ("\u{26f5}", FullyQualified),
+    ("\u{26f7}", Unqualified),
+    ("\u{26f7}\u{fe0f}", FullyQualified),
+    ("\u{26f8}", Unqualified),
+    ("\u{26f8}\u{fe0f}", FullyQualified),
+    ("\u{26f9}", Unqualified),
+    ("\u{26f9}\u{200d}\u{2640}", Unqualified),
+    ("\u{26f9}\u{200d}\u{2640}\u{fe0f}", Unqualified),
+    ("\u{26f9}\u{200d}\u{2642}", Unqualified),
+    ("\u{26f9}\u{200d}\u{2642}\u{fe0f}", Unqualified),
+    ("\u{26f9}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fb}", FullyQualified),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fc}", FullyQualified),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fd}", FullyQualified),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fe}", FullyQualified),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3ff}", FullyQualified),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{26fa}", FullyQualified),
+    ("\u{26fd}", FullyQualified),
+    ("\u{2702}", Unqualified),
+    ("\u{2702}\u{fe0f}", FullyQualified),
+    ("\u{2705}", FullyQualified),
+    ("\u{2708}", Unqualified),
+    ("\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{2709}", Unqualified),
+    ("\u{2709}\u{fe0f}", FullyQualified),
+    ("\u{270a}", FullyQualified),
+    ("\u{270a}\u{1f3fb}", FullyQualified),
+    ("\u{270a}\u{1f3fc}", FullyQualified),
+    ("\u{270a}\u{1f3fd}", FullyQualified),
+    ("\u{270a}\u{1f3fe}", FullyQualified),
+    ("\u{270a}\u{1f3ff}", FullyQualified),
+    ("\u{270b}", FullyQualified),
+    ("\u{270b}\u{1f3fb}", FullyQualified),
+    ("\u{270b}\u{1f3fc}", FullyQualified),
+    ("\u{270b}\u{1f3fd}", FullyQualified),
+    ("\u{270b}\u{1f3fe}", FullyQualified),
+    ("\u{270b}\u{1f3ff}", FullyQualified),
+    ("\u{270c}", Unqualified),
+    ("\u{270c}\u{fe0f}", FullyQualified),
+    ("\u{270c}\u{1f3fb}", FullyQualified),
+    ("\u{270c}\u{1f3fc}", FullyQualified),
+    ("\u{270c}\u{1f3fd}", FullyQualified),
+    ("\u{270c}\u{1f3fe}", FullyQualified),
+    ("\u{270c}\u{1f3ff}", FullyQualified),
+    ("\u{270d}", Unqualified),
+    ("\u{270d}\u{fe0f}", FullyQualified),
+    ("\u{270d}\u{1f3fb}", FullyQualified),
+    ("\u{270d}\u{1f3fc}", FullyQualified),
+    ("\u{270d}\u{1f3fd}", FullyQualified),
+    ("\u{270d}\u{1f3fe}", FullyQualified),
+    ("\u{270d}\u{1f3ff}", FullyQualified),
+    ("\u{270f}", Unqualified),
+    ("\u{270f}\u{fe0f}", FullyQualified),
+    ("\u{2712}", Unqualified),
+    ("\u{2712}\u{fe0f}", FullyQualified),
+    ("\u{2714}", Unqualified),
+    ("\u{2714}\u{fe0f}", FullyQualified),
+    ("\u{2716}", Unqualified),
+    ("\u{2716}\u{fe0f}", FullyQualified),
+    ("\u{271d}", Unqualified),
+    ("\u{271d}\u{fe0f}", FullyQualified),
+    ("\u{2721}", Unqualified),
+    ("\u{2721}\u{fe0f}", FullyQualified),
+    ("\u{2728}", FullyQualified),
+    ("\u{2733}", Unqualified),
+    ("\u{2733}\u{fe0f}", FullyQualified),
+    ("\u{2734}", Unqualified),
+    ("\u{2734}\u{fe0f}", FullyQualified),
+    ("\u{2744}", Unqualified),
+    ("\u{2744}\u{fe0f}", FullyQualified),
+    ("\u{2747}", Unqualified),
+    ("\u{2747}\u{fe0f}", FullyQualified),
+    ("\u{274c}", FullyQualified),
+    ("\u{274e}", FullyQualified),
+    ("\u{2753}", FullyQualified),
+    ("\u{2754}", FullyQualified),
+    ("\u{2755}", FullyQualified),
+    ("\u{2757}", FullyQualified),
+    ("\u{2763}", Unqualified),
+    ("\u{2763}\u{fe0f}", FullyQualified),
+    ("\u{2764}", Unqualified),
+    ("\u{2764}\u{200d}\u{1f525}", Unqualified),
+    ("\u{2764}\u{200d}\u{1fa79}", Unqualified),
+    ("\u{2764}\u{fe0f}", FullyQualified),
+    ("\u{2764}\u{fe0f}\u{200d}\u{1f525}", FullyQualified),
+    ("\u{2764}\u{fe0f}\u{200d}\u{1fa79}", FullyQualified),
+    ("\u{2795}", FullyQualified),
+    ("\u{2796}", FullyQualified),
+    ("\u{2797}", FullyQualified),
+    ("\u{27a1}", Unqualified),
+    ("\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{27b0}", FullyQualified),
+    ("\u{27bf}", FullyQualified),
+    ("\u{2934}", Unqualified),
+    ("\u{2934}\u{fe0f}", FullyQualified),
+    ("\u{2935}", Unqualified),
+    ("\u{2935}\u{fe0f}", FullyQualified),
+    ("\u{2b05}", Unqualified),
+    ("\u{2b05}\u{fe0f}", FullyQualified),
+    ("\u{2b06}", Unqualified),
+    ("\u{2b06}\u{fe0f}", FullyQualified),
+    ("\u{2b07}", Unqualified),
+    ("\u{2b07}\u{fe0f}", FullyQualified),
+    ("\u{2b1b}", FullyQualified),
+    ("\u{2b1c}", FullyQualified),
+    ("\u{2b50}", FullyQualified),
+    ("\u{2b55}", FullyQualified),
+    ("\u{3030}", Unqualified),
+    ("\u{3030}\u{fe0f}", FullyQualified),
+    ("\u{303d}", Unqualified),
+    ("\u{303d}\u{fe0f}", FullyQualified),
+    ("\u{3297}", Unqualified),
+    ("\u{3297}\u{fe0f}", FullyQualified),
+    ("\u{3299}", Unqualified),
+    ("\u{3299}\u{fe0f}", FullyQualified),
+    ("\u{1f004}", FullyQualified),
+    ("\u{1f0cf}", FullyQualified),
+    ("\u{1f170}", Unqualified),
+    ("\u{1f170}\u{fe0f}", FullyQualified),
+    ("\u{1f171}", Unqualified),
+    ("\u{1f171}\u{fe0f}", FullyQualified),
+    ("\u{1f17e}", Unqualified),
+    ("\u{1f17e}\u{fe0f}", FullyQualified),
+    ("\u{1f17f}", Unqualified),
+    ("\u{1f17f}\u{fe0f}", FullyQualified),
+    ("\u{1f18e}", FullyQualified),
+    ("\u{1f191}", FullyQualified),
+    ("\u{1f192}", FullyQualified),
+    ("\u{1f193}", FullyQualified),
+    ("\u{1f194}", FullyQualified),
+    ("\u{1f195}", FullyQualified),
+    ("\u{1f196}", FullyQualified),
+    ("\u{1f197}", FullyQualified),
+    ("\u{1f198}", FullyQualified),
+    ("\u{1f199}", FullyQualified),
+    ("\u{1f19a}", FullyQualified),
+    ("\u{1f1e6}\u{1f1e8}", FullyQualified),
+    ("\u{1f1e6}\u{1f1e9}", FullyQualified),
+    ("\u{1f1e6}\u{1f1ea}", FullyQualified),
+    ("\u{1f1e6}\u{1f1eb}", FullyQualified),
+    ("\u{1f1e6}\u{1f1ec}", FullyQualified),
+    ("\u{1f1e6}\u{1f1ee}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f1}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f2}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f4}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f6}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f7}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f8}", FullyQualified),
+    ("\u{1f1e6}\u{1f1f9}", FullyQualified),
+    ("\u{1f1e6}\u{1f1fa}", FullyQualified),
+    ("\u{1f1e6}\u{1f1fc}", FullyQualified),
+    ("\u{1f1e6}\u{1f1fd}", FullyQualified),
+    ("\u{1f1e6}\u{1f1ff}", FullyQualified),
+    ("\u{1f1e7}\u{1f1e6}", FullyQualified),
+    ("\u{1f1e7}\u{1f1e7}", FullyQualified),
+    ("\u{1f1e7}\u{1f1e9}", FullyQualified),
+    ("\u{1f1e7}\u{1f1ea}", FullyQualified),
+    ("\u{1f1e7}\u{1f1eb}", FullyQualified),
+    ("\u{1f1e7}\u{1f1ec}", FullyQualified),
+    ("\u{1f1e7}\u{1f1ed}", FullyQualified),
+    ("\u{1f1e7}\u{1f1ee}", FullyQualified),
+    ("\u{1f1e7}\u{1f1ef}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f1}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f2}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f3}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f4}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f6}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f7}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f8}", FullyQualified),
+    ("\u{1f1e7}\u{1f1f9}", FullyQualified),
+    ("\u{1f1e7}\u{1f1fb}", FullyQualified),
+    ("\u{1f1e7}\u{1f1fc}", FullyQualified),
+    ("\u{1f1e7}\u{1f1fe}", FullyQualified),
+    ("\u{1f1e7}\u{1f1ff}", FullyQualified),
+    ("\u{1f1e8}\u{1f1e6}", FullyQualified),
+    ("\u{1f1e8}\u{1f1e8}", FullyQualified),
+    ("\u{1f1e8}\u{1f1e9}", FullyQualified),
+    ("\u{1f1e8}\u{1f1eb}", FullyQualified),
+    ("\u{1f1e8}\u{1f1ec}", FullyQualified),
+    ("\u{1f1e8}\u{1f1ed}", FullyQualified),
+    ("\u{1f1e8}\u{1f1ee}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f0}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f1}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f2}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f3}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f4}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f5}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f6}", FullyQualified),
+    ("\u{1f1e8}\u{1f1f7}", FullyQualified),
+    ("\u{1f1e8}\u{1f1fa}", FullyQualified),
+    ("\u{1f1e8}\u{1f1fb}", FullyQualified),
+    ("\u{1f1e8}\u{1f1fc}", FullyQualified),
+    ("\u{1f1e8}\u{1f1fd}", FullyQualified),
+    ("\u{1f1e8}\u{1f1fe}", FullyQualified),
+    ("\u{1f1e8}\u{1f1ff}", FullyQualified),
+    ("\u{1f1e9}\u{1f1ea}", FullyQualified),
+    ("\u{1f1e9}\u{1f1ec}", FullyQualified),
+    ("\u{1f1e9}\u{1f1ef}", FullyQualified),
+    ("\u{1f1e9}\u{1f1f0}", FullyQualified),
+    ("\u{1f1e9}\u{1f1f2}", FullyQualified),
+    ("\u{1f1e9}\u{1f1f4}", FullyQualified),
+    ("\u{1f1e9}\u{1f1ff}", FullyQualified),
+    ("\u{1f1ea}\u{1f1e6}", FullyQualified),
+    ("\u{1f1ea}\u{1f1e8}", FullyQualified),
+    ("\u{1f1ea}\u{1f1ea}", FullyQualified),
+    ("\u{1f1ea}\u{1f1ec}", FullyQualified),
+    ("\u{1f1ea}\u{1f1ed}", FullyQualified),
+    ("\u{1f1ea}\u{1f1f7}", FullyQualified),
+    ("\u{1f1ea}\u{1f1f8}", FullyQualified),
+    ("\u{1f1ea}\u{1f1f9}", FullyQualified),
+    ("\u{1f1ea}\u{1f1fa}", FullyQualified),
+    ("\u{1f1eb}\u{1f1ee}", FullyQualified),
+    ("\u{1f1eb}\u{1f1ef}", FullyQualified),
+    ("\u{1f1eb}\u{1f1f0}", FullyQualified),
+    ("\u{1f1eb}\u{1f1f2}", FullyQualified),
+    ("\u{1f1eb}\u{1f1f4}", FullyQualified),
+    ("\u{1f1eb}\u{1f1f7}", FullyQualified),
+    ("\u{1f1ec}\u{1f1e6}", FullyQualified),
+    ("\u{1f1ec}\u{1f1e7}", FullyQualified),
+    ("\u{1f1ec}\u{1f1e9}", FullyQualified),
+    ("\u{1f1ec}\u{1f1ea}", FullyQualified),
+    ("\u{1f1ec}\u{1f1eb}", FullyQualified),
+    ("\u{1f1ec}\u{1f1ec}", FullyQualified),
+    ("\u{1f1ec}\u{1f1ed}", FullyQualified),
+    ("\u{1f1ec}\u{1f1ee}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f1}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f2}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f3}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f5}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f6}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f7}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f8}", FullyQualified),
+    ("\u{1f1ec}\u{1f1f9}", FullyQualified),
+    ("\u{1f1ec}\u{1f1fa}", FullyQualified),
+    ("\u{1f1ec}\u{1f1fc}", FullyQualified),
+    ("\u{1f1ec}\u{1f1fe}", FullyQualified),
+    ("\u{1f1ed}\u{1f1f0}", FullyQualified),
+    ("\u{1f1ed}\u{1f1f2}", FullyQualified),
+    ("\u{1f1ed}\u{1f1f3}", FullyQualified),
+    ("\u{1f1ed}\u{1f1f7}", FullyQualified),
+    ("\u{1f1ed}\u{1f1f9}", FullyQualified),
+    ("\u{1f1ed}\u{1f1fa}", FullyQualified),
+    ("\u{1f1ee}\u{1f1e8}", FullyQualified),
+    ("\u{1f1ee}\u{1f1e9}", FullyQualified),
+    ("\u{1f1ee}\u{1f1ea}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f1}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f2}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f3}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f4}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f6}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f7}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f8}", FullyQualified),
+    ("\u{1f1ee}\u{1f1f9}", FullyQualified),
+    ("\u{1f1ef}\u{1f1ea}", FullyQualified),
+    ("\u{1f1ef}\u{1f1f2}", FullyQualified),
+    ("\u{1f1ef}\u{1f1f4}", FullyQualified),
+    ("\u{1f1ef}\u{1f1f5}", FullyQualified),
+    ("\u{1f1f0}\u{1f1ea}", FullyQualified),
+    ("\u{1f1f0}\u{1f1ec}", FullyQualified),
+    ("\u{1f1f0}\u{1f1ed}", FullyQualified),
+    ("\u{1f1f0}\u{1f1ee}", FullyQualified),
+    ("\u{1f1f0}\u{1f1f2}", FullyQualified),
+    ("\u{1f1f0}\u{1f1f3}", FullyQualified),
+    ("\u{1f1f0}\u{1f1f5}", FullyQualified),
+    ("\u{1f1f0}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f0}\u{1f1fc}", FullyQualified),
+    ("\u{1f1f0}\u{1f1fe}", FullyQualified),
+    ("\u{1f1f0}\u{1f1ff}", FullyQualified),
+    ("\u{1f1f1}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f1}\u{1f1e7}", FullyQualified),
+    ("\u{1f1f1}\u{1f1e8}", FullyQualified),
+    ("\u{1f1f1}\u{1f1ee}", FullyQualified),
+    ("\u{1f1f1}\u{1f1f0}", FullyQualified),
+    ("\u{1f1f1}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f1}\u{1f1f8}", FullyQualified),
+    ("\u{1f1f1}\u{1f1f9}", FullyQualified),
+    ("\u{1f1f1}\u{1f1fa}", FullyQualified),
+    ("\u{1f1f1}\u{1f1fb}", FullyQualified),
+    ("\u{1f1f1}\u{1f1fe}", FullyQualified),
+    ("\u{1f1f2}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f2}\u{1f1e8}", FullyQualified),
+    ("\u{1f1f2}\u{1f1e9}", FullyQualified),
+    ("\u{1f1f2}\u{1f1ea}", FullyQualified),
+    ("\u{1f1f2}\u{1f1eb}", FullyQualified),
+    ("\u{1f1f2}\u{1f1ec}", FullyQualified),
+    ("\u{1f1f2}\u{1f1ed}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f0}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f1}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f2}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f3}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f4}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f5}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f6}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f8}", FullyQualified),
+    ("\u{1f1f2}\u{1f1f9}", FullyQualified),
+    ("\u{1f1f2}\u{1f1fa}", FullyQualified),
+    ("\u{1f1f2}\u{1f1fb}", FullyQualified),
+    ("\u{1f1f2}\u{1f1fc}", FullyQualified),
+    ("\u{1f1f2}\u{1f1fd}", FullyQualified),
+    ("\u{1f1f2}\u{1f1fe}", FullyQualified),
+    ("\u{1f1f2}\u{1f1ff}", FullyQualified),
+    ("\u{1f1f3}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f3}\u{1f1e8}", FullyQualified),
+    ("\u{1f1f3}\u{1f1ea}", FullyQualified),
+    ("\u{1f1f3}\u{1f1eb}", FullyQualified),
+    ("\u{1f1f3}\u{1f1ec}", FullyQualified),
+    ("\u{1f1f3}\u{1f1ee}", FullyQualified),
+    ("\u{1f1f3}\u{1f1f1}", FullyQualified),
+    ("\u{1f1f3}\u{1f1f4}", FullyQualified),
+    ("\u{1f1f3}\u{1f1f5}", FullyQualified),
+    ("\u{1f1f3}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f3}\u{1f1fa}", FullyQualified),
+    ("\u{1f1f3}\u{1f1ff}", FullyQualified),
+    ("\u{1f1f4}\u{1f1f2}", FullyQualified),
+    ("\u{1f1f5}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f5}\u{1f1ea}", FullyQualified),
+    ("\u{1f1f5}\u{1f1eb}", FullyQualified),
+    ("\u{1f1f5}\u{1f1ec}", FullyQualified),
+    ("\u{1f1f5}\u{1f1ed}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f0}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f1}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f2}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f3}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f8}", FullyQualified),
+    ("\u{1f1f5}\u{1f1f9}", FullyQualified),
+    ("\u{1f1f5}\u{1f1fc}", FullyQualified),
+    ("\u{1f1f5}\u{1f1fe}", FullyQualified),
+    ("\u{1f1f6}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f7}\u{1f1ea}", FullyQualified),
+    ("\u{1f1f7}\u{1f1f4}", FullyQualified),
+    ("\u{1f1f7}\u{1f1f8}", FullyQualified),
+    ("\u{1f1f7}\u{1f1fa}", FullyQualified),
+    ("\u{1f1f7}\u{1f1fc}", FullyQualified),
+    ("\u{1f1f8}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f8}\u{1f1e7}", FullyQualified),
+    ("\u{1f1f8}\u{1f1e8}", FullyQualified),
+    ("\u{1f1f8}\u{1f1e9}", FullyQualified),
+    ("\u{1f1f8}\u{1f1ea}", FullyQualified),
+    ("\u{1f1f8}\u{1f1ec}", FullyQualified),
+    ("\u{1f1f8}\u{1f1ed}", FullyQualified),
+    ("\u{1f1f8}\u{1f1ee}", FullyQualified),
+    ("\u{1f1f8}\u{1f1ef}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f0}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f1}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f2}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f3}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f4}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f8}", FullyQualified),
+    ("\u{1f1f8}\u{1f1f9}", FullyQualified),
+    ("\u{1f1f8}\u{1f1fb}", FullyQualified),
+    ("\u{1f1f8}\u{1f1fd}", FullyQualified),
+    ("\u{1f1f8}\u{1f1fe}", FullyQualified),
+    ("\u{1f1f8}\u{1f1ff}", FullyQualified),
+    ("\u{1f1f9}\u{1f1e6}", FullyQualified),
+    ("\u{1f1f9}\u{1f1e8}", FullyQualified),
+    ("\u{1f1f9}\u{1f1e9}", FullyQualified),
+    ("\u{1f1f9}\u{1f1eb}", FullyQualified),
+    ("\u{1f1f9}\u{1f1ec}", FullyQualified),
+    ("\u{1f1f9}\u{1f1ed}", FullyQualified),
+    ("\u{1f1f9}\u{1f1ef}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f0}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f1}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f2}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f3}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f4}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f7}", FullyQualified),
+    ("\u{1f1f9}\u{1f1f9}", FullyQualified),
+    ("\u{1f1f9}\u{1f1fb}", FullyQualified),
+    ("\u{1f1f9}\u{1f1fc}", FullyQualified),
+    ("\u{1f1f9}\u{1f1ff}", FullyQualified),
+    ("\u{1f1fa}\u{1f1e6}", FullyQualified),
+    ("\u{1f1fa}\u{1f1ec}", FullyQualified),
+    ("\u{1f1fa}\u{1f1f2}", FullyQualified),
+    ("\u{1f1fa}\u{1f1f3}", FullyQualified),
+    ("\u{1f1fa}\u{1f1f8}", FullyQualified),
+    ("\u{1f1fa}\u{1f1fe}", FullyQualified),
+    ("\u{1f1fa}\u{1f1ff}", FullyQualified),
+    ("\u{1f1fb}\u{1f1e6}", FullyQualified),
+    ("\u{1f1fb}\u{1f1e8}", FullyQualified),
+    ("\u{1f1fb}\u{1f1ea}", FullyQualified),
+    ("\u{1f1fb}\u{1f1ec}", FullyQualified),
+    ("\u{1f1fb}\u{1f1ee}", FullyQualified),
+    ("\u{1f1fb}\u{1f1f3}", FullyQualified),
+    ("\u{1f1fb}\u{1f1fa}", FullyQualified),
+    ("\u{1f1fc}\u{1f1eb}", FullyQualified),
+    ("\u{1f1fc}\u{1f1f8}", FullyQualified),
+    ("\u{1f1fd}\u{1f1f0}", FullyQualified),
+    ("\u{1f1fe}\u{1f1ea}", FullyQualified),
+    ("\u{1f1fe}\u{1f1f9}", FullyQualified),
+    ("\u{1f1ff}\u{1f1e6}", FullyQualified),
+    ("\u{1f1ff}\u{1f1f2}", FullyQualified),
+    ("\u{1f1ff}\u{1f1fc}", FullyQualified),
+    ("\u{1f201}", FullyQualified),
+    ("\u{1f202}", Unqualified),
+    ("\u{1f202}\u{fe0f}", FullyQualified),
+    ("\u{1f21a}", FullyQualified),
+    ("\u{1f22f}", FullyQualified),
+    ("\u{1f232}", FullyQualified),
+    ("\u{1f233}", FullyQualified),
+    ("\u{1f234}", FullyQualified),
+    ("\u{1f235}", FullyQualified),
+    ("\u{1f236}", FullyQualified),
+    ("\u{1f237}", Unqualified),
+    ("\u{1f237}\u{fe0f}", FullyQualified),
+    ("\u{1f238}", FullyQualified),
+    ("\u{1f239}", FullyQualified),
+    ("\u{1f23a}", FullyQualified),
+    ("\u{1f250}", FullyQualified),
+    ("\u{1f251}", FullyQualified),
+    ("\u{1f300}", FullyQualified),
+    ("\u{1f301}", FullyQualified),
+    ("\u{1f302}", FullyQualified),
+    ("\u{1f303}", FullyQualified),
+    ("\u{1f304}", FullyQualified),
+    ("\u{1f305}", FullyQualified),
+    ("\u{1f306}", FullyQualified),
+    ("\u{1f307}", FullyQualified),
+    ("\u{1f308}", FullyQualified),
+    ("\u{1f309}", FullyQualified),
+    ("\u{1f30a}", FullyQualified),
+    ("\u{1f30b}", FullyQualified),
+    ("\u{1f30c}", FullyQualified),
+    ("\u{1f30d}", FullyQualified),
+    ("\u{1f30e}", FullyQualified),
+    ("\u{1f30f}", FullyQualified),
+    ("\u{1f310}", FullyQualified),
+    ("\u{1f311}", FullyQualified),
+    ("\u{1f312}", FullyQualified),
+    ("\u{1f313}", FullyQualified),
+    ("\u{1f314}", FullyQualified),
+    ("\u{1f315}", FullyQualified),
+    ("\u{1f316}", FullyQualified),
+    ("\u{1f317}", FullyQualified),
+    ("\u{1f318}", FullyQualified),
+    ("\u{1f319}", FullyQualified),
+    ("\u{1f31a}", FullyQualified),
+    ("\u{1f31b}", FullyQualified),
+    ("\u{1f31c}", FullyQualified),
+    ("\u{1f31d}", FullyQualified),
+    ("\u{1f31e}", FullyQualified),
+    ("\u{1f31f}", FullyQualified),
+    ("\u{1f320}", FullyQualified),
+    ("\u{1f321}", Unqualified),
+    ("\u{1f321}\u{fe0f}", FullyQualified),
+    ("\u{1f324}", Unqualified),
+    ("\u{1f324}\u{fe0f}", FullyQualified),
+    ("\u{1f325}", Unqualified),
+    ("\u{1f325}\u{fe0f}", FullyQualified),
+    ("\u{1f326}", Unqualified),
+    ("\u{1f326}\u{fe0f}", FullyQualified),
+    ("\u{1f327}", Unqualified),
+    ("\u{1f327}\u{fe0f}", FullyQualified),
+    ("\u{1f328}", Unqualified),
+    ("\u{1f328}\u{fe0f}", FullyQualified),
+    ("\u{1f329}", Unqualified),
+    ("\u{1f329}\u{fe0f}", FullyQualified),
+    ("\u{1f32a}", Unqualified),
+    ("\u{1f32a}\u{fe0f}", FullyQualified),
+    ("\u{1f32b}", Unqualified),
+    ("\u{1f32b}\u{fe0f}", FullyQualified),
+    ("\u{1f32c}", Unqualified),
+    ("\u{1f32c}\u{fe0f}", FullyQualified),
+    ("\u{1f32d}", FullyQualified),
+    ("\u{1f32e}", FullyQualified),
+    ("\u{1f32f}", FullyQualified),
+    ("\u{1f330}", FullyQualified),
+    ("\u{1f331}", FullyQualified),
+    ("\u{1f332}", FullyQualified),
+    ("\u{1f333}", FullyQualified),
+    ("\u{1f334}", FullyQualified),
+    ("\u{1f335}", FullyQualified),
+    ("\u{1f336}", Unqualified),
+    ("\u{1f336}\u{fe0f}", FullyQualified),
+    ("\u{1f337}", FullyQualified),
+    ("\u{1f338}", FullyQualified),
+    ("\u{1f339}", FullyQualified),
+    ("\u{1f33a}", FullyQualified),
+    ("\u{1f33b}", FullyQualified),
+    ("\u{1f33c}", FullyQualified),
+    ("\u{1f33d}", FullyQualified),
+    ("\u{1f33e}", FullyQualified),
+    ("\u{1f33f}", FullyQualified),
+    ("\u{1f340}", FullyQualified),
+    ("\u{1f341}", FullyQualified),
+    ("\u{1f342}", FullyQualified),
+    ("\u{1f343}", FullyQualified),
+    ("\u{1f344}", FullyQualified),
+    ("\u{1f344}\u{200d}\u{1f7eb}", FullyQualified),
+    ("\u{1f345}", FullyQualified),
+    ("\u{1f346}", FullyQualified),
+    ("\u{1f347}", FullyQualified),
+    ("\u{1f348}", FullyQualified),
+    ("\u{1f349}", FullyQualified),
+    ("\u{1f34a}", FullyQualified),
+    ("\u{1f34b}", FullyQualified),
+    ("\u{1f34b}\u{200d}\u{1f7e9}", FullyQualified),
+    ("\u{1f34c}", FullyQualified),
+    ("\u{1f34d}", FullyQualified),
+    ("\u{1f34e}", FullyQualified),
+    ("\u{1f34f}", FullyQualified),
+    ("\u{1f350}", FullyQualified),
+    ("\u{1f351}", FullyQualified),
+    ("\u{1f352}", FullyQualified),
+    ("\u{1f353}", FullyQualified),
+    ("\u{1f354}", FullyQualified),
+    ("\u{1f355}", FullyQualified),
+    ("\u{1f356}", FullyQualified),
+    ("\u{1f357}", FullyQualified),
+    ("\u{1f358}", FullyQualified),
+    ("\u{1f359}", FullyQualified),
+    ("\u{1f35a}", FullyQualified),
+    ("\u{1f35b}", FullyQualified),
+    ("\u{1f35c}", FullyQualified),
+    ("\u{1f35d}", FullyQualified),
+    ("\u{1f35e}", FullyQualified),
+    ("\u{1f35f}", FullyQualified),
+    ("\u{1f360}", FullyQualified),
+    ("\u{1f361}", FullyQualified),
+    ("\u{1f362}", FullyQualified),
+    ("\u{1f363}", FullyQualified),
+    ("\u{1f364}", FullyQualified),
+    ("\u{1f365}", FullyQualified),
+    ("\u{1f366}", FullyQualified),
+    ("\u{1f367}", FullyQualified),
+    ("\u{1f368}", FullyQualified),
+    ("\u{1f369}", FullyQualified),
+    ("\u{1f36a}", FullyQualified),
+    ("\u{1f36b}", FullyQualified),
+    ("\u{1f36c}", FullyQualified),
+    ("\u{1f36d}", FullyQualified),
+    ("\u{1f36e}", FullyQualified),
+    ("\u{1f36f}", FullyQualified),
+    ("\u{1f370}", FullyQualified),
+    ("\u{1f371}", FullyQualified),
+    ("\u{1f372}", FullyQualified),
+    ("\u{1f373}", FullyQualified),
+    ("\u{1f374}", FullyQualified),
+    ("\u{1f375}", FullyQualified),
+    ("\u{1f376}", FullyQualified),
+    ("\u{1f377}", FullyQualified),
+    ("\u{1f378}", FullyQualified),
+    ("\u{1f379}", FullyQualified),
+    ("\u{1f37a}", FullyQualified),
+    ("\u{1f37b}", FullyQualified),
+    ("\u{1f37c}", FullyQualified),
+    ("\u{1f37d}", Unqualified),
+    ("\u{1f37d}\u{fe0f}", FullyQualified),
+    ("\u{1f37e}", FullyQualified),
+    ("\u{1f37f}", FullyQualified),
+    ("\u{1f380}", FullyQualified),
+    ("\u{1f381}", FullyQualified),
+    ("\u{1f382}", FullyQualified),
+    ("\u{1f383}", FullyQualified),
+    ("\u{1f384}", FullyQualified),
+    ("\u{1f385}", FullyQualified),
+    ("\u{1f385}\u{1f3fb}", FullyQualified),
+    ("\u{1f385}\u{1f3fc}", FullyQualified),
+    ("\u{1f385}\u{1f3fd}", FullyQualified),
+    ("\u{1f385}\u{1f3fe}", FullyQualified),
+    ("\u{1f385}\u{1f3ff}", FullyQualified),
+    ("\u{1f386}", FullyQualified),
+    ("\u{1f387}", FullyQualified),
+    ("\u{1f388}", FullyQualified),
+    ("\u{1f389}", FullyQualified),
+    ("\u{1f38a}", FullyQualified),
+    ("\u{1f38b}", FullyQualified),
+    ("\u{1f38c}", FullyQualified),
+    ("\u{1f38d}", FullyQualified),
+    ("\u{1f38e}", FullyQualified),
+    ("\u{1f38f}", FullyQualified),
+    ("\u{1f390}", FullyQualified),
+    ("\u{1f391}", FullyQualified),
+    ("\u{1f392}", FullyQualified),
+    ("\u{1f393}", FullyQualified),
+    ("\u{1f396}", Unqualified),
+    ("\u{1f396}\u{fe0f}", FullyQualified),
+    ("\u{1f397}", Unqualified),
+    ("\u{1f397}\u{fe0f}", FullyQualified),
+    ("\u{1f399}", Unqualified),
+    ("\u{1f399}\u{fe0f}", FullyQualified),
+    ("\u{1f39a}", Unqualified),
+    ("\u{1f39a}\u{fe0f}", FullyQualified),
+    ("\u{1f39b}", Unqualified),
+    ("\u{1f39b}\u{fe0f}", FullyQualified),
+    ("\u{1f39e}", Unqualified),
+    ("\u{1f39e}\u{fe0f}", FullyQualified),
+    ("\u{1f39f}", Unqualified),
+    ("\u{1f39f}\u{fe0f}", FullyQualified),
+    ("\u{1f3a0}", FullyQualified),
+    ("\u{1f3a1}", FullyQualified),
+    ("\u{1f3a2}", FullyQualified),
+    ("\u{1f3a3}", FullyQualified),
+    ("\u{1f3a4}", FullyQualified),
+    ("\u{1f3a5}", FullyQualified),
+    ("\u{1f3a6}", FullyQualified),
+    ("\u{1f3a7}", FullyQualified),
+    ("\u{1f3a8}", FullyQualified),
+    ("\u{1f3a9}", FullyQualified),
+    ("\u{1f3aa}", FullyQualified),
+    ("\u{1f3ab}", FullyQualified),
+    ("\u{1f3ac}", FullyQualified),
+    ("\u{1f3ad}", FullyQualified),
+    ("\u{1f3ae}", FullyQualified),
+    ("\u{1f3af}", FullyQualified),
+    ("\u{1f3b0}", FullyQualified),
+    ("\u{1f3b1}", FullyQualified),
+    ("\u{1f3b2}", FullyQualified),
+    ("\u{1f3b3}", FullyQualified),
+    ("\u{1f3b4}", FullyQualified),
+    ("\u{1f3b5}", FullyQualified),
+    ("\u{1f3b6}", FullyQualified),
+    ("\u{1f3b7}", FullyQualified),
+    ("\u{1f3b8}", FullyQualified),
+    ("\u{1f3b9}", FullyQualified),
+    ("\u{1f3ba}", FullyQualified),
+    ("\u{1f3bb}", FullyQualified),
+    ("\u{1f3bc}", FullyQualified),
+    ("\u{1f3bd}", FullyQualified),
+    ("\u{1f3be}", FullyQualified),
+    ("\u{1f3bf}", FullyQualified),
+    ("\u{1f3c0}", FullyQualified),
+    ("\u{1f3c1}", FullyQualified),
+    ("\u{1f3c2}", FullyQualified),
+    ("\u{1f3c2}\u{1f3fb}", FullyQualified),
+    ("\u{1f3c2}\u{1f3fc}", FullyQualified),
+    ("\u{1f3c2}\u{1f3fd}", FullyQualified),
+    ("\u{1f3c2}\u{1f3fe}", FullyQualified),
+    ("\u{1f3c2}\u{1f3ff}", FullyQualified),
+    ("\u{1f3c3}", FullyQualified),
+    ("\u{1f3c3}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fb}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fc}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fd}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fe}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3ff}", FullyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}", FullyQualified),
+    ("\u{1f3c4}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c4}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c4}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fb}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fc}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fd}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fe}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3ff}", FullyQualified),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3c5}", FullyQualified),
+    ("\u{1f3c6}", FullyQualified),
+    ("\u{1f3c7}", FullyQualified),
+    ("\u{1f3c7}\u{1f3fb}", FullyQualified),
+    ("\u{1f3c7}\u{1f3fc}", FullyQualified),
+    ("\u{1f3c7}\u{1f3fd}", FullyQualified),
+    ("\u{1f3c7}\u{1f3fe}", FullyQualified),
+    ("\u{1f3c7}\u{1f3ff}", FullyQualified),
+    ("\u{1f3c8}", FullyQualified),
+    ("\u{1f3c9}", FullyQualified),
+    ("\u{1f3ca}", FullyQualified),
+    ("\u{1f3ca}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3ca}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3ca}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fb}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fc}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fd}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fe}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3ff}", FullyQualified),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}", Unqualified),
+    ("\u{1f3cb}\u{200d}\u{2640}", Unqualified),
+    ("\u{1f3cb}\u{200d}\u{2640}\u{fe0f}", Unqualified),
+    ("\u{1f3cb}\u{200d}\u{2642}", Unqualified),
+    ("\u{1f3cb}\u{200d}\u{2642}\u{fe0f}", Unqualified),
+    ("\u{1f3cb}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fb}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fc}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fd}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fe}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3ff}", FullyQualified),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}", Unqualified),
+    ("\u{1f3cc}\u{200d}\u{2640}", Unqualified),
+    ("\u{1f3cc}\u{200d}\u{2640}\u{fe0f}", Unqualified),
+    ("\u{1f3cc}\u{200d}\u{2642}", Unqualified),
+    ("\u{1f3cc}\u{200d}\u{2642}\u{fe0f}", Unqualified),
+    ("\u{1f3cc}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fb}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fc}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fd}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fe}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3ff}", FullyQualified),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f3cd}", Unqualified),
+    ("\u{1f3cd}\u{fe0f}", FullyQualified),
+    ("\u{1f3ce}", Unqualified),
+    ("\u{1f3ce}\u{fe0f}", FullyQualified),
+    ("\u{1f3cf}", FullyQualified),
+    ("\u{1f3d0}", FullyQualified),
+    ("\u{1f3d1}", FullyQualified),
+    ("\u{1f3d2}", FullyQualified),
+    ("\u{1f3d3}", FullyQualified),
+    ("\u{1f3d4}", Unqualified),
+    ("\u{1f3d4}\u{fe0f}", FullyQualified),
+    ("\u{1f3d5}", Unqualified),
+    ("\u{1f3d5}\u{fe0f}", FullyQualified),
+    ("\u{1f3d6}", Unqualified),
+    ("\u{1f3d6}\u{fe0f}", FullyQualified),
+    ("\u{1f3d7}", Unqualified),
+    ("\u{1f3d7}\u{fe0f}", FullyQualified),
+    ("\u{1f3d8}", Unqualified),
+    ("\u{1f3d8}\u{fe0f}", FullyQualified),
+    ("\u{1f3d9}", Unqualified),
+    ("\u{1f3d9}\u{fe0f}", FullyQualified),
+    ("\u{1f3da}", Unqualified),
+    ("\u{1f3da}\u{fe0f}", FullyQualified),
+    ("\u{1f3db}", Unqualified),
+    ("\u{1f3db}\u{fe0f}", FullyQualified),
+    ("\u{1f3dc}", Unqualified),
+    ("\u{1f3dc}\u{fe0f}", FullyQualified),
+    ("\u{1f3dd}", Unqualified),
+    ("\u{1f3dd}\u{fe0f}", FullyQualified),
+    ("\u{1f3de}", Unqualified),
+    ("\u{1f3de}\u{fe0f}", FullyQualified),
+    ("\u{1f3df}", Unqualified),
+    ("\u{1f3df}\u{fe0f}", FullyQualified),
+    ("\u{1f3e0}", FullyQualified),
+    ("\u{1f3e1}", FullyQualified),
+    ("\u{1f3e2}", FullyQualified),
+    ("\u{1f3e3}", FullyQualified),
+    ("\u{1f3e4}", FullyQualified),
+    ("\u{1f3e5}", FullyQualified),
+    ("\u{1f3e6}", FullyQualified),
+    ("\u{1f3e7}", FullyQualified),
+    ("\u{1f3e8}", FullyQualified),
+    ("\u{1f3e9}", FullyQualified),
+    ("\u{1f3ea}", FullyQualified),
+    ("\u{1f3eb}", FullyQualified),
+    ("\u{1f3ec}", FullyQualified),
+    ("\u{1f3ed}", FullyQualified),
+    ("\u{1f3ee}", FullyQualified),
+    ("\u{1f3ef}", FullyQualified),
+    ("\u{1f3f0}", FullyQualified),
+    ("\u{1f3f3}", Unqualified),
+    ("\u{1f3f3}\u{200d}\u{26a7}", Unqualified),
+    ("\u{1f3f3}\u{200d}\u{26a7}\u{fe0f}", Unqualified),
+    ("\u{1f3f3}\u{200d}\u{1f308}", Unqualified),
+    ("\u{1f3f3}\u{fe0f}", FullyQualified),
+    ("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}", MinimallyQualified),
+    ("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}", FullyQualified),
+    ("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}", FullyQualified),
+    ("\u{1f3f4}", FullyQualified),
+    ("\u{1f3f4}\u{200d}\u{2620}", MinimallyQualified),
+    ("\u{1f3f4}\u{200d}\u{2620}\u{fe0f}", FullyQualified),
+    ("\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", FullyQualified),
+    ("\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", FullyQualified),
+    ("\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", FullyQualified),
+    ("\u{1f3f5}", Unqualified),
+    ("\u{1f3f5}\u{fe0f}", FullyQualified),
+    ("\u{1f3f7}", Unqualified),
+    ("\u{1f3f7}\u{fe0f}", FullyQualified),
+    ("\u{1f3f8}", FullyQualified),
+    ("\u{1f3f9}", FullyQualified),
+    ("\u{1f3fa}", FullyQualified),
+    ("\u{1f3fb}", Component),
+    ("\u{1f3fc}", Component),
+    ("\u{1f3fd}", Component),
+    ("\u{1f3fe}", Component),
+    ("\u{1f3ff}", Component),
+    ("\u{1f400}", FullyQualified),
+    ("\u{1f401}", FullyQualified),
+    ("\u{1f402}", FullyQualified),
+    ("\u{1f403}", FullyQualified),
+    ("\u{1f404}", FullyQualified),
+    ("\u{1f405}", FullyQualified),
+    ("\u{1f406}", FullyQualified),
+    ("\u{1f407}", FullyQualified),
+    ("\u{1f408}", FullyQualified),
+    ("\u{1f408}\u{200d}\u{2b1b}", FullyQualified),
+    ("\u{1f409}", FullyQualified),
+    ("\u{1f40a}", FullyQualified),
+    ("\u{1f40b}", FullyQualified),
+    ("\u{1f40c}", FullyQualified),
+    ("\u{1f40d}", FullyQualified),
+    ("\u{1f40e}", FullyQualified),
+    ("\u{1f40f}", FullyQualified),
+    ("\u{1f410}", FullyQualified),
+    ("\u{1f411}", FullyQualified),
+    ("\u{1f412}", FullyQualified),
+    ("\u{1f413}", FullyQualified),
+    ("\u{1f414}", FullyQualified),
+    ("\u{1f415}", FullyQualified),
+    ("\u{1f415}\u{200d}\u{1f9ba}", FullyQualified),
+    ("\u{1f416}", FullyQualified),
+    ("\u{1f417}", FullyQualified),
+    ("\u{1f418}", FullyQualified),
+    ("\u{1f419}", FullyQualified),
+    ("\u{1f41a}", FullyQualified),
+    ("\u{1f41b}", FullyQualified),
+    ("\u{1f41c}", FullyQualified),
+    ("\u{1f41d}", FullyQualified),
+    ("\u{1f41e}", FullyQualified),
+    ("\u{1f41f}", FullyQualified),
+    ("\u{1f420}", FullyQualified),
+    ("\u{1f421}", FullyQualified),
+    ("\u{1f422}", FullyQualified),
+    ("\u{1f423}", FullyQualified),
+    ("\u{1f424}", FullyQualified),
+    ("\u{1f425}", FullyQualified),
+    ("\u{1f426}", FullyQualified),
+    ("\u{1f426}\u{200d}\u{2b1b}", FullyQualified),
+    ("\u{1f426}\u{200d}\u{1f525}", FullyQualified),
+    ("\u{1f427}", FullyQualified),
+    ("\u{1f428}", FullyQualified),
+    ("\u{1f429}", FullyQualified),
+    ("\u{1f42a}", FullyQualified),
+    ("\u{1f42b}", FullyQualified),
+    ("\u{1f42c}", FullyQualified),
+    ("\u{1f42d}", FullyQualified),
+    ("\u{1f42e}", FullyQualified),
+    ("\u{1f42f}", FullyQualified),
+    ("\u{1f430}", FullyQualified),
+    ("\u{1f431}", FullyQualified),
+    ("\u{1f432}", FullyQualified),
+    ("\u{1f433}", FullyQualified),
+    ("\u{1f434}", FullyQualified),
+    ("\u{1f435}", FullyQualified),
+    ("\u{1f436}", FullyQualified),
+    ("\u{1f437}", FullyQualified),
+    ("\u{1f438}", FullyQualified),
+    ("\u{1f439}", FullyQualified),
+    ("\u{1f43a}", FullyQualified),
+    ("\u{1f43b}", FullyQualified),
+    ("\u{1f43b}\u{200d}\u{2744}", MinimallyQualified),
+    ("\u{1f43b}\u{200d}\u{2744}\u{fe0f}", FullyQualified),
+    ("\u{1f43c}", FullyQualified),
+    ("\u{1f43d}", FullyQualified),
+    ("\u{1f43e}", FullyQualified),
+    ("\u{1f43f}", Unqualified),
+    ("\u{1f43f}\u{fe0f}", FullyQualified),
+    ("\u{1f440}", FullyQualified),
+    ("\u{1f441}", Unqualified),
+    ("\u{1f441}\u{200d}\u{1f5e8}", Unqualified),
+    ("\u{1f441}\u{200d}\u{1f5e8}\u{fe0f}", Unqualified),
+    ("\u{1f441}\u{fe0f}", FullyQualified),
+    ("\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}", MinimallyQualified),
+    ("\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}", FullyQualified),
+    ("\u{1f442}", FullyQualified),
+    ("\u{1f442}\u{1f3fb}", FullyQualified),
+    ("\u{1f442}\u{1f3fc}", FullyQualified),
+    ("\u{1f442}\u{1f3fd}", FullyQualified),
+    ("\u{1f442}\u{1f3fe}", FullyQualified),
+    ("\u{1f442}\u{1f3ff}", FullyQualified),
+    ("\u{1f443}", FullyQualified),
+    ("\u{1f443}\u{1f3fb}", FullyQualified),
+    ("\u{1f443}\u{1f3fc}", FullyQualified),
+    ("\u{1f443}\u{1f3fd}", FullyQualified),
+    ("\u{1f443}\u{1f3fe}", FullyQualified),
+    ("\u{1f443}\u{1f3ff}", FullyQualified),
+    ("\u{1f444}", FullyQualified),
+    ("\u{1f445}", FullyQualified),
+    ("\u{1f446}", FullyQualified),
+    ("\u{1f446}\u{1f3fb}", FullyQualified),
+    ("\u{1f446}\u{1f3fc}", FullyQualified),
+    ("\u{1f446}\u{1f3fd}", FullyQualified),
+    ("\u{1f446}\u{1f3fe}", FullyQualified),
+    ("\u{1f446}\u{1f3ff}", FullyQualified),
+    ("\u{1f447}", FullyQualified),
+    ("\u{1f447}\u{1f3fb}", FullyQualified),
+    ("\u{1f447}\u{1f3fc}", FullyQualified),
+    ("\u{1f447}\u{1f3fd}", FullyQualified),
+    ("\u{1f447}\u{1f3fe}", FullyQualified),
+    ("\u{1f447}\u{1f3ff}", FullyQualified),
+    ("\u{1f448}", FullyQualified),
+    ("\u{1f448}\u{1f3fb}", FullyQualified),
+    ("\u{1f448}\u{1f3fc}", FullyQualified),
+    ("\u{1f448}\u{1f3fd}", FullyQualified),
+    ("\u{1f448}\u{1f3fe}", FullyQualified),
+    ("\u{1f448}\u{1f3ff}", FullyQualified),
+    ("\u{1f449}", FullyQualified),
+    ("\u{1f449}\u{1f3fb}", FullyQualified),
+    ("\u{1f449}\u{1f3fc}", FullyQualified),
+    ("\u{1f449}\u{1f3fd}", FullyQualified),
+    ("\u{1f449}\u{1f3fe}", FullyQualified),
+    ("\u{1f449}\u{1f3ff}", FullyQualified),
+    ("\u{1f44a}", FullyQualified),
+    ("\u{1f44a}\u{1f3fb}", FullyQualified),
+    ("\u{1f44a}\u{1f3fc}", FullyQualified),
+    ("\u{1f44a}\u{1f3fd}", FullyQualified),
+    ("\u{1f44a}\u{1f3fe}", FullyQualified),
+    ("\u{1f44a}\u{1f3ff}", FullyQualified),
+    ("\u{1f44b}", FullyQualified),
+    ("\u{1f44b}\u{1f3fb}", FullyQualified),
+    ("\u{1f44b}\u{1f3fc}", FullyQualified),
+    ("\u{1f44b}\u{1f3fd}", FullyQualified),
+    ("\u{1f44b}\u{1f3fe}", FullyQualified),
+    ("\u{1f44b}\u{1f3ff}", FullyQualified),
+    ("\u{1f44c}", FullyQualified),
+    ("\u{1f44c}\u{1f3fb}", FullyQualified),
+    ("\u{1f44c}\u{1f3fc}", FullyQualified),
+    ("\u{1f44c}\u{1f3fd}", FullyQualified),
+    ("\u{1f44c}\u{1f3fe}", FullyQualified),
+    ("\u{1f44c}\u{1f3ff}", FullyQualified),
+    ("\u{1f44d}", FullyQualified),
+    ("\u{1f44d}\u{1f3fb}", FullyQualified),
+    ("\u{1f44d}\u{1f3fc}", FullyQualified),
+    ("\u{1f44d}\u{1f3fd}", FullyQualified),
+    ("\u{1f44d}\u{1f3fe}", FullyQualified),
+    ("\u{1f44d}\u{1f3ff}", FullyQualified),
+    ("\u{1f44e}", FullyQualified),
+    ("\u{1f44e}\u{1f3fb}", FullyQualified),
+    ("\u{1f44e}\u{1f3fc}", FullyQualified),
+    ("\u{1f44e}\u{1f3fd}", FullyQualified),
+    ("\u{1f44e}\u{1f3fe}", FullyQualified),
+    ("\u{1f44e}\u{1f3ff}", FullyQualified),
+    ("\u{1f44f}", FullyQualified),
+    ("\u{1f44f}\u{1f3fb}", FullyQualified),
+    ("\u{1f44f}\u{1f3fc}", FullyQualified),
+    ("\u{1f44f}\u{1f3fd}", FullyQualified),
+    ("\u{1f44f}\u{1f3fe}", FullyQualified),
+    ("\u{1f44f}\u{1f3ff}", FullyQualified),
+    ("\u{1f450}", FullyQualified),
+    ("\u{1f450}\u{1f3fb}", FullyQualified),
+    ("\u{1f450}\u{1f3fc}", FullyQualified),
+    ("\u{1f450}\u{1f3fd}", FullyQualified),
+    ("\u{1f450}\u{1f3fe}", FullyQualified),
+    ("\u{1f450}\u{1f3ff}", FullyQualified),
+    ("\u{1f451}", FullyQualified),
+    ("\u{1f452}", FullyQualified),
+    ("\u{1f453}", FullyQualified),
+    ("\u{1f454}", FullyQualified),
+    ("\u{1f455}", FullyQualified),
+    ("\u{1f456}", FullyQualified),
+    ("\u{1f457}", FullyQualified),
+    ("\u{1f458}", FullyQualified),
+    ("\u{1f459}", FullyQualified),
+    ("\u{1f45a}", FullyQualified),
+    ("\u{1f45b}", FullyQualified),
+    ("\u{1f45c}", FullyQualified),
+    ("\u{1f45d}", FullyQualified),
+    ("\u{1f45e}", FullyQualified),
+    ("\u{1f45f}", FullyQualified),
+    ("\u{1f460}", FullyQualified),
+    ("\u{1f461}", FullyQualified),
+    ("\u{1f462}", FullyQualified),
+    ("\u{1f463}", FullyQualified),
+    ("\u{1f464}", FullyQualified),
+    ("\u{1f465}", FullyQualified),
+    ("\u{1f466}", FullyQualified),
+    ("\u{1f466}\u{1f3fb}", FullyQualified),
+    ("\u{1f466}\u{1f3fc}", FullyQualified),
+    ("\u{1f466}\u{1f3fd}", FullyQualified),
+    ("\u{1f466}\u{1f3fe}", FullyQualified),
+    ("\u{1f466}\u{1f3ff}", FullyQualified),
+    ("\u{1f467}", FullyQualified),
+    ("\u{1f467}\u{1f3fb}", FullyQualified),
+    ("\u{1f467}\u{1f3fc}", FullyQualified),
+    ("\u{1f467}\u{1f3fd}", FullyQualified),
+    ("\u{1f467}\u{1f3fe}", FullyQualified),
+    ("\u{1f467}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{2764}\u{200d}\u{1f468}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f468}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f469}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f46a}", FullyQualified),
+    ("\u{1f46b}", FullyQualified),
+    ("\u{1f46b}\u{1f3fb}", FullyQualified),
+    ("\u{1f46b}\u{1f3fc}", FullyQualified),
+    ("\u{1f46b}\u{1f3fd}", FullyQualified),
+    ("\u{1f46b}\u{1f3fe}", FullyQualified),
+    ("\u{1f46b}\u{1f3ff}", FullyQualified),
+    ("\u{1f46c}", FullyQualified),
+    ("\u{1f46c}\u{1f3fb}", FullyQualified),
+    ("\u{1f46c}\u{1f3fc}", FullyQualified),
+    ("\u{1f46c}\u{1f3fd}", FullyQualified),
+    ("\u{1f46c}\u{1f3fe}", FullyQualified),
+    ("\u{1f46c}\u{1f3ff}", FullyQualified),
+    ("\u{1f46d}", FullyQualified),
+    ("\u{1f46d}\u{1f3fb}", FullyQualified),
+    ("\u{1f46d}\u{1f3fc}", FullyQualified),
+    ("\u{1f46d}\u{1f3fd}", FullyQualified),
+    ("\u{1f46d}\u{1f3fe}", FullyQualified),
+    ("\u{1f46d}\u{1f3ff}", FullyQualified),
+    ("\u{1f46e}", FullyQualified),
+    ("\u{1f46e}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46e}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46e}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fb}", FullyQualified),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fc}", FullyQualified),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fd}", FullyQualified),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fe}", FullyQualified),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3ff}", FullyQualified),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f46f}", FullyQualified),
+    ("\u{1f46f}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f46f}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f46f}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f46f}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f470}", FullyQualified),
+    ("\u{1f470}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f470}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f470}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fb}", FullyQualified),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fc}", FullyQualified),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fd}", FullyQualified),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fe}", FullyQualified),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3ff}", FullyQualified),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f471}", FullyQualified),
+    ("\u{1f471}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f471}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f471}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fb}", FullyQualified),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fc}", FullyQualified),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fd}", FullyQualified),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fe}", FullyQualified),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3ff}", FullyQualified),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f472}", FullyQualified),
+    ("\u{1f472}\u{1f3fb}", FullyQualified),
+    ("\u{1f472}\u{1f3fc}", FullyQualified),
+    ("\u{1f472}\u{1f3fd}", FullyQualified),
+    ("\u{1f472}\u{1f3fe}", FullyQualified),
+    ("\u{1f472}\u{1f3ff}", FullyQualified),
+    ("\u{1f473}", FullyQualified),
+    ("\u{1f473}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f473}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f473}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fb}", FullyQualified),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fc}", FullyQualified),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fd}", FullyQualified),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fe}", FullyQualified),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3ff}", FullyQualified),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f474}", FullyQualified),
+    ("\u{1f474}\u{1f3fb}", FullyQualified),
+    ("\u{1f474}\u{1f3fc}", FullyQualified),
+    ("\u{1f474}\u{1f3fd}", FullyQualified),
+    ("\u{1f474}\u{1f3fe}", FullyQualified),
+    ("\u{1f474}\u{1f3ff}", FullyQualified),
+    ("\u{1f475}", FullyQualified),
+    ("\u{1f475}\u{1f3fb}", FullyQualified),
+    ("\u{1f475}\u{1f3fc}", FullyQualified),
+    ("\u{1f475}\u{1f3fd}", FullyQualified),
+    ("\u{1f475}\u{1f3fe}", FullyQualified),
+    ("\u{1f475}\u{1f3ff}", FullyQualified),
+    ("\u{1f476}", FullyQualified),
+    ("\u{1f476}\u{1f3fb}", FullyQualified),
+    ("\u{1f476}\u{1f3fc}", FullyQualified),
+    ("\u{1f476}\u{1f3fd}", FullyQualified),
+    ("\u{1f476}\u{1f3fe}", FullyQualified),
+    ("\u{1f476}\u{1f3ff}", FullyQualified),
+    ("\u{1f477}", FullyQualified),
+    ("\u{1f477}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f477}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f477}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fb}", FullyQualified),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fc}", FullyQualified),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fd}", FullyQualified),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fe}", FullyQualified),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3ff}", FullyQualified),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f478}", FullyQualified),
+    ("\u{1f478}\u{1f3fb}", FullyQualified),
+    ("\u{1f478}\u{1f3fc}", FullyQualified),
+    ("\u{1f478}\u{1f3fd}", FullyQualified),
+    ("\u{1f478}\u{1f3fe}", FullyQualified),
+    ("\u{1f478}\u{1f3ff}", FullyQualified),
+    ("\u{1f479}", FullyQualified),
+    ("\u{1f47a}", FullyQualified),
+    ("\u{1f47b}", FullyQualified),
+    ("\u{1f47c}", FullyQualified),
+    ("\u{1f47c}\u{1f3fb}", FullyQualified),
+    ("\u{1f47c}\u{1f3fc}", FullyQualified),
+    ("\u{1f47c}\u{1f3fd}", FullyQualified),
+    ("\u{1f47c}\u{1f3fe}", FullyQualified),
+    ("\u{1f47c}\u{1f3ff}", FullyQualified),
+    ("\u{1f47d}", FullyQualified),
+    ("\u{1f47e}", FullyQualified),
+    ("\u{1f47f}", FullyQualified),
+    ("\u{1f480}", FullyQualified),
+    ("\u{1f481}", FullyQualified),
+    ("\u{1f481}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f481}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f481}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fb}", FullyQualified),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fc}", FullyQualified),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fd}", FullyQualified),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fe}", FullyQualified),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3ff}", FullyQualified),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f482}", FullyQualified),
+    ("\u{1f482}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f482}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f482}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fb}", FullyQualified),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fc}", FullyQualified),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fd}", FullyQualified),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fe}", FullyQualified),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3ff}", FullyQualified),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f483}", FullyQualified),
+    ("\u{1f483}\u{1f3fb}", FullyQualified),
+    ("\u{1f483}\u{1f3fc}", FullyQualified),
+    ("\u{1f483}\u{1f3fd}", FullyQualified),
+    ("\u{1f483}\u{1f3fe}", FullyQualified),
+    ("\u{1f483}\u{1f3ff}", FullyQualified),
+    ("\u{1f484}", FullyQualified),
+    ("\u{1f485}", FullyQualified),
+    ("\u{1f485}\u{1f3fb}", FullyQualified),
+    ("\u{1f485}\u{1f3fc}", FullyQualified),
+    ("\u{1f485}\u{1f3fd}", FullyQualified),
+    ("\u{1f485}\u{1f3fe}", FullyQualified),
+    ("\u{1f485}\u{1f3ff}", FullyQualified),
+    ("\u{1f486}", FullyQualified),
+    ("\u{1f486}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f486}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f486}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fb}", FullyQualified),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fc}", FullyQualified),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fd}", FullyQualified),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fe}", FullyQualified),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3ff}", FullyQualified),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f487}", FullyQualified),
+    ("\u{1f487}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f487}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f487}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fb}", FullyQualified),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fc}", FullyQualified),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fd}", FullyQualified),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fe}", FullyQualified),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3ff}", FullyQualified),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f488}", FullyQualified),
+    ("\u{1f489}", FullyQualified),
+    ("\u{1f48a}", FullyQualified),
+    ("\u{1f48b}", FullyQualified),
+    ("\u{1f48c}", FullyQualified),
+    ("\u{1f48d}", FullyQualified),
+    ("\u{1f48e}", FullyQualified),
+    ("\u{1f48f}", FullyQualified),
+    ("\u{1f48f}\u{1f3fb}", FullyQualified),
+    ("\u{1f48f}\u{1f3fc}", FullyQualified),
+    ("\u{1f48f}\u{1f3fd}", FullyQualified),
+    ("\u{1f48f}\u{1f3fe}", FullyQualified),
+    ("\u{1f48f}\u{1f3ff}", FullyQualified),
+    ("\u{1f490}", FullyQualified),
+    ("\u{1f491}", FullyQualified),
+    ("\u{1f491}\u{1f3fb}", FullyQualified),
+    ("\u{1f491}\u{1f3fc}", FullyQualified),
+    ("\u{1f491}\u{1f3fd}", FullyQualified),
+    ("\u{1f491}\u{1f3fe}", FullyQualified),
+    ("\u{1f491}\u{1f3ff}", FullyQualified),
+    ("\u{1f492}", FullyQualified),
+    ("\u{1f493}", FullyQualified),
+    ("\u{1f494}", FullyQualified),
+    ("\u{1f495}", FullyQualified),
+    ("\u{1f496}", FullyQualified),
+    ("\u{1f497}", FullyQualified),
+    ("\u{1f498}", FullyQualified),
+    ("\u{1f499}", FullyQualified),
+    ("\u{1f49a}", FullyQualified),
+    ("\u{1f49b}", FullyQualified),
+    ("\u{1f49c}", FullyQualified),
+    ("\u{1f49d}", FullyQualified),
+    ("\u{1f49e}", FullyQualified),
+    ("\u{1f49f}", FullyQualified),
+    ("\u{1f4a0}", FullyQualified),
+    ("\u{1f4a1}", FullyQualified),
+    ("\u{1f4a2}", FullyQualified),
+    ("\u{1f4a3}", FullyQualified),
+    ("\u{1f4a4}", FullyQualified),
+    ("\u{1f4a5}", FullyQualified),
+    ("\u{1f4a6}", FullyQualified),
+    ("\u{1f4a7}", FullyQualified),
+    ("\u{1f4a8}", FullyQualified),
+    ("\u{1f4a9}", FullyQualified),
+    ("\u{1f4aa}", FullyQualified),
+    ("\u{1f4aa}\u{1f3fb}", FullyQualified),
+    ("\u{1f4aa}\u{1f3fc}", FullyQualified),
+    ("\u{1f4aa}\u{1f3fd}", FullyQualified),
+    ("\u{1f4aa}\u{1f3fe}", FullyQualified),
+    ("\u{1f4aa}\u{1f3ff}", FullyQualified),
+    ("\u{1f4ab}", FullyQualified),
+    ("\u{1f4ac}", FullyQualified),
+    ("\u{1f4ad}", FullyQualified),
+    ("\u{1f4ae}", FullyQualified),
+    ("\u{1f4af}", FullyQualified),
+    ("\u{1f4b0}", FullyQualified),
+    ("\u{1f4b1}", FullyQualified),
+    ("\u{1f4b2}", FullyQualified),
+    ("\u{1f4b3}", FullyQualified),
+    ("\u{1f4b4}", FullyQualified),
+    ("\u{1f4b5}", FullyQualified),
+    ("\u{1f4b6}", FullyQualified),
+    ("\u{1f4b7}", FullyQualified),
+    ("\u{1f4b8}", FullyQualified),
+    ("\u{1f4b9}", FullyQualified),
+    ("\u{1f4ba}", FullyQualified),
+    ("\u{1f4bb}", FullyQualified),
+    ("\u{1f4bc}", FullyQualified),
+    ("\u{1f4bd}", FullyQualified),
+    ("\u{1f4be}", FullyQualified),
+    ("\u{1f4bf}", FullyQualified),
+    ("\u{1f4c0}", FullyQualified),
+    ("\u{1f4c1}", FullyQualified),
+    ("\u{1f4c2}", FullyQualified),
+    ("\u{1f4c3}", FullyQualified),
+    ("\u{1f4c4}", FullyQualified),
+    ("\u{1f4c5}", FullyQualified),
+    ("\u{1f4c6}", FullyQualified),
+    ("\u{1f4c7}", FullyQualified),
+    ("\u{1f4c8}", FullyQualified),
+    ("\u{1f4c9}", FullyQualified),
+    ("\u{1f4ca}", FullyQualified),
+    ("\u{1f4cb}", FullyQualified),
+    ("\u{1f4cc}", FullyQualified),
+    ("\u{1f4cd}", FullyQualified),
+    ("\u{1f4ce}", FullyQualified),
+    ("\u{1f4cf}", FullyQualified),
+    ("\u{1f4d0}", FullyQualified),
+    ("\u{1f4d1}", FullyQualified),
+    ("\u{1f4d2}", FullyQualified),
+    ("\u{1f4d3}", FullyQualified),
+    ("\u{1f4d4}", FullyQualified),
+    ("\u{1f4d5}", FullyQualified),
+    ("\u{1f4d6}", FullyQualified),
+    ("\u{1f4d7}", FullyQualified),
+    ("\u{1f4d8}", FullyQualified),
+    ("\u{1f4d9}", FullyQualified),
+    ("\u{1f4da}", FullyQualified),
+    ("\u{1f4db}", FullyQualified),
+    ("\u{1f4dc}", FullyQualified),
+    ("\u{1f4dd}", FullyQualified),
+    ("\u{1f4de}", FullyQualified),
+    ("\u{1f4df}", FullyQualified),
+    ("\u{1f4e0}", FullyQualified),
+    ("\u{1f4e1}", FullyQualified),
+    ("\u{1f4e2}", FullyQualified),
+    ("\u{1f4e3}", FullyQualified),
+    ("\u{1f4e4}", FullyQualified),
+    ("\u{1f4e5}", FullyQualified),
+    ("\u{1f4e6}", FullyQualified),
+    ("\u{1f4e7}", FullyQualified),
+    ("\u{1f4e8}", FullyQualified),
+    ("\u{1f4e9}", FullyQualified),
+    ("\u{1f4ea}", FullyQualified),
+    ("\u{1f4eb}", FullyQualified),
+    ("\u{1f4ec}", FullyQualified),
+    ("\u{1f4ed}", FullyQualified),
+    ("\u{1f4ee}", FullyQualified),
+    ("\u{1f4ef}", FullyQualified),
+    ("\u{1f4f0}", FullyQualified),
+    ("\u{1f4f1}", FullyQualified),
+    ("\u{1f4f2}", FullyQualified),
+    ("\u{1f4f3}", FullyQualified),
+    ("\u{1f4f4}", FullyQualified),
+    ("\u{1f4f5}", FullyQualified),
+    ("\u{1f4f6}", FullyQualified),
+    ("\u{1f4f7}", FullyQualified),
+    ("\u{1f4f8}", FullyQualified),
+    ("\u{1f4f9}", FullyQualified),
+    ("\u{1f4fa}", FullyQualified),
+    ("\u{1f4fb}", FullyQualified),
+    ("\u{1f4fc}", FullyQualified),
+    ("\u{1f4fd}", Unqualified),
+    ("\u{1f4fd}\u{fe0f}", FullyQualified),
+    ("\u{1f4ff}", FullyQualified),
+    ("\u{1f500}", FullyQualified),
+    ("\u{1f501}", FullyQualified),
+    ("\u{1f502}", FullyQualified),
+    ("\u{1f503}", FullyQualified),
+    ("\u{1f504}", FullyQualified),
+    ("\u{1f505}", FullyQualified),
+    ("\u{1f506}", FullyQualified),
+    ("\u{1f507}", FullyQualified),
+    ("\u{1f508}", FullyQualified),
+    ("\u{1f509}", FullyQualified),
+    ("\u{1f50a}", FullyQualified),
+    ("\u{1f50b}", FullyQualified),
+    ("\u{1f50c}", FullyQualified),
+    ("\u{1f50d}", FullyQualified),
+    ("\u{1f50e}", FullyQualified),
+    ("\u{1f50f}", FullyQualified),
+    ("\u{1f510}", FullyQualified),
+    ("\u{1f511}", FullyQualified),
+    ("\u{1f512}", FullyQualified),
+    ("\u{1f513}", FullyQualified),
+    ("\u{1f514}", FullyQualified),
+    ("\u{1f515}", FullyQualified),
+    ("\u{1f516}", FullyQualified),
+    ("\u{1f517}", FullyQualified),
+    ("\u{1f518}", FullyQualified),
+    ("\u{1f519}", FullyQualified),
+    ("\u{1f51a}", FullyQualified),
+    ("\u{1f51b}", FullyQualified),
+    ("\u{1f51c}", FullyQualified),
+    ("\u{1f51d}", FullyQualified),
+    ("\u{1f51e}", FullyQualified),
+    ("\u{1f51f}", FullyQualified),
+    ("\u{1f520}", FullyQualified),
+    ("\u{1f521}", FullyQualified),
+    ("\u{1f522}", FullyQualified),
+    ("\u{1f523}", FullyQualified),
+    ("\u{1f524}", FullyQualified),
+    ("\u{1f525}", FullyQualified),
+    ("\u{1f526}", FullyQualified),
+    ("\u{1f527}", FullyQualified),
+    ("\u{1f528}", FullyQualified),
+    ("\u{1f529}", FullyQualified),
+    ("\u{1f52a}", FullyQualified),
+    ("\u{1f52b}", FullyQualified),
+    ("\u{1f52c}", FullyQualified),
+    ("\u{1f52d}", FullyQualified),
+    ("\u{1f52e}", FullyQualified),
+    ("\u{1f52f}", FullyQualified),
+    ("\u{1f530}", FullyQualified),
+    ("\u{1f531}", FullyQualified),
+    ("\u{1f532}", FullyQualified),
+    ("\u{1f533}", FullyQualified),
+    ("\u{1f534}", FullyQualified),
+    ("\u{1f535}", FullyQualified),
+    ("\u{1f536}", FullyQualified),
+    ("\u{1f537}", FullyQualified),
+    ("\u{1f538}", FullyQualified),
+    ("\u{1f539}", FullyQualified),
+    ("\u{1f53a}", FullyQualified),
+    ("\u{1f53b}", FullyQualified),
+    ("\u{1f53c}", FullyQualified),
+    ("\u{1f53d}", FullyQualified),
+    ("\u{1f549}", Unqualified),
+    ("\u{1f549}\u{fe0f}", FullyQualified),
+    ("\u{1f54a}", Unqualified),
+    ("\u{1f54a}\u{fe0f}", FullyQualified),
+    ("\u{1f54b}", FullyQualified),
+    ("\u{1f54c}", FullyQualified),
+    ("\u{1f54d}", FullyQualified),
+    ("\u{1f54e}", FullyQualified),
+    ("\u{1f550}", FullyQualified),
+    ("\u{1f551}", FullyQualified),
+    ("\u{1f552}", FullyQualified),
+    ("\u{1f553}", FullyQualified),
+    ("\u{1f554}", FullyQualified),
+    ("\u{1f555}", FullyQualified),
+    ("\u{1f556}", FullyQualified),
+    ("\u{1f557}", FullyQualified),
+    ("\u{1f558}", FullyQualified),
+    ("\u{1f559}", FullyQualified),
+    ("\u{1f55a}", FullyQualified),
+    ("\u{1f55b}", FullyQualified),
+    ("\u{1f55c}", FullyQualified),
+    ("\u{1f55d}", FullyQualified),
+    ("\u{1f55e}", FullyQualified),
+    ("\u{1f55f}", FullyQualified),
+    ("\u{1f560}", FullyQualified),
+    ("\u{1f561}", FullyQualified),
+    ("\u{1f562}", FullyQualified),
+    ("\u{1f563}", FullyQualified),
+    ("\u{1f564}", FullyQualified),
+    ("\u{1f565}", FullyQualified),
+    ("\u{1f566}", FullyQualified),
+    ("\u{1f567}", FullyQualified),
+    ("\u{1f56f}", Unqualified),
+    ("\u{1f56f}\u{fe0f}", FullyQualified),
+    ("\u{1f570}", Unqualified),
+    ("\u{1f570}\u{fe0f}", FullyQualified),
+    ("\u{1f573}", Unqualified),
+    ("\u{1f573}\u{fe0f}", FullyQualified),
+    ("\u{1f574}", Unqualified),
+    ("\u{1f574}\u{fe0f}", FullyQualified),
+    ("\u{1f574}\u{1f3fb}", FullyQualified),
+    ("\u{1f574}\u{1f3fc}", FullyQualified),
+    ("\u{1f574}\u{1f3fd}", FullyQualified),
+    ("\u{1f574}\u{1f3fe}", FullyQualified),
+    ("\u{1f574}\u{1f3ff}", FullyQualified),
+    ("\u{1f575}", Unqualified),
+    ("\u{1f575}\u{200d}\u{2640}", Unqualified),
+    ("\u{1f575}\u{200d}\u{2640}\u{fe0f}", Unqualified),
+    ("\u{1f575}\u{200d}\u{2642}", Unqualified),
+    ("\u{1f575}\u{200d}\u{2642}\u{fe0f}", Unqualified),
+    ("\u{1f575}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fb}", FullyQualified),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fc}", FullyQualified),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fd}", FullyQualified),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fe}", FullyQualified),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3ff}", FullyQualified),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f576}", Unqualified),
+    ("\u{1f576}\u{fe0f}", FullyQualified),
+    ("\u{1f577}", Unqualified),
+    ("\u{1f577}\u{fe0f}", FullyQualified),
+    ("\u{1f578}", Unqualified),
+    ("\u{1f578}\u{fe0f}", FullyQualified),
+    ("\u{1f579}", Unqualified),
+    ("\u{1f579}\u{fe0f}", FullyQualified),
+    ("\u{1f57a}", FullyQualified),
+    ("\u{1f57a}\u{1f3fb}", FullyQualified),
+    ("\u{1f57a}\u{1f3fc}", FullyQualified),
+    ("\u{1f57a}\u{1f3fd}", FullyQualified),
+    ("\u{1f57a}\u{1f3fe}", FullyQualified),
+    ("\u{1f57a}\u{1f3ff}", FullyQualified),
+    ("\u{1f587}", Unqualified),
+    ("\u{1f587}\u{fe0f}", FullyQualified),
+    ("\u{1f58a}", Unqualified),
+    ("\u{1f58a}\u{fe0f}", FullyQualified),
+    ("\u{1f58b}", Unqualified),
+    ("\u{1f58b}\u{fe0f}", FullyQualified),
+    ("\u{1f58c}", Unqualified),
+    ("\u{1f58c}\u{fe0f}", FullyQualified),
+    ("\u{1f58d}", Unqualified),
+    ("\u{1f58d}\u{fe0f}", FullyQualified),
+    ("\u{1f590}", Unqualified),
+    ("\u{1f590}\u{fe0f}", FullyQualified),
+    ("\u{1f590}\u{1f3fb}", FullyQualified),
+    ("\u{1f590}\u{1f3fc}", FullyQualified),
+    ("\u{1f590}\u{1f3fd}", FullyQualified),
+    ("\u{1f590}\u{1f3fe}", FullyQualified),
+    ("\u{1f590}\u{1f3ff}", FullyQualified),
+    ("\u{1f595}", FullyQualified),
+    ("\u{1f595}\u{1f3fb}", FullyQualified),
+    ("\u{1f595}\u{1f3fc}", FullyQualified),
+    ("\u{1f595}\u{1f3fd}", FullyQualified),
+    ("\u{1f595}\u{1f3fe}", FullyQualified),
+    ("\u{1f595}\u{1f3ff}", FullyQualified),
+    ("\u{1f596}", FullyQualified),
+    ("\u{1f596}\u{1f3fb}", FullyQualified),
+    ("\u{1f596}\u{1f3fc}", FullyQualified),
+    ("\u{1f596}\u{1f3fd}", FullyQualified),
+    ("\u{1f596}\u{1f3fe}", FullyQualified),
+    ("\u{1f596}\u{1f3ff}", FullyQualified),
+    ("\u{1f5a4}", FullyQualified),
+    ("\u{1f5a5}", Unqualified),
+    ("\u{1f5a5}\u{fe0f}", FullyQualified),
+    ("\u{1f5a8}", Unqualified),
+    ("\u{1f5a8}\u{fe0f}", FullyQualified),
+    ("\u{1f5b1}", Unqualified),
+    ("\u{1f5b1}\u{fe0f}", FullyQualified),
+    ("\u{1f5b2}", Unqualified),
+    ("\u{1f5b2}\u{fe0f}", FullyQualified),
+    ("\u{1f5bc}", Unqualified),
+    ("\u{1f5bc}\u{fe0f}", FullyQualified),
+    ("\u{1f5c2}", Unqualified),
+    ("\u{1f5c2}\u{fe0f}", FullyQualified),
+    ("\u{1f5c3}", Unqualified),
+    ("\u{1f5c3}\u{fe0f}", FullyQualified),
+    ("\u{1f5c4}", Unqualified),
+    ("\u{1f5c4}\u{fe0f}", FullyQualified),
+    ("\u{1f5d1}", Unqualified),
+    ("\u{1f5d1}\u{fe0f}", FullyQualified),
+    ("\u{1f5d2}", Unqualified),
+    ("\u{1f5d2}\u{fe0f}", FullyQualified),
+    ("\u{1f5d3}", Unqualified),
+    ("\u{1f5d3}\u{fe0f}", FullyQualified),
+    ("\u{1f5dc}", Unqualified),
+    ("\u{1f5dc}\u{fe0f}", FullyQualified),
+    ("\u{1f5dd}", Unqualified),
+    ("\u{1f5dd}\u{fe0f}", FullyQualified),
+    ("\u{1f5de}", Unqualified),
+    ("\u{1f5de}\u{fe0f}", FullyQualified),
+    ("\u{1f5e1}", Unqualified),
+    ("\u{1f5e1}\u{fe0f}", FullyQualified),
+    ("\u{1f5e3}", Unqualified),
+    ("\u{1f5e3}\u{fe0f}", FullyQualified),
+    ("\u{1f5e8}", Unqualified),
+    ("\u{1f5e8}\u{fe0f}", FullyQualified),
+    ("\u{1f5ef}", Unqualified),
+    ("\u{1f5ef}\u{fe0f}", FullyQualified),
+    ("\u{1f5f3}", Unqualified),
+    ("\u{1f5f3}\u{fe0f}", FullyQualified),
+    ("\u{1f5fa}", Unqualified),
+    ("\u{1f5fa}\u{fe0f}", FullyQualified),
+    ("\u{1f5fb}", FullyQualified),
+    ("\u{1f5fc}", FullyQualified),
+    ("\u{1f5fd}", FullyQualified),
+    ("\u{1f5fe}", FullyQualified),
+    ("\u{1f5ff}", FullyQualified),
+    ("\u{1f600}", FullyQualified),
+    ("\u{1f601}", FullyQualified),
+    ("\u{1f602}", FullyQualified),
+    ("\u{1f603}", FullyQualified),
+    ("\u{1f604}", FullyQualified),
+    ("\u{1f605}", FullyQualified),
+    ("\u{1f606}", FullyQualified),
+    ("\u{1f607}", FullyQualified),
+    ("\u{1f608}", FullyQualified),
+    ("\u{1f609}", FullyQualified),
+    ("\u{1f60a}", FullyQualified),
+    ("\u{1f60b}", FullyQualified),
+    ("\u{1f60c}", FullyQualified),
+    ("\u{1f60d}", FullyQualified),
+    ("\u{1f60e}", FullyQualified),
+    ("\u{1f60f}", FullyQualified),
+    ("\u{1f610}", FullyQualified),
+    ("\u{1f611}", FullyQualified),
+    ("\u{1f612}", FullyQualified),
+    ("\u{1f613}", FullyQualified),
+    ("\u{1f614}", FullyQualified),
+    ("\u{1f615}", FullyQualified),
+    ("\u{1f616}", FullyQualified),
+    ("\u{1f617}", FullyQualified),
+    ("\u{1f618}", FullyQualified),
+    ("\u{1f619}", FullyQualified),
+    ("\u{1f61a}", FullyQualified),
+    ("\u{1f61b}", FullyQualified),
+    ("\u{1f61c}", FullyQualified),
+    ("\u{1f61d}", FullyQualified),
+    ("\u{1f61e}", FullyQualified),
+    ("\u{1f61f}", FullyQualified),
+    ("\u{1f620}", FullyQualified),
+    ("\u{1f621}", FullyQualified),
+    ("\u{1f622}", FullyQualified),
+    ("\u{1f623}", FullyQualified),
+    ("\u{1f624}", FullyQualified),
+    ("\u{1f625}", FullyQualified),
+    ("\u{1f626}", FullyQualified),
+    ("\u{1f627}", FullyQualified),
+    ("\u{1f628}", FullyQualified),
+    ("\u{1f629}", FullyQualified),
+    ("\u{1f62a}", FullyQualified),
+    ("\u{1f62b}", FullyQualified),
+    ("\u{1f62c}", FullyQualified),
+    ("\u{1f62d}", FullyQualified),
+    ("\u{1f62e}", FullyQualified),
+    ("\u{1f62e}\u{200d}\u{1f4a8}", FullyQualified),
+    ("\u{1f62f}", FullyQualified),
+    ("\u{1f630}", FullyQualified),
+    ("\u{1f631}", FullyQualified),
+    ("\u{1f632}", FullyQualified),
+    ("\u{1f633}", FullyQualified),
+    ("\u{1f634}", FullyQualified),
+    ("\u{1f635}", FullyQualified),
+    ("\u{1f635}\u{200d}\u{1f4ab}", FullyQualified),
+    ("\u{1f636}", FullyQualified),
+    ("\u{1f636}\u{200d}\u{1f32b}", MinimallyQualified),
+    ("\u{1f636}\u{200d}\u{1f32b}\u{fe0f}", FullyQualified),
+    ("\u{1f637}", FullyQualified),
+    ("\u{1f638}", FullyQualified),
+    ("\u{1f639}", FullyQualified),
+    ("\u{1f63a}", FullyQualified),
+    ("\u{1f63b}", FullyQualified),
+    ("\u{1f63c}", FullyQualified),
+    ("\u{1f63d}", FullyQualified),
+    ("\u{1f63e}", FullyQualified),
+    ("\u{1f63f}", FullyQualified),
+    ("\u{1f640}", FullyQualified),
+    ("\u{1f641}", FullyQualified),
+    ("\u{1f642}", FullyQualified),
+    ("\u{1f642}\u{200d}\u{2194}", MinimallyQualified),
+    ("\u{1f642}\u{200d}\u{2194}\u{fe0f}", FullyQualified),
+    ("\u{1f642}\u{200d}\u{2195}", MinimallyQualified),
+    ("\u{1f642}\u{200d}\u{2195}\u{fe0f}", FullyQualified),
+    ("\u{1f643}", FullyQualified),
+    ("\u{1f644}", FullyQualified),
+    ("\u{1f645}", FullyQualified),
+    ("\u{1f645}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f645}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f645}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fb}", FullyQualified),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fc}", FullyQualified),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fd}", FullyQualified),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fe}", FullyQualified),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3ff}", FullyQualified),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f646}", FullyQualified),
+    ("\u{1f646}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f646}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f646}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fb}", FullyQualified),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fc}", FullyQualified),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fd}", FullyQualified),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fe}", FullyQualified),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3ff}", FullyQualified),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f647}", FullyQualified),
+    ("\u{1f647}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f647}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f647}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fb}", FullyQualified),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fc}", FullyQualified),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fd}", FullyQualified),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fe}", FullyQualified),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3ff}", FullyQualified),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f648}", FullyQualified),
+    ("\u{1f649}", FullyQualified),
+    ("\u{1f64a}", FullyQualified),
+    ("\u{1f64b}", FullyQualified),
+    ("\u{1f64b}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64b}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64b}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fb}", FullyQualified),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fc}", FullyQualified),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fd}", FullyQualified),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fe}", FullyQualified),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3ff}", FullyQualified),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64c}", FullyQualified),
+    ("\u{1f64c}\u{1f3fb}", FullyQualified),
+    ("\u{1f64c}\u{1f3fc}", FullyQualified),
+    ("\u{1f64c}\u{1f3fd}", FullyQualified),
+    ("\u{1f64c}\u{1f3fe}", FullyQualified),
+    ("\u{1f64c}\u{1f3ff}", FullyQualified),
+    ("\u{1f64d}", FullyQualified),
+    ("\u{1f64d}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64d}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64d}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fb}", FullyQualified),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fc}", FullyQualified),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fd}", FullyQualified),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fe}", FullyQualified),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3ff}", FullyQualified),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}", FullyQualified),
+    ("\u{1f64e}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64e}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64e}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fb}", FullyQualified),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fc}", FullyQualified),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fd}", FullyQualified),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fe}", FullyQualified),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3ff}", FullyQualified),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f64f}", FullyQualified),
+    ("\u{1f64f}\u{1f3fb}", FullyQualified),
+    ("\u{1f64f}\u{1f3fc}", FullyQualified),
+    ("\u{1f64f}\u{1f3fd}", FullyQualified),
+    ("\u{1f64f}\u{1f3fe}", FullyQualified),
+    ("\u{1f64f}\u{1f3ff}", FullyQualified),
+    ("\u{1f680}", FullyQualified),
+    ("\u{1f681}", FullyQualified),
+    ("\u{1f682}", FullyQualified),
+    ("\u{1f683}", FullyQualified),
+    ("\u{1f684}", FullyQualified),
+    ("\u{1f685}", FullyQualified),
+    ("\u{1f686}", FullyQualified),
+    ("\u{1f687}", FullyQualified),
+    ("\u{1f688}", FullyQualified),
+    ("\u{1f689}", FullyQualified),
+    ("\u{1f68a}", FullyQualified),
+    ("\u{1f68b}", FullyQualified),
+    ("\u{1f68c}", FullyQualified),
+    ("\u{1f68d}", FullyQualified),
+    ("\u{1f68e}", FullyQualified),
+    ("\u{1f68f}", FullyQualified),
+    ("\u{1f690}", FullyQualified),
+    ("\u{1f691}", FullyQualified),
+    ("\u{1f692}", FullyQualified),
+    ("\u{1f693}", FullyQualified),
+    ("\u{1f694}", FullyQualified),
+    ("\u{1f695}", FullyQualified),
+    ("\u{1f696}", FullyQualified),
+    ("\u{1f697}", FullyQualified),
+    ("\u{1f698}", FullyQualified),
+    ("\u{1f699}", FullyQualified),
+    ("\u{1f69a}", FullyQualified),
+    ("\u{1f69b}", FullyQualified),
+    ("\u{1f69c}", FullyQualified),
+    ("\u{1f69d}", FullyQualified),
+    ("\u{1f69e}", FullyQualified),
+    ("\u{1f69f}", FullyQualified),
+    ("\u{1f6a0}", FullyQualified),
+    ("\u{1f6a1}", FullyQualified),
+    ("\u{1f6a2}", FullyQualified),
+    ("\u{1f6a3}", FullyQualified),
+    ("\u{1f6a3}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6a3}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6a3}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fb}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fc}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fd}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fe}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3ff}", FullyQualified),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6a4}", FullyQualified),
+    ("\u{1f6a5}", FullyQualified),
+    ("\u{1f6a6}", FullyQualified),
+    ("\u{1f6a7}", FullyQualified),
+    ("\u{1f6a8}", FullyQualified),
+    ("\u{1f6a9}", FullyQualified),
+    ("\u{1f6aa}", FullyQualified),
+    ("\u{1f6ab}", FullyQualified),
+    ("\u{1f6ac}", FullyQualified),
+    ("\u{1f6ad}", FullyQualified),
+    ("\u{1f6ae}", FullyQualified),
+    ("\u{1f6af}", FullyQualified),
+    ("\u{1f6b0}", FullyQualified),
+    ("\u{1f6b1}", FullyQualified),
+    ("\u{1f6b2}", FullyQualified),
+    ("\u{1f6b3}", FullyQualified),
+    ("\u{1f6b4}", FullyQualified),
+    ("\u{1f6b4}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b4}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b4}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fb}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fc}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fd}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fe}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3ff}", FullyQualified),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}", FullyQualified),
+    ("\u{1f6b5}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b5}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b5}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fb}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fc}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fd}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fe}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3ff}", FullyQualified),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}", FullyQualified),
+    ("\u{1f6b6}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fb}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fc}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fd}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fe}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3ff}", FullyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f6b7}", FullyQualified),
+    ("\u{1f6b8}", FullyQualified),
+    ("\u{1f6b9}", FullyQualified),
+    ("\u{1f6ba}", FullyQualified),
+    ("\u{1f6bb}", FullyQualified),
+    ("\u{1f6bc}", FullyQualified),
+    ("\u{1f6bd}", FullyQualified),
+    ("\u{1f6be}", FullyQualified),
+    ("\u{1f6bf}", FullyQualified),
+    ("\u{1f6c0}", FullyQualified),
+    ("\u{1f6c0}\u{1f3fb}", FullyQualified),
+    ("\u{1f6c0}\u{1f3fc}", FullyQualified),
+    ("\u{1f6c0}\u{1f3fd}", FullyQualified),
+    ("\u{1f6c0}\u{1f3fe}", FullyQualified),
+    ("\u{1f6c0}\u{1f3ff}", FullyQualified),
+    ("\u{1f6c1}", FullyQualified),
+    ("\u{1f6c2}", FullyQualified),
+    ("\u{1f6c3}", FullyQualified),
+    ("\u{1f6c4}", FullyQualified),
+    ("\u{1f6c5}", FullyQualified),
+    ("\u{1f6cb}", Unqualified),
+    ("\u{1f6cb}\u{fe0f}", FullyQualified),
+    ("\u{1f6cc}", FullyQualified),
+    ("\u{1f6cc}\u{1f3fb}", FullyQualified),
+    ("\u{1f6cc}\u{1f3fc}", FullyQualified),
+    ("\u{1f6cc}\u{1f3fd}", FullyQualified),
+    ("\u{1f6cc}\u{1f3fe}", FullyQualified),
+    ("\u{1f6cc}\u{1f3ff}", FullyQualified),
+    ("\u{1f6cd}", Unqualified),
+    ("\u{1f6cd}\u{fe0f}", FullyQualified),
+    ("\u{1f6ce}", Unqualified),
+    ("\u{1f6ce}\u{fe0f}", FullyQualified),
+    ("\u{1f6cf}", Unqualified),
+    ("\u{1f6cf}\u{fe0f}", FullyQualified),
+    ("\u{1f6d0}", FullyQualified),
+    ("\u{1f6d1}", FullyQualified),
+    ("\u{1f6d2}", FullyQualified),
+    ("\u{1f6d5}", FullyQualified),
+    ("\u{1f6d6}", FullyQualified),
+    ("\u{1f6d7}", FullyQualified),
+    ("\u{1f6dc}", FullyQualified),
+    ("\u{1f6dd}", FullyQualified),
+    ("\u{1f6de}", FullyQualified),
+    ("\u{1f6df}", FullyQualified),
+    ("\u{1f6e0}", Unqualified),
+    ("\u{1f6e0}\u{fe0f}", FullyQualified),
+    ("\u{1f6e1}", Unqualified),
+    ("\u{1f6e1}\u{fe0f}", FullyQualified),
+    ("\u{1f6e2}", Unqualified),
+    ("\u{1f6e2}\u{fe0f}", FullyQualified),
+    ("\u{1f6e3}", Unqualified),
+    ("\u{1f6e3}\u{fe0f}", FullyQualified),
+    ("\u{1f6e4}", Unqualified),
+    ("\u{1f6e4}\u{fe0f}", FullyQualified),
+    ("\u{1f6e5}", Unqualified),
+    ("\u{1f6e5}\u{fe0f}", FullyQualified),
+    ("\u{1f6e9}", Unqualified),
+    ("\u{1f6e9}\u{fe0f}", FullyQualified),
+    ("\u{1f6eb}", FullyQualified),
+    ("\u{1f6ec}", FullyQualified),
+    ("\u{1f6f0}", Unqualified),
+    ("\u{1f6f0}\u{fe0f}", FullyQualified),
+    ("\u{1f6f3}", Unqualified),
+    ("\u{1f6f3}\u{fe0f}", FullyQualified),
+    ("\u{1f6f4}", FullyQualified),
+    ("\u{1f6f5}", FullyQualified),
+    ("\u{1f6f6}", FullyQualified),
+    ("\u{1f6f7}", FullyQualified),
+    ("\u{1f6f8}", FullyQualified),
+    ("\u{1f6f9}", FullyQualified),
+    ("\u{1f6fa}", FullyQualified),
+    ("\u{1f6fb}", FullyQualified),
+    ("\u{1f6fc}", FullyQualified),
+    ("\u{1f7e0}", FullyQualified),
+    ("\u{1f7e1}", FullyQualified),
+    ("\u{1f7e2}", FullyQualified),
+    ("\u{1f7e3}", FullyQualified),
+    ("\u{1f7e4}", FullyQualified),
+    ("\u{1f7e5}", FullyQualified),
+    ("\u{1f7e6}", FullyQualified),
+    ("\u{1f7e7}", FullyQualified),
+    ("\u{1f7e8}", FullyQualified),
+    ("\u{1f7e9}", FullyQualified),
+    ("\u{1f7ea}", FullyQualified),
+    ("\u{1f7eb}", FullyQualified),
+    ("\u{1f7f0}", FullyQualified),
+    ("\u{1f90c}", FullyQualified),
+    ("\u{1f90c}\u{1f3fb}", FullyQualified),
+    ("\u{1f90c}\u{1f3fc}", FullyQualified),
+    ("\u{1f90c}\u{1f3fd}", FullyQualified),
+    ("\u{1f90c}\u{1f3fe}", FullyQualified),
+    ("\u{1f90c}\u{1f3ff}", FullyQualified),
+    ("\u{1f90d}", FullyQualified),
+    ("\u{1f90e}", FullyQualified),
+    ("\u{1f90f}", FullyQualified),
+    ("\u{1f90f}\u{1f3fb}", FullyQualified),
+    ("\u{1f90f}\u{1f3fc}", FullyQualified),
+    ("\u{1f90f}\u{1f3fd}", FullyQualified),
+    ("\u{1f90f}\u{1f3fe}", FullyQualified),
+    ("\u{1f90f}\u{1f3ff}", FullyQualified),
+    ("\u{1f910}", FullyQualified),
+    ("\u{1f911}", FullyQualified),
+    ("\u{1f912}", FullyQualified),
+    ("\u{1f913}", FullyQualified),
+    ("\u{1f914}", FullyQualified),
+    ("\u{1f915}", FullyQualified),
+    ("\u{1f916}", FullyQualified),
+    ("\u{1f917}", FullyQualified),
+    ("\u{1f918}", FullyQualified),
+    ("\u{1f918}\u{1f3fb}", FullyQualified),
+    ("\u{1f918}\u{1f3fc}", FullyQualified),
+    ("\u{1f918}\u{1f3fd}", FullyQualified),
+    ("\u{1f918}\u{1f3fe}", FullyQualified),
+    ("\u{1f918}\u{1f3ff}", FullyQualified),
+    ("\u{1f919}", FullyQualified),
+    ("\u{1f919}\u{1f3fb}", FullyQualified),
+    ("\u{1f919}\u{1f3fc}", FullyQualified),
+    ("\u{1f919}\u{1f3fd}", FullyQualified),
+    ("\u{1f919}\u{1f3fe}", FullyQualified),
+    ("\u{1f919}\u{1f3ff}", FullyQualified),
+    ("\u{1f91a}", FullyQualified),
+    ("\u{1f91a}\u{1f3fb}", FullyQualified),
+    ("\u{1f91a}\u{1f3fc}", FullyQualified),
+    ("\u{1f91a}\u{1f3fd}", FullyQualified),
+    ("\u{1f91a}\u{1f3fe}", FullyQualified),
+    ("\u{1f91a}\u{1f3ff}", FullyQualified),
+    ("\u{1f91b}", FullyQualified),
+    ("\u{1f91b}\u{1f3fb}", FullyQualified),
+    ("\u{1f91b}\u{1f3fc}", FullyQualified),
+    ("\u{1f91b}\u{1f3fd}", FullyQualified),
+    ("\u{1f91b}\u{1f3fe}", FullyQualified),
+    ("\u{1f91b}\u{1f3ff}", FullyQualified),
+    ("\u{1f91c}", FullyQualified),
+    ("\u{1f91c}\u{1f3fb}", FullyQualified),
+    ("\u{1f91c}\u{1f3fc}", FullyQualified),
+    ("\u{1f91c}\u{1f3fd}", FullyQualified),
+    ("\u{1f91c}\u{1f3fe}", FullyQualified),
+    ("\u{1f91c}\u{1f3ff}", FullyQualified),
+    ("\u{1f91d}", FullyQualified),
+    ("\u{1f91d}\u{1f3fb}", FullyQualified),
+    ("\u{1f91d}\u{1f3fc}", FullyQualified),
+    ("\u{1f91d}\u{1f3fd}", FullyQualified),
+    ("\u{1f91d}\u{1f3fe}", FullyQualified),
+    ("\u{1f91d}\u{1f3ff}", FullyQualified),
+    ("\u{1f91e}", FullyQualified),
+    ("\u{1f91e}\u{1f3fb}", FullyQualified),
+    ("\u{1f91e}\u{1f3fc}", FullyQualified),
+    ("\u{1f91e}\u{1f3fd}", FullyQualified),
+    ("\u{1f91e}\u{1f3fe}", FullyQualified),
+    ("\u{1f91e}\u{1f3ff}", FullyQualified),
+    ("\u{1f91f}", FullyQualified),
+    ("\u{1f91f}\u{1f3fb}", FullyQualified),
+    ("\u{1f91f}\u{1f3fc}", FullyQualified),
+    ("\u{1f91f}\u{1f3fd}", FullyQualified),
+    ("\u{1f91f}\u{1f3fe}", FullyQualified),
+    ("\u{1f91f}\u{1f3ff}", FullyQualified),
+    ("\u{1f920}", FullyQualified),
+    ("\u{1f921}", FullyQualified),
+    ("\u{1f922}", FullyQualified),
+    ("\u{1f923}", FullyQualified),
+    ("\u{1f924}", FullyQualified),
+    ("\u{1f925}", FullyQualified),
+    ("\u{1f926}", FullyQualified),
+    ("\u{1f926}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f926}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f926}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fb}", FullyQualified),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fc}", FullyQualified),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fd}", FullyQualified),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fe}", FullyQualified),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3ff}", FullyQualified),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f927}", FullyQualified),
+    ("\u{1f928}", FullyQualified),
+    ("\u{1f929}", FullyQualified),
+    ("\u{1f92a}", FullyQualified),
+    ("\u{1f92b}", FullyQualified),
+    ("\u{1f92c}", FullyQualified),
+    ("\u{1f92d}", FullyQualified),
+    ("\u{1f92e}", FullyQualified),
+    ("\u{1f92f}", FullyQualified),
+    ("\u{1f930}", FullyQualified),
+    ("\u{1f930}\u{1f3fb}", FullyQualified),
+    ("\u{1f930}\u{1f3fc}", FullyQualified),
+    ("\u{1f930}\u{1f3fd}", FullyQualified),
+    ("\u{1f930}\u{1f3fe}", FullyQualified),
+    ("\u{1f930}\u{1f3ff}", FullyQualified),
+    ("\u{1f931}", FullyQualified),
+    ("\u{1f931}\u{1f3fb}", FullyQualified),
+    ("\u{1f931}\u{1f3fc}", FullyQualified),
+    ("\u{1f931}\u{1f3fd}", FullyQualified),
+    ("\u{1f931}\u{1f3fe}", FullyQualified),
+    ("\u{1f931}\u{1f3ff}", FullyQualified),
+    ("\u{1f932}", FullyQualified),
+    ("\u{1f932}\u{1f3fb}", FullyQualified),
+    ("\u{1f932}\u{1f3fc}", FullyQualified),
+    ("\u{1f932}\u{1f3fd}", FullyQualified),
+    ("\u{1f932}\u{1f3fe}", FullyQualified),
+    ("\u{1f932}\u{1f3ff}", FullyQualified),
+    ("\u{1f933}", FullyQualified),
+    ("\u{1f933}\u{1f3fb}", FullyQualified),
+    ("\u{1f933}\u{1f3fc}", FullyQualified),
+    ("\u{1f933}\u{1f3fd}", FullyQualified),
+    ("\u{1f933}\u{1f3fe}", FullyQualified),
+    ("\u{1f933}\u{1f3ff}", FullyQualified),
+    ("\u{1f934}", FullyQualified),
+    ("\u{1f934}\u{1f3fb}", FullyQualified),
+    ("\u{1f934}\u{1f3fc}", FullyQualified),
+    ("\u{1f934}\u{1f3fd}", FullyQualified),
+    ("\u{1f934}\u{1f3fe}", FullyQualified),
+    ("\u{1f934}\u{1f3ff}", FullyQualified),
+    ("\u{1f935}", FullyQualified),
+    ("\u{1f935}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f935}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f935}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fb}", FullyQualified),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fc}", FullyQualified),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fd}", FullyQualified),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fe}", FullyQualified),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3ff}", FullyQualified),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f936}", FullyQualified),
+    ("\u{1f936}\u{1f3fb}", FullyQualified),
+    ("\u{1f936}\u{1f3fc}", FullyQualified),
+    ("\u{1f936}\u{1f3fd}", FullyQualified),
+    ("\u{1f936}\u{1f3fe}", FullyQualified),
+    ("\u{1f936}\u{1f3ff}", FullyQualified),
+    ("\u{1f937}", FullyQualified),
+    ("\u{1f937}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f937}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f937}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fb}", FullyQualified),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fc}", FullyQualified),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fd}", FullyQualified),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fe}", FullyQualified),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3ff}", FullyQualified),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f938}", FullyQualified),
+    ("\u{1f938}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f938}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f938}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fb}", FullyQualified),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fc}", FullyQualified),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fd}", FullyQualified),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fe}", FullyQualified),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3ff}", FullyQualified),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f939}", FullyQualified),
+    ("\u{1f939}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f939}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f939}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fb}", FullyQualified),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fc}", FullyQualified),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fd}", FullyQualified),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fe}", FullyQualified),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3ff}", FullyQualified),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93a}", FullyQualified),
+    ("\u{1f93c}", FullyQualified),
+    ("\u{1f93c}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93c}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93c}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93c}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}", FullyQualified),
+    ("\u{1f93d}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93d}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93d}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fb}", FullyQualified),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fc}", FullyQualified),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fd}", FullyQualified),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fe}", FullyQualified),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3ff}", FullyQualified),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}", FullyQualified),
+    ("\u{1f93e}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93e}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93e}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fb}", FullyQualified),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fc}", FullyQualified),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fd}", FullyQualified),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fe}", FullyQualified),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3ff}", FullyQualified),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f93f}", FullyQualified),
+    ("\u{1f940}", FullyQualified),
+    ("\u{1f941}", FullyQualified),
+    ("\u{1f942}", FullyQualified),
+    ("\u{1f943}", FullyQualified),
+    ("\u{1f944}", FullyQualified),
+    ("\u{1f945}", FullyQualified),
+    ("\u{1f947}", FullyQualified),
+    ("\u{1f948}", FullyQualified),
+    ("\u{1f949}", FullyQualified),
+    ("\u{1f94a}", FullyQualified),
+    ("\u{1f94b}", FullyQualified),
+    ("\u{1f94c}", FullyQualified),
+    ("\u{1f94d}", FullyQualified),
+    ("\u{1f94e}", FullyQualified),
+    ("\u{1f94f}", FullyQualified),
+    ("\u{1f950}", FullyQualified),
+    ("\u{1f951}", FullyQualified),
+    ("\u{1f952}", FullyQualified),
+    ("\u{1f953}", FullyQualified),
+    ("\u{1f954}", FullyQualified),
+    ("\u{1f955}", FullyQualified),
+    ("\u{1f956}", FullyQualified),
+    ("\u{1f957}", FullyQualified),
+    ("\u{1f958}", FullyQualified),
+    ("\u{1f959}", FullyQualified),
+    ("\u{1f95a}", FullyQualified),
+    ("\u{1f95b}", FullyQualified),
+    ("\u{1f95c}", FullyQualified),
+    ("\u{1f95d}", FullyQualified),
+    ("\u{1f95e}", FullyQualified),
+    ("\u{1f95f}", FullyQualified),
+    ("\u{1f960}", FullyQualified),
+    ("\u{1f961}", FullyQualified),
+    ("\u{1f962}", FullyQualified),
+    ("\u{1f963}", FullyQualified),
+    ("\u{1f964}", FullyQualified),
+    ("\u{1f965}", FullyQualified),
+    ("\u{1f966}", FullyQualified),
+    ("\u{1f967}", FullyQualified),
+    ("\u{1f968}", FullyQualified),
+    ("\u{1f969}", FullyQualified),
+    ("\u{1f96a}", FullyQualified),
+    ("\u{1f96b}", FullyQualified),
+    ("\u{1f96c}", FullyQualified),
+    ("\u{1f96d}", FullyQualified),
+    ("\u{1f96e}", FullyQualified),
+    ("\u{1f96f}", FullyQualified),
+    ("\u{1f970}", FullyQualified),
+    ("\u{1f971}", FullyQualified),
+    ("\u{1f972}", FullyQualified),
+    ("\u{1f973}", FullyQualified),
+    ("\u{1f974}", FullyQualified),
+    ("\u{1f975}", FullyQualified),
+    ("\u{1f976}", FullyQualified),
+    ("\u{1f977}", FullyQualified),
+    ("\u{1f977}\u{1f3fb}", FullyQualified),
+    ("\u{1f977}\u{1f3fc}", FullyQualified),
+    ("\u{1f977}\u{1f3fd}", FullyQualified),
+    ("\u{1f977}\u{1f3fe}", FullyQualified),
+    ("\u{1f977}\u{1f3ff}", FullyQualified),
+    ("\u{1f978}", FullyQualified),
+    ("\u{1f979}", FullyQualified),
+    ("\u{1f97a}", FullyQualified),
+    ("\u{1f97b}", FullyQualified),
+    ("\u{1f97c}", FullyQualified),
+    ("\u{1f97d}", FullyQualified),
+    ("\u{1f97e}", FullyQualified),
+    ("\u{1f97f}", FullyQualified),
+    ("\u{1f980}", FullyQualified),
+    ("\u{1f981}", FullyQualified),
+    ("\u{1f982}", FullyQualified),
+    ("\u{1f983}", FullyQualified),
+    ("\u{1f984}", FullyQualified),
+    ("\u{1f985}", FullyQualified),
+    ("\u{1f986}", FullyQualified),
+    ("\u{1f987}", FullyQualified),
+    ("\u{1f988}", FullyQualified),
+    ("\u{1f989}", FullyQualified),
+    ("\u{1f98a}", FullyQualified),
+    ("\u{1f98b}", FullyQualified),
+    ("\u{1f98c}", FullyQualified),
+    ("\u{1f98d}", FullyQualified),
+    ("\u{1f98e}", FullyQualified),
+    ("\u{1f98f}", FullyQualified),
+    ("\u{1f990}", FullyQualified),
+    ("\u{1f991}", FullyQualified),
+    ("\u{1f992}", FullyQualified),
+    ("\u{1f993}", FullyQualified),
+    ("\u{1f994}", FullyQualified),
+    ("\u{1f995}", FullyQualified),
+    ("\u{1f996}", FullyQualified),
+    ("\u{1f997}", FullyQualified),
+    ("\u{1f998}", FullyQualified),
+    ("\u{1f999}", FullyQualified),
+    ("\u{1f99a}", FullyQualified),
+    ("\u{1f99b}", FullyQualified),
+    ("\u{1f99c}", FullyQualified),
+    ("\u{1f99d}", FullyQualified),
+    ("\u{1f99e}", FullyQualified),
+    ("\u{1f99f}", FullyQualified),
+    ("\u{1f9a0}", FullyQualified),
+    ("\u{1f9a1}", FullyQualified),
+    ("\u{1f9a2}", FullyQualified),
+    ("\u{1f9a3}", FullyQualified),
+    ("\u{1f9a4}", FullyQualified),
+    ("\u{1f9a5}", FullyQualified),
+    ("\u{1f9a6}", FullyQualified),
+    ("\u{1f9a7}", FullyQualified),
+    ("\u{1f9a8}", FullyQualified),
+    ("\u{1f9a9}", FullyQualified),
+    ("\u{1f9aa}", FullyQualified),
+    ("\u{1f9ab}", FullyQualified),
+    ("\u{1f9ac}", FullyQualified),
+    ("\u{1f9ad}", FullyQualified),
+    ("\u{1f9ae}", FullyQualified),
+    ("\u{1f9af}", FullyQualified),
+    ("\u{1f9b0}", Component),
+    ("\u{1f9b1}", Component),
+    ("\u{1f9b2}", Component),
+    ("\u{1f9b3}", Component),
+    ("\u{1f9b4}", FullyQualified),
+    ("\u{1f9b5}", FullyQualified),
+    ("\u{1f9b5}\u{1f3fb}", FullyQualified),
+    ("\u{1f9b5}\u{1f3fc}", FullyQualified),
+    ("\u{1f9b5}\u{1f3fd}", FullyQualified),
+    ("\u{1f9b5}\u{1f3fe}", FullyQualified),
+    ("\u{1f9b5}\u{1f3ff}", FullyQualified),
+    ("\u{1f9b6}", FullyQualified),
+    ("\u{1f9b6}\u{1f3fb}", FullyQualified),
+    ("\u{1f9b6}\u{1f3fc}", FullyQualified),
+    ("\u{1f9b6}\u{1f3fd}", FullyQualified),
+    ("\u{1f9b6}\u{1f3fe}", FullyQualified),
+    ("\u{1f9b6}\u{1f3ff}", FullyQualified),
+    ("\u{1f9b7}", FullyQualified),
+    ("\u{1f9b8}", FullyQualified),
+    ("\u{1f9b8}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b8}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b8}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fb}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fc}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fd}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fe}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3ff}", FullyQualified),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}", FullyQualified),
+    ("\u{1f9b9}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b9}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b9}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fb}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fc}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fd}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fe}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3ff}", FullyQualified),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ba}", FullyQualified),
+    ("\u{1f9bb}", FullyQualified),
+    ("\u{1f9bb}\u{1f3fb}", FullyQualified),
+    ("\u{1f9bb}\u{1f3fc}", FullyQualified),
+    ("\u{1f9bb}\u{1f3fd}", FullyQualified),
+    ("\u{1f9bb}\u{1f3fe}", FullyQualified),
+    ("\u{1f9bb}\u{1f3ff}", FullyQualified),
+    ("\u{1f9bc}", FullyQualified),
+    ("\u{1f9bd}", FullyQualified),
+    ("\u{1f9be}", FullyQualified),
+    ("\u{1f9bf}", FullyQualified),
+    ("\u{1f9c0}", FullyQualified),
+    ("\u{1f9c1}", FullyQualified),
+    ("\u{1f9c2}", FullyQualified),
+    ("\u{1f9c3}", FullyQualified),
+    ("\u{1f9c4}", FullyQualified),
+    ("\u{1f9c5}", FullyQualified),
+    ("\u{1f9c6}", FullyQualified),
+    ("\u{1f9c7}", FullyQualified),
+    ("\u{1f9c8}", FullyQualified),
+    ("\u{1f9c9}", FullyQualified),
+    ("\u{1f9ca}", FullyQualified),
+    ("\u{1f9cb}", FullyQualified),
+    ("\u{1f9cc}", FullyQualified),
+    ("\u{1f9cd}", FullyQualified),
+    ("\u{1f9cd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fb}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fc}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fd}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fe}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3ff}", FullyQualified),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}", FullyQualified),
+    ("\u{1f9ce}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fb}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fc}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fd}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fe}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3ff}", FullyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}\u{fe0f}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}", FullyQualified),
+    ("\u{1f9cf}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cf}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cf}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fb}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fc}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fd}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fe}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3ff}", FullyQualified),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d0}", FullyQualified),
+    ("\u{1f9d1}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f9d1}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f9d1}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f9d1}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f384}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9d2}", FullyQualified),
+    ("\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f384}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f384}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f384}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f384}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f33e}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f373}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f37c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f384}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f393}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a4}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a8}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3eb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3ed}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f527}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f52c}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f680}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f692}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b0}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b1}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b2}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b3}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}", FullyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", MinimallyQualified),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", FullyQualified),
+    ("\u{1f9d2}", FullyQualified),
+    ("\u{1f9d2}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d2}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d2}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d2}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d2}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d3}", FullyQualified),
+    ("\u{1f9d3}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d3}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d3}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d3}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d3}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d4}", FullyQualified),
+    ("\u{1f9d4}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d4}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d4}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d5}", FullyQualified),
+    ("\u{1f9d5}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d5}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d5}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d5}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d5}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d6}", FullyQualified),
+    ("\u{1f9d6}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d6}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d6}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}", FullyQualified),
+    ("\u{1f9d7}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d7}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d7}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}", FullyQualified),
+    ("\u{1f9d8}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d8}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d8}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}", FullyQualified),
+    ("\u{1f9d9}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d9}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d9}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fb}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fc}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fd}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fe}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3ff}", FullyQualified),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}", FullyQualified),
+    ("\u{1f9da}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9da}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9da}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fb}", FullyQualified),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fc}", FullyQualified),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fd}", FullyQualified),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fe}", FullyQualified),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3ff}", FullyQualified),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}", FullyQualified),
+    ("\u{1f9db}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9db}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9db}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fb}", FullyQualified),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fc}", FullyQualified),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fd}", FullyQualified),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fe}", FullyQualified),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3ff}", FullyQualified),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}", FullyQualified),
+    ("\u{1f9dc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fb}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fc}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fd}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fe}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3ff}", FullyQualified),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}", FullyQualified),
+    ("\u{1f9dd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fb}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fc}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fd}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fe}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3ff}", FullyQualified),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9de}", FullyQualified),
+    ("\u{1f9de}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9de}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9de}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9de}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9df}", FullyQualified),
+    ("\u{1f9df}\u{200d}\u{2640}", MinimallyQualified),
+    ("\u{1f9df}\u{200d}\u{2640}\u{fe0f}", FullyQualified),
+    ("\u{1f9df}\u{200d}\u{2642}", MinimallyQualified),
+    ("\u{1f9df}\u{200d}\u{2642}\u{fe0f}", FullyQualified),
+    ("\u{1f9e0}", FullyQualified),
+    ("\u{1f9e1}", FullyQualified),
+    ("\u{1f9e2}", FullyQualified),
+    ("\u{1f9e3}", FullyQualified),
+    ("\u{1f9e4}", FullyQualified),
+    ("\u{1f9e5}", FullyQualified),
+    ("\u{1f9e6}", FullyQualified),
+    ("\u{1f9e7}", FullyQualified),
+    ("\u{1f9e8}", FullyQualified),
+    ("\u{1f9e9}", FullyQualified),
+    ("\u{1f9ea}", FullyQualified),
+    ("\u{1f9eb}", FullyQualified),
+    ("\u{1f9ec}", FullyQualified),
+    ("\u{1f9ed}", FullyQualified),
+    ("\u{1f9ee}", FullyQualified),
+    ("\u{1f9ef}", FullyQualified),
+    ("\u{1f9f0}", FullyQualified),
+    ("\u{1f9f1}", FullyQualified),
+    ("\u{1f9f2}", FullyQualified),
+    ("\u{1f9f3}", FullyQualified),
+    ("\u{1f9f4}", FullyQualified),
+    ("\u{1f9f5}", FullyQualified),
+    ("\u{1f9f6}", FullyQualified),
+    ("\u{1f9f7}", FullyQualified),
+    ("\u{1f9f8}", FullyQualified),
+    ("\u{1f9f9}", FullyQualified),
+    ("\u{1f9fa}", FullyQualified),
+    ("\u{1f9fb}", FullyQualified),
+    ("\u{1f9fc}", FullyQualified),
+    ("\u{1f9fd}", FullyQualified),
+    ("\u{1f9fe}", FullyQualified),
+    ("\u{1f9ff}", FullyQualified),
+    ("\u{1fa70}", FullyQualified),
+    ("\u{1fa71}", FullyQualified),
+    ("\u{1fa72}", FullyQualified),
+    ("\u{1fa73}", FullyQualified),
+    ("\u{1fa74}", FullyQualified),
+    ("\u{1fa75}", FullyQualified),
+    ("\u{1fa76}", FullyQualified),
+    ("\u{1fa77}", FullyQualified),
+    ("\u{1fa78}", FullyQualified),
+    ("\u{1fa79}", FullyQualified),
+    ("\u{1fa7a}", FullyQualified),
+    ("\u{1fa7b}", FullyQualified),
+    ("\u{1fa7c}", FullyQualified),
+    ("\u{1fa80}", FullyQualified),
+    ("\u{1fa81}", FullyQualified),
+    ("\u{1fa82}", FullyQualified),
+    ("\u{1fa83}", FullyQualified),
+    ("\u{1fa84}", FullyQualified),
+    ("\u{1fa85}", FullyQualified),
+    ("\u{1fa86}", FullyQualified),
+    ("\u{1fa87}", FullyQualified),
+    ("\u{1fa88}", FullyQualified),
+    ("\u{1fa89}", FullyQualified),
+    ("\u{1fa8f}", FullyQualified),
+    ("\u{1fa90}", FullyQualified),
+    ("\u{1fa91}", FullyQualified),
+    ("\u{1fa92}", FullyQualified),
+    ("\u{1fa93}", FullyQualified),
+    ("\u{1fa94}", FullyQualified),
+    ("\u{1fa95}", FullyQualified),
+    ("\u{1fa96}", FullyQualified),
+    ("\u{1fa97}", FullyQualified),
+    ("\u{1fa98}", FullyQualified),
+    ("\u{1fa99}", FullyQualified),
+    ("\u{1fa9a}", FullyQualified),
+    ("\u{1fa9b}", FullyQualified),
+    ("\u{1fa9c}", FullyQualified),
+    ("\u{1fa9d}", FullyQualified),
+    ("\u{1fa9e}", FullyQualified),
+    ("\u{1fa9f}", FullyQualified),
+    ("\u{1faa0}", FullyQualified),
+    ("\u{1faa1}", FullyQualified),
+    ("\u{1faa2}", FullyQualified),
+    ("\u{1faa3}", FullyQualified),
+    ("\u{1faa4}", FullyQualified),
+    ("\u{1faa5}", FullyQualified),
+    ("\u{1faa6}", FullyQualified),
+    ("\u{1faa7}", FullyQualified),
+    ("\u{1faa8}", FullyQualified),
+    ("\u{1faa9}", FullyQualified),
+    ("\u{1faaa}", FullyQualified),
+    ("\u{1faab}", FullyQualified),
+    ("\u{1faac}", FullyQualified),
+    ("\u{1faad}", FullyQualified),
+    ("\u{1faae}", FullyQualified),
+    ("\u{1faaf}", FullyQualified),
+    ("\u{1fab0}", FullyQualified),
+    ("\u{1fab1}", FullyQualified),
+    ("\u{1fab2}", FullyQualified),
+    ("\u{1fab3}", FullyQualified),
+    ("\u{1fab4}", FullyQualified),
+    ("\u{1fab5}", FullyQualified),
+    ("\u{1fab6}", FullyQualified),
+    ("\u{1fab7}", FullyQualified),
+    ("\u{1fab8}", FullyQualified),
+    ("\u{1fab9}", FullyQualified),
+    ("\u{1faba}", FullyQualified),
+    ("\u{1fabb}", FullyQualified),
+    ("\u{1fabc}", FullyQualified),
+    ("\u{1fabd}", FullyQualified),
+    ("\u{1fabe}", FullyQualified),
+    ("\u{1fabf}", FullyQualified),
+    ("\u{1fac0}", FullyQualified),
+    ("\u{1fac1}", FullyQualified),
+    ("\u{1fac2}", FullyQualified),
+    ("\u{1fac3}", FullyQualified),
+    ("\u{1fac3}\u{1f3fb}", FullyQualified),
+    ("\u{1fac3}\u{1f3fc}", FullyQualified),
+    ("\u{1fac3}\u{1f3fd}", FullyQualified),
+    ("\u{1fac3}\u{1f3fe}", FullyQualified),
+    ("\u{1fac3}\u{1f3ff}", FullyQualified),
+    ("\u{1fac4}", FullyQualified),
+    ("\u{1fac4}\u{1f3fb}", FullyQualified),
+    ("\u{1fac4}\u{1f3fc}", FullyQualified),
+    ("\u{1fac4}\u{1f3fd}", FullyQualified),
+    ("\u{1fac4}\u{1f3fe}", FullyQualified),
+    ("\u{1fac4}\u{1f3ff}", FullyQualified),
+    ("\u{1fac5}", FullyQualified),
+    ("\u{1fac5}\u{1f3fb}", FullyQualified),
+    ("\u{1fac5}\u{1f3fc}", FullyQualified),
+    ("\u{1fac5}\u{1f3fd}", FullyQualified),
+    ("\u{1fac5}\u{1f3fe}", FullyQualified),
+    ("\u{1fac5}\u{1f3ff}", FullyQualified),
+    ("\u{1fac6}", FullyQualified),
+    ("\u{1face}", FullyQualified),
+    ("\u{1facf}", FullyQualified),
+    ("\u{1fad0}", FullyQualified),
+    ("\u{1fad1}", FullyQualified),
+    ("\u{1fad2}", FullyQualified),
+    ("\u{1fad3}", FullyQualified),
+    ("\u{1fad4}", FullyQualified),
+    ("\u{1fad5}", FullyQualified),
+    ("\u{1fad6}", FullyQualified),
+    ("\u{1fad7}", FullyQualified),
+    ("\u{1fad8}", FullyQualified),
+    ("\u{1fad9}", FullyQualified),
+    ("\u{1fada}", FullyQualified),
+    ("\u{1fadb}", FullyQualified),
+    ("\u{1fadc}", FullyQualified),
+    ("\u{1fadf}", FullyQualified),
+    ("\u{1fae0}", FullyQualified),
+    ("\u{1fae1}", FullyQualified),
+    ("\u{1fae2}", FullyQualified),
+    ("\u{1fae3}", FullyQualified),
+    ("\u{1fae4}", FullyQualified),
+    ("\u{1fae5}", FullyQualified),
+    ("\u{1fae6}", FullyQualified),
+    ("\u{1fae7}", FullyQualified),
+    ("\u{1fae8}", FullyQualified),
+    ("\u{1fae9}", FullyQualified),
+    ("\u{1faf0}", FullyQualified),
+    ("\u{1faf0}\u{1f3fb}", FullyQualified),
+    ("\u{1faf0}\u{1f3fc}", FullyQualified),
+    ("\u{1faf0}\u{1f3fd}", FullyQualified),
+    ("\u{1faf0}\u{1f3fe}", FullyQualified),
+    ("\u{1faf0}\u{1f3ff}", FullyQualified),
+    ("\u{1faf1}", FullyQualified),
+    ("\u{1faf1}\u{1f3fb}", FullyQualified),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fc}", FullyQualified),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fd}", FullyQualified),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fe}", FullyQualified),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3ff}", FullyQualified),
+    ("\u{1faf1}\u{1f3fc}", FullyQualified),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fb}", FullyQualified),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fd}", FullyQualified),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fe}", FullyQualified),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3ff}", FullyQualified),
+    ("\u{1faf1}\u{1f3fd}", FullyQualified),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fb}", FullyQualified),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fc}", FullyQualified),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fe}", FullyQualified),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3ff}", FullyQualified),
+    ("\u{1faf1}\u{1f3fe}", FullyQualified),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fb}", FullyQualified),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fc}", FullyQualified),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fd}", FullyQualified),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3ff}", FullyQualified),
+    ("\u{1faf1}\u{1f3ff}", FullyQualified),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fb}", FullyQualified),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fc}", FullyQualified),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fd}", FullyQualified),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fe}", FullyQualified),
+    ("\u{1faf2}", FullyQualified),
+    ("\u{1faf2}\u{1f3fb}", FullyQualified),
+    ("\u{1faf2}\u{1f3fc}", FullyQualified),
+    ("\u{1faf2}\u{1f3fd}", FullyQualified),
+    ("\u{1faf2}\u{1f3fe}", FullyQualified),
+    ("\u{1faf2}\u{1f3ff}", FullyQualified),
+    ("\u{1faf3}", FullyQualified),
+    ("\u{1faf3}\u{1f3fb}", FullyQualified),
+    ("\u{1faf3}\u{1f3fc}", FullyQualified),
+    ("\u{1faf3}\u{1f3fd}", FullyQualified),
+    ("\u{1faf3}\u{1f3fe}", FullyQualified),
+    ("\u{1faf3}\u{1f3ff}", FullyQualified),
+    ("\u{1faf4}", FullyQualified),
+    ("\u{1faf4}\u{1f3fb}", FullyQualified),
+    ("\u{1faf4}\u{1f3fc}", FullyQualified),
+    ("\u{1faf4}\u{1f3fd}", FullyQualified),
+    ("\u{1faf4}\u{1f3fe}", FullyQualified),
+    ("\u{1faf4}\u{1f3ff}", FullyQualified),
+    ("\u{1faf5}", FullyQualified),
+    ("\u{1faf5}\u{1f3fb}", FullyQualified),
+    ("\u{1faf5}\u{1f3fc}", FullyQualified),
+    ("\u{1faf5}\u{1f3fd}", FullyQualified),
+    ("\u{1faf5}\u{1f3fe}", FullyQualified),
+    ("\u{1faf5}\u{1f3ff}", FullyQualified),
+    ("\u{1faf6}", FullyQualified),
+    ("\u{1faf6}\u{1f3fb}", FullyQualified),
+    ("\u{1faf6}\u{1f3fc}", FullyQualified),
+    ("\u{1faf6}\u{1f3fd}", FullyQualified),
+    ("\u{1faf6}\u{1f3fe}", FullyQualified),
+    ("\u{1faf6}\u{1f3ff}", FullyQualified),
+    ("\u{1faf7}", FullyQualified),
+    ("\u{1faf7}\u{1f3fb}", FullyQualified),
+    ("\u{1faf7}\u{1f3fc}", FullyQualified),
+    ("\u{1faf7}\u{1f3fd}", FullyQualified),
+    ("\u{1faf7}\u{1f3fe}", FullyQualified),
+    ("\u{1faf7}\u{1f3ff}", FullyQualified),
+    ("\u{1faf8}", FullyQualified),
+    ("\u{1faf8}\u{1f3fb}", FullyQualified),
+    ("\u{1faf8}\u{1f3fc}", FullyQualified),
+    ("\u{1faf8}\u{1f3fd}", FullyQualified),
+    ("\u{1faf8}\u{1f3fe}", FullyQualified),
+    ("\u{1faf8}\u{1f3ff}", FullyQualified),
+];
+
+/// Fully-qualified emoji with emoji presentation selectors (`U+FE0F`), by their form without
+/// them, sorted for binary search
+pub(crate) const FULLY_QUALIFIED_FORMS: &[(&str, &str)] = &[
+    ("\u{23}\u{20e3}", "\u{23}\u{fe0f}\u{20e3}"),
+    ("\u{2a}\u{20e3}", "\u{2a}\u{fe0f}\u{20e3}"),
+    ("\u{30}\u{20e3}", "\u{30}\u{fe0f}\u{20e3}"),
+    ("\u{31}\u{20e3}", "\u{31}\u{fe0f}\u{20e3}"),
+    ("\u{32}\u{20e3}", "\u{32}\u{fe0f}\u{20e3}"),
+    ("\u{33}\u{20e3}", "\u{33}\u{fe0f}\u{20e3}"),
+    ("\u{34}\u{20e3}", "\u{34}\u{fe0f}\u{20e3}"),
+    ("\u{35}\u{20e3}", "\u{35}\u{fe0f}\u{20e3}"),
+    ("\u{36}\u{20e3}", "\u{36}\u{fe0f}\u{20e3}"),
+    ("\u{37}\u{20e3}", "\u{37}\u{fe0f}\u{20e3}"),
+    ("\u{38}\u{20e3}", "\u{38}\u{fe0f}\u{20e3}"),
+    ("\u{39}\u{20e3}", "\u{39}\u{fe0f}\u{20e3}"),
+    ("\u{a9}", "\u{a9}\u{fe0f}"),
+    ("\u{ae}", "\u{ae}\u{fe0f}"),
+    ("\u{203c}", "\u{203c}\u{fe0f}"),
+    ("\u{2049}", "\u{2049}\u{fe0f}"),
+    ("\u{2122}", "\u{2122}\u{fe0f}"),
+    ("\u{2139}", "\u{2139}\u{fe0f}"),
+    ("\u{2194}", "\u{2194}\u{fe0f}"),
+    ("\u{2195}", "\u{2195}\u{fe0f}"),
+    ("\u{2196}", "\u{2196}\u{fe0f}"),
+    ("\u{2197}", "\u{2197}\u{fe0f}"),
+    ("\u{2198}", "\u{2198}\u{fe0f}"),
+    ("\u{2199}", "\u{2199}\u{fe0f}"),
+    ("\u{21a9}", "\u{21a9}\u{fe0f}"),
+    ("\u{21aa}", "\u{21aa}\u{fe0f}"),
+    ("\u{2328}", "\u{2328}\u{fe0f}"),
+    ("\u{23cf}", "\u{23cf}\u{fe0f}"),
+    ("\u{23ed}", "\u{23ed}\u{fe0f}"),
+    ("\u{23ee}", "\u{23ee}\u{fe0f}"),
+    ("\u{23ef}", "\u{23ef}\u{fe0f}"),
+    ("\u{23f1}", "\u{23f1}\u{fe0f}"),
+    ("\u{23f2}", "\u{23f2}\u{fe0f}"),
+    ("\u{23f8}", "\u{23f8}\u{fe0f}"),
+    ("\u{23f9}", "\u{23f9}\u{fe0f}"),
+    ("\u{23fa}", "\u{23fa}\u{fe0f}"),
+    ("\u{24c2}", "\u{24c2}\u{fe0f}"),
+    ("\u{25aa}", "\u{25aa}\u{fe0f}"),
+    ("\u{25ab}", "\u{25ab}\u{fe0f}"),
+    ("\u{25b6}", "\u{25b6}\u{fe0f}"),
+    ("\u{25c0}", "\u{25c0}\u{fe0f}"),
+    ("\u{25fb}", "\u{25fb}\u{fe0f}"),
+    ("\u{25fc}", "\u{25fc}\u{fe0f}"),
+    ("\u{2600}", "\u{2600}\u{fe0f}"),
+    ("\u{2601}", "\u{2601}\u{fe0f}"),
+    ("\u{2602}", "\u{2602}\u{fe0f}"),
+    ("\u{2603}", "\u{2603}\u{fe0f}"),
+    ("\u{2604}", "\u{2604}\u{fe0f}"),
+    ("\u{260e}", "\u{260e}\u{fe0f}"),
+    ("\u{2611}", "\u{2611}\u{fe0f}"),
+    ("\u{2618}", "\u{2618}\u{fe0f}"),
+    ("\u{261d}", "\u{261d}\u{fe0f}"),
+    ("\u{2620}", "\u{2620}\u{fe0f}"),
+    ("\u{2622}", "\u{2622}\u{fe0f}"),
+    ("\u{2623}", "\u{2623}\u{fe0f}"),
+    ("\u{2626}", "\u{2626}\u{fe0f}"),
+    ("\u{262a}", "\u{262a}\u{fe0f}"),
+    ("\u{262e}", "\u{262e}\u{fe0f}"),
+    ("\u{262f}", "\u{262f}\u{fe0f}"),
+    ("\u{2638}", "\u{2638}\u{fe0f}"),
+    ("\u{2639}", "\u{2639}\u{fe0f}"),
+    ("\u{263a}", "\u{263a}\u{fe0f}"),
+    ("\u{2640}", "\u{2640}\u{fe0f}"),
+    ("\u{2642}", "\u{2642}\u{fe0f}"),
+    ("\u{265f}", "\u{265f}\u{fe0f}"),
+    ("\u{2660}", "\u{2660}\u{fe0f}"),
+    ("\u{2663}", "\u{2663}\u{fe0f}"),
+    ("\u{2665}", "\u{2665}\u{fe0f}"),
+    ("\u{2666}", "\u{2666}\u{fe0f}"),
+    ("\u{2668}", "\u{2668}\u{fe0f}"),
+    ("\u{267b}", "\u{267b}\u{fe0f}"),
+    ("\u{267e}", "\u{267e}\u{fe0f}"),
+    ("\u{2692}", "\u{2692}\u{fe0f}"),
+    ("\u{2694}", "\u{2694}\u{fe0f}"),
+    ("\u{2695}", "\u{2695}\u{fe0f}"),
+    ("\u{2696}", "\u{2696}\u{fe0f}"),
+    ("\u{2697}", "\u{2697}\u{fe0f}"),
+    ("\u{2699}", "\u{2699}\u{fe0f}"),
+    ("\u{269b}", "\u{269b}\u{fe0f}"),
+    ("\u{269c}", "\u{269c}\u{fe0f}"),
+    ("\u{26a0}", "\u{26a0}\u{fe0f}"),
+    ("\u{26a7}", "\u{26a7}\u{fe0f}"),
+    ("\u{26b0}", "\u{26b0}\u{fe0f}"),
+    ("\u{26b1}", "\u{26b1}\u{fe0f}"),
+    ("\u{26c8}", "\u{26c8}\u{fe0f}"),
+    ("\u{26cf}", "\u{26cf}\u{fe0f}"),
+    ("\u{26d1}", "\u{26d1}\u{fe0f}"),
+    ("\u{26d3}", "\u{26d3}\u{fe0f}"),
+    ("\u{26d3}\u{200d}\u{1f4a5}", "\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}"),
+    ("\u{26e9}", "\u{26e9}\u{fe0f}"),
+    ("\u{26f0}", "\u{26f0}\u{fe0f}"),
+    ("\u{26f1}", "\u{26f1}\u{fe0f}"),
+    ("\u{26f4}", "\u{26f4}\u{fe0f}"),
+    ("\u{26f7}", "\u{26f7}\u{fe0f}"),
+    ("\u{26f8}", "\u{26f8}\u{fe0f}"),
+    ("\u{26f9}", "\u{26f9}\u{fe0f}"),
+    ("\u{26f9}\u{200d}\u{2640}", "\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{26f9}\u{200d}\u{2642}", "\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2640}", "\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2642}", "\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2640}", "\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2642}", "\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2640}", "\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2642}", "\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2640}", "\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2642}", "\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2640}", "\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2642}", "\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{2702}", "\u{2702}\u{fe0f}"),
+    ("\u{2708}", "\u{2708}\u{fe0f}"),
+    ("\u{2709}", "\u{2709}\u{fe0f}"),
+    ("\u{270c}", "\u{270c}\u{fe0f}"),
+    ("\u{270d}", "\u{270d}\u{fe0f}"),
+    ("\u{270f}", "\u{270f}\u{fe0f}"),
+    ("\u{2712}", "\u{2712}\u{fe0f}"),
+    ("\u{2714}", "\u{2714}\u{fe0f}"),
+    ("\u{2716}", "\u{2716}\u{fe0f}"),
+    ("\u{271d}", "\u{271d}\u{fe0f}"),
+    ("\u{2721}", "\u{2721}\u{fe0f}"),
+    ("\u{2733}", "\u{2733}\u{fe0f}"),
+    ("\u{2734}", "\u{2734}\u{fe0f}"),
+    ("\u{2744}", "\u{2744}\u{fe0f}"),
+    ("\u{2747}", "\u{2747}\u{fe0f}"),
+    ("\u{2763}", "\u{2763}\u{fe0f}"),
+    ("\u{2764}", "\u{2764}\u{fe0f}"),
+    ("\u{2764}\u{200d}\u{1f525}", "\u{2764}\u{fe0f}\u{200d}\u{1f525}"),
+    ("\u{2764}\u{200d}\u{1fa79}", "\u{2764}\u{fe0f}\u{200d}\u{1fa79}"),
+    ("\u{27a1}", "\u{27a1}\u{fe0f}"),
+    ("\u{2934}", "\u{2934}\u{fe0f}"),
+    ("\u{2935}", "\u{2935}\u{fe0f}"),
+    ("\u{2b05}", "\u{2b05}\u{fe0f}"),
+    ("\u{2b06}", "\u{2b06}\u{fe0f}"),
+    ("\u{2b07}", "\u{2b07}\u{fe0f}"),
+    ("\u{3030}", "\u{3030}\u{fe0f}"),
+    ("\u{303d}", "\u{303d}\u{fe0f}"),
+    ("\u{3297}", "\u{3297}\u{fe0f}"),
+    ("\u{3299}", "\u{3299}\u{fe0f}"),
+    ("\u{1f170}", "\u{1f170}\u{fe0f}"),
+    ("\u{1f171}", "\u{1f171}\u{fe0f}"),
+    ("\u{1f17e}", "\u{1f17e}\u{fe0f}"),
+    ("\u{1f17f}", "\u{1f17f}\u{fe0f}"),
+    ("\u{1f202}", "\u{1f202}\u{fe0f}"),
+    ("\u{1f237}", "\u{1f237}\u{fe0f}"),
+    ("\u{1f321}", "\u{1f321}\u{fe0f}"),
+    ("\u{1f324}", "\u{1f324}\u{fe0f}"),
+    ("\u{1f325}", "\u{1f325}\u{fe0f}"),
+    ("\u{1f326}", "\u{1f326}\u{fe0f}"),
+    ("\u{1f327}", "\u{1f327}\u{fe0f}"),
+    ("\u{1f328}", "\u{1f328}\u{fe0f}"),
+    ("\u{1f329}", "\u{1f329}\u{fe0f}"),
+    ("\u{1f32a}", "\u{1f32a}\u{fe0f}"),
+    ("\u{1f32b}", "\u{1f32b}\u{fe0f}"),
+    ("\u{1f32c}", "\u{1f32c}\u{fe0f}"),
+    ("\u{1f336}", "\u{1f336}\u{fe0f}"),
+    ("\u{1f37d}", "\u{1f37d}\u{fe0f}"),
+    ("\u{1f396}", "\u{1f396}\u{fe0f}"),
+    ("\u{1f397}", "\u{1f397}\u{fe0f}"),
+    ("\u{1f399}", "\u{1f399}\u{fe0f}"),
+    ("\u{1f39a}", "\u{1f39a}\u{fe0f}"),
+    ("\u{1f39b}", "\u{1f39b}\u{fe0f}"),
+    ("\u{1f39e}", "\u{1f39e}\u{fe0f}"),
+    ("\u{1f39f}", "\u{1f39f}\u{fe0f}"),
+    ("\u{1f3c3}\u{200d}\u{2640}", "\u{1f3c3}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{200d}\u{2642}", "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{200d}\u{27a1}", "\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}", "\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}", "\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f3c4}\u{200d}\u{2640}", "\u{1f3c4}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c4}\u{200d}\u{2642}", "\u{1f3c4}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}", "\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}", "\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}", "\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}", "\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}", "\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}", "\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}", "\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}", "\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}", "\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}", "\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3ca}\u{200d}\u{2640}", "\u{1f3ca}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3ca}\u{200d}\u{2642}", "\u{1f3ca}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}", "\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}", "\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}", "\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}", "\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}", "\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}", "\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}", "\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}", "\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}", "\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}", "\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cb}", "\u{1f3cb}\u{fe0f}"),
+    ("\u{1f3cb}\u{200d}\u{2640}", "\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cb}\u{200d}\u{2642}", "\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}", "\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}", "\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}", "\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}", "\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}", "\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}", "\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}", "\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}", "\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}", "\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}", "\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cc}", "\u{1f3cc}\u{fe0f}"),
+    ("\u{1f3cc}\u{200d}\u{2640}", "\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cc}\u{200d}\u{2642}", "\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}", "\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}", "\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}", "\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}", "\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}", "\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}", "\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}", "\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}", "\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}", "\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}", "\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f3cd}", "\u{1f3cd}\u{fe0f}"),
+    ("\u{1f3ce}", "\u{1f3ce}\u{fe0f}"),
+    ("\u{1f3d4}", "\u{1f3d4}\u{fe0f}"),
+    ("\u{1f3d5}", "\u{1f3d5}\u{fe0f}"),
+    ("\u{1f3d6}", "\u{1f3d6}\u{fe0f}"),
+    ("\u{1f3d7}", "\u{1f3d7}\u{fe0f}"),
+    ("\u{1f3d8}", "\u{1f3d8}\u{fe0f}"),
+    ("\u{1f3d9}", "\u{1f3d9}\u{fe0f}"),
+    ("\u{1f3da}", "\u{1f3da}\u{fe0f}"),
+    ("\u{1f3db}", "\u{1f3db}\u{fe0f}"),
+    ("\u{1f3dc}", "\u{1f3dc}\u{fe0f}"),
+    ("\u{1f3dd}", "\u{1f3dd}\u{fe0f}"),
+    ("\u{1f3de}", "\u{1f3de}\u{fe0f}"),
+    ("\u{1f3df}", "\u{1f3df}\u{fe0f}"),
+    ("\u{1f3f3}", "\u{1f3f3}\u{fe0f}"),
+    ("\u{1f3f3}\u{200d}\u{26a7}", "\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}"),
+    ("\u{1f3f3}\u{200d}\u{1f308}", "\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"),
+    ("\u{1f3f4}\u{200d}\u{2620}", "\u{1f3f4}\u{200d}\u{2620}\u{fe0f}"),
+    ("\u{1f3f5}", "\u{1f3f5}\u{fe0f}"),
+    ("\u{1f3f7}", "\u{1f3f7}\u{fe0f}"),
+    ("\u{1f43b}\u{200d}\u{2744}", "\u{1f43b}\u{200d}\u{2744}\u{fe0f}"),
+    ("\u{1f43f}", "\u{1f43f}\u{fe0f}"),
+    ("\u{1f441}", "\u{1f441}\u{fe0f}"),
+    ("\u{1f441}\u{200d}\u{1f5e8}", "\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}"),
+    ("\u{1f468}\u{200d}\u{2695}", "\u{1f468}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f468}\u{200d}\u{2696}", "\u{1f468}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f468}\u{200d}\u{2708}", "\u{1f468}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f468}\u{200d}\u{2764}\u{200d}\u{1f468}", "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}"),
+    ("\u{1f468}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}", "\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}"),
+    ("\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2695}", "\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2696}", "\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2708}", "\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2695}", "\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2696}", "\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2708}", "\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2695}", "\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2696}", "\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2708}", "\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2695}", "\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2696}", "\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2708}", "\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2695}", "\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2696}", "\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2708}", "\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{200d}\u{2695}", "\u{1f469}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f469}\u{200d}\u{2696}", "\u{1f469}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f469}\u{200d}\u{2708}", "\u{1f469}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f468}", "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}"),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f469}", "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}"),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}", "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}"),
+    ("\u{1f469}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}", "\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}"),
+    ("\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2695}", "\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2696}", "\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2708}", "\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2695}", "\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2696}", "\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2708}", "\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2695}", "\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2696}", "\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2708}", "\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2695}", "\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2696}", "\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2708}", "\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2695}", "\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2696}", "\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2708}", "\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f46e}\u{200d}\u{2640}", "\u{1f46e}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46e}\u{200d}\u{2642}", "\u{1f46e}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2640}", "\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2642}", "\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2640}", "\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2642}", "\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2640}", "\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2642}", "\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2640}", "\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2642}", "\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2640}", "\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2642}", "\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f46f}\u{200d}\u{2640}", "\u{1f46f}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f46f}\u{200d}\u{2642}", "\u{1f46f}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f470}\u{200d}\u{2640}", "\u{1f470}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f470}\u{200d}\u{2642}", "\u{1f470}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2640}", "\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2642}", "\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2640}", "\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2642}", "\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2640}", "\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2642}", "\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2640}", "\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2642}", "\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2640}", "\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2642}", "\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f471}\u{200d}\u{2640}", "\u{1f471}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f471}\u{200d}\u{2642}", "\u{1f471}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2640}", "\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2642}", "\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2640}", "\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2642}", "\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2640}", "\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2642}", "\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2640}", "\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2642}", "\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2640}", "\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2642}", "\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f473}\u{200d}\u{2640}", "\u{1f473}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f473}\u{200d}\u{2642}", "\u{1f473}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2640}", "\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2642}", "\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2640}", "\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2642}", "\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2640}", "\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2642}", "\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2640}", "\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2642}", "\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2640}", "\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2642}", "\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f477}\u{200d}\u{2640}", "\u{1f477}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f477}\u{200d}\u{2642}", "\u{1f477}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2640}", "\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2642}", "\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2640}", "\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2642}", "\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2640}", "\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2642}", "\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2640}", "\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2642}", "\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2640}", "\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2642}", "\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f481}\u{200d}\u{2640}", "\u{1f481}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f481}\u{200d}\u{2642}", "\u{1f481}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2640}", "\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2642}", "\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2640}", "\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2642}", "\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2640}", "\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2642}", "\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2640}", "\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2642}", "\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2640}", "\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2642}", "\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f482}\u{200d}\u{2640}", "\u{1f482}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f482}\u{200d}\u{2642}", "\u{1f482}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2640}", "\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2642}", "\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2640}", "\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2642}", "\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2640}", "\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2642}", "\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2640}", "\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2642}", "\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2640}", "\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2642}", "\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f486}\u{200d}\u{2640}", "\u{1f486}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f486}\u{200d}\u{2642}", "\u{1f486}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2640}", "\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2642}", "\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2640}", "\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2642}", "\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2640}", "\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2642}", "\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2640}", "\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2642}", "\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2640}", "\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2642}", "\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f487}\u{200d}\u{2640}", "\u{1f487}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f487}\u{200d}\u{2642}", "\u{1f487}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2640}", "\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2642}", "\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2640}", "\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2642}", "\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2640}", "\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2642}", "\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2640}", "\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2642}", "\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2640}", "\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2642}", "\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f4fd}", "\u{1f4fd}\u{fe0f}"),
+    ("\u{1f549}", "\u{1f549}\u{fe0f}"),
+    ("\u{1f54a}", "\u{1f54a}\u{fe0f}"),
+    ("\u{1f56f}", "\u{1f56f}\u{fe0f}"),
+    ("\u{1f570}", "\u{1f570}\u{fe0f}"),
+    ("\u{1f573}", "\u{1f573}\u{fe0f}"),
+    ("\u{1f574}", "\u{1f574}\u{fe0f}"),
+    ("\u{1f575}", "\u{1f575}\u{fe0f}"),
+    ("\u{1f575}\u{200d}\u{2640}", "\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f575}\u{200d}\u{2642}", "\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2640}", "\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2642}", "\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2640}", "\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2642}", "\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2640}", "\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2642}", "\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2640}", "\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2642}", "\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2640}", "\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2642}", "\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f576}", "\u{1f576}\u{fe0f}"),
+    ("\u{1f577}", "\u{1f577}\u{fe0f}"),
+    ("\u{1f578}", "\u{1f578}\u{fe0f}"),
+    ("\u{1f579}", "\u{1f579}\u{fe0f}"),
+    ("\u{1f587}", "\u{1f587}\u{fe0f}"),
+    ("\u{1f58a}", "\u{1f58a}\u{fe0f}"),
+    ("\u{1f58b}", "\u{1f58b}\u{fe0f}"),
+    ("\u{1f58c}", "\u{1f58c}\u{fe0f}"),
+    ("\u{1f58d}", "\u{1f58d}\u{fe0f}"),
+    ("\u{1f590}", "\u{1f590}\u{fe0f}"),
+    ("\u{1f5a5}", "\u{1f5a5}\u{fe0f}"),
+    ("\u{1f5a8}", "\u{1f5a8}\u{fe0f}"),
+    ("\u{1f5b1}", "\u{1f5b1}\u{fe0f}"),
+    ("\u{1f5b2}", "\u{1f5b2}\u{fe0f}"),
+    ("\u{1f5bc}", "\u{1f5bc}\u{fe0f}"),
+    ("\u{1f5c2}", "\u{1f5c2}\u{fe0f}"),
+    ("\u{1f5c3}", "\u{1f5c3}\u{fe0f}"),
+    ("\u{1f5c4}", "\u{1f5c4}\u{fe0f}"),
+    ("\u{1f5d1}", "\u{1f5d1}\u{fe0f}"),
+    ("\u{1f5d2}", "\u{1f5d2}\u{fe0f}"),
+    ("\u{1f5d3}", "\u{1f5d3}\u{fe0f}"),
+    ("\u{1f5dc}", "\u{1f5dc}\u{fe0f}"),
+    ("\u{1f5dd}", "\u{1f5dd}\u{fe0f}"),
+    ("\u{1f5de}", "\u{1f5de}\u{fe0f}"),
+    ("\u{1f5e1}", "\u{1f5e1}\u{fe0f}"),
+    ("\u{1f5e3}", "\u{1f5e3}\u{fe0f}"),
+    ("\u{1f5e8}", "\u{1f5e8}\u{fe0f}"),
+    ("\u{1f5ef}", "\u{1f5ef}\u{fe0f}"),
+    ("\u{1f5f3}", "\u{1f5f3}\u{fe0f}"),
+    ("\u{1f5fa}", "\u{1f5fa}\u{fe0f}"),
+    ("\u{1f636}\u{200d}\u{1f32b}", "\u{1f636}\u{200d}\u{1f32b}\u{fe0f}"),
+    ("\u{1f642}\u{200d}\u{2194}", "\u{1f642}\u{200d}\u{2194}\u{fe0f}"),
+    ("\u{1f642}\u{200d}\u{2195}", "\u{1f642}\u{200d}\u{2195}\u{fe0f}"),
+    ("\u{1f645}\u{200d}\u{2640}", "\u{1f645}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f645}\u{200d}\u{2642}", "\u{1f645}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2640}", "\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2642}", "\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2640}", "\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2642}", "\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2640}", "\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2642}", "\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2640}", "\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2642}", "\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2640}", "\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2642}", "\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f646}\u{200d}\u{2640}", "\u{1f646}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f646}\u{200d}\u{2642}", "\u{1f646}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2640}", "\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2642}", "\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2640}", "\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2642}", "\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2640}", "\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2642}", "\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2640}", "\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2642}", "\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2640}", "\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2642}", "\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f647}\u{200d}\u{2640}", "\u{1f647}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f647}\u{200d}\u{2642}", "\u{1f647}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2640}", "\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2642}", "\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2640}", "\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2642}", "\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2640}", "\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2642}", "\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2640}", "\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2642}", "\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2640}", "\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2642}", "\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64b}\u{200d}\u{2640}", "\u{1f64b}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64b}\u{200d}\u{2642}", "\u{1f64b}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2640}", "\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2642}", "\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2640}", "\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2642}", "\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2640}", "\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2642}", "\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2640}", "\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2642}", "\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2640}", "\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2642}", "\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64d}\u{200d}\u{2640}", "\u{1f64d}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64d}\u{200d}\u{2642}", "\u{1f64d}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2640}", "\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2642}", "\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2640}", "\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2642}", "\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2640}", "\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2642}", "\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2640}", "\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2642}", "\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2640}", "\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2642}", "\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64e}\u{200d}\u{2640}", "\u{1f64e}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64e}\u{200d}\u{2642}", "\u{1f64e}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2640}", "\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2642}", "\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2640}", "\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2642}", "\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2640}", "\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2642}", "\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2640}", "\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2642}", "\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2640}", "\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2642}", "\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6a3}\u{200d}\u{2640}", "\u{1f6a3}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6a3}\u{200d}\u{2642}", "\u{1f6a3}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}", "\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}", "\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}", "\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}", "\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}", "\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}", "\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}", "\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}", "\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}", "\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}", "\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b4}\u{200d}\u{2640}", "\u{1f6b4}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b4}\u{200d}\u{2642}", "\u{1f6b4}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}", "\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}", "\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}", "\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}", "\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}", "\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}", "\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}", "\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}", "\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}", "\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}", "\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b5}\u{200d}\u{2640}", "\u{1f6b5}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b5}\u{200d}\u{2642}", "\u{1f6b5}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}", "\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}", "\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}", "\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}", "\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}", "\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}", "\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}", "\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}", "\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}", "\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}", "\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{200d}\u{2640}", "\u{1f6b6}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{200d}\u{2642}", "\u{1f6b6}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{200d}\u{27a1}", "\u{1f6b6}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}", "\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}", "\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f6cb}", "\u{1f6cb}\u{fe0f}"),
+    ("\u{1f6cd}", "\u{1f6cd}\u{fe0f}"),
+    ("\u{1f6ce}", "\u{1f6ce}\u{fe0f}"),
+    ("\u{1f6cf}", "\u{1f6cf}\u{fe0f}"),
+    ("\u{1f6e0}", "\u{1f6e0}\u{fe0f}"),
+    ("\u{1f6e1}", "\u{1f6e1}\u{fe0f}"),
+    ("\u{1f6e2}", "\u{1f6e2}\u{fe0f}"),
+    ("\u{1f6e3}", "\u{1f6e3}\u{fe0f}"),
+    ("\u{1f6e4}", "\u{1f6e4}\u{fe0f}"),
+    ("\u{1f6e5}", "\u{1f6e5}\u{fe0f}"),
+    ("\u{1f6e9}", "\u{1f6e9}\u{fe0f}"),
+    ("\u{1f6f0}", "\u{1f6f0}\u{fe0f}"),
+    ("\u{1f6f3}", "\u{1f6f3}\u{fe0f}"),
+    ("\u{1f926}\u{200d}\u{2640}", "\u{1f926}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f926}\u{200d}\u{2642}", "\u{1f926}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2640}", "\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2642}", "\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2640}", "\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2642}", "\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2640}", "\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2642}", "\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2640}", "\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2642}", "\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2640}", "\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2642}", "\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f935}\u{200d}\u{2640}", "\u{1f935}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f935}\u{200d}\u{2642}", "\u{1f935}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2640}", "\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2642}", "\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2640}", "\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2642}", "\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2640}", "\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2642}", "\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2640}", "\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2642}", "\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2640}", "\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2642}", "\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f937}\u{200d}\u{2640}", "\u{1f937}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f937}\u{200d}\u{2642}", "\u{1f937}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2640}", "\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2642}", "\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2640}", "\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2642}", "\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2640}", "\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2642}", "\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2640}", "\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2642}", "\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2640}", "\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2642}", "\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f938}\u{200d}\u{2640}", "\u{1f938}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f938}\u{200d}\u{2642}", "\u{1f938}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2640}", "\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2642}", "\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2640}", "\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2642}", "\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2640}", "\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2642}", "\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2640}", "\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2642}", "\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2640}", "\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2642}", "\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f939}\u{200d}\u{2640}", "\u{1f939}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f939}\u{200d}\u{2642}", "\u{1f939}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2640}", "\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2642}", "\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2640}", "\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2642}", "\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2640}", "\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2642}", "\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2640}", "\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2642}", "\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2640}", "\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2642}", "\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93c}\u{200d}\u{2640}", "\u{1f93c}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93c}\u{200d}\u{2642}", "\u{1f93c}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93d}\u{200d}\u{2640}", "\u{1f93d}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93d}\u{200d}\u{2642}", "\u{1f93d}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2640}", "\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2642}", "\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2640}", "\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2642}", "\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2640}", "\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2642}", "\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2640}", "\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2642}", "\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2640}", "\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2642}", "\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93e}\u{200d}\u{2640}", "\u{1f93e}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93e}\u{200d}\u{2642}", "\u{1f93e}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2640}", "\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2642}", "\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2640}", "\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2642}", "\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2640}", "\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2642}", "\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2640}", "\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2642}", "\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2640}", "\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2642}", "\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b8}\u{200d}\u{2640}", "\u{1f9b8}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b8}\u{200d}\u{2642}", "\u{1f9b8}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b9}\u{200d}\u{2640}", "\u{1f9b9}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b9}\u{200d}\u{2642}", "\u{1f9b9}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cd}\u{200d}\u{2640}", "\u{1f9cd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cd}\u{200d}\u{2642}", "\u{1f9cd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{200d}\u{2640}", "\u{1f9ce}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{200d}\u{2642}", "\u{1f9ce}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{200d}\u{27a1}", "\u{1f9ce}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9cf}\u{200d}\u{2640}", "\u{1f9cf}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cf}\u{200d}\u{2642}", "\u{1f9cf}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d1}\u{200d}\u{2695}", "\u{1f9d1}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f9d1}\u{200d}\u{2696}", "\u{1f9d1}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f9d1}\u{200d}\u{2708}", "\u{1f9d1}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3ff}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"),
+    ("\u{1f9d4}\u{200d}\u{2640}", "\u{1f9d4}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d4}\u{200d}\u{2642}", "\u{1f9d4}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d6}\u{200d}\u{2640}", "\u{1f9d6}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d6}\u{200d}\u{2642}", "\u{1f9d6}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d7}\u{200d}\u{2640}", "\u{1f9d7}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d7}\u{200d}\u{2642}", "\u{1f9d7}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d8}\u{200d}\u{2640}", "\u{1f9d8}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d8}\u{200d}\u{2642}", "\u{1f9d8}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d9}\u{200d}\u{2640}", "\u{1f9d9}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d9}\u{200d}\u{2642}", "\u{1f9d9}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9da}\u{200d}\u{2640}", "\u{1f9da}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9da}\u{200d}\u{2642}", "\u{1f9da}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9db}\u{200d}\u{2640}", "\u{1f9db}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9db}\u{200d}\u{2642}", "\u{1f9db}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dc}\u{200d}\u{2640}", "\u{1f9dc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dc}\u{200d}\u{2642}", "\u{1f9dc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dd}\u{200d}\u{2640}", "\u{1f9dd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dd}\u{200d}\u{2642}", "\u{1f9dd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}", "\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}", "\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}", "\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}", "\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}", "\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}", "\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}", "\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}", "\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}", "\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}", "\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9de}\u{200d}\u{2640}", "\u{1f9de}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9de}\u{200d}\u{2642}", "\u{1f9de}\u{200d}\u{2642}\u{fe0f}"),
+    ("\u{1f9df}\u{200d}\u{2640}", "\u{1f9df}\u{200d}\u{2640}\u{fe0f}"),
+    ("\u{1f9df}\u{200d}\u{2642}", "\u{1f9df}\u{200d}\u{2642}\u{fe0f}"),
+];
+
+/// Emoji without skin tones (and without `U+FE0F`) whose base emoji is not the same emoji
+/// with skin tones, like a handshake with different skin tones, sorted for binary search
+pub(crate) const SKIN_TONE_BASES: &[(&str, &str)] = &[
+    ("\u{1f468}\u{200d}\u{1f91d}\u{200d}\u{1f468}", "\u{1f46c}"),
+    ("\u{1f469}\u{200d}\u{1f91d}\u{200d}\u{1f468}", "\u{1f46b}"),
+    ("\u{1f469}\u{200d}\u{1f91d}\u{200d}\u{1f469}", "\u{1f46d}"),
+    ("\u{1f9d1}\u{200d}\u{2764}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}", "\u{1f48f}"),
+    ("\u{1f9d1}\u{200d}\u{2764}\u{200d}\u{1f9d1}", "\u{1f491}"),
+    ("\u{1faf1}\u{200d}\u{1faf2}", "\u{1f91d}"),
+];
+
+/// Emoji that support skin tones with their light, medium-light, medium, medium-dark
+/// and dark variant, sorted for binary search
+pub(crate) const SKIN_TONE_VARIANTS: &[(&str, [&str; 5])] = &[
+    ("\u{261d}\u{fe0f}", ["\u{261d}\u{1f3fb}", "\u{261d}\u{1f3fc}", "\u{261d}\u{1f3fd}", "\u{261d}\u{1f3fe}", "\u{261d}\u{1f3ff}"]),
+    ("\u{26f9}\u{fe0f}", ["\u{26f9}\u{1f3fb}", "\u{26f9}\u{1f3fc}", "\u{26f9}\u{1f3fd}", "\u{26f9}\u{1f3fe}", "\u{26f9}\u{1f3ff}"]),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", ["\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", ["\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{270a}", ["\u{270a}\u{1f3fb}", "\u{270a}\u{1f3fc}", "\u{270a}\u{1f3fd}", "\u{270a}\u{1f3fe}", "\u{270a}\u{1f3ff}"]),
+    ("\u{270b}", ["\u{270b}\u{1f3fb}", "\u{270b}\u{1f3fc}", "\u{270b}\u{1f3fd}", "\u{270b}\u{1f3fe}", "\u{270b}\u{1f3ff}"]),
+    ("\u{270c}\u{fe0f}", ["\u{270c}\u{1f3fb}", "\u{270c}\u{1f3fc}", "\u{270c}\u{1f3fd}", "\u{270c}\u{1f3fe}", "\u{270c}\u{1f3ff}"]),
+    ("\u{270d}\u{fe0f}", ["\u{270d}\u{1f3fb}", "\u{270d}\u{1f3fc}", "\u{270d}\u{1f3fd}", "\u{270d}\u{1f3fe}", "\u{270d}\u{1f3ff}"]),
+    ("\u{1f385}", ["\u{1f385}\u{1f3fb}", "\u{1f385}\u{1f3fc}", "\u{1f385}\u{1f3fd}", "\u{1f385}\u{1f3fe}", "\u{1f385}\u{1f3ff}"]),
+    ("\u{1f3c2}", ["\u{1f3c2}\u{1f3fb}", "\u{1f3c2}\u{1f3fc}", "\u{1f3c2}\u{1f3fd}", "\u{1f3c2}\u{1f3fe}", "\u{1f3c2}\u{1f3ff}"]),
+    ("\u{1f3c3}", ["\u{1f3c3}\u{1f3fb}", "\u{1f3c3}\u{1f3fc}", "\u{1f3c3}\u{1f3fd}", "\u{1f3c3}\u{1f3fe}", "\u{1f3c3}\u{1f3ff}"]),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}", ["\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}", ["\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", "\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f3c4}", ["\u{1f3c4}\u{1f3fb}", "\u{1f3c4}\u{1f3fc}", "\u{1f3c4}\u{1f3fd}", "\u{1f3c4}\u{1f3fe}", "\u{1f3c4}\u{1f3ff}"]),
+    ("\u{1f3c4}\u{200d}\u{2640}\u{fe0f}", ["\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f3c4}\u{200d}\u{2642}\u{fe0f}", ["\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f3c7}", ["\u{1f3c7}\u{1f3fb}", "\u{1f3c7}\u{1f3fc}", "\u{1f3c7}\u{1f3fd}", "\u{1f3c7}\u{1f3fe}", "\u{1f3c7}\u{1f3ff}"]),
+    ("\u{1f3ca}", ["\u{1f3ca}\u{1f3fb}", "\u{1f3ca}\u{1f3fc}", "\u{1f3ca}\u{1f3fd}", "\u{1f3ca}\u{1f3fe}", "\u{1f3ca}\u{1f3ff}"]),
+    ("\u{1f3ca}\u{200d}\u{2640}\u{fe0f}", ["\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f3ca}\u{200d}\u{2642}\u{fe0f}", ["\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f3cb}\u{fe0f}", ["\u{1f3cb}\u{1f3fb}", "\u{1f3cb}\u{1f3fc}", "\u{1f3cb}\u{1f3fd}", "\u{1f3cb}\u{1f3fe}", "\u{1f3cb}\u{1f3ff}"]),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", ["\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", ["\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f3cc}\u{fe0f}", ["\u{1f3cc}\u{1f3fb}", "\u{1f3cc}\u{1f3fc}", "\u{1f3cc}\u{1f3fd}", "\u{1f3cc}\u{1f3fe}", "\u{1f3cc}\u{1f3ff}"]),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", ["\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", ["\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f442}", ["\u{1f442}\u{1f3fb}", "\u{1f442}\u{1f3fc}", "\u{1f442}\u{1f3fd}", "\u{1f442}\u{1f3fe}", "\u{1f442}\u{1f3ff}"]),
+    ("\u{1f443}", ["\u{1f443}\u{1f3fb}", "\u{1f443}\u{1f3fc}", "\u{1f443}\u{1f3fd}", "\u{1f443}\u{1f3fe}", "\u{1f443}\u{1f3ff}"]),
+    ("\u{1f446}", ["\u{1f446}\u{1f3fb}", "\u{1f446}\u{1f3fc}", "\u{1f446}\u{1f3fd}", "\u{1f446}\u{1f3fe}", "\u{1f446}\u{1f3ff}"]),
+    ("\u{1f447}", ["\u{1f447}\u{1f3fb}", "\u{1f447}\u{1f3fc}", "\u{1f447}\u{1f3fd}", "\u{1f447}\u{1f3fe}", "\u{1f447}\u{1f3ff}"]),
+    ("\u{1f448}", ["\u{1f448}\u{1f3fb}", "\u{1f448}\u{1f3fc}", "\u{1f448}\u{1f3fd}", "\u{1f448}\u{1f3fe}", "\u{1f448}\u{1f3ff}"]),
+    ("\u{1f449}", ["\u{1f449}\u{1f3fb}", "\u{1f449}\u{1f3fc}", "\u{1f449}\u{1f3fd}", "\u{1f449}\u{1f3fe}", "\u{1f449}\u{1f3ff}"]),
+    ("\u{1f44a}", ["\u{1f44a}\u{1f3fb}", "\u{1f44a}\u{1f3fc}", "\u{1f44a}\u{1f3fd}", "\u{1f44a}\u{1f3fe}", "\u{1f44a}\u{1f3ff}"]),
+    ("\u{1f44b}", ["\u{1f44b}\u{1f3fb}", "\u{1f44b}\u{1f3fc}", "\u{1f44b}\u{1f3fd}", "\u{1f44b}\u{1f3fe}", "\u{1f44b}\u{1f3ff}"]),
+    ("\u{1f44c}", ["\u{1f44c}\u{1f3fb}", "\u{1f44c}\u{1f3fc}", "\u{1f44c}\u{1f3fd}", "\u{1f44c}\u{1f3fe}", "\u{1f44c}\u{1f3ff}"]),
+    ("\u{1f44d}", ["\u{1f44d}\u{1f3fb}", "\u{1f44d}\u{1f3fc}", "\u{1f44d}\u{1f3fd}", "\u{1f44d}\u{1f3fe}", "\u{1f44d}\u{1f3ff}"]),
+    ("\u{1f44e}", ["\u{1f44e}\u{1f3fb}", "\u{1f44e}\u{1f3fc}", "\u{1f44e}\u{1f3fd}", "\u{1f44e}\u{1f3fe}", "\u{1f44e}\u{1f3ff}"]),
+    ("\u{1f44f}", ["\u{1f44f}\u{1f3fb}", "\u{1f44f}\u{1f3fc}", "\u{1f44f}\u{1f3fd}", "\u{1f44f}\u{1f3fe}", "\u{1f44f}\u{1f3ff}"]),
+    ("\u{1f450}", ["\u{1f450}\u{1f3fb}", "\u{1f450}\u{1f3fc}", "\u{1f450}\u{1f3fd}", "\u{1f450}\u{1f3fe}", "\u{1f450}\u{1f3ff}"]),
+    ("\u{1f466}", ["\u{1f466}\u{1f3fb}", "\u{1f466}\u{1f3fc}", "\u{1f466}\u{1f3fd}", "\u{1f466}\u{1f3fe}", "\u{1f466}\u{1f3ff}"]),
+    ("\u{1f467}", ["\u{1f467}\u{1f3fb}", "\u{1f467}\u{1f3fc}", "\u{1f467}\u{1f3fd}", "\u{1f467}\u{1f3fe}", "\u{1f467}\u{1f3ff}"]),
+    ("\u{1f468}", ["\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3ff}"]),
+    ("\u{1f468}\u{200d}\u{2695}\u{fe0f}", ["\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", "\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", "\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", "\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", "\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}"]),
+    ("\u{1f468}\u{200d}\u{2696}\u{fe0f}", ["\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", "\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", "\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", "\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", "\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}"]),
+    ("\u{1f468}\u{200d}\u{2708}\u{fe0f}", ["\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", "\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", "\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", "\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", "\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}"]),
+    ("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", ["\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"]),
+    ("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", ["\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"]),
+    ("\u{1f468}\u{200d}\u{1f33e}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}"]),
+    ("\u{1f468}\u{200d}\u{1f373}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f373}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f373}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f373}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f373}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f373}"]),
+    ("\u{1f468}\u{200d}\u{1f37c}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f37c}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f37c}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f37c}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f37c}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f37c}"]),
+    ("\u{1f468}\u{200d}\u{1f393}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f393}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f393}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f393}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f393}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f393}"]),
+    ("\u{1f468}\u{200d}\u{1f3a4}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}"]),
+    ("\u{1f468}\u{200d}\u{1f3a8}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}"]),
+    ("\u{1f468}\u{200d}\u{1f3eb}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}"]),
+    ("\u{1f468}\u{200d}\u{1f3ed}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}"]),
+    ("\u{1f468}\u{200d}\u{1f4bb}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}"]),
+    ("\u{1f468}\u{200d}\u{1f4bc}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}"]),
+    ("\u{1f468}\u{200d}\u{1f527}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f527}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f527}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f527}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f527}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f527}"]),
+    ("\u{1f468}\u{200d}\u{1f52c}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}"]),
+    ("\u{1f468}\u{200d}\u{1f680}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f680}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f680}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f680}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f680}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f680}"]),
+    ("\u{1f468}\u{200d}\u{1f692}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f692}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f692}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f692}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f692}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f692}"]),
+    ("\u{1f468}\u{200d}\u{1f9af}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}"]),
+    ("\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f468}\u{200d}\u{1f9b0}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}"]),
+    ("\u{1f468}\u{200d}\u{1f9b1}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}"]),
+    ("\u{1f468}\u{200d}\u{1f9b2}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}"]),
+    ("\u{1f468}\u{200d}\u{1f9b3}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}"]),
+    ("\u{1f468}\u{200d}\u{1f9bc}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}"]),
+    ("\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f468}\u{200d}\u{1f9bd}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}"]),
+    ("\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f469}", ["\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3ff}"]),
+    ("\u{1f469}\u{200d}\u{2695}\u{fe0f}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", "\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", "\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", "\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", "\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}"]),
+    ("\u{1f469}\u{200d}\u{2696}\u{fe0f}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", "\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", "\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", "\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", "\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}"]),
+    ("\u{1f469}\u{200d}\u{2708}\u{fe0f}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", "\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", "\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", "\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", "\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}"]),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}"]),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}"]),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}"]),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}", ["\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}"]),
+    ("\u{1f469}\u{200d}\u{1f33e}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}"]),
+    ("\u{1f469}\u{200d}\u{1f373}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f373}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f373}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f373}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f373}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f373}"]),
+    ("\u{1f469}\u{200d}\u{1f37c}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f37c}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f37c}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f37c}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f37c}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f37c}"]),
+    ("\u{1f469}\u{200d}\u{1f393}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f393}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f393}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f393}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f393}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f393}"]),
+    ("\u{1f469}\u{200d}\u{1f3a4}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}"]),
+    ("\u{1f469}\u{200d}\u{1f3a8}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}"]),
+    ("\u{1f469}\u{200d}\u{1f3eb}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}"]),
+    ("\u{1f469}\u{200d}\u{1f3ed}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}"]),
+    ("\u{1f469}\u{200d}\u{1f4bb}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}"]),
+    ("\u{1f469}\u{200d}\u{1f4bc}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}"]),
+    ("\u{1f469}\u{200d}\u{1f527}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f527}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f527}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f527}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f527}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f527}"]),
+    ("\u{1f469}\u{200d}\u{1f52c}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}"]),
+    ("\u{1f469}\u{200d}\u{1f680}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f680}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f680}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f680}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f680}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f680}"]),
+    ("\u{1f469}\u{200d}\u{1f692}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f692}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f692}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f692}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f692}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f692}"]),
+    ("\u{1f469}\u{200d}\u{1f9af}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}"]),
+    ("\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f469}\u{200d}\u{1f9b0}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}"]),
+    ("\u{1f469}\u{200d}\u{1f9b1}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}"]),
+    ("\u{1f469}\u{200d}\u{1f9b2}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}"]),
+    ("\u{1f469}\u{200d}\u{1f9b3}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}"]),
+    ("\u{1f469}\u{200d}\u{1f9bc}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}"]),
+    ("\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f469}\u{200d}\u{1f9bd}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}"]),
+    ("\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f46b}", ["\u{1f46b}\u{1f3fb}", "\u{1f46b}\u{1f3fc}", "\u{1f46b}\u{1f3fd}", "\u{1f46b}\u{1f3fe}", "\u{1f46b}\u{1f3ff}"]),
+    ("\u{1f46c}", ["\u{1f46c}\u{1f3fb}", "\u{1f46c}\u{1f3fc}", "\u{1f46c}\u{1f3fd}", "\u{1f46c}\u{1f3fe}", "\u{1f46c}\u{1f3ff}"]),
+    ("\u{1f46d}", ["\u{1f46d}\u{1f3fb}", "\u{1f46d}\u{1f3fc}", "\u{1f46d}\u{1f3fd}", "\u{1f46d}\u{1f3fe}", "\u{1f46d}\u{1f3ff}"]),
+    ("\u{1f46e}", ["\u{1f46e}\u{1f3fb}", "\u{1f46e}\u{1f3fc}", "\u{1f46e}\u{1f3fd}", "\u{1f46e}\u{1f3fe}", "\u{1f46e}\u{1f3ff}"]),
+    ("\u{1f46e}\u{200d}\u{2640}\u{fe0f}", ["\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f46e}\u{200d}\u{2642}\u{fe0f}", ["\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f470}", ["\u{1f470}\u{1f3fb}", "\u{1f470}\u{1f3fc}", "\u{1f470}\u{1f3fd}", "\u{1f470}\u{1f3fe}", "\u{1f470}\u{1f3ff}"]),
+    ("\u{1f470}\u{200d}\u{2640}\u{fe0f}", ["\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f470}\u{200d}\u{2642}\u{fe0f}", ["\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f471}", ["\u{1f471}\u{1f3fb}", "\u{1f471}\u{1f3fc}", "\u{1f471}\u{1f3fd}", "\u{1f471}\u{1f3fe}", "\u{1f471}\u{1f3ff}"]),
+    ("\u{1f471}\u{200d}\u{2640}\u{fe0f}", ["\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f471}\u{200d}\u{2642}\u{fe0f}", ["\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f472}", ["\u{1f472}\u{1f3fb}", "\u{1f472}\u{1f3fc}", "\u{1f472}\u{1f3fd}", "\u{1f472}\u{1f3fe}", "\u{1f472}\u{1f3ff}"]),
+    ("\u{1f473}", ["\u{1f473}\u{1f3fb}", "\u{1f473}\u{1f3fc}", "\u{1f473}\u{1f3fd}", "\u{1f473}\u{1f3fe}", "\u{1f473}\u{1f3ff}"]),
+    ("\u{1f473}\u{200d}\u{2640}\u{fe0f}", ["\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f473}\u{200d}\u{2642}\u{fe0f}", ["\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f474}", ["\u{1f474}\u{1f3fb}", "\u{1f474}\u{1f3fc}", "\u{1f474}\u{1f3fd}", "\u{1f474}\u{1f3fe}", "\u{1f474}\u{1f3ff}"]),
+    ("\u{1f475}", ["\u{1f475}\u{1f3fb}", "\u{1f475}\u{1f3fc}", "\u{1f475}\u{1f3fd}", "\u{1f475}\u{1f3fe}", "\u{1f475}\u{1f3ff}"]),
+    ("\u{1f476}", ["\u{1f476}\u{1f3fb}", "\u{1f476}\u{1f3fc}", "\u{1f476}\u{1f3fd}", "\u{1f476}\u{1f3fe}", "\u{1f476}\u{1f3ff}"]),
+    ("\u{1f477}", ["\u{1f477}\u{1f3fb}", "\u{1f477}\u{1f3fc}", "\u{1f477}\u{1f3fd}", "\u{1f477}\u{1f3fe}", "\u{1f477}\u{1f3ff}"]),
+    ("\u{1f477}\u{200d}\u{2640}\u{fe0f}", ["\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f477}\u{200d}\u{2642}\u{fe0f}", ["\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f478}", ["\u{1f478}\u{1f3fb}", "\u{1f478}\u{1f3fc}", "\u{1f478}\u{1f3fd}", "\u{1f478}\u{1f3fe}", "\u{1f478}\u{1f3ff}"]),
+    ("\u{1f47c}", ["\u{1f47c}\u{1f3fb}", "\u{1f47c}\u{1f3fc}", "\u{1f47c}\u{1f3fd}", "\u{1f47c}\u{1f3fe}", "\u{1f47c}\u{1f3ff}"]),
+    ("\u{1f481}", ["\u{1f481}\u{1f3fb}", "\u{1f481}\u{1f3fc}", "\u{1f481}\u{1f3fd}", "\u{1f481}\u{1f3fe}", "\u{1f481}\u{1f3ff}"]),
+    ("\u{1f481}\u{200d}\u{2640}\u{fe0f}", ["\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f481}\u{200d}\u{2642}\u{fe0f}", ["\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f482}", ["\u{1f482}\u{1f3fb}", "\u{1f482}\u{1f3fc}", "\u{1f482}\u{1f3fd}", "\u{1f482}\u{1f3fe}", "\u{1f482}\u{1f3ff}"]),
+    ("\u{1f482}\u{200d}\u{2640}\u{fe0f}", ["\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f482}\u{200d}\u{2642}\u{fe0f}", ["\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f483}", ["\u{1f483}\u{1f3fb}", "\u{1f483}\u{1f3fc}", "\u{1f483}\u{1f3fd}", "\u{1f483}\u{1f3fe}", "\u{1f483}\u{1f3ff}"]),
+    ("\u{1f485}", ["\u{1f485}\u{1f3fb}", "\u{1f485}\u{1f3fc}", "\u{1f485}\u{1f3fd}", "\u{1f485}\u{1f3fe}", "\u{1f485}\u{1f3ff}"]),
+    ("\u{1f486}", ["\u{1f486}\u{1f3fb}", "\u{1f486}\u{1f3fc}", "\u{1f486}\u{1f3fd}", "\u{1f486}\u{1f3fe}", "\u{1f486}\u{1f3ff}"]),
+    ("\u{1f486}\u{200d}\u{2640}\u{fe0f}", ["\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f486}\u{200d}\u{2642}\u{fe0f}", ["\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f487}", ["\u{1f487}\u{1f3fb}", "\u{1f487}\u{1f3fc}", "\u{1f487}\u{1f3fd}", "\u{1f487}\u{1f3fe}", "\u{1f487}\u{1f3ff}"]),
+    ("\u{1f487}\u{200d}\u{2640}\u{fe0f}", ["\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f487}\u{200d}\u{2642}\u{fe0f}", ["\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f48f}", ["\u{1f48f}\u{1f3fb}", "\u{1f48f}\u{1f3fc}", "\u{1f48f}\u{1f3fd}", "\u{1f48f}\u{1f3fe}", "\u{1f48f}\u{1f3ff}"]),
+    ("\u{1f491}", ["\u{1f491}\u{1f3fb}", "\u{1f491}\u{1f3fc}", "\u{1f491}\u{1f3fd}", "\u{1f491}\u{1f3fe}", "\u{1f491}\u{1f3ff}"]),
+    ("\u{1f4aa}", ["\u{1f4aa}\u{1f3fb}", "\u{1f4aa}\u{1f3fc}", "\u{1f4aa}\u{1f3fd}", "\u{1f4aa}\u{1f3fe}", "\u{1f4aa}\u{1f3ff}"]),
+    ("\u{1f574}\u{fe0f}", ["\u{1f574}\u{1f3fb}", "\u{1f574}\u{1f3fc}", "\u{1f574}\u{1f3fd}", "\u{1f574}\u{1f3fe}", "\u{1f574}\u{1f3ff}"]),
+    ("\u{1f575}\u{fe0f}", ["\u{1f575}\u{1f3fb}", "\u{1f575}\u{1f3fc}", "\u{1f575}\u{1f3fd}", "\u{1f575}\u{1f3fe}", "\u{1f575}\u{1f3ff}"]),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", ["\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", ["\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f57a}", ["\u{1f57a}\u{1f3fb}", "\u{1f57a}\u{1f3fc}", "\u{1f57a}\u{1f3fd}", "\u{1f57a}\u{1f3fe}", "\u{1f57a}\u{1f3ff}"]),
+    ("\u{1f590}\u{fe0f}", ["\u{1f590}\u{1f3fb}", "\u{1f590}\u{1f3fc}", "\u{1f590}\u{1f3fd}", "\u{1f590}\u{1f3fe}", "\u{1f590}\u{1f3ff}"]),
+    ("\u{1f595}", ["\u{1f595}\u{1f3fb}", "\u{1f595}\u{1f3fc}", "\u{1f595}\u{1f3fd}", "\u{1f595}\u{1f3fe}", "\u{1f595}\u{1f3ff}"]),
+    ("\u{1f596}", ["\u{1f596}\u{1f3fb}", "\u{1f596}\u{1f3fc}", "\u{1f596}\u{1f3fd}", "\u{1f596}\u{1f3fe}", "\u{1f596}\u{1f3ff}"]),
+    ("\u{1f645}", ["\u{1f645}\u{1f3fb}", "\u{1f645}\u{1f3fc}", "\u{1f645}\u{1f3fd}", "\u{1f645}\u{1f3fe}", "\u{1f645}\u{1f3ff}"]),
+    ("\u{1f645}\u{200d}\u{2640}\u{fe0f}", ["\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f645}\u{200d}\u{2642}\u{fe0f}", ["\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f646}", ["\u{1f646}\u{1f3fb}", "\u{1f646}\u{1f3fc}", "\u{1f646}\u{1f3fd}", "\u{1f646}\u{1f3fe}", "\u{1f646}\u{1f3ff}"]),
+    ("\u{1f646}\u{200d}\u{2640}\u{fe0f}", ["\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f646}\u{200d}\u{2642}\u{fe0f}", ["\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f647}", ["\u{1f647}\u{1f3fb}", "\u{1f647}\u{1f3fc}", "\u{1f647}\u{1f3fd}", "\u{1f647}\u{1f3fe}", "\u{1f647}\u{1f3ff}"]),
+    ("\u{1f647}\u{200d}\u{2640}\u{fe0f}", ["\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f647}\u{200d}\u{2642}\u{fe0f}", ["\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f64b}", ["\u{1f64b}\u{1f3fb}", "\u{1f64b}\u{1f3fc}", "\u{1f64b}\u{1f3fd}", "\u{1f64b}\u{1f3fe}", "\u{1f64b}\u{1f3ff}"]),
+    ("\u{1f64b}\u{200d}\u{2640}\u{fe0f}", ["\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f64b}\u{200d}\u{2642}\u{fe0f}", ["\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f64c}", ["\u{1f64c}\u{1f3fb}", "\u{1f64c}\u{1f3fc}", "\u{1f64c}\u{1f3fd}", "\u{1f64c}\u{1f3fe}", "\u{1f64c}\u{1f3ff}"]),
+    ("\u{1f64d}", ["\u{1f64d}\u{1f3fb}", "\u{1f64d}\u{1f3fc}", "\u{1f64d}\u{1f3fd}", "\u{1f64d}\u{1f3fe}", "\u{1f64d}\u{1f3ff}"]),
+    ("\u{1f64d}\u{200d}\u{2640}\u{fe0f}", ["\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f64d}\u{200d}\u{2642}\u{fe0f}", ["\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f64e}", ["\u{1f64e}\u{1f3fb}", "\u{1f64e}\u{1f3fc}", "\u{1f64e}\u{1f3fd}", "\u{1f64e}\u{1f3fe}", "\u{1f64e}\u{1f3ff}"]),
+    ("\u{1f64e}\u{200d}\u{2640}\u{fe0f}", ["\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f64e}\u{200d}\u{2642}\u{fe0f}", ["\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f64f}", ["\u{1f64f}\u{1f3fb}", "\u{1f64f}\u{1f3fc}", "\u{1f64f}\u{1f3fd}", "\u{1f64f}\u{1f3fe}", "\u{1f64f}\u{1f3ff}"]),
+    ("\u{1f6a3}", ["\u{1f6a3}\u{1f3fb}", "\u{1f6a3}\u{1f3fc}", "\u{1f6a3}\u{1f3fd}", "\u{1f6a3}\u{1f3fe}", "\u{1f6a3}\u{1f3ff}"]),
+    ("\u{1f6a3}\u{200d}\u{2640}\u{fe0f}", ["\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f6a3}\u{200d}\u{2642}\u{fe0f}", ["\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f6b4}", ["\u{1f6b4}\u{1f3fb}", "\u{1f6b4}\u{1f3fc}", "\u{1f6b4}\u{1f3fd}", "\u{1f6b4}\u{1f3fe}", "\u{1f6b4}\u{1f3ff}"]),
+    ("\u{1f6b4}\u{200d}\u{2640}\u{fe0f}", ["\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f6b4}\u{200d}\u{2642}\u{fe0f}", ["\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f6b5}", ["\u{1f6b5}\u{1f3fb}", "\u{1f6b5}\u{1f3fc}", "\u{1f6b5}\u{1f3fd}", "\u{1f6b5}\u{1f3fe}", "\u{1f6b5}\u{1f3ff}"]),
+    ("\u{1f6b5}\u{200d}\u{2640}\u{fe0f}", ["\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f6b5}\u{200d}\u{2642}\u{fe0f}", ["\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f6b6}", ["\u{1f6b6}\u{1f3fb}", "\u{1f6b6}\u{1f3fc}", "\u{1f6b6}\u{1f3fd}", "\u{1f6b6}\u{1f3fe}", "\u{1f6b6}\u{1f3ff}"]),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}", ["\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}", ["\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f6b6}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", "\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f6c0}", ["\u{1f6c0}\u{1f3fb}", "\u{1f6c0}\u{1f3fc}", "\u{1f6c0}\u{1f3fd}", "\u{1f6c0}\u{1f3fe}", "\u{1f6c0}\u{1f3ff}"]),
+    ("\u{1f6cc}", ["\u{1f6cc}\u{1f3fb}", "\u{1f6cc}\u{1f3fc}", "\u{1f6cc}\u{1f3fd}", "\u{1f6cc}\u{1f3fe}", "\u{1f6cc}\u{1f3ff}"]),
+    ("\u{1f90c}", ["\u{1f90c}\u{1f3fb}", "\u{1f90c}\u{1f3fc}", "\u{1f90c}\u{1f3fd}", "\u{1f90c}\u{1f3fe}", "\u{1f90c}\u{1f3ff}"]),
+    ("\u{1f90f}", ["\u{1f90f}\u{1f3fb}", "\u{1f90f}\u{1f3fc}", "\u{1f90f}\u{1f3fd}", "\u{1f90f}\u{1f3fe}", "\u{1f90f}\u{1f3ff}"]),
+    ("\u{1f918}", ["\u{1f918}\u{1f3fb}", "\u{1f918}\u{1f3fc}", "\u{1f918}\u{1f3fd}", "\u{1f918}\u{1f3fe}", "\u{1f918}\u{1f3ff}"]),
+    ("\u{1f919}", ["\u{1f919}\u{1f3fb}", "\u{1f919}\u{1f3fc}", "\u{1f919}\u{1f3fd}", "\u{1f919}\u{1f3fe}", "\u{1f919}\u{1f3ff}"]),
+    ("\u{1f91a}", ["\u{1f91a}\u{1f3fb}", "\u{1f91a}\u{1f3fc}", "\u{1f91a}\u{1f3fd}", "\u{1f91a}\u{1f3fe}", "\u{1f91a}\u{1f3ff}"]),
+    ("\u{1f91b}", ["\u{1f91b}\u{1f3fb}", "\u{1f91b}\u{1f3fc}", "\u{1f91b}\u{1f3fd}", "\u{1f91b}\u{1f3fe}", "\u{1f91b}\u{1f3ff}"]),
+    ("\u{1f91c}", ["\u{1f91c}\u{1f3fb}", "\u{1f91c}\u{1f3fc}", "\u{1f91c}\u{1f3fd}", "\u{1f91c}\u{1f3fe}", "\u{1f91c}\u{1f3ff}"]),
+    ("\u{1f91d}", ["\u{1f91d}\u{1f3fb}", "\u{1f91d}\u{1f3fc}", "\u{1f91d}\u{1f3fd}", "\u{1f91d}\u{1f3fe}", "\u{1f91d}\u{1f3ff}"]),
+    ("\u{1f91e}", ["\u{1f91e}\u{1f3fb}", "\u{1f91e}\u{1f3fc}", "\u{1f91e}\u{1f3fd}", "\u{1f91e}\u{1f3fe}", "\u{1f91e}\u{1f3ff}"]),
+    ("\u{1f91f}", ["\u{1f91f}\u{1f3fb}", "\u{1f91f}\u{1f3fc}", "\u{1f91f}\u{1f3fd}", "\u{1f91f}\u{1f3fe}", "\u{1f91f}\u{1f3ff}"]),
+    ("\u{1f926}", ["\u{1f926}\u{1f3fb}", "\u{1f926}\u{1f3fc}", "\u{1f926}\u{1f3fd}", "\u{1f926}\u{1f3fe}", "\u{1f926}\u{1f3ff}"]),
+    ("\u{1f926}\u{200d}\u{2640}\u{fe0f}", ["\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f926}\u{200d}\u{2642}\u{fe0f}", ["\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f930}", ["\u{1f930}\u{1f3fb}", "\u{1f930}\u{1f3fc}", "\u{1f930}\u{1f3fd}", "\u{1f930}\u{1f3fe}", "\u{1f930}\u{1f3ff}"]),
+    ("\u{1f931}", ["\u{1f931}\u{1f3fb}", "\u{1f931}\u{1f3fc}", "\u{1f931}\u{1f3fd}", "\u{1f931}\u{1f3fe}", "\u{1f931}\u{1f3ff}"]),
+    ("\u{1f932}", ["\u{1f932}\u{1f3fb}", "\u{1f932}\u{1f3fc}", "\u{1f932}\u{1f3fd}", "\u{1f932}\u{1f3fe}", "\u{1f932}\u{1f3ff}"]),
+    ("\u{1f933}", ["\u{1f933}\u{1f3fb}", "\u{1f933}\u{1f3fc}", "\u{1f933}\u{1f3fd}", "\u{1f933}\u{1f3fe}", "\u{1f933}\u{1f3ff}"]),
+    ("\u{1f934}", ["\u{1f934}\u{1f3fb}", "\u{1f934}\u{1f3fc}", "\u{1f934}\u{1f3fd}", "\u{1f934}\u{1f3fe}", "\u{1f934}\u{1f3ff}"]),
+    ("\u{1f935}", ["\u{1f935}\u{1f3fb}", "\u{1f935}\u{1f3fc}", "\u{1f935}\u{1f3fd}", "\u{1f935}\u{1f3fe}", "\u{1f935}\u{1f3ff}"]),
+    ("\u{1f935}\u{200d}\u{2640}\u{fe0f}", ["\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f935}\u{200d}\u{2642}\u{fe0f}", ["\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f936}", ["\u{1f936}\u{1f3fb}", "\u{1f936}\u{1f3fc}", "\u{1f936}\u{1f3fd}", "\u{1f936}\u{1f3fe}", "\u{1f936}\u{1f3ff}"]),
+    ("\u{1f937}", ["\u{1f937}\u{1f3fb}", "\u{1f937}\u{1f3fc}", "\u{1f937}\u{1f3fd}", "\u{1f937}\u{1f3fe}", "\u{1f937}\u{1f3ff}"]),
+    ("\u{1f937}\u{200d}\u{2640}\u{fe0f}", ["\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f937}\u{200d}\u{2642}\u{fe0f}", ["\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f938}", ["\u{1f938}\u{1f3fb}", "\u{1f938}\u{1f3fc}", "\u{1f938}\u{1f3fd}", "\u{1f938}\u{1f3fe}", "\u{1f938}\u{1f3ff}"]),
+    ("\u{1f938}\u{200d}\u{2640}\u{fe0f}", ["\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f938}\u{200d}\u{2642}\u{fe0f}", ["\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f939}", ["\u{1f939}\u{1f3fb}", "\u{1f939}\u{1f3fc}", "\u{1f939}\u{1f3fd}", "\u{1f939}\u{1f3fe}", "\u{1f939}\u{1f3ff}"]),
+    ("\u{1f939}\u{200d}\u{2640}\u{fe0f}", ["\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f939}\u{200d}\u{2642}\u{fe0f}", ["\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f93d}", ["\u{1f93d}\u{1f3fb}", "\u{1f93d}\u{1f3fc}", "\u{1f93d}\u{1f3fd}", "\u{1f93d}\u{1f3fe}", "\u{1f93d}\u{1f3ff}"]),
+    ("\u{1f93d}\u{200d}\u{2640}\u{fe0f}", ["\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f93d}\u{200d}\u{2642}\u{fe0f}", ["\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f93e}", ["\u{1f93e}\u{1f3fb}", "\u{1f93e}\u{1f3fc}", "\u{1f93e}\u{1f3fd}", "\u{1f93e}\u{1f3fe}", "\u{1f93e}\u{1f3ff}"]),
+    ("\u{1f93e}\u{200d}\u{2640}\u{fe0f}", ["\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f93e}\u{200d}\u{2642}\u{fe0f}", ["\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f977}", ["\u{1f977}\u{1f3fb}", "\u{1f977}\u{1f3fc}", "\u{1f977}\u{1f3fd}", "\u{1f977}\u{1f3fe}", "\u{1f977}\u{1f3ff}"]),
+    ("\u{1f9b5}", ["\u{1f9b5}\u{1f3fb}", "\u{1f9b5}\u{1f3fc}", "\u{1f9b5}\u{1f3fd}", "\u{1f9b5}\u{1f3fe}", "\u{1f9b5}\u{1f3ff}"]),
+    ("\u{1f9b6}", ["\u{1f9b6}\u{1f3fb}", "\u{1f9b6}\u{1f3fc}", "\u{1f9b6}\u{1f3fd}", "\u{1f9b6}\u{1f3fe}", "\u{1f9b6}\u{1f3ff}"]),
+    ("\u{1f9b8}", ["\u{1f9b8}\u{1f3fb}", "\u{1f9b8}\u{1f3fc}", "\u{1f9b8}\u{1f3fd}", "\u{1f9b8}\u{1f3fe}", "\u{1f9b8}\u{1f3ff}"]),
+    ("\u{1f9b8}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9b8}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9b9}", ["\u{1f9b9}\u{1f3fb}", "\u{1f9b9}\u{1f3fc}", "\u{1f9b9}\u{1f3fd}", "\u{1f9b9}\u{1f3fe}", "\u{1f9b9}\u{1f3ff}"]),
+    ("\u{1f9b9}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9b9}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9bb}", ["\u{1f9bb}\u{1f3fb}", "\u{1f9bb}\u{1f3fc}", "\u{1f9bb}\u{1f3fd}", "\u{1f9bb}\u{1f3fe}", "\u{1f9bb}\u{1f3ff}"]),
+    ("\u{1f9cd}", ["\u{1f9cd}\u{1f3fb}", "\u{1f9cd}\u{1f3fc}", "\u{1f9cd}\u{1f3fd}", "\u{1f9cd}\u{1f3fe}", "\u{1f9cd}\u{1f3ff}"]),
+    ("\u{1f9cd}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9cd}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9ce}", ["\u{1f9ce}\u{1f3fb}", "\u{1f9ce}\u{1f3fc}", "\u{1f9ce}\u{1f3fd}", "\u{1f9ce}\u{1f3fe}", "\u{1f9ce}\u{1f3ff}"]),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f9ce}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f9cf}", ["\u{1f9cf}\u{1f3fb}", "\u{1f9cf}\u{1f3fc}", "\u{1f9cf}\u{1f3fd}", "\u{1f9cf}\u{1f3fe}", "\u{1f9cf}\u{1f3ff}"]),
+    ("\u{1f9cf}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9cf}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9d1}", ["\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3ff}"]),
+    ("\u{1f9d1}\u{200d}\u{2695}\u{fe0f}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}"]),
+    ("\u{1f9d1}\u{200d}\u{2696}\u{fe0f}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}"]),
+    ("\u{1f9d1}\u{200d}\u{2708}\u{fe0f}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}"]),
+    ("\u{1f9d1}\u{200d}\u{1f33e}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f33e}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f33e}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f33e}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f33e}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f33e}"]),
+    ("\u{1f9d1}\u{200d}\u{1f373}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f373}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f373}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f373}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f373}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f373}"]),
+    ("\u{1f9d1}\u{200d}\u{1f37c}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f37c}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f37c}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f37c}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f37c}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f37c}"]),
+    ("\u{1f9d1}\u{200d}\u{1f384}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f384}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f384}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f384}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f384}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f384}"]),
+    ("\u{1f9d1}\u{200d}\u{1f393}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f393}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f393}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f393}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f393}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f393}"]),
+    ("\u{1f9d1}\u{200d}\u{1f3a4}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a4}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a4}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a4}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a4}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a4}"]),
+    ("\u{1f9d1}\u{200d}\u{1f3a8}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a8}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a8}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a8}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a8}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a8}"]),
+    ("\u{1f9d1}\u{200d}\u{1f3eb}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3eb}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3eb}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3eb}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3eb}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3eb}"]),
+    ("\u{1f9d1}\u{200d}\u{1f3ed}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3ed}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3ed}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3ed}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3ed}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3ed}"]),
+    ("\u{1f9d1}\u{200d}\u{1f4bb}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bb}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bb}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bb}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bb}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bb}"]),
+    ("\u{1f9d1}\u{200d}\u{1f4bc}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bc}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bc}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bc}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bc}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bc}"]),
+    ("\u{1f9d1}\u{200d}\u{1f527}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f527}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f527}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f527}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f527}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f527}"]),
+    ("\u{1f9d1}\u{200d}\u{1f52c}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f52c}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f52c}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f52c}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f52c}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f52c}"]),
+    ("\u{1f9d1}\u{200d}\u{1f680}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f680}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f680}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f680}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f680}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f680}"]),
+    ("\u{1f9d1}\u{200d}\u{1f692}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f692}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f692}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f692}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f692}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f692}"]),
+    ("\u{1f9d1}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9af}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9b0}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b0}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b0}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b0}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b0}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b0}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9b1}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b1}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b1}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b1}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b1}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b1}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9b2}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b2}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b2}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b2}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b2}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b2}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9b3}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b3}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b3}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b3}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b3}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b3}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}"]),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", ["\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}"]),
+    ("\u{1f9d2}", ["\u{1f9d2}\u{1f3fb}", "\u{1f9d2}\u{1f3fc}", "\u{1f9d2}\u{1f3fd}", "\u{1f9d2}\u{1f3fe}", "\u{1f9d2}\u{1f3ff}"]),
+    ("\u{1f9d3}", ["\u{1f9d3}\u{1f3fb}", "\u{1f9d3}\u{1f3fc}", "\u{1f9d3}\u{1f3fd}", "\u{1f9d3}\u{1f3fe}", "\u{1f9d3}\u{1f3ff}"]),
+    ("\u{1f9d4}", ["\u{1f9d4}\u{1f3fb}", "\u{1f9d4}\u{1f3fc}", "\u{1f9d4}\u{1f3fd}", "\u{1f9d4}\u{1f3fe}", "\u{1f9d4}\u{1f3ff}"]),
+    ("\u{1f9d4}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9d4}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9d5}", ["\u{1f9d5}\u{1f3fb}", "\u{1f9d5}\u{1f3fc}", "\u{1f9d5}\u{1f3fd}", "\u{1f9d5}\u{1f3fe}", "\u{1f9d5}\u{1f3ff}"]),
+    ("\u{1f9d6}", ["\u{1f9d6}\u{1f3fb}", "\u{1f9d6}\u{1f3fc}", "\u{1f9d6}\u{1f3fd}", "\u{1f9d6}\u{1f3fe}", "\u{1f9d6}\u{1f3ff}"]),
+    ("\u{1f9d6}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9d6}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9d7}", ["\u{1f9d7}\u{1f3fb}", "\u{1f9d7}\u{1f3fc}", "\u{1f9d7}\u{1f3fd}", "\u{1f9d7}\u{1f3fe}", "\u{1f9d7}\u{1f3ff}"]),
+    ("\u{1f9d7}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9d7}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9d8}", ["\u{1f9d8}\u{1f3fb}", "\u{1f9d8}\u{1f3fc}", "\u{1f9d8}\u{1f3fd}", "\u{1f9d8}\u{1f3fe}", "\u{1f9d8}\u{1f3ff}"]),
+    ("\u{1f9d8}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9d8}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9d9}", ["\u{1f9d9}\u{1f3fb}", "\u{1f9d9}\u{1f3fc}", "\u{1f9d9}\u{1f3fd}", "\u{1f9d9}\u{1f3fe}", "\u{1f9d9}\u{1f3ff}"]),
+    ("\u{1f9d9}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9d9}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9da}", ["\u{1f9da}\u{1f3fb}", "\u{1f9da}\u{1f3fc}", "\u{1f9da}\u{1f3fd}", "\u{1f9da}\u{1f3fe}", "\u{1f9da}\u{1f3ff}"]),
+    ("\u{1f9da}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9da}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9db}", ["\u{1f9db}\u{1f3fb}", "\u{1f9db}\u{1f3fc}", "\u{1f9db}\u{1f3fd}", "\u{1f9db}\u{1f3fe}", "\u{1f9db}\u{1f3ff}"]),
+    ("\u{1f9db}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9db}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9dc}", ["\u{1f9dc}\u{1f3fb}", "\u{1f9dc}\u{1f3fc}", "\u{1f9dc}\u{1f3fd}", "\u{1f9dc}\u{1f3fe}", "\u{1f9dc}\u{1f3ff}"]),
+    ("\u{1f9dc}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9dc}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1f9dd}", ["\u{1f9dd}\u{1f3fb}", "\u{1f9dd}\u{1f3fc}", "\u{1f9dd}\u{1f3fd}", "\u{1f9dd}\u{1f3fe}", "\u{1f9dd}\u{1f3ff}"]),
+    ("\u{1f9dd}\u{200d}\u{2640}\u{fe0f}", ["\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}"]),
+    ("\u{1f9dd}\u{200d}\u{2642}\u{fe0f}", ["\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}"]),
+    ("\u{1fac3}", ["\u{1fac3}\u{1f3fb}", "\u{1fac3}\u{1f3fc}", "\u{1fac3}\u{1f3fd}", "\u{1fac3}\u{1f3fe}", "\u{1fac3}\u{1f3ff}"]),
+    ("\u{1fac4}", ["\u{1fac4}\u{1f3fb}", "\u{1fac4}\u{1f3fc}", "\u{1fac4}\u{1f3fd}", "\u{1fac4}\u{1f3fe}", "\u{1fac4}\u{1f3ff}"]),
+    ("\u{1fac5}", ["\u{1fac5}\u{1f3fb}", "\u{1fac5}\u{1f3fc}", "\u{1fac5}\u{1f3fd}", "\u{1fac5}\u{1f3fe}", "\u{1fac5}\u{1f3ff}"]),
+    ("\u{1faf0}", ["\u{1faf0}\u{1f3fb}", "\u{1faf0}\u{1f3fc}", "\u{1faf0}\u{1f3fd}", "\u{1faf0}\u{1f3fe}", "\u{1faf0}\u{1f3ff}"]),
+    ("\u{1faf1}", ["\u{1faf1}\u{1f3fb}", "\u{1faf1}\u{1f3fc}", "\u{1faf1}\u{1f3fd}", "\u{1faf1}\u{1f3fe}", "\u{1faf1}\u{1f3ff}"]),
+    ("\u{1faf2}", ["\u{1faf2}\u{1f3fb}", "\u{1faf2}\u{1f3fc}", "\u{1faf2}\u{1f3fd}", "\u{1faf2}\u{1f3fe}", "\u{1faf2}\u{1f3ff}"]),
+    ("\u{1faf3}", ["\u{1faf3}\u{1f3fb}", "\u{1faf3}\u{1f3fc}", "\u{1faf3}\u{1f3fd}", "\u{1faf3}\u{1f3fe}", "\u{1faf3}\u{1f3ff}"]),
+    ("\u{1faf4}", ["\u{1faf4}\u{1f3fb}", "\u{1faf4}\u{1f3fc}", "\u{1faf4}\u{1f3fd}", "\u{1faf4}\u{1f3fe}", "\u{1faf4}\u{1f3ff}"]),
+    ("\u{1faf5}", ["\u{1faf5}\u{1f3fb}", "\u{1faf5}\u{1f3fc}", "\u{1faf5}\u{1f3fd}", "\u{1faf5}\u{1f3fe}", "\u{1faf5}\u{1f3ff}"]),
+    ("\u{1faf6}", ["\u{1faf6}\u{1f3fb}", "\u{1faf6}\u{1f3fc}", "\u{1faf6}\u{1f3fd}", "\u{1faf6}\u{1f3fe}", "\u{1faf6}\u{1f3ff}"]),
+    ("\u{1faf7}", ["\u{1faf7}\u{1f3fb}", "\u{1faf7}\u{1f3fc}", "\u{1faf7}\u{1f3fd}", "\u{1faf7}\u{1f3fe}", "\u{1faf7}\u{1f3ff}"]),
+    ("\u{1faf8}", ["\u{1faf8}\u{1f3fb}", "\u{1faf8}\u{1f3fc}", "\u{1faf8}\u{1f3fd}", "\u{1faf8}\u{1f3fe}", "\u{1faf8}\u{1f3ff}"]),
+];
@@ -0,0 +1,3914 @@
+// DO NOT EDIT, generated by `cargo xtask generate-unicode-tables` from unicode/emoji-test.txt.
+
+/// Emoji groups in the order of emoji-test.txt
+pub(crate) const EMOJI_GROUPS: &[&str] = &[
+    "Smileys & Emotion",
+    "People & Body",
+    "Component",
+    "Animals & Nature",
+    "Food & Drink",
+    "Travel & Places",
+    "Activities",
+    "Objects",
+    "Symbols",
+    "Flags",
+];
+
+/// Emoji subgroups in the order of emoji-test.txt, with the index of their group in [EMOJI_GROUPS]
+pub(crate) const EMOJI_SUBGROUPS: &[(&str, u8)] = &[
+    ("face-smiling", 0),
+    ("face-affection", 0),
+    ("face-tongue", 0),
+    ("face-hand", 0),
+    ("face-neutral-skeptical", 0),
+    ("face-sleepy", 0),
+    ("face-unwell", 0),
+    ("face-hat", 0),
+    ("face-glasses", 0),
+    ("face-concerned", 0),
+    ("face-negative", 0),
+    ("face-costume", 0),
+    ("cat-face", 0),
+    ("monkey-face", 0),
+    ("heart", 0),
+    ("emotion", 0),
+    ("hand-fingers-open", 1),
+    ("hand-fingers-partial", 1),
+    ("hand-single-finger", 1),
+    ("hand-fingers-closed", 1),
+    ("hands", 1),
+    ("hand-prop", 1),
+    ("body-parts", 1),
+    ("person", 1),
+    ("person-gesture", 1),
+    ("person-role", 1),
+    ("person-fantasy", 1),
+    ("person-activity", 1),
+    ("person-sport", 1),
+    ("person-resting", 1),
+    ("family", 1),
+    ("person-symbol", 1),
+    ("skin-tone", 2),
+    ("hair-style", 2),
+    ("animal-mammal", 3),
+    ("animal-bird", 3),
+    ("animal-amphibian", 3),
+    ("animal-reptile", 3),
+    ("animal-marine", 3),
+    ("animal-bug", 3),
+    ("plant-flower", 3),
+    ("plant-other", 3),
+    ("food-fruit", 4),
+    ("food-vegetable", 4),
+    ("food-prepared", 4),
+    ("food-asian", 4),
+    ("food-sweet", 4),
+    ("drink", 4),
+    ("dishware", 4),
+    ("place-map", 5),
+    ("place-geographic", 5),
+    ("place-building", 5),
+    ("place-religious", 5),
+    ("place-other", 5),
+    ("transport-ground", 5),
+    ("transport-water", 5),
+    ("transport-air", 5),
+    ("hotel", 5),
+    ("time", 5),
+    ("sky & weather", 5),
+    ("event", 6),
+    ("award-medal", 6),
+    ("sport", 6),
+    ("game", 6),
+    ("arts & crafts", 6),
+    ("clothing", 7),
+    ("sound", 7),
+    ("music", 7),
+    ("musical-instrument", 7),
+    ("phone", 7),
+    ("computer", 7),
+    ("light & video", 7),
+    ("book-paper", 7),
+    ("money", 7),
+    ("mail", 7),
+    ("writing", 7),
+    ("office", 7),
+    ("lock", 7),
+    ("tool", 7),
+    ("science", 7),
+    ("medical", 7),
+    ("household", 7),
+    ("other-object", 7),
+    ("transport-sign", 8),
+    ("warning", 8),
+    ("arrow", 8),
+    ("religion", 8),
+    ("zodiac", 8),
+    ("av-symbol", 8),
+    ("gender", 8),
+    ("math", 8),
+    ("punctuation", 8),
+    ("currency", 8),
+    ("other-symbol", 8),
+    ("keycap", 8),
+    ("alphanum", 8),
+    ("geometric", 8),
+    ("flag", 9),
+    ("country-flag", 9),
+    ("subdivision-flag", 9),
+];
+
+/// Emoji with their name, the index of their subgroup in [EMOJI_SUBGROUPS]
+/// and the emoji version they were introduced in, sorted for binary search
+pub(crate) const EMOJI_METADATA: &[(&str, &str, u8, (u8, u8))] = &[
+    ("\u{23}\u{fe0f}\u{20e3}", "keycap: #", 94, (0, 6)),
+    ("\u{2a}\u{fe0f}\u{20e3}", "keycap: *", 94, (2, 0)),
+    ("\u{30}\u{fe0f}\u{20e3}", "keycap: 0", 94, (0, 6)),
+    ("\u{31}\u{fe0f}\u{20e3}", "keycap: 1", 94, (0, 6)),
+    ("\u{32}\u{fe0f}\u{20e3}", "keycap: 2", 94, (0, 6)),
+    ("\u{33}\u{fe0f}\u{20e3}", "keycap: 3", 94, (0, 6)),
+    ("\u{34}\u{fe0f}\u{20e3}", "keycap: 4", 94, (0, 6)),
+    ("\u{35}\u{fe0f}\u{20e3}", "keycap: 5", 94, (0, 6)),
+    ("\u{36}\u{fe0f}\u{20e3}", "keycap: 6", 94, (0, 6)),
+    ("\u{37}\u{fe0f}\u{20e3}", "keycap: 7", 94, (0, 6)),
+    ("\u{38}\u{fe0f}\u{20e3}", "keycap: 8", 94, (0, 6)),
+    ("\u{39}\u{fe0f}\u{20e3}", "keycap: 9", 94, (0, 6)),
+    ("\u{a9}\u{fe0f}", "copyright", 93, (0, 6)),
+    ("\u{ae}\u{fe0f}", "registered", 93, (0, 6)),
+    ("\u{203c}\u{fe0f}", "double exclamation mark", 91, (0, 6)),
+    ("\u{2049}\u{fe0f}", "exclamation question mark", 91, (0, 6)),
+    ("\u{2122}\u{fe0f}", "trade mark", 93, (0, 6)),
+    ("\u{2139}\u{fe0f}", "information", 95, (0, 6)),
+    ("\u{2194}\u{fe0f}", "left-right arrow", 85, (0, 6)),
+    ("\u{2195}\u{fe0f}", "up-down arrow", 85, (0, 6)),
+    ("\u{2196}\u{fe0f}", "up-left arrow", 85, (0, 6)),
+    ("\u{2197}\u{fe0f}", "up-right arrow", 85, (0, 6)),
+    ("\u{2198}\u{fe0f}", "down-right arrow", 85, (0, 6)),
+    ("\u{2199}\u{fe0f}", "down-left arrow", 85, (0, 6)),
+    ("\u{21a9}\u{fe0f}", "right arrow curving left", 85, (0, 6)),
+    ("\u{21aa}\u{fe0f}", "left arrow curving right", 85, (0, 6)),
+    ("\u{231a}", "watch", 58, (0, 6)),
+    ("\u{231b}", "hourglass done", 58, (0, 6)),
+    ("\u{2328}\u{fe0f}", "keyboard", 70, (1, 0)),
+    ("\u{23cf}\u{fe0f}", "eject button", 88, (1, 0)),
+    ("\u{23e9}", "fast-forward button", 88, (0, 6)),
+    ("\u{23ea}", "fast reverse button", 88, (0, 6)),
+    ("\u{23eb}", "fast up button", 88, (0, 6)),
+    ("\u{23ec}", "fast down button", 88, (0, 6)),
+    ("\u{23ed}\u{fe0f}", "next track button", 88, (0, 7)),
+    ("\u{23ee}\u{fe0f}", "last track button", 88, (0, 7)),
+    ("\u{23ef}\u{fe0f}", "play or pause button", 88, (1, 0)),
+    ("\u{23f0}", "alarm clock", 58, (0, 6)),
+    ("\u{23f1}\u{fe0f}", "stopwatch", 58, (1, 0)),
+    ("\u{23f2}\u{fe0f}", "timer clock", 58, (1, 0)),
+    ("\u{23f3}", "hourglass not done", 58, (0, 6)),
+    ("\u{23f8}\u{fe0f}", "pause button", 88, (0, 7)),
+    ("\u{23f9}\u{fe0f}", "stop button", 88, (0, 7)),
+    ("\u{23fa}\u{fe0f}", "record button", 88, (0, 7)),
+    ("\u{24c2}\u{fe0f}", "circled M", 95, (0, 6)),
+    ("\u{25aa}\u{fe0f}", "black small square", 96, (0, 6)),
+    ("\u{25ab}\u{fe0f}", "white small square", 96, (0, 6)),
+    ("\u{25b6}\u{fe0f}", "play button", 88, (0, 6)),
+    ("\u{25c0}\u{fe0f}", "reverse button", 88, (0, 6)),
+    ("\u{25fb}\u{fe0f}", "white medium square", 96, (0, 6)),
+    ("\u{25fc}\u{fe0f}", "black medium square", 96, (0, 6)),
+    ("\u{25fd}", "white medium-small square", 96, (0, 6)),
+    ("\u{25fe}", "black medium-small square", 96, (0, 6)),
+    ("\u{2600}\u{fe0f}", "sun", 59, (0, 6)),
+    ("\u{2601}\u{fe0f}", "cloud", 59, (0, 6)),
+    ("\u{2602}\u{fe0f}", "umbrella", 59, (0, 7)),
+    ("\u{2603}\u{fe0f}", "snowman", 59, (0, 7)),
+    ("\u{2604}\u{fe0f}", "comet", 59, (1, 0)),
+    ("\u{260e}\u{fe0f}", "telephone", 69, (0, 6)),
+    ("\u{2611}\u{fe0f}", "check box with check", 93, (0, 6)),
+    ("\u{2614}", "umbrella with rain drops", 59, (0, 6)),
+    ("\u{2615}", "hot beverage", 47, (0, 6)),
+    ("\u{2618}\u{fe0f}", "shamrock", 41, (1, 0)),
+    ("\u{261d}\u{fe0f}", "index pointing up", 18, (0, 6)),
+    ("\u{261d}\u{1f3fb}", "index pointing up: light skin tone", 18, (1, 0)),
+    ("\u{261d}\u{1f3fc}", "index pointing up: medium-light skin tone", 18, (1, 0)),
+    ("\u{261d}\u{1f3fd}", "index pointing up: medium skin tone", 18, (1, 0)),
+    ("\u{261d}\u{1f3fe}", "index pointing up: medium-dark skin tone", 18, (1, 0)),
+    ("\u{261d}\u{1f3ff}", "index pointing up: dark skin tone", 18, (1, 0)),
+    ("\u{2620}\u{fe0f}", "skull and crossbones", 10, (1, 0)),
+    ("\u{2622}\u{fe0f}", "radioactive", 84, (1, 0)),
+    ("\u{2623}\u{fe0f}", "biohazard", 84, (1, 0)),
+    ("\u{2626}\u{fe0f}", "orthodox cross", 86, (1, 0)),
+    ("\u{262a}\u{fe0f}", "star and crescent", 86, (0, 7)),
+    ("\u{262e}\u{fe0f}", "peace symbol", 86, (1, 0)),
+    ("\u{262f}\u{fe0f}", "yin yang", 86, (0, 7)),
+    ("\u{2638}\u{fe0f}", "wheel of dharma", 86, (0, 7)),
+    ("\u{2639}\u{fe0f}", "frowning face", 9, (0, 7)),
+    ("\u{263a}\u{fe0f}", "smiling face", 1, (0, 6)),
+    ("\u{2640}\u{fe0f}", "female sign", 89, (4, 0)),
+    ("\u{2642}\u{fe0f}", "male sign", 89, (4, 0)),
+    ("\u{2648}", "Aries", 87, (0, 6)),
+    ("\u{2649}", "Taurus", 87, (0, 6)),
+    ("\u{264a}", "Gemini", 87, (0, 6)),
+    ("\u{264b}", "Cancer", 87, (0, 6)),
+    ("\u{264c}", "Leo", 87, (0, 6)),
+    ("\u{264d}", "Virgo", 87, (0, 6)),
+    ("\u{264e}", "Libra", 87, (0, 6)),
+    ("\u{264f}", "Scorpio", 87, (0, 6)),
+    ("\u{2650}", "Sagittarius", 87, (0, 6)),
+    ("\u{2651}", "Capricorn", 87, (0, 6)),
+    ("\u{2652}", "Aquarius", 87, (0, 6)),
+    ("\u{2653}", "Pisces", 87, (0, 6)),
+    ("\u{265f}\u{fe0f}", "chess pawn", 63, (11, 0)),
+    ("\u{2660}\u{fe0f}", "spade suit", 63, (0, 6)),
+    ("\u{2663}\u{fe0f}", "club suit", 63, (0, 6)),
+    ("\u{2665}\u{fe0f}", "heart suit", 63, (0, 6)),
+    ("\u{2666}\u{fe0f}", "diamond suit", 63, (0, 6)),
+    ("\u{2668}\u{fe0f}", "hot springs", 53, (0, 6)),
+    ("\u{267b}\u{fe0f}", "recycling symbol", 93, (0, 6)),
+    ("\u{267e}\u{fe0f}", "infinity", 90, (11, 0)),
+    ("\u{267f}", "wheelchair symbol", 83, (0, 6)),
+    ("\u{2692}\u{fe0f}", "hammer and pick", 78, (1, 0)),
+    ("\u{2693}", "anchor", 55, (0, 6)),
+    ("\u{2694}\u{fe0f}", "crossed swords", 78, (1, 0)),
+    ("\u{2695}\u{fe0f}", "medical symbol", 93, (4, 0)),
+    ("\u{2696}\u{fe0f}", "balance scale", 78, (1, 0)),
+    ("\u{2697}\u{fe0f}", "alembic", 79, (1, 0)),
+    ("\u{2699}\u{fe0f}", "gear", 78, (1, 0)),
+    ("\u{269b}\u{fe0f}", "atom symbol", 86, (1, 0)),
+    ("\u{269c}\u{fe0f}", "fleur-de-lis", 93, (1, 0)),
+    ("\u{26a0}\u{fe0f}", "warning", 84, (0, 6)),
+    ("\u{26a1}", "high voltage", 59, (0, 6)),
+    ("\u{26a7}\u{fe0f}", "transgender symbol", 89, (13, 0)),
+    ("\u{26aa}", "white circle", 96, (0, 6)),
+    ("\u{26ab}", "black circle", 96, (0, 6)),
+    ("\u{26b0}\u{fe0f}", "coffin", 82, (1, 0)),
+    ("\u{26b1}\u{fe0f}", "funeral urn", 82, (1, 0)),
+    ("\u{26bd}", "soccer ball", 62, (0, 6)),
+    ("\u{26be}", "baseball", 62, (0, 6)),
+    ("\u{26c4}", "snowman without snow", 59, (0, 6)),
+    ("\u{26c5}", "sun behind cloud", 59, (0, 6)),
+    ("\u{26c8}\u{fe0f}", "cloud with lightning and rain", 59, (0, 7)),
+    ("\u{26ce}", "Ophiuchus", 87, (0, 6)),
+    ("\u{26cf}\u{fe0f}", "pick", 78, (0, 7)),
+    ("\u{26d1}\u{fe0f}", "rescue worker’s helmet", 65, (0, 7)),
+    ("\u{26d3}\u{fe0f}", "chains", 78, (0, 7)),
+    ("\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}", "broken chain", 78, (15, 1)),
+    ("\u{26d4}", "no entry", 84, (0, 6)),
+    ("\u{26e9}\u{fe0f}", "shinto shrine", 52, (0, 7)),
+    ("\u{26ea}", "church", 52, (0, 6)),
+    ("\u{26f0}\u{fe0f}", "mountain", 50, (0, 7)),
+    ("\u{26f1}\u{fe0f}", "umbrella on ground", 59, (0, 7)),
+    ("\u{26f2}", "fountain", 53, (0, 6)),
+    ("\u{26f3}", "flag in hole", 62, (0, 6)),
+    ("\u{26f4}\u{fe0f}", "ferry", 55, (0, 7)),
+    ("\u{26f5}", "sailboat", 55, (0, 6)),
+    ("\u{26f7}\u{fe0f}", "skier", 28, (0, 7)),
+    ("\u{26f8}\u{fe0f}", "ice skate", 62, (0, 7)),
+    ("\u{26f9}\u{fe0f}", "person bouncing ball", 28, (0, 7)),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", "woman bouncing ball", 28, (4, 0)),
+    ("\u{26f9}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", "man bouncing ball", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fb}", "person bouncing ball: light skin tone", 28, (2, 0)),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman bouncing ball: light skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man bouncing ball: light skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fc}", "person bouncing ball: medium-light skin tone", 28, (2, 0)),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman bouncing ball: medium-light skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man bouncing ball: medium-light skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fd}", "person bouncing ball: medium skin tone", 28, (2, 0)),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman bouncing ball: medium skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man bouncing ball: medium skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fe}", "person bouncing ball: medium-dark skin tone", 28, (2, 0)),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman bouncing ball: medium-dark skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man bouncing ball: medium-dark skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3ff}", "person bouncing ball: dark skin tone", 28, (2, 0)),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman bouncing ball: dark skin tone", 28, (4, 0)),
+    ("\u{26f9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man bouncing ball: dark skin tone", 28, (4, 0)),
+    ("\u{26fa}", "tent", 53, (0, 6)),
+    ("\u{26fd}", "fuel pump", 54, (0, 6)),
+    ("\u{2702}\u{fe0f}", "scissors", 76, (0, 6)),
+    ("\u{2705}", "check mark button", 93, (0, 6)),
+    ("\u{2708}\u{fe0f}", "airplane", 56, (0, 6)),
+    ("\u{2709}\u{fe0f}", "envelope", 74, (0, 6)),
+    ("\u{270a}", "raised fist", 19, (0, 6)),
+    ("\u{270a}\u{1f3fb}", "raised fist: light skin tone", 19, (1, 0)),
+    ("\u{270a}\u{1f3fc}", "raised fist: medium-light skin tone", 19, (1, 0)),
+    ("\u{270a}\u{1f3fd}", "raised fist: medium skin tone", 19, (1, 0)),
+    ("\u{270a}\u{1f3fe}", "raised fist: medium-dark skin tone", 19, (1, 0)),
+    ("\u{270a}\u{1f3ff}", "raised fist: dark skin tone", 19, (1, 0)),
+    ("\u{270b}", "raised hand", 16, (0, 6)),
+    ("\u{270b}\u{1f3fb}", "raised hand: light skin tone", 16, (1, 0)),
+    ("\u{270b}\u{1f3fc}", "raised hand: medium-light skin tone", 16, (1, 0)),
+    ("\u{270b}\u{1f3fd}", "raised hand: medium skin tone", 16, (1, 0)),
+    ("\u{270b}\u{1f3fe}", "raised hand: medium-dark skin tone", 16, (1, 0)),
+    ("\u{270b}\u{1f3ff}", "raised hand: dark skin tone", 16, (1, 0)),
+    ("\u{270c}\u{fe0f}", "victory hand", 17, (0, 6)),
+    ("\u{270c}\u{1f3fb}", "victory hand: light skin tone", 17, (1, 0)),
+    ("\u{270c}\u{1f3fc}", "victory hand: medium-light skin tone", 17, (1, 0)),
+    ("\u{270c}\u{1f3fd}", "victory hand: medium skin tone", 17, (1, 0)),
+    ("\u{270c}\u{1f3fe}", "victory hand: medium-dark skin tone", 17, (1, 0)),
+    ("\u{270c}\u{1f3ff}", "victory hand: dark skin tone", 17, (1, 0)),
+    ("\u{270d}\u{fe0f}", "writing hand", 21, (0, 7)),
+    ("\u{270d}\u{1f3fb}", "writing hand: light skin tone", 21, (1, 0)),
+    ("\u{270d}\u{1f3fc}", "writing hand: medium-light skin tone", 21, (1, 0)),
+    ("\u{270d}\u{1f3fd}", "writing hand: medium skin tone", 21, (1, 0)),
+    ("\u{270d}\u{1f3fe}", "writing hand: medium-dark skin tone", 21, (1, 0)),
+    ("\u{270d}\u{1f3ff}", "writing hand: dark skin tone", 21, (1, 0)),
+    ("\u{270f}\u{fe0f}", "pencil", 75, (0, 6)),
+    ("\u{2712}\u{fe0f}", "black nib", 75, (0, 6)),
+    ("\u{2714}\u{fe0f}", "check mark", 93, (0, 6)),
+    ("\u{2716}\u{fe0f}", "multiply", 90, (0, 6)),
+    ("\u{271d}\u{fe0f}", "latin cross", 86, (0, 7)),
+    ("\u{2721}\u{fe0f}", "star of David", 86, (0, 7)),
+    ("\u{2728}", "sparkles", 60, (0, 6)),
+    ("\u{2733}\u{fe0f}", "eight-spoked asterisk", 93, (0, 6)),
+    ("\u{2734}\u{fe0f}", "eight-pointed star", 93, (0, 6)),
+    ("\u{2744}\u{fe0f}", "snowflake", 59, (0, 6)),
+    ("\u{2747}\u{fe0f}", "sparkle", 93, (0, 6)),
+    ("\u{274c}", "cross mark", 93, (0, 6)),
+    ("\u{274e}", "cross mark button", 93, (0, 6)),
+    ("\u{2753}", "red question mark", 91, (0, 6)),
+    ("\u{2754}", "white question mark", 91, (0, 6)),
+    ("\u{2755}", "white exclamation mark", 91, (0, 6)),
+    ("\u{2757}", "red exclamation mark", 91, (0, 6)),
+    ("\u{2763}\u{fe0f}", "heart exclamation", 14, (1, 0)),
+    ("\u{2764}\u{fe0f}", "red heart", 14, (0, 6)),
+    ("\u{2764}\u{fe0f}\u{200d}\u{1f525}", "heart on fire", 14, (13, 1)),
+    ("\u{2764}\u{fe0f}\u{200d}\u{1fa79}", "mending heart", 14, (13, 1)),
+    ("\u{2795}", "plus", 90, (0, 6)),
+    ("\u{2796}", "minus", 90, (0, 6)),
+    ("\u{2797}", "divide", 90, (0, 6)),
+    ("\u{27a1}\u{fe0f}", "right arrow", 85, (0, 6)),
+    ("\u{27b0}", "curly loop", 93, (0, 6)),
+    ("\u{27bf}", "double curly loop", 93, (1, 0)),
+    ("\u{2934}\u{fe0f}", "right arrow curving up", 85, (0, 6)),
+    ("\u{2935}\u{fe0f}", "right arrow curving down", 85, (0, 6)),
+    ("\u{2b05}\u{fe0f}", "left arrow", 85, (0, 6)),
+    ("\u{2b06}\u{fe0f}", "up arrow", 85, (0, 6)),
+    ("\u{2b07}\u{fe0f}", "down arrow", 85, (0, 6)),
+    ("\u{2b1b}", "black large square", 96, (0, 6)),
+    ("\u{2b1c}", "white large square", 96, (0, 6)),
+    ("\u{2b50}", "star", 59, (0, 6)),
+    ("\u{2b55}", "hollow red circle", 93, (0, 6)),
+    ("\u{3030}\u{fe0f}", "wavy dash", 91, (0, 6)),
+    ("\u{303d}\u{fe0f}", "part alternation mark", 93, (0, 6)),
+    ("\u{3297}\u{fe0f}", "Japanese “congratulations” button", 95, (0, 6)),
+    ("\u{3299}\u{fe0f}", "Japanese “secret” button", 95, (0, 6)),
+    ("\u{1f004}", "mahjong red dragon", 63, (0, 6)),
+    ("\u{1f0cf}", "joker", 63, (0, 6)),
+    ("\u{1f170}\u{fe0f}", "A button (blood type)", 95, (0, 6)),
+    ("\u{1f171}\u{fe0f}", "B button (blood type)", 95, (0, 6)),
+    ("\u{1f17e}\u{fe0f}", "O button (blood type)", 95, (0, 6)),
+    ("\u{1f17f}\u{fe0f}", "P button", 95, (0, 6)),
+    ("\u{1f18e}", "AB button (blood type)", 95, (0, 6)),
+    ("\u{1f191}", "CL button", 95, (0, 6)),
+    ("\u{1f192}", "COOL button", 95, (0, 6)),
+    ("\u{1f193}", "FREE button", 95, (0, 6)),
+    ("\u{1f194}", "ID button", 95, (0, 6)),
+    ("\u{1f195}", "NEW button", 95, (0, 6)),
+    ("\u{1f196}", "NG button", 95, (0, 6)),
+    ("\u{1f197}", "OK button", 95, (0, 6)),
+    ("\u{1f198}", "SOS button", 95, (0, 6)),
+    ("\u{1f199}", "UP! button", 95, (0, 6)),
+    ("\u{1f19a}", "VS button", 95, (0, 6)),
+    ("\u{1f1e6}\u{1f1e8}", "flag: Ascension Island", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1e9}", "flag: Andorra", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1ea}", "flag: United Arab Emirates", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1eb}", "flag: Afghanistan", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1ec}", "flag: Antigua & Barbuda", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1ee}", "flag: Anguilla", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f1}", "flag: Albania", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f2}", "flag: Armenia", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f4}", "flag: Angola", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f6}", "flag: Antarctica", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f7}", "flag: Argentina", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f8}", "flag: American Samoa", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1f9}", "flag: Austria", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1fa}", "flag: Australia", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1fc}", "flag: Aruba", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1fd}", "flag: Åland Islands", 98, (2, 0)),
+    ("\u{1f1e6}\u{1f1ff}", "flag: Azerbaijan", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1e6}", "flag: Bosnia & Herzegovina", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1e7}", "flag: Barbados", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1e9}", "flag: Bangladesh", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1ea}", "flag: Belgium", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1eb}", "flag: Burkina Faso", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1ec}", "flag: Bulgaria", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1ed}", "flag: Bahrain", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1ee}", "flag: Burundi", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1ef}", "flag: Benin", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f1}", "flag: St. Barthélemy", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f2}", "flag: Bermuda", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f3}", "flag: Brunei", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f4}", "flag: Bolivia", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f6}", "flag: Caribbean Netherlands", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f7}", "flag: Brazil", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f8}", "flag: Bahamas", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1f9}", "flag: Bhutan", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1fb}", "flag: Bouvet Island", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1fc}", "flag: Botswana", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1fe}", "flag: Belarus", 98, (2, 0)),
+    ("\u{1f1e7}\u{1f1ff}", "flag: Belize", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1e6}", "flag: Canada", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1e8}", "flag: Cocos (Keeling) Islands", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1e9}", "flag: Congo - Kinshasa", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1eb}", "flag: Central African Republic", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1ec}", "flag: Congo - Brazzaville", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1ed}", "flag: Switzerland", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1ee}", "flag: Côte d’Ivoire", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1f0}", "flag: Cook Islands", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1f1}", "flag: Chile", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1f2}", "flag: Cameroon", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1f3}", "flag: China", 98, (0, 6)),
+    ("\u{1f1e8}\u{1f1f4}", "flag: Colombia", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1f5}", "flag: Clipperton Island", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1f6}", "flag: Sark", 98, (16, 0)),
+    ("\u{1f1e8}\u{1f1f7}", "flag: Costa Rica", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1fa}", "flag: Cuba", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1fb}", "flag: Cape Verde", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1fc}", "flag: Curaçao", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1fd}", "flag: Christmas Island", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1fe}", "flag: Cyprus", 98, (2, 0)),
+    ("\u{1f1e8}\u{1f1ff}", "flag: Czechia", 98, (2, 0)),
+    ("\u{1f1e9}\u{1f1ea}", "flag: Germany", 98, (0, 6)),
+    ("\u{1f1e9}\u{1f1ec}", "flag: Diego Garcia", 98, (2, 0)),
+    ("\u{1f1e9}\u{1f1ef}", "flag: Djibouti", 98, (2, 0)),
+    ("\u{1f1e9}\u{1f1f0}", "flag: Denmark", 98, (2, 0)),
+    ("\u{1f1e9}\u{1f1f2}", "flag: Dominica", 98, (2, 0)),
+    ("\u{1f1e9}\u{1f1f4}", "flag: Dominican Republic", 98, (2, 0)),
+    ("\u{1f1e9}\u{1f1ff}", "flag: Algeria", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1e6}", "flag: Ceuta & Melilla", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1e8}", "flag: Ecuador", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1ea}", "flag: Estonia", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1ec}", "flag: Egypt", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1ed}", "flag: Western Sahara", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1f7}", "flag: Eritrea", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1f8}", "flag: Spain", 98, (0, 6)),
+    ("\u{1f1ea}\u{1f1f9}", "flag: Ethiopia", 98, (2, 0)),
+    ("\u{1f1ea}\u{1f1fa}", "flag: European Union", 98, (2, 0)),
+    ("\u{1f1eb}\u{1f1ee}", "flag: Finland", 98, (2, 0)),
+    ("\u{1f1eb}\u{1f1ef}", "flag: Fiji", 98, (2, 0)),
+    ("\u{1f1eb}\u{1f1f0}", "flag: Falkland Islands", 98, (2, 0)),
+    ("\u{1f1eb}\u{1f1f2}", "flag: Micronesia", 98, (2, 0)),
+    ("\u{1f1eb}\u{1f1f4}", "flag: Faroe Islands", 98, (2, 0)),
+    ("\u{1f1eb}\u{1f1f7}", "flag: France", 98, (0, 6)),
+    ("\u{1f1ec}\u{1f1e6}", "flag: Gabon", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1e7}", "flag: United Kingdom", 98, (0, 6)),
+    ("\u{1f1ec}\u{1f1e9}", "flag: Grenada", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1ea}", "flag: Georgia", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1eb}", "flag: French Guiana", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1ec}", "flag: Guernsey", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1ed}", "flag: Ghana", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1ee}", "flag: Gibraltar", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f1}", "flag: Greenland", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f2}", "flag: Gambia", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f3}", "flag: Guinea", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f5}", "flag: Guadeloupe", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f6}", "flag: Equatorial Guinea", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f7}", "flag: Greece", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f8}", "flag: South Georgia & South Sandwich Islands", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1f9}", "flag: Guatemala", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1fa}", "flag: Guam", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1fc}", "flag: Guinea-Bissau", 98, (2, 0)),
+    ("\u{1f1ec}\u{1f1fe}", "flag: Guyana", 98, (2, 0)),
+    ("\u{1f1ed}\u{1f1f0}", "flag: Hong Kong SAR China", 98, (2, 0)),
+    ("\u{1f1ed}\u{1f1f2}", "flag: Heard & McDonald Islands", 98, (2, 0)),
+    ("\u{1f1ed}\u{1f1f3}", "flag: Honduras", 98, (2, 0)),
+    ("\u{1f1ed}\u{1f1f7}", "flag: Croatia", 98, (2, 0)),
+    ("\u{1f1ed}\u{1f1f9}", "flag: Haiti", 98, (2, 0)),
+    ("\u{1f1ed}\u{1f1fa}", "flag: Hungary", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1e8}", "flag: Canary Islands", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1e9}", "flag: Indonesia", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1ea}", "flag: Ireland", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f1}", "flag: Israel", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f2}", "flag: Isle of Man", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f3}", "flag: India", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f4}", "flag: British Indian Ocean Territory", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f6}", "flag: Iraq", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f7}", "flag: Iran", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f8}", "flag: Iceland", 98, (2, 0)),
+    ("\u{1f1ee}\u{1f1f9}", "flag: Italy", 98, (0, 6)),
+    ("\u{1f1ef}\u{1f1ea}", "flag: Jersey", 98, (2, 0)),
+    ("\u{1f1ef}\u{1f1f2}", "flag: Jamaica", 98, (2, 0)),
+    ("\u{1f1ef}\u{1f1f4}", "flag: Jordan", 98, (2, 0)),
+    ("\u{1f1ef}\u{1f1f5}", "flag: Japan", 98, (0, 6)),
+    ("\u{1f1f0}\u{1f1ea}", "flag: Kenya", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1ec}", "flag: Kyrgyzstan", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1ed}", "flag: Cambodia", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1ee}", "flag: Kiribati", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1f2}", "flag: Comoros", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1f3}", "flag: St. Kitts & Nevis", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1f5}", "flag: North Korea", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1f7}", "flag: South Korea", 98, (0, 6)),
+    ("\u{1f1f0}\u{1f1fc}", "flag: Kuwait", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1fe}", "flag: Cayman Islands", 98, (2, 0)),
+    ("\u{1f1f0}\u{1f1ff}", "flag: Kazakhstan", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1e6}", "flag: Laos", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1e7}", "flag: Lebanon", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1e8}", "flag: St. Lucia", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1ee}", "flag: Liechtenstein", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1f0}", "flag: Sri Lanka", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1f7}", "flag: Liberia", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1f8}", "flag: Lesotho", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1f9}", "flag: Lithuania", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1fa}", "flag: Luxembourg", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1fb}", "flag: Latvia", 98, (2, 0)),
+    ("\u{1f1f1}\u{1f1fe}", "flag: Libya", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1e6}", "flag: Morocco", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1e8}", "flag: Monaco", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1e9}", "flag: Moldova", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1ea}", "flag: Montenegro", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1eb}", "flag: St. Martin", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1ec}", "flag: Madagascar", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1ed}", "flag: Marshall Islands", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f0}", "flag: North Macedonia", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f1}", "flag: Mali", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f2}", "flag: Myanmar (Burma)", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f3}", "flag: Mongolia", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f4}", "flag: Macao SAR China", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f5}", "flag: Northern Mariana Islands", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f6}", "flag: Martinique", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f7}", "flag: Mauritania", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f8}", "flag: Montserrat", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1f9}", "flag: Malta", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1fa}", "flag: Mauritius", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1fb}", "flag: Maldives", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1fc}", "flag: Malawi", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1fd}", "flag: Mexico", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1fe}", "flag: Malaysia", 98, (2, 0)),
+    ("\u{1f1f2}\u{1f1ff}", "flag: Mozambique", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1e6}", "flag: Namibia", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1e8}", "flag: New Caledonia", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1ea}", "flag: Niger", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1eb}", "flag: Norfolk Island", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1ec}", "flag: Nigeria", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1ee}", "flag: Nicaragua", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1f1}", "flag: Netherlands", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1f4}", "flag: Norway", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1f5}", "flag: Nepal", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1f7}", "flag: Nauru", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1fa}", "flag: Niue", 98, (2, 0)),
+    ("\u{1f1f3}\u{1f1ff}", "flag: New Zealand", 98, (2, 0)),
+    ("\u{1f1f4}\u{1f1f2}", "flag: Oman", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1e6}", "flag: Panama", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1ea}", "flag: Peru", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1eb}", "flag: French Polynesia", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1ec}", "flag: Papua New Guinea", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1ed}", "flag: Philippines", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f0}", "flag: Pakistan", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f1}", "flag: Poland", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f2}", "flag: St. Pierre & Miquelon", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f3}", "flag: Pitcairn Islands", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f7}", "flag: Puerto Rico", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f8}", "flag: Palestinian Territories", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1f9}", "flag: Portugal", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1fc}", "flag: Palau", 98, (2, 0)),
+    ("\u{1f1f5}\u{1f1fe}", "flag: Paraguay", 98, (2, 0)),
+    ("\u{1f1f6}\u{1f1e6}", "flag: Qatar", 98, (2, 0)),
+    ("\u{1f1f7}\u{1f1ea}", "flag: Réunion", 98, (2, 0)),
+    ("\u{1f1f7}\u{1f1f4}", "flag: Romania", 98, (2, 0)),
+    ("\u{1f1f7}\u{1f1f8}", "flag: Serbia", 98, (2, 0)),
+    ("\u{1f1f7}\u{1f1fa}", "flag: Russia", 98, (0, 6)),
+    ("\u{1f1f7}\u{1f1fc}", "flag: Rwanda", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1e6}", "flag: Saudi Arabia", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1e7}", "flag: Solomon Islands", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1e8}", "flag: Seychelles", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1e9}", "flag: Sudan", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1ea}", "flag: Sweden", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1ec}", "flag: Singapore", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1ed}", "flag: St. Helena", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1ee}", "flag: Slovenia", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1ef}", "flag: Svalbard & Jan Mayen", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f0}", "flag: Slovakia", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f1}", "flag: Sierra Leone", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f2}", "flag: San Marino", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f3}", "flag: Senegal", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f4}", "flag: Somalia", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f7}", "flag: Suriname", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f8}", "flag: South Sudan", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1f9}", "flag: São Tomé & Príncipe", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1fb}", "flag: El Salvador", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1fd}", "flag: Sint Maarten", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1fe}", "flag: Syria", 98, (2, 0)),
+    ("\u{1f1f8}\u{1f1ff}", "flag: Eswatini", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1e6}", "flag: Tristan da Cunha", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1e8}", "flag: Turks & Caicos Islands", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1e9}", "flag: Chad", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1eb}", "flag: French Southern Territories", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1ec}", "flag: Togo", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1ed}", "flag: Thailand", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1ef}", "flag: Tajikistan", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f0}", "flag: Tokelau", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f1}", "flag: Timor-Leste", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f2}", "flag: Turkmenistan", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f3}", "flag: Tunisia", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f4}", "flag: Tonga", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f7}", "flag: Türkiye", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1f9}", "flag: Trinidad & Tobago", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1fb}", "flag: Tuvalu", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1fc}", "flag: Taiwan", 98, (2, 0)),
+    ("\u{1f1f9}\u{1f1ff}", "flag: Tanzania", 98, (2, 0)),
+    ("\u{1f1fa}\u{1f1e6}", "flag: Ukraine", 98, (2, 0)),
+    ("\u{1f1fa}\u{1f1ec}", "flag: Uganda", 98, (2, 0)),
+    ("\u{1f1fa}\u{1f1f2}", "flag: U.S. Outlying Islands", 98, (2, 0)),
+    ("\u{1f1fa}\u{1f1f3}", "flag: United Nations", 98, (4, 0)),
+    ("\u{1f1fa}\u{1f1f8}", "flag: United States", 98, (0, 6)),
+    ("\u{1f1fa}\u{1f1fe}", "flag: Uruguay", 98, (2, 0)),
+    ("\u{1f1fa}\u{1f1ff}", "flag: Uzbekistan", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1e6}", "flag: Vatican City", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1e8}", "flag: St. Vincent & Grenadines", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1ea}", "flag: Venezuela", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1ec}", "flag: British Virgin Islands", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1ee}", "flag: U.S. Virgin Islands", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1f3}", "flag: Vietnam", 98, (2, 0)),
+    ("\u{1f1fb}\u{1f1fa}", "flag: Vanuatu", 98, (2, 0)),
+    ("\u{1f1fc}\u{1f1eb}", "flag: Wallis & Futuna", 98, (2, 0)),
+    ("\u{1f1fc}\u{1f1f8}", "flag: Samoa", 98, (2, 0)),
+    ("\u{1f1fd}\u{1f1f0}", "flag: Kosovo", 98, (2, 0)),
+    ("\u{1f1fe}\u{1f1ea}", "flag: Yemen", 98, (2, 0)),
+    ("\u{1f1fe}\u{1f1f9}", "flag: Mayotte", 98, (2, 0)),
+    ("\u{1f1ff}\u{1f1e6}", "flag: South Africa", 98, (2, 0)),
+    ("\u{1f1ff}\u{1f1f2}", "flag: Zambia", 98, (2, 0)),
+    ("\u{1f1ff}\u{1f1fc}", "flag: Zimbabwe", 98, (2, 0)),
+    ("\u{1f201}", "Japanese “here” button", 95, (0, 6)),
+    ("\u{1f202}\u{fe0f}", "Japanese “service charge” button", 95, (0, 6)),
+    ("\u{1f21a}", "Japanese “free of charge” button", 95, (0, 6)),
+    ("\u{1f22f}", "Japanese “reserved” button", 95, (0, 6)),
+    ("\u{1f232}", "Japanese “prohibited” button", 95, (0, 6)),
+    ("\u{1f233}", "Japanese “vacancy” button", 95, (0, 6)),
+    ("\u{1f234}", "Japanese “passing grade” button", 95, (0, 6)),
+    ("\u{1f235}", "Japanese “no vacancy” button", 95, (0, 6)),
+    ("\u{1f236}", "Japanese “not free of charge” button", 95, (0, 6)),
+    ("\u{1f237}\u{fe0f}", "Japanese “monthly amount” button", 95, (0, 6)),
+    ("\u{1f238}", "Japanese “application” button", 95, (0, 6)),
+    ("\u{1f239}", "Japanese “discount” button", 95, (0, 6)),
+    ("\u{1f23a}", "Japanese “open for business” button", 95, (0, 6)),
+    ("\u{1f250}", "Japanese “bargain” button", 95, (0, 6)),
+    ("\u{1f251}", "Japanese “acceptable” button", 95, (0, 6)),
+    ("\u{1f300}", "cyclone", 59, (0, 6)),
+    ("\u{1f301}", "foggy", 53, (0, 6)),
+    ("\u{1f302}", "closed umbrella", 59, (0, 6)),
+    ("\u{1f303}", "night with stars", 53, (0, 6)),
+    ("\u{1f304}", "sunrise over mountains", 53, (0, 6)),
+    ("\u{1f305}", "sunrise", 53, (0, 6)),
+    ("\u{1f306}", "cityscape at dusk", 53, (0, 6)),
+    ("\u{1f307}", "sunset", 53, (0, 6)),
+    ("\u{1f308}", "rainbow", 59, (0, 6)),
+    ("\u{1f309}", "bridge at night", 53, (0, 6)),
+    ("\u{1f30a}", "water wave", 59, (0, 6)),
+    ("\u{1f30b}", "volcano", 50, (0, 6)),
+    ("\u{1f30c}", "milky way", 59, (0, 6)),
+    ("\u{1f30d}", "globe showing Europe-Africa", 49, (0, 7)),
+    ("\u{1f30e}", "globe showing Americas", 49, (0, 7)),
+    ("\u{1f30f}", "globe showing Asia-Australia", 49, (0, 6)),
+    ("\u{1f310}", "globe with meridians", 49, (1, 0)),
+    ("\u{1f311}", "new moon", 59, (0, 6)),
+    ("\u{1f312}", "waxing crescent moon", 59, (1, 0)),
+    ("\u{1f313}", "first quarter moon", 59, (0, 6)),
+    ("\u{1f314}", "waxing gibbous moon", 59, (0, 6)),
+    ("\u{1f315}", "full moon", 59, (0, 6)),
+    ("\u{1f316}", "waning gibbous moon", 59, (1, 0)),
+    ("\u{1f317}", "last quarter moon", 59, (1, 0)),
+    ("\u{1f318}", "waning crescent moon", 59, (1, 0)),
+    ("\u{1f319}", "crescent moon", 59, (0, 6)),
+    ("\u{1f31a}", "new moon face", 59, (1, 0)),
+    ("\u{1f31b}", "first quarter moon face", 59, (0, 6)),
+    ("\u{1f31c}", "last quarter moon face", 59, (0, 7)),
+    ("\u{1f31d}", "full moon face", 59, (1, 0)),
+    ("\u{1f31e}", "sun with face", 59, (1, 0)),
+    ("\u{1f31f}", "glowing star", 59, (0, 6)),
+    ("\u{1f320}", "shooting star", 59, (0, 6)),
+    ("\u{1f321}\u{fe0f}", "thermometer", 59, (0, 7)),
+    ("\u{1f324}\u{fe0f}", "sun behind small cloud", 59, (0, 7)),
+    ("\u{1f325}\u{fe0f}", "sun behind large cloud", 59, (0, 7)),
+    ("\u{1f326}\u{fe0f}", "sun behind rain cloud", 59, (0, 7)),
+    ("\u{1f327}\u{fe0f}", "cloud with rain", 59, (0, 7)),
+    ("\u{1f328}\u{fe0f}", "cloud with snow", 59, (0, 7)),
+    ("\u{1f329}\u{fe0f}", "cloud with lightning", 59, (0, 7)),
+    ("\u{1f32a}\u{fe0f}", "tornado", 59, (0, 7)),
+    ("\u{1f32b}\u{fe0f}", "fog", 59, (0, 7)),
+    ("\u{1f32c}\u{fe0f}", "wind face", 59, (0, 7)),
+    ("\u{1f32d}", "hot dog", 44, (1, 0)),
+    ("\u{1f32e}", "taco", 44, (1, 0)),
+    ("\u{1f32f}", "burrito", 44, (1, 0)),
+    ("\u{1f330}", "chestnut", 43, (0, 6)),
+    ("\u{1f331}", "seedling", 41, (0, 6)),
+    ("\u{1f332}", "evergreen tree", 41, (1, 0)),
+    ("\u{1f333}", "deciduous tree", 41, (1, 0)),
+    ("\u{1f334}", "palm tree", 41, (0, 6)),
+    ("\u{1f335}", "cactus", 41, (0, 6)),
+    ("\u{1f336}\u{fe0f}", "hot pepper", 43, (0, 7)),
+    ("\u{1f337}", "tulip", 40, (0, 6)),
+    ("\u{1f338}", "cherry blossom", 40, (0, 6)),
+    ("\u{1f339}", "rose", 40, (0, 6)),
+    ("\u{1f33a}", "hibiscus", 40, (0, 6)),
+    ("\u{1f33b}", "sunflower", 40, (0, 6)),
+    ("\u{1f33c}", "blossom", 40, (0, 6)),
+    ("\u{1f33d}", "ear of corn", 43, (0, 6)),
+    ("\u{1f33e}", "sheaf of rice", 41, (0, 6)),
+    ("\u{1f33f}", "herb", 41, (0, 6)),
+    ("\u{1f340}", "four leaf clover", 41, (0, 6)),
+    ("\u{1f341}", "maple leaf", 41, (0, 6)),
+    ("\u{1f342}", "fallen leaf", 41, (0, 6)),
+    ("\u{1f343}", "leaf fluttering in wind", 41, (0, 6)),
+    ("\u{1f344}", "mushroom", 41, (0, 6)),
+    ("\u{1f344}\u{200d}\u{1f7eb}", "brown mushroom", 43, (15, 1)),
+    ("\u{1f345}", "tomato", 42, (0, 6)),
+    ("\u{1f346}", "eggplant", 43, (0, 6)),
+    ("\u{1f347}", "grapes", 42, (0, 6)),
+    ("\u{1f348}", "melon", 42, (0, 6)),
+    ("\u{1f349}", "watermelon", 42, (0, 6)),
+    ("\u{1f34a}", "tangerine", 42, (0, 6)),
+    ("\u{1f34b}", "lemon", 42, (1, 0)),
+    ("\u{1f34b}\u{200d}\u{1f7e9}", "lime", 42, (15, 1)),
+    ("\u{1f34c}", "banana", 42, (0, 6)),
+    ("\u{1f34d}", "pineapple", 42, (0, 6)),
+    ("\u{1f34e}", "red apple", 42, (0, 6)),
+    ("\u{1f34f}", "green apple", 42, (0, 6)),
+    ("\u{1f350}", "pear", 42, (1, 0)),
+    ("\u{1f351}", "peach", 42, (0, 6)),
+    ("\u{1f352}", "cherries", 42, (0, 6)),
+    ("\u{1f353}", "strawberry", 42, (0, 6)),
+    ("\u{1f354}", "hamburger", 44, (0, 6)),
+    ("\u{1f355}", "pizza", 44, (0, 6)),
+    ("\u{1f356}", "meat on bone", 44, (0, 6)),
+    ("\u{1f357}", "poultry leg", 44, (0, 6)),
+    ("\u{1f358}", "rice cracker", 45, (0, 6)),
+    ("\u{1f359}", "rice ball", 45, (0, 6)),
+    ("\u{1f35a}", "cooked rice", 45, (0, 6)),
+    ("\u{1f35b}", "curry rice", 45, (0, 6)),
+    ("\u{1f35c}", "steaming bowl", 45, (0, 6)),
+    ("\u{1f35d}", "spaghetti", 45, (0, 6)),
+    ("\u{1f35e}", "bread", 44, (0, 6)),
+    ("\u{1f35f}", "french fries", 44, (0, 6)),
+    ("\u{1f360}", "roasted sweet potato", 45, (0, 6)),
+    ("\u{1f361}", "dango", 45, (0, 6)),
+    ("\u{1f362}", "oden", 45, (0, 6)),
+    ("\u{1f363}", "sushi", 45, (0, 6)),
+    ("\u{1f364}", "fried shrimp", 45, (0, 6)),
+    ("\u{1f365}", "fish cake with swirl", 45, (0, 6)),
+    ("\u{1f366}", "soft ice cream", 46, (0, 6)),
+    ("\u{1f367}", "shaved ice", 46, (0, 6)),
+    ("\u{1f368}", "ice cream", 46, (0, 6)),
+    ("\u{1f369}", "doughnut", 46, (0, 6)),
+    ("\u{1f36a}", "cookie", 46, (0, 6)),
+    ("\u{1f36b}", "chocolate bar", 46, (0, 6)),
+    ("\u{1f36c}", "candy", 46, (0, 6)),
+    ("\u{1f36d}", "lollipop", 46, (0, 6)),
+    ("\u{1f36e}", "custard", 46, (0, 6)),
+    ("\u{1f36f}", "honey pot", 46, (0, 6)),
+    ("\u{1f370}", "shortcake", 46, (0, 6)),
+    ("\u{1f371}", "bento box", 45, (0, 6)),
+    ("\u{1f372}", "pot of food", 44, (0, 6)),
+    ("\u{1f373}", "cooking", 44, (0, 6)),
+    ("\u{1f374}", "fork and knife", 48, (0, 6)),
+    ("\u{1f375}", "teacup without handle", 47, (0, 6)),
+    ("\u{1f376}", "sake", 47, (0, 6)),
+    ("\u{1f377}", "wine glass", 47, (0, 6)),
+    ("\u{1f378}", "cocktail glass", 47, (0, 6)),
+    ("\u{1f379}", "tropical drink", 47, (0, 6)),
+    ("\u{1f37a}", "beer mug", 47, (0, 6)),
+    ("\u{1f37b}", "clinking beer mugs", 47, (0, 6)),
+    ("\u{1f37c}", "baby bottle", 47, (1, 0)),
+    ("\u{1f37d}\u{fe0f}", "fork and knife with plate", 48, (0, 7)),
+    ("\u{1f37e}", "bottle with popping cork", 47, (1, 0)),
+    ("\u{1f37f}", "popcorn", 44, (1, 0)),
+    ("\u{1f380}", "ribbon", 60, (0, 6)),
+    ("\u{1f381}", "wrapped gift", 60, (0, 6)),
+    ("\u{1f382}", "birthday cake", 46, (0, 6)),
+    ("\u{1f383}", "jack-o-lantern", 60, (0, 6)),
+    ("\u{1f384}", "Christmas tree", 60, (0, 6)),
+    ("\u{1f385}", "Santa Claus", 26, (0, 6)),
+    ("\u{1f385}\u{1f3fb}", "Santa Claus: light skin tone", 26, (1, 0)),
+    ("\u{1f385}\u{1f3fc}", "Santa Claus: medium-light skin tone", 26, (1, 0)),
+    ("\u{1f385}\u{1f3fd}", "Santa Claus: medium skin tone", 26, (1, 0)),
+    ("\u{1f385}\u{1f3fe}", "Santa Claus: medium-dark skin tone", 26, (1, 0)),
+    ("\u{1f385}\u{1f3ff}", "Santa Claus: dark skin tone", 26, (1, 0)),
+    ("\u{1f386}", "fireworks", 60, (0, 6)),
+    ("\u{1f387}", "sparkler", 60, (0, 6)),
+    ("\u{1f388}", "balloon", 60, (0, 6)),
+    ("\u{1f389}", "party popper", 60, (0, 6)),
+    ("\u{1f38a}", "confetti ball", 60, (0, 6)),
+    ("\u{1f38b}", "tanabata tree", 60, (0, 6)),
+    ("\u{1f38c}", "crossed flags", 97, (0, 6)),
+    ("\u{1f38d}", "pine decoration", 60, (0, 6)),
+    ("\u{1f38e}", "Japanese dolls", 60, (0, 6)),
+    ("\u{1f38f}", "carp streamer", 60, (0, 6)),
+    ("\u{1f390}", "wind chime", 60, (0, 6)),
+    ("\u{1f391}", "moon viewing ceremony", 60, (0, 6)),
+    ("\u{1f392}", "backpack", 65, (0, 6)),
+    ("\u{1f393}", "graduation cap", 65, (0, 6)),
+    ("\u{1f396}\u{fe0f}", "military medal", 61, (0, 7)),
+    ("\u{1f397}\u{fe0f}", "reminder ribbon", 60, (0, 7)),
+    ("\u{1f399}\u{fe0f}", "studio microphone", 67, (0, 7)),
+    ("\u{1f39a}\u{fe0f}", "level slider", 67, (0, 7)),
+    ("\u{1f39b}\u{fe0f}", "control knobs", 67, (0, 7)),
+    ("\u{1f39e}\u{fe0f}", "film frames", 71, (0, 7)),
+    ("\u{1f39f}\u{fe0f}", "admission tickets", 60, (0, 7)),
+    ("\u{1f3a0}", "carousel horse", 53, (0, 6)),
+    ("\u{1f3a1}", "ferris wheel", 53, (0, 6)),
+    ("\u{1f3a2}", "roller coaster", 53, (0, 6)),
+    ("\u{1f3a3}", "fishing pole", 62, (0, 6)),
+    ("\u{1f3a4}", "microphone", 67, (0, 6)),
+    ("\u{1f3a5}", "movie camera", 71, (0, 6)),
+    ("\u{1f3a6}", "cinema", 88, (0, 6)),
+    ("\u{1f3a7}", "headphone", 67, (0, 6)),
+    ("\u{1f3a8}", "artist palette", 64, (0, 6)),
+    ("\u{1f3a9}", "top hat", 65, (0, 6)),
+    ("\u{1f3aa}", "circus tent", 53, (0, 6)),
+    ("\u{1f3ab}", "ticket", 60, (0, 6)),
+    ("\u{1f3ac}", "clapper board", 71, (0, 6)),
+    ("\u{1f3ad}", "performing arts", 64, (0, 6)),
+    ("\u{1f3ae}", "video game", 63, (0, 6)),
+    ("\u{1f3af}", "bullseye", 63, (0, 6)),
+    ("\u{1f3b0}", "slot machine", 63, (0, 6)),
+    ("\u{1f3b1}", "pool 8 ball", 63, (0, 6)),
+    ("\u{1f3b2}", "game die", 63, (0, 6)),
+    ("\u{1f3b3}", "bowling", 62, (0, 6)),
+    ("\u{1f3b4}", "flower playing cards", 63, (0, 6)),
+    ("\u{1f3b5}", "musical note", 67, (0, 6)),
+    ("\u{1f3b6}", "musical notes", 67, (0, 6)),
+    ("\u{1f3b7}", "saxophone", 68, (0, 6)),
+    ("\u{1f3b8}", "guitar", 68, (0, 6)),
+    ("\u{1f3b9}", "musical keyboard", 68, (0, 6)),
+    ("\u{1f3ba}", "trumpet", 68, (0, 6)),
+    ("\u{1f3bb}", "violin", 68, (0, 6)),
+    ("\u{1f3bc}", "musical score", 67, (0, 6)),
+    ("\u{1f3bd}", "running shirt", 62, (0, 6)),
+    ("\u{1f3be}", "tennis", 62, (0, 6)),
+    ("\u{1f3bf}", "skis", 62, (0, 6)),
+    ("\u{1f3c0}", "basketball", 62, (0, 6)),
+    ("\u{1f3c1}", "chequered flag", 97, (0, 6)),
+    ("\u{1f3c2}", "snowboarder", 28, (0, 6)),
+    ("\u{1f3c2}\u{1f3fb}", "snowboarder: light skin tone", 28, (1, 0)),
+    ("\u{1f3c2}\u{1f3fc}", "snowboarder: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f3c2}\u{1f3fd}", "snowboarder: medium skin tone", 28, (1, 0)),
+    ("\u{1f3c2}\u{1f3fe}", "snowboarder: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f3c2}\u{1f3ff}", "snowboarder: dark skin tone", 28, (1, 0)),
+    ("\u{1f3c3}", "person running", 27, (0, 6)),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}", "woman running", 27, (4, 0)),
+    ("\u{1f3c3}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman running facing right", 27, (15, 1)),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}", "man running", 27, (4, 0)),
+    ("\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man running facing right", 27, (15, 1)),
+    ("\u{1f3c3}\u{200d}\u{27a1}\u{fe0f}", "person running facing right", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fb}", "person running: light skin tone", 27, (1, 0)),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman running: light skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman running facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man running: light skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man running facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", "person running facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fc}", "person running: medium-light skin tone", 27, (1, 0)),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman running: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman running facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man running: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man running facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", "person running facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fd}", "person running: medium skin tone", 27, (1, 0)),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman running: medium skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman running facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man running: medium skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man running facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", "person running facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fe}", "person running: medium-dark skin tone", 27, (1, 0)),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman running: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman running facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man running: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man running facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", "person running facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3ff}", "person running: dark skin tone", 27, (1, 0)),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman running: dark skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman running facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man running: dark skin tone", 27, (4, 0)),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man running facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f3c3}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", "person running facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f3c4}", "person surfing", 28, (0, 6)),
+    ("\u{1f3c4}\u{200d}\u{2640}\u{fe0f}", "woman surfing", 28, (4, 0)),
+    ("\u{1f3c4}\u{200d}\u{2642}\u{fe0f}", "man surfing", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fb}", "person surfing: light skin tone", 28, (1, 0)),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman surfing: light skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man surfing: light skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fc}", "person surfing: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman surfing: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man surfing: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fd}", "person surfing: medium skin tone", 28, (1, 0)),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman surfing: medium skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man surfing: medium skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fe}", "person surfing: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman surfing: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man surfing: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3ff}", "person surfing: dark skin tone", 28, (1, 0)),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman surfing: dark skin tone", 28, (4, 0)),
+    ("\u{1f3c4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man surfing: dark skin tone", 28, (4, 0)),
+    ("\u{1f3c5}", "sports medal", 61, (1, 0)),
+    ("\u{1f3c6}", "trophy", 61, (0, 6)),
+    ("\u{1f3c7}", "horse racing", 28, (1, 0)),
+    ("\u{1f3c7}\u{1f3fb}", "horse racing: light skin tone", 28, (1, 0)),
+    ("\u{1f3c7}\u{1f3fc}", "horse racing: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f3c7}\u{1f3fd}", "horse racing: medium skin tone", 28, (1, 0)),
+    ("\u{1f3c7}\u{1f3fe}", "horse racing: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f3c7}\u{1f3ff}", "horse racing: dark skin tone", 28, (1, 0)),
+    ("\u{1f3c8}", "american football", 62, (0, 6)),
+    ("\u{1f3c9}", "rugby football", 62, (1, 0)),
+    ("\u{1f3ca}", "person swimming", 28, (0, 6)),
+    ("\u{1f3ca}\u{200d}\u{2640}\u{fe0f}", "woman swimming", 28, (4, 0)),
+    ("\u{1f3ca}\u{200d}\u{2642}\u{fe0f}", "man swimming", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fb}", "person swimming: light skin tone", 28, (1, 0)),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman swimming: light skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man swimming: light skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fc}", "person swimming: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman swimming: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man swimming: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fd}", "person swimming: medium skin tone", 28, (1, 0)),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman swimming: medium skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man swimming: medium skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fe}", "person swimming: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman swimming: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man swimming: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3ff}", "person swimming: dark skin tone", 28, (1, 0)),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman swimming: dark skin tone", 28, (4, 0)),
+    ("\u{1f3ca}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man swimming: dark skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{fe0f}", "person lifting weights", 28, (0, 7)),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", "woman lifting weights", 28, (4, 0)),
+    ("\u{1f3cb}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", "man lifting weights", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fb}", "person lifting weights: light skin tone", 28, (2, 0)),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman lifting weights: light skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man lifting weights: light skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fc}", "person lifting weights: medium-light skin tone", 28, (2, 0)),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman lifting weights: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man lifting weights: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fd}", "person lifting weights: medium skin tone", 28, (2, 0)),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman lifting weights: medium skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man lifting weights: medium skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fe}", "person lifting weights: medium-dark skin tone", 28, (2, 0)),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman lifting weights: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man lifting weights: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3ff}", "person lifting weights: dark skin tone", 28, (2, 0)),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman lifting weights: dark skin tone", 28, (4, 0)),
+    ("\u{1f3cb}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man lifting weights: dark skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{fe0f}", "person golfing", 28, (0, 7)),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", "woman golfing", 28, (4, 0)),
+    ("\u{1f3cc}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", "man golfing", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fb}", "person golfing: light skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman golfing: light skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man golfing: light skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fc}", "person golfing: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman golfing: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man golfing: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fd}", "person golfing: medium skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman golfing: medium skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man golfing: medium skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fe}", "person golfing: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman golfing: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man golfing: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3ff}", "person golfing: dark skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman golfing: dark skin tone", 28, (4, 0)),
+    ("\u{1f3cc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man golfing: dark skin tone", 28, (4, 0)),
+    ("\u{1f3cd}\u{fe0f}", "motorcycle", 54, (0, 7)),
+    ("\u{1f3ce}\u{fe0f}", "racing car", 54, (0, 7)),
+    ("\u{1f3cf}", "cricket game", 62, (1, 0)),
+    ("\u{1f3d0}", "volleyball", 62, (1, 0)),
+    ("\u{1f3d1}", "field hockey", 62, (1, 0)),
+    ("\u{1f3d2}", "ice hockey", 62, (1, 0)),
+    ("\u{1f3d3}", "ping pong", 62, (1, 0)),
+    ("\u{1f3d4}\u{fe0f}", "snow-capped mountain", 50, (0, 7)),
+    ("\u{1f3d5}\u{fe0f}", "camping", 50, (0, 7)),
+    ("\u{1f3d6}\u{fe0f}", "beach with umbrella", 50, (0, 7)),
+    ("\u{1f3d7}\u{fe0f}", "building construction", 51, (0, 7)),
+    ("\u{1f3d8}\u{fe0f}", "houses", 51, (0, 7)),
+    ("\u{1f3d9}\u{fe0f}", "cityscape", 53, (0, 7)),
+    ("\u{1f3da}\u{fe0f}", "derelict house", 51, (0, 7)),
+    ("\u{1f3db}\u{fe0f}", "classical building", 51, (0, 7)),
+    ("\u{1f3dc}\u{fe0f}", "desert", 50, (0, 7)),
+    ("\u{1f3dd}\u{fe0f}", "desert island", 50, (0, 7)),
+    ("\u{1f3de}\u{fe0f}", "national park", 50, (0, 7)),
+    ("\u{1f3df}\u{fe0f}", "stadium", 51, (0, 7)),
+    ("\u{1f3e0}", "house", 51, (0, 6)),
+    ("\u{1f3e1}", "house with garden", 51, (0, 6)),
+    ("\u{1f3e2}", "office building", 51, (0, 6)),
+    ("\u{1f3e3}", "Japanese post office", 51, (0, 6)),
+    ("\u{1f3e4}", "post office", 51, (1, 0)),
+    ("\u{1f3e5}", "hospital", 51, (0, 6)),
+    ("\u{1f3e6}", "bank", 51, (0, 6)),
+    ("\u{1f3e7}", "ATM sign", 83, (0, 6)),
+    ("\u{1f3e8}", "hotel", 51, (0, 6)),
+    ("\u{1f3e9}", "love hotel", 51, (0, 6)),
+    ("\u{1f3ea}", "convenience store", 51, (0, 6)),
+    ("\u{1f3eb}", "school", 51, (0, 6)),
+    ("\u{1f3ec}", "department store", 51, (0, 6)),
+    ("\u{1f3ed}", "factory", 51, (0, 6)),
+    ("\u{1f3ee}", "red paper lantern", 71, (0, 6)),
+    ("\u{1f3ef}", "Japanese castle", 51, (0, 6)),
+    ("\u{1f3f0}", "castle", 51, (0, 6)),
+    ("\u{1f3f3}\u{fe0f}", "white flag", 97, (0, 7)),
+    ("\u{1f3f3}\u{fe0f}\u{200d}\u{26a7}\u{fe0f}", "transgender flag", 97, (13, 0)),
+    ("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}", "rainbow flag", 97, (4, 0)),
+    ("\u{1f3f4}", "black flag", 97, (1, 0)),
+    ("\u{1f3f4}\u{200d}\u{2620}\u{fe0f}", "pirate flag", 97, (11, 0)),
+    ("\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", "flag: England", 99, (5, 0)),
+    ("\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", "flag: Scotland", 99, (5, 0)),
+    ("\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", "flag: Wales", 99, (5, 0)),
+    ("\u{1f3f5}\u{fe0f}", "rosette", 40, (0, 7)),
+    ("\u{1f3f7}\u{fe0f}", "label", 72, (0, 7)),
+    ("\u{1f3f8}", "badminton", 62, (1, 0)),
+    ("\u{1f3f9}", "bow and arrow", 78, (1, 0)),
+    ("\u{1f3fa}", "amphora", 48, (1, 0)),
+    ("\u{1f3fb}", "light skin tone", 32, (1, 0)),
+    ("\u{1f3fc}", "medium-light skin tone", 32, (1, 0)),
+    ("\u{1f3fd}", "medium skin tone", 32, (1, 0)),
+    ("\u{1f3fe}", "medium-dark skin tone", 32, (1, 0)),
+    ("\u{1f3ff}", "dark skin tone", 32, (1, 0)),
+    ("\u{1f400}", "rat", 34, (1, 0)),
+    ("\u{1f401}", "mouse", 34, (1, 0)),
+    ("\u{1f402}", "ox", 34, (1, 0)),
+    ("\u{1f403}", "water buffalo", 34, (1, 0)),
+    ("\u{1f404}", "cow", 34, (1, 0)),
+    ("\u{1f405}", "tiger", 34, (1, 0)),
+    ("\u{1f406}", "leopard", 34, (1, 0)),
+    ("\u{1f407}", "rabbit", 34, (1, 0)),
+    ("\u{1f408}", "cat", 34, (0, 7)),
+    ("\u{1f408}\u{200d}\u{2b1b}", "black cat", 34, (13, 0)),
+    ("\u{1f409}", "dragon", 37, (1, 0)),
+    ("\u{1f40a}", "crocodile", 37, (1, 0)),
+    ("\u{1f40b}", "whale", 38, (1, 0)),
+    ("\u{1f40c}", "snail", 39, (0, 6)),
+    ("\u{1f40d}", "snake", 37, (0, 6)),
+    ("\u{1f40e}", "horse", 34, (0, 6)),
+    ("\u{1f40f}", "ram", 34, (1, 0)),
+    ("\u{1f410}", "goat", 34, (1, 0)),
+    ("\u{1f411}", "ewe", 34, (0, 6)),
+    ("\u{1f412}", "monkey", 34, (0, 6)),
+    ("\u{1f413}", "rooster", 35, (1, 0)),
+    ("\u{1f414}", "chicken", 35, (0, 6)),
+    ("\u{1f415}", "dog", 34, (0, 7)),
+    ("\u{1f415}\u{200d}\u{1f9ba}", "service dog", 34, (12, 0)),
+    ("\u{1f416}", "pig", 34, (1, 0)),
+    ("\u{1f417}", "boar", 34, (0, 6)),
+    ("\u{1f418}", "elephant", 34, (0, 6)),
+    ("\u{1f419}", "octopus", 38, (0, 6)),
+    ("\u{1f41a}", "spiral shell", 38, (0, 6)),
+    ("\u{1f41b}", "bug", 39, (0, 6)),
+    ("\u{1f41c}", "ant", 39, (0, 6)),
+    ("\u{1f41d}", "honeybee", 39, (0, 6)),
+    ("\u{1f41e}", "lady beetle", 39, (0, 6)),
+    ("\u{1f41f}", "fish", 38, (0, 6)),
+    ("\u{1f420}", "tropical fish", 38, (0, 6)),
+    ("\u{1f421}", "blowfish", 38, (0, 6)),
+    ("\u{1f422}", "turtle", 37, (0, 6)),
+    ("\u{1f423}", "hatching chick", 35, (0, 6)),
+    ("\u{1f424}", "baby chick", 35, (0, 6)),
+    ("\u{1f425}", "front-facing baby chick", 35, (0, 6)),
+    ("\u{1f426}", "bird", 35, (0, 6)),
+    ("\u{1f426}\u{200d}\u{2b1b}", "black bird", 35, (15, 0)),
+    ("\u{1f426}\u{200d}\u{1f525}", "phoenix", 35, (15, 1)),
+    ("\u{1f427}", "penguin", 35, (0, 6)),
+    ("\u{1f428}", "koala", 34, (0, 6)),
+    ("\u{1f429}", "poodle", 34, (0, 6)),
+    ("\u{1f42a}", "camel", 34, (1, 0)),
+    ("\u{1f42b}", "two-hump camel", 34, (0, 6)),
+    ("\u{1f42c}", "dolphin", 38, (0, 6)),
+    ("\u{1f42d}", "mouse face", 34, (0, 6)),
+    ("\u{1f42e}", "cow face", 34, (0, 6)),
+    ("\u{1f42f}", "tiger face", 34, (0, 6)),
+    ("\u{1f430}", "rabbit face", 34, (0, 6)),
+    ("\u{1f431}", "cat face", 34, (0, 6)),
+    ("\u{1f432}", "dragon face", 37, (0, 6)),
+    ("\u{1f433}", "spouting whale", 38, (0, 6)),
+    ("\u{1f434}", "horse face", 34, (0, 6)),
+    ("\u{1f435}", "monkey face", 34, (0, 6)),
+    ("\u{1f436}", "dog face", 34, (0, 6)),
+    ("\u{1f437}", "pig face", 34, (0, 6)),
+    ("\u{1f438}", "frog", 36, (0, 6)),
+    ("\u{1f439}", "hamster", 34, (0, 6)),
+    ("\u{1f43a}", "wolf", 34, (0, 6)),
+    ("\u{1f43b}", "bear", 34, (0, 6)),
+    ("\u{1f43b}\u{200d}\u{2744}\u{fe0f}", "polar bear", 34, (13, 0)),
+    ("\u{1f43c}", "panda", 34, (0, 6)),
+    ("\u{1f43d}", "pig nose", 34, (0, 6)),
+    ("\u{1f43e}", "paw prints", 34, (0, 6)),
+    ("\u{1f43f}\u{fe0f}", "chipmunk", 34, (0, 7)),
+    ("\u{1f440}", "eyes", 22, (0, 6)),
+    ("\u{1f441}\u{fe0f}", "eye", 22, (0, 7)),
+    ("\u{1f441}\u{fe0f}\u{200d}\u{1f5e8}\u{fe0f}", "eye in speech bubble", 15, (2, 0)),
+    ("\u{1f442}", "ear", 22, (0, 6)),
+    ("\u{1f442}\u{1f3fb}", "ear: light skin tone", 22, (1, 0)),
+    ("\u{1f442}\u{1f3fc}", "ear: medium-light skin tone", 22, (1, 0)),
+    ("\u{1f442}\u{1f3fd}", "ear: medium skin tone", 22, (1, 0)),
+    ("\u{1f442}\u{1f3fe}", "ear: medium-dark skin tone", 22, (1, 0)),
+    ("\u{1f442}\u{1f3ff}", "ear: dark skin tone", 22, (1, 0)),
+    ("\u{1f443}", "nose", 22, (0, 6)),
+    ("\u{1f443}\u{1f3fb}", "nose: light skin tone", 22, (1, 0)),
+    ("\u{1f443}\u{1f3fc}", "nose: medium-light skin tone", 22, (1, 0)),
+    ("\u{1f443}\u{1f3fd}", "nose: medium skin tone", 22, (1, 0)),
+    ("\u{1f443}\u{1f3fe}", "nose: medium-dark skin tone", 22, (1, 0)),
+    ("\u{1f443}\u{1f3ff}", "nose: dark skin tone", 22, (1, 0)),
+    ("\u{1f444}", "mouth", 22, (0, 6)),
+    ("\u{1f445}", "tongue", 22, (0, 6)),
+    ("\u{1f446}", "backhand index pointing up", 18, (0, 6)),
+    ("\u{1f446}\u{1f3fb}", "backhand index pointing up: light skin tone", 18, (1, 0)),
+    ("\u{1f446}\u{1f3fc}", "backhand index pointing up: medium-light skin tone", 18, (1, 0)),
+    ("\u{1f446}\u{1f3fd}", "backhand index pointing up: medium skin tone", 18, (1, 0)),
+    ("\u{1f446}\u{1f3fe}", "backhand index pointing up: medium-dark skin tone", 18, (1, 0)),
+    ("\u{1f446}\u{1f3ff}", "backhand index pointing up: dark skin tone", 18, (1, 0)),
+    ("\u{1f447}", "backhand index pointing down", 18, (0, 6)),
+    ("\u{1f447}\u{1f3fb}", "backhand index pointing down: light skin tone", 18, (1, 0)),
+    ("\u{1f447}\u{1f3fc}", "backhand index pointing down: medium-light skin tone", 18, (1, 0)),
+    ("\u{1f447}\u{1f3fd}", "backhand index pointing down: medium skin tone", 18, (1, 0)),
+    ("\u{1f447}\u{1f3fe}", "backhand index pointing down: medium-dark skin tone", 18, (1, 0)),
+    ("\u{1f447}\u{1f3ff}", "backhand index pointing down: dark skin tone", 18, (1, 0)),
+    ("\u{1f448}", "backhand index pointing left", 18, (0, 6)),
+    ("\u{1f448}\u{1f3fb}", "backhand index pointing left: light skin tone", 18, (1, 0)),
+    ("\u{1f448}\u{1f3fc}", "backhand index pointing left: medium-light skin tone", 18, (1, 0)),
+    ("\u{1f448}\u{1f3fd}", "backhand index pointing left: medium skin tone", 18, (1, 0)),
+    ("\u{1f448}\u{1f3fe}", "backhand index pointing left: medium-dark skin tone", 18, (1, 0)),
+    ("\u{1f448}\u{1f3ff}", "backhand index pointing left: dark skin tone", 18, (1, 0)),
+    ("\u{1f449}", "backhand index pointing right", 18, (0, 6)),
+    ("\u{1f449}\u{1f3fb}", "backhand index pointing right: light skin tone", 18, (1, 0)),
+    ("\u{1f449}\u{1f3fc}", "backhand index pointing right: medium-light skin tone", 18, (1, 0)),
+    ("\u{1f449}\u{1f3fd}", "backhand index pointing right: medium skin tone", 18, (1, 0)),
+    ("\u{1f449}\u{1f3fe}", "backhand index pointing right: medium-dark skin tone", 18, (1, 0)),
+    ("\u{1f449}\u{1f3ff}", "backhand index pointing right: dark skin tone", 18, (1, 0)),
+    ("\u{1f44a}", "oncoming fist", 19, (0, 6)),
+    ("\u{1f44a}\u{1f3fb}", "oncoming fist: light skin tone", 19, (1, 0)),
+    ("\u{1f44a}\u{1f3fc}", "oncoming fist: medium-light skin tone", 19, (1, 0)),
+    ("\u{1f44a}\u{1f3fd}", "oncoming fist: medium skin tone", 19, (1, 0)),
+    ("\u{1f44a}\u{1f3fe}", "oncoming fist: medium-dark skin tone", 19, (1, 0)),
+    ("\u{1f44a}\u{1f3ff}", "oncoming fist: dark skin tone", 19, (1, 0)),
+    ("\u{1f44b}", "waving hand", 16, (0, 6)),
+    ("\u{1f44b}\u{1f3fb}", "waving hand: light skin tone", 16, (1, 0)),
+    ("\u{1f44b}\u{1f3fc}", "waving hand: medium-light skin tone", 16, (1, 0)),
+    ("\u{1f44b}\u{1f3fd}", "waving hand: medium skin tone", 16, (1, 0)),
+    ("\u{1f44b}\u{1f3fe}", "waving hand: medium-dark skin tone", 16, (1, 0)),
+    ("\u{1f44b}\u{1f3ff}", "waving hand: dark skin tone", 16, (1, 0)),
+    ("\u{1f44c}", "OK hand", 17, (0, 6)),
+    ("\u{1f44c}\u{1f3fb}", "OK hand: light skin tone", 17, (1, 0)),
+    ("\u{1f44c}\u{1f3fc}", "OK hand: medium-light skin tone", 17, (1, 0)),
+    ("\u{1f44c}\u{1f3fd}", "OK hand: medium skin tone", 17, (1, 0)),
+    ("\u{1f44c}\u{1f3fe}", "OK hand: medium-dark skin tone", 17, (1, 0)),
+    ("\u{1f44c}\u{1f3ff}", "OK hand: dark skin tone", 17, (1, 0)),
+    ("\u{1f44d}", "thumbs up", 19, (0, 6)),
+    ("\u{1f44d}\u{1f3fb}", "thumbs up: light skin tone", 19, (1, 0)),
+    ("\u{1f44d}\u{1f3fc}", "thumbs up: medium-light skin tone", 19, (1, 0)),
+    ("\u{1f44d}\u{1f3fd}", "thumbs up: medium skin tone", 19, (1, 0)),
+    ("\u{1f44d}\u{1f3fe}", "thumbs up: medium-dark skin tone", 19, (1, 0)),
+    ("\u{1f44d}\u{1f3ff}", "thumbs up: dark skin tone", 19, (1, 0)),
+    ("\u{1f44e}", "thumbs down", 19, (0, 6)),
+    ("\u{1f44e}\u{1f3fb}", "thumbs down: light skin tone", 19, (1, 0)),
+    ("\u{1f44e}\u{1f3fc}", "thumbs down: medium-light skin tone", 19, (1, 0)),
+    ("\u{1f44e}\u{1f3fd}", "thumbs down: medium skin tone", 19, (1, 0)),
+    ("\u{1f44e}\u{1f3fe}", "thumbs down: medium-dark skin tone", 19, (1, 0)),
+    ("\u{1f44e}\u{1f3ff}", "thumbs down: dark skin tone", 19, (1, 0)),
+    ("\u{1f44f}", "clapping hands", 20, (0, 6)),
+    ("\u{1f44f}\u{1f3fb}", "clapping hands: light skin tone", 20, (1, 0)),
+    ("\u{1f44f}\u{1f3fc}", "clapping hands: medium-light skin tone", 20, (1, 0)),
+    ("\u{1f44f}\u{1f3fd}", "clapping hands: medium skin tone", 20, (1, 0)),
+    ("\u{1f44f}\u{1f3fe}", "clapping hands: medium-dark skin tone", 20, (1, 0)),
+    ("\u{1f44f}\u{1f3ff}", "clapping hands: dark skin tone", 20, (1, 0)),
+    ("\u{1f450}", "open hands", 20, (0, 6)),
+    ("\u{1f450}\u{1f3fb}", "open hands: light skin tone", 20, (1, 0)),
+    ("\u{1f450}\u{1f3fc}", "open hands: medium-light skin tone", 20, (1, 0)),
+    ("\u{1f450}\u{1f3fd}", "open hands: medium skin tone", 20, (1, 0)),
+    ("\u{1f450}\u{1f3fe}", "open hands: medium-dark skin tone", 20, (1, 0)),
+    ("\u{1f450}\u{1f3ff}", "open hands: dark skin tone", 20, (1, 0)),
+    ("\u{1f451}", "crown", 65, (0, 6)),
+    ("\u{1f452}", "woman’s hat", 65, (0, 6)),
+    ("\u{1f453}", "glasses", 65, (0, 6)),
+    ("\u{1f454}", "necktie", 65, (0, 6)),
+    ("\u{1f455}", "t-shirt", 65, (0, 6)),
+    ("\u{1f456}", "jeans", 65, (0, 6)),
+    ("\u{1f457}", "dress", 65, (0, 6)),
+    ("\u{1f458}", "kimono", 65, (0, 6)),
+    ("\u{1f459}", "bikini", 65, (0, 6)),
+    ("\u{1f45a}", "woman’s clothes", 65, (0, 6)),
+    ("\u{1f45b}", "purse", 65, (0, 6)),
+    ("\u{1f45c}", "handbag", 65, (0, 6)),
+    ("\u{1f45d}", "clutch bag", 65, (0, 6)),
+    ("\u{1f45e}", "man’s shoe", 65, (0, 6)),
+    ("\u{1f45f}", "running shoe", 65, (0, 6)),
+    ("\u{1f460}", "high-heeled shoe", 65, (0, 6)),
+    ("\u{1f461}", "woman’s sandal", 65, (0, 6)),
+    ("\u{1f462}", "woman’s boot", 65, (0, 6)),
+    ("\u{1f463}", "footprints", 31, (0, 6)),
+    ("\u{1f464}", "bust in silhouette", 31, (0, 6)),
+    ("\u{1f465}", "busts in silhouette", 31, (1, 0)),
+    ("\u{1f466}", "boy", 23, (0, 6)),
+    ("\u{1f466}\u{1f3fb}", "boy: light skin tone", 23, (1, 0)),
+    ("\u{1f466}\u{1f3fc}", "boy: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f466}\u{1f3fd}", "boy: medium skin tone", 23, (1, 0)),
+    ("\u{1f466}\u{1f3fe}", "boy: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f466}\u{1f3ff}", "boy: dark skin tone", 23, (1, 0)),
+    ("\u{1f467}", "girl", 23, (0, 6)),
+    ("\u{1f467}\u{1f3fb}", "girl: light skin tone", 23, (1, 0)),
+    ("\u{1f467}\u{1f3fc}", "girl: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f467}\u{1f3fd}", "girl: medium skin tone", 23, (1, 0)),
+    ("\u{1f467}\u{1f3fe}", "girl: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f467}\u{1f3ff}", "girl: dark skin tone", 23, (1, 0)),
+    ("\u{1f468}", "man", 23, (0, 6)),
+    ("\u{1f468}\u{200d}\u{2695}\u{fe0f}", "man health worker", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{2696}\u{fe0f}", "man judge", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{2708}\u{fe0f}", "man pilot", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", "couple with heart: man, man", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", "kiss: man, man", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f33e}", "man farmer", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f373}", "man cook", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f37c}", "man feeding baby", 25, (13, 0)),
+    ("\u{1f468}\u{200d}\u{1f393}", "man student", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f3a4}", "man singer", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f3a8}", "man artist", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f3eb}", "man teacher", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f3ed}", "man factory worker", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f466}", "family: man, boy", 30, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", "family: man, boy, boy", 30, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f467}", "family: man, girl", 30, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", "family: man, girl, boy", 30, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", "family: man, girl, girl", 30, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}", "family: man, man, boy", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f466}\u{200d}\u{1f466}", "family: man, man, boy, boy", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}", "family: man, man, girl", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f466}", "family: man, man, girl, boy", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f468}\u{200d}\u{1f467}\u{200d}\u{1f467}", "family: man, man, girl, girl", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}", "family: man, woman, boy", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", "family: man, woman, boy, boy", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", "family: man, woman, girl", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", "family: man, woman, girl, boy", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", "family: man, woman, girl, girl", 30, (2, 0)),
+    ("\u{1f468}\u{200d}\u{1f4bb}", "man technologist", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f4bc}", "man office worker", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f527}", "man mechanic", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f52c}", "man scientist", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f680}", "man astronaut", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f692}", "man firefighter", 25, (4, 0)),
+    ("\u{1f468}\u{200d}\u{1f9af}", "man with white cane", 27, (12, 0)),
+    ("\u{1f468}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "man with white cane facing right", 27, (15, 1)),
+    ("\u{1f468}\u{200d}\u{1f9b0}", "man: red hair", 23, (11, 0)),
+    ("\u{1f468}\u{200d}\u{1f9b1}", "man: curly hair", 23, (11, 0)),
+    ("\u{1f468}\u{200d}\u{1f9b2}", "man: bald", 23, (11, 0)),
+    ("\u{1f468}\u{200d}\u{1f9b3}", "man: white hair", 23, (11, 0)),
+    ("\u{1f468}\u{200d}\u{1f9bc}", "man in motorized wheelchair", 27, (12, 0)),
+    ("\u{1f468}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "man in motorized wheelchair facing right", 27, (15, 1)),
+    ("\u{1f468}\u{200d}\u{1f9bd}", "man in manual wheelchair", 27, (12, 0)),
+    ("\u{1f468}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "man in manual wheelchair facing right", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fb}", "man: light skin tone", 23, (1, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", "man health worker: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", "man judge: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", "man pilot: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: man, man, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: man, man, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: man, man, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: man, man, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: man, man, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: man, man, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: man, man, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: man, man, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: man, man, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: man, man, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f33e}", "man farmer: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f373}", "man cook: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f37c}", "man feeding baby: light skin tone", 25, (13, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f393}", "man student: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3a4}", "man singer: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3a8}", "man artist: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3eb}", "man teacher: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f3ed}", "man factory worker: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f4bb}", "man technologist: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f4bc}", "man office worker: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f527}", "man mechanic: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f52c}", "man scientist: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f680}", "man astronaut: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f692}", "man firefighter: light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "men holding hands: light skin tone, medium-light skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "men holding hands: light skin tone, medium skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "men holding hands: light skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "men holding hands: light skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}", "man with white cane: light skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "man with white cane facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b0}", "man: light skin tone, red hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b1}", "man: light skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b2}", "man: light skin tone, bald", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9b3}", "man: light skin tone, white hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}", "man in motorized wheelchair: light skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "man in motorized wheelchair facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}", "man in manual wheelchair: light skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "man in manual wheelchair facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fc}", "man: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", "man health worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", "man judge: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", "man pilot: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: man, man, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: man, man, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: man, man, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: man, man, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: man, man, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: man, man, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: man, man, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: man, man, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: man, man, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: man, man, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f33e}", "man farmer: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f373}", "man cook: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f37c}", "man feeding baby: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f393}", "man student: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3a4}", "man singer: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3a8}", "man artist: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3eb}", "man teacher: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f3ed}", "man factory worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f4bb}", "man technologist: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f4bc}", "man office worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f527}", "man mechanic: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f52c}", "man scientist: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f680}", "man astronaut: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f692}", "man firefighter: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "men holding hands: medium-light skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "men holding hands: medium-light skin tone, medium skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "men holding hands: medium-light skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "men holding hands: medium-light skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}", "man with white cane: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "man with white cane facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b0}", "man: medium-light skin tone, red hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b1}", "man: medium-light skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b2}", "man: medium-light skin tone, bald", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9b3}", "man: medium-light skin tone, white hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}", "man in motorized wheelchair: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "man in motorized wheelchair facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}", "man in manual wheelchair: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "man in manual wheelchair facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fd}", "man: medium skin tone", 23, (1, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", "man health worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", "man judge: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", "man pilot: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: man, man, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: man, man, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: man, man, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: man, man, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: man, man, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: man, man, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: man, man, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: man, man, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: man, man, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: man, man, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f33e}", "man farmer: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f373}", "man cook: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f37c}", "man feeding baby: medium skin tone", 25, (13, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f393}", "man student: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3a4}", "man singer: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3a8}", "man artist: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3eb}", "man teacher: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f3ed}", "man factory worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f4bb}", "man technologist: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f4bc}", "man office worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f527}", "man mechanic: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f52c}", "man scientist: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f680}", "man astronaut: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f692}", "man firefighter: medium skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "men holding hands: medium skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "men holding hands: medium skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "men holding hands: medium skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "men holding hands: medium skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}", "man with white cane: medium skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "man with white cane facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b0}", "man: medium skin tone, red hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b1}", "man: medium skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b2}", "man: medium skin tone, bald", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9b3}", "man: medium skin tone, white hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}", "man in motorized wheelchair: medium skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "man in motorized wheelchair facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}", "man in manual wheelchair: medium skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "man in manual wheelchair facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fe}", "man: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", "man health worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", "man judge: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", "man pilot: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: man, man, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: man, man, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: man, man, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: man, man, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: man, man, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: man, man, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: man, man, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: man, man, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: man, man, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: man, man, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f33e}", "man farmer: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f373}", "man cook: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f37c}", "man feeding baby: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f393}", "man student: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3a4}", "man singer: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3a8}", "man artist: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3eb}", "man teacher: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f3ed}", "man factory worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f4bb}", "man technologist: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f4bc}", "man office worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f527}", "man mechanic: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f52c}", "man scientist: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f680}", "man astronaut: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f692}", "man firefighter: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "men holding hands: medium-dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "men holding hands: medium-dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "men holding hands: medium-dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "men holding hands: medium-dark skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}", "man with white cane: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "man with white cane facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b0}", "man: medium-dark skin tone, red hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b1}", "man: medium-dark skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b2}", "man: medium-dark skin tone, bald", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9b3}", "man: medium-dark skin tone, white hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}", "man in motorized wheelchair: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "man in motorized wheelchair facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}", "man in manual wheelchair: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "man in manual wheelchair facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3ff}", "man: dark skin tone", 23, (1, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", "man health worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", "man judge: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", "man pilot: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: man, man, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: man, man, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: man, man, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: man, man, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: man, man, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: man, man, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: man, man, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: man, man, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: man, man, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: man, man, dark skin tone", 30, (13, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f33e}", "man farmer: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f373}", "man cook: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f37c}", "man feeding baby: dark skin tone", 25, (13, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f393}", "man student: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3a4}", "man singer: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3a8}", "man artist: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3eb}", "man teacher: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f3ed}", "man factory worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f4bb}", "man technologist: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f4bc}", "man office worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f527}", "man mechanic: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f52c}", "man scientist: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f680}", "man astronaut: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f692}", "man firefighter: dark skin tone", 25, (4, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "men holding hands: dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "men holding hands: dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "men holding hands: dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "men holding hands: dark skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}", "man with white cane: dark skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "man with white cane facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b0}", "man: dark skin tone, red hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b1}", "man: dark skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b2}", "man: dark skin tone, bald", 23, (11, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9b3}", "man: dark skin tone, white hair", 23, (11, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}", "man in motorized wheelchair: dark skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "man in motorized wheelchair facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}", "man in manual wheelchair: dark skin tone", 27, (12, 0)),
+    ("\u{1f468}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "man in manual wheelchair facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f469}", "woman", 23, (0, 6)),
+    ("\u{1f469}\u{200d}\u{2695}\u{fe0f}", "woman health worker", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{2696}\u{fe0f}", "woman judge", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{2708}\u{fe0f}", "woman pilot", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}", "couple with heart: woman, man", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}", "couple with heart: woman, woman", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}", "kiss: woman, man", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}", "kiss: woman, woman", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{1f33e}", "woman farmer", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f373}", "woman cook", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f37c}", "woman feeding baby", 25, (13, 0)),
+    ("\u{1f469}\u{200d}\u{1f393}", "woman student", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f3a4}", "woman singer", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f3a8}", "woman artist", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f3eb}", "woman teacher", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f3ed}", "woman factory worker", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f466}", "family: woman, boy", 30, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", "family: woman, boy, boy", 30, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f467}", "family: woman, girl", 30, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", "family: woman, girl, boy", 30, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", "family: woman, girl, girl", 30, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}", "family: woman, woman, boy", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f466}\u{200d}\u{1f466}", "family: woman, woman, boy, boy", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}", "family: woman, woman, girl", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}", "family: woman, woman, girl, boy", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f467}", "family: woman, woman, girl, girl", 30, (2, 0)),
+    ("\u{1f469}\u{200d}\u{1f4bb}", "woman technologist", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f4bc}", "woman office worker", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f527}", "woman mechanic", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f52c}", "woman scientist", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f680}", "woman astronaut", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f692}", "woman firefighter", 25, (4, 0)),
+    ("\u{1f469}\u{200d}\u{1f9af}", "woman with white cane", 27, (12, 0)),
+    ("\u{1f469}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "woman with white cane facing right", 27, (15, 1)),
+    ("\u{1f469}\u{200d}\u{1f9b0}", "woman: red hair", 23, (11, 0)),
+    ("\u{1f469}\u{200d}\u{1f9b1}", "woman: curly hair", 23, (11, 0)),
+    ("\u{1f469}\u{200d}\u{1f9b2}", "woman: bald", 23, (11, 0)),
+    ("\u{1f469}\u{200d}\u{1f9b3}", "woman: white hair", 23, (11, 0)),
+    ("\u{1f469}\u{200d}\u{1f9bc}", "woman in motorized wheelchair", 27, (12, 0)),
+    ("\u{1f469}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "woman in motorized wheelchair facing right", 27, (15, 1)),
+    ("\u{1f469}\u{200d}\u{1f9bd}", "woman in manual wheelchair", 27, (12, 0)),
+    ("\u{1f469}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "woman in manual wheelchair facing right", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fb}", "woman: light skin tone", 23, (1, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", "woman health worker: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", "woman judge: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", "woman pilot: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: woman, man, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: woman, man, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: woman, man, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: woman, man, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: woman, man, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", "couple with heart: woman, woman, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", "couple with heart: woman, woman, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", "couple with heart: woman, woman, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", "couple with heart: woman, woman, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", "couple with heart: woman, woman, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: woman, man, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: woman, man, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: woman, man, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: woman, man, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: woman, man, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "kiss: woman, woman, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "kiss: woman, woman, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "kiss: woman, woman, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "kiss: woman, woman, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "kiss: woman, woman, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f33e}", "woman farmer: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f373}", "woman cook: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f37c}", "woman feeding baby: light skin tone", 25, (13, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f393}", "woman student: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3a4}", "woman singer: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3a8}", "woman artist: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3eb}", "woman teacher: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f3ed}", "woman factory worker: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f4bb}", "woman technologist: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f4bc}", "woman office worker: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f527}", "woman mechanic: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f52c}", "woman scientist: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f680}", "woman astronaut: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f692}", "woman firefighter: light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "woman and man holding hands: light skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "woman and man holding hands: light skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "woman and man holding hands: light skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "woman and man holding hands: light skin tone, dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", "women holding hands: light skin tone, medium-light skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", "women holding hands: light skin tone, medium skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", "women holding hands: light skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", "women holding hands: light skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}", "woman with white cane: light skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "woman with white cane facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b0}", "woman: light skin tone, red hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b1}", "woman: light skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b2}", "woman: light skin tone, bald", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9b3}", "woman: light skin tone, white hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}", "woman in motorized wheelchair: light skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "woman in motorized wheelchair facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}", "woman in manual wheelchair: light skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "woman in manual wheelchair facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fc}", "woman: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", "woman health worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", "woman judge: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", "woman pilot: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: woman, man, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: woman, man, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: woman, man, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: woman, man, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: woman, man, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", "couple with heart: woman, woman, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", "couple with heart: woman, woman, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", "couple with heart: woman, woman, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", "couple with heart: woman, woman, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", "couple with heart: woman, woman, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: woman, man, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: woman, man, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: woman, man, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: woman, man, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: woman, man, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "kiss: woman, woman, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "kiss: woman, woman, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "kiss: woman, woman, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "kiss: woman, woman, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "kiss: woman, woman, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f33e}", "woman farmer: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f373}", "woman cook: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f37c}", "woman feeding baby: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f393}", "woman student: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3a4}", "woman singer: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3a8}", "woman artist: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3eb}", "woman teacher: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f3ed}", "woman factory worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f4bb}", "woman technologist: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f4bc}", "woman office worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f527}", "woman mechanic: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f52c}", "woman scientist: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f680}", "woman astronaut: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f692}", "woman firefighter: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "woman and man holding hands: medium-light skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "woman and man holding hands: medium-light skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "woman and man holding hands: medium-light skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "woman and man holding hands: medium-light skin tone, dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", "women holding hands: medium-light skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", "women holding hands: medium-light skin tone, medium skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", "women holding hands: medium-light skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", "women holding hands: medium-light skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}", "woman with white cane: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "woman with white cane facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b0}", "woman: medium-light skin tone, red hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b1}", "woman: medium-light skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b2}", "woman: medium-light skin tone, bald", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9b3}", "woman: medium-light skin tone, white hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}", "woman in motorized wheelchair: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "woman in motorized wheelchair facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}", "woman in manual wheelchair: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "woman in manual wheelchair facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fd}", "woman: medium skin tone", 23, (1, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", "woman health worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", "woman judge: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", "woman pilot: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: woman, man, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: woman, man, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: woman, man, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: woman, man, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: woman, man, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", "couple with heart: woman, woman, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", "couple with heart: woman, woman, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", "couple with heart: woman, woman, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", "couple with heart: woman, woman, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", "couple with heart: woman, woman, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: woman, man, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: woman, man, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: woman, man, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: woman, man, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: woman, man, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "kiss: woman, woman, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "kiss: woman, woman, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "kiss: woman, woman, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "kiss: woman, woman, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "kiss: woman, woman, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f33e}", "woman farmer: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f373}", "woman cook: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f37c}", "woman feeding baby: medium skin tone", 25, (13, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f393}", "woman student: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3a4}", "woman singer: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3a8}", "woman artist: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3eb}", "woman teacher: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f3ed}", "woman factory worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}", "woman technologist: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bc}", "woman office worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f527}", "woman mechanic: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f52c}", "woman scientist: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f680}", "woman astronaut: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f692}", "woman firefighter: medium skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "woman and man holding hands: medium skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "woman and man holding hands: medium skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "woman and man holding hands: medium skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "woman and man holding hands: medium skin tone, dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", "women holding hands: medium skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", "women holding hands: medium skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", "women holding hands: medium skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", "women holding hands: medium skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}", "woman with white cane: medium skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "woman with white cane facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b0}", "woman: medium skin tone, red hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b1}", "woman: medium skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b2}", "woman: medium skin tone, bald", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9b3}", "woman: medium skin tone, white hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}", "woman in motorized wheelchair: medium skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "woman in motorized wheelchair facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}", "woman in manual wheelchair: medium skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "woman in manual wheelchair facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fe}", "woman: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", "woman health worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", "woman judge: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", "woman pilot: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: woman, man, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: woman, man, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: woman, man, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: woman, man, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: woman, man, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", "couple with heart: woman, woman, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", "couple with heart: woman, woman, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", "couple with heart: woman, woman, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", "couple with heart: woman, woman, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", "couple with heart: woman, woman, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: woman, man, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: woman, man, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: woman, man, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: woman, man, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: woman, man, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "kiss: woman, woman, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "kiss: woman, woman, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "kiss: woman, woman, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "kiss: woman, woman, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "kiss: woman, woman, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f33e}", "woman farmer: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f373}", "woman cook: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f37c}", "woman feeding baby: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f393}", "woman student: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3a4}", "woman singer: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3a8}", "woman artist: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3eb}", "woman teacher: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f3ed}", "woman factory worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f4bb}", "woman technologist: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f4bc}", "woman office worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f527}", "woman mechanic: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f52c}", "woman scientist: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f680}", "woman astronaut: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f692}", "woman firefighter: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "woman and man holding hands: medium-dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "woman and man holding hands: medium-dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "woman and man holding hands: medium-dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3ff}", "woman and man holding hands: medium-dark skin tone, dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", "women holding hands: medium-dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", "women holding hands: medium-dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", "women holding hands: medium-dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3ff}", "women holding hands: medium-dark skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}", "woman with white cane: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "woman with white cane facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b0}", "woman: medium-dark skin tone, red hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b1}", "woman: medium-dark skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b2}", "woman: medium-dark skin tone, bald", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9b3}", "woman: medium-dark skin tone, white hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}", "woman in motorized wheelchair: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "woman in motorized wheelchair facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}", "woman in manual wheelchair: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "woman in manual wheelchair facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3ff}", "woman: dark skin tone", 23, (1, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", "woman health worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", "woman judge: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", "woman pilot: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fb}", "couple with heart: woman, man, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fc}", "couple with heart: woman, man, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fd}", "couple with heart: woman, man, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3fe}", "couple with heart: woman, man, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f468}\u{1f3ff}", "couple with heart: woman, man, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fb}", "couple with heart: woman, woman, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fc}", "couple with heart: woman, woman, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fd}", "couple with heart: woman, woman, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3fe}", "couple with heart: woman, woman, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f469}\u{1f3ff}", "couple with heart: woman, woman, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fb}", "kiss: woman, man, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fc}", "kiss: woman, man, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fd}", "kiss: woman, man, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3fe}", "kiss: woman, man, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f468}\u{1f3ff}", "kiss: woman, man, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fb}", "kiss: woman, woman, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fc}", "kiss: woman, woman, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fd}", "kiss: woman, woman, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3fe}", "kiss: woman, woman, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f469}\u{1f3ff}", "kiss: woman, woman, dark skin tone", 30, (13, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f33e}", "woman farmer: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f373}", "woman cook: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f37c}", "woman feeding baby: dark skin tone", 25, (13, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f393}", "woman student: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3a4}", "woman singer: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3a8}", "woman artist: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3eb}", "woman teacher: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f3ed}", "woman factory worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f4bb}", "woman technologist: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f4bc}", "woman office worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f527}", "woman mechanic: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f52c}", "woman scientist: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f680}", "woman astronaut: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f692}", "woman firefighter: dark skin tone", 25, (4, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fb}", "woman and man holding hands: dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fc}", "woman and man holding hands: dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fd}", "woman and man holding hands: dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f468}\u{1f3fe}", "woman and man holding hands: dark skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fb}", "women holding hands: dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fc}", "women holding hands: dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fd}", "women holding hands: dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f469}\u{1f3fe}", "women holding hands: dark skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}", "woman with white cane: dark skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "woman with white cane facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b0}", "woman: dark skin tone, red hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b1}", "woman: dark skin tone, curly hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b2}", "woman: dark skin tone, bald", 23, (11, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9b3}", "woman: dark skin tone, white hair", 23, (11, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}", "woman in motorized wheelchair: dark skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "woman in motorized wheelchair facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}", "woman in manual wheelchair: dark skin tone", 27, (12, 0)),
+    ("\u{1f469}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "woman in manual wheelchair facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f46a}", "family", 31, (0, 6)),
+    ("\u{1f46b}", "woman and man holding hands", 30, (0, 6)),
+    ("\u{1f46b}\u{1f3fb}", "woman and man holding hands: light skin tone", 30, (12, 0)),
+    ("\u{1f46b}\u{1f3fc}", "woman and man holding hands: medium-light skin tone", 30, (12, 0)),
+    ("\u{1f46b}\u{1f3fd}", "woman and man holding hands: medium skin tone", 30, (12, 0)),
+    ("\u{1f46b}\u{1f3fe}", "woman and man holding hands: medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f46b}\u{1f3ff}", "woman and man holding hands: dark skin tone", 30, (12, 0)),
+    ("\u{1f46c}", "men holding hands", 30, (1, 0)),
+    ("\u{1f46c}\u{1f3fb}", "men holding hands: light skin tone", 30, (12, 0)),
+    ("\u{1f46c}\u{1f3fc}", "men holding hands: medium-light skin tone", 30, (12, 0)),
+    ("\u{1f46c}\u{1f3fd}", "men holding hands: medium skin tone", 30, (12, 0)),
+    ("\u{1f46c}\u{1f3fe}", "men holding hands: medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f46c}\u{1f3ff}", "men holding hands: dark skin tone", 30, (12, 0)),
+    ("\u{1f46d}", "women holding hands", 30, (1, 0)),
+    ("\u{1f46d}\u{1f3fb}", "women holding hands: light skin tone", 30, (12, 0)),
+    ("\u{1f46d}\u{1f3fc}", "women holding hands: medium-light skin tone", 30, (12, 0)),
+    ("\u{1f46d}\u{1f3fd}", "women holding hands: medium skin tone", 30, (12, 0)),
+    ("\u{1f46d}\u{1f3fe}", "women holding hands: medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f46d}\u{1f3ff}", "women holding hands: dark skin tone", 30, (12, 0)),
+    ("\u{1f46e}", "police officer", 25, (0, 6)),
+    ("\u{1f46e}\u{200d}\u{2640}\u{fe0f}", "woman police officer", 25, (4, 0)),
+    ("\u{1f46e}\u{200d}\u{2642}\u{fe0f}", "man police officer", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fb}", "police officer: light skin tone", 25, (1, 0)),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman police officer: light skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man police officer: light skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fc}", "police officer: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman police officer: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man police officer: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fd}", "police officer: medium skin tone", 25, (1, 0)),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman police officer: medium skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man police officer: medium skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fe}", "police officer: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman police officer: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man police officer: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3ff}", "police officer: dark skin tone", 25, (1, 0)),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman police officer: dark skin tone", 25, (4, 0)),
+    ("\u{1f46e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man police officer: dark skin tone", 25, (4, 0)),
+    ("\u{1f46f}", "people with bunny ears", 27, (0, 6)),
+    ("\u{1f46f}\u{200d}\u{2640}\u{fe0f}", "women with bunny ears", 27, (4, 0)),
+    ("\u{1f46f}\u{200d}\u{2642}\u{fe0f}", "men with bunny ears", 27, (4, 0)),
+    ("\u{1f470}", "person with veil", 25, (0, 6)),
+    ("\u{1f470}\u{200d}\u{2640}\u{fe0f}", "woman with veil", 25, (13, 0)),
+    ("\u{1f470}\u{200d}\u{2642}\u{fe0f}", "man with veil", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fb}", "person with veil: light skin tone", 25, (1, 0)),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman with veil: light skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man with veil: light skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fc}", "person with veil: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman with veil: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man with veil: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fd}", "person with veil: medium skin tone", 25, (1, 0)),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman with veil: medium skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man with veil: medium skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fe}", "person with veil: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman with veil: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man with veil: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3ff}", "person with veil: dark skin tone", 25, (1, 0)),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman with veil: dark skin tone", 25, (13, 0)),
+    ("\u{1f470}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man with veil: dark skin tone", 25, (13, 0)),
+    ("\u{1f471}", "person: blond hair", 23, (0, 6)),
+    ("\u{1f471}\u{200d}\u{2640}\u{fe0f}", "woman: blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{200d}\u{2642}\u{fe0f}", "man: blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fb}", "person: light skin tone, blond hair", 23, (1, 0)),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman: light skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man: light skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fc}", "person: medium-light skin tone, blond hair", 23, (1, 0)),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman: medium-light skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man: medium-light skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fd}", "person: medium skin tone, blond hair", 23, (1, 0)),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman: medium skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man: medium skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fe}", "person: medium-dark skin tone, blond hair", 23, (1, 0)),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman: medium-dark skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man: medium-dark skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3ff}", "person: dark skin tone, blond hair", 23, (1, 0)),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman: dark skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f471}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man: dark skin tone, blond hair", 23, (4, 0)),
+    ("\u{1f472}", "person with skullcap", 25, (0, 6)),
+    ("\u{1f472}\u{1f3fb}", "person with skullcap: light skin tone", 25, (1, 0)),
+    ("\u{1f472}\u{1f3fc}", "person with skullcap: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f472}\u{1f3fd}", "person with skullcap: medium skin tone", 25, (1, 0)),
+    ("\u{1f472}\u{1f3fe}", "person with skullcap: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f472}\u{1f3ff}", "person with skullcap: dark skin tone", 25, (1, 0)),
+    ("\u{1f473}", "person wearing turban", 25, (0, 6)),
+    ("\u{1f473}\u{200d}\u{2640}\u{fe0f}", "woman wearing turban", 25, (4, 0)),
+    ("\u{1f473}\u{200d}\u{2642}\u{fe0f}", "man wearing turban", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fb}", "person wearing turban: light skin tone", 25, (1, 0)),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman wearing turban: light skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man wearing turban: light skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fc}", "person wearing turban: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman wearing turban: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man wearing turban: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fd}", "person wearing turban: medium skin tone", 25, (1, 0)),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman wearing turban: medium skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man wearing turban: medium skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fe}", "person wearing turban: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman wearing turban: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man wearing turban: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3ff}", "person wearing turban: dark skin tone", 25, (1, 0)),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman wearing turban: dark skin tone", 25, (4, 0)),
+    ("\u{1f473}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man wearing turban: dark skin tone", 25, (4, 0)),
+    ("\u{1f474}", "old man", 23, (0, 6)),
+    ("\u{1f474}\u{1f3fb}", "old man: light skin tone", 23, (1, 0)),
+    ("\u{1f474}\u{1f3fc}", "old man: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f474}\u{1f3fd}", "old man: medium skin tone", 23, (1, 0)),
+    ("\u{1f474}\u{1f3fe}", "old man: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f474}\u{1f3ff}", "old man: dark skin tone", 23, (1, 0)),
+    ("\u{1f475}", "old woman", 23, (0, 6)),
+    ("\u{1f475}\u{1f3fb}", "old woman: light skin tone", 23, (1, 0)),
+    ("\u{1f475}\u{1f3fc}", "old woman: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f475}\u{1f3fd}", "old woman: medium skin tone", 23, (1, 0)),
+    ("\u{1f475}\u{1f3fe}", "old woman: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f475}\u{1f3ff}", "old woman: dark skin tone", 23, (1, 0)),
+    ("\u{1f476}", "baby", 23, (0, 6)),
+    ("\u{1f476}\u{1f3fb}", "baby: light skin tone", 23, (1, 0)),
+    ("\u{1f476}\u{1f3fc}", "baby: medium-light skin tone", 23, (1, 0)),
+    ("\u{1f476}\u{1f3fd}", "baby: medium skin tone", 23, (1, 0)),
+    ("\u{1f476}\u{1f3fe}", "baby: medium-dark skin tone", 23, (1, 0)),
+    ("\u{1f476}\u{1f3ff}", "baby: dark skin tone", 23, (1, 0)),
+    ("\u{1f477}", "construction worker", 25, (0, 6)),
+    ("\u{1f477}\u{200d}\u{2640}\u{fe0f}", "woman construction worker", 25, (4, 0)),
+    ("\u{1f477}\u{200d}\u{2642}\u{fe0f}", "man construction worker", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fb}", "construction worker: light skin tone", 25, (1, 0)),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman construction worker: light skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man construction worker: light skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fc}", "construction worker: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman construction worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man construction worker: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fd}", "construction worker: medium skin tone", 25, (1, 0)),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman construction worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man construction worker: medium skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fe}", "construction worker: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman construction worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man construction worker: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3ff}", "construction worker: dark skin tone", 25, (1, 0)),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman construction worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f477}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man construction worker: dark skin tone", 25, (4, 0)),
+    ("\u{1f478}", "princess", 25, (0, 6)),
+    ("\u{1f478}\u{1f3fb}", "princess: light skin tone", 25, (1, 0)),
+    ("\u{1f478}\u{1f3fc}", "princess: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f478}\u{1f3fd}", "princess: medium skin tone", 25, (1, 0)),
+    ("\u{1f478}\u{1f3fe}", "princess: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f478}\u{1f3ff}", "princess: dark skin tone", 25, (1, 0)),
+    ("\u{1f479}", "ogre", 11, (0, 6)),
+    ("\u{1f47a}", "goblin", 11, (0, 6)),
+    ("\u{1f47b}", "ghost", 11, (0, 6)),
+    ("\u{1f47c}", "baby angel", 26, (0, 6)),
+    ("\u{1f47c}\u{1f3fb}", "baby angel: light skin tone", 26, (1, 0)),
+    ("\u{1f47c}\u{1f3fc}", "baby angel: medium-light skin tone", 26, (1, 0)),
+    ("\u{1f47c}\u{1f3fd}", "baby angel: medium skin tone", 26, (1, 0)),
+    ("\u{1f47c}\u{1f3fe}", "baby angel: medium-dark skin tone", 26, (1, 0)),
+    ("\u{1f47c}\u{1f3ff}", "baby angel: dark skin tone", 26, (1, 0)),
+    ("\u{1f47d}", "alien", 11, (0, 6)),
+    ("\u{1f47e}", "alien monster", 11, (0, 6)),
+    ("\u{1f47f}", "angry face with horns", 10, (0, 6)),
+    ("\u{1f480}", "skull", 10, (0, 6)),
+    ("\u{1f481}", "person tipping hand", 24, (0, 6)),
+    ("\u{1f481}\u{200d}\u{2640}\u{fe0f}", "woman tipping hand", 24, (4, 0)),
+    ("\u{1f481}\u{200d}\u{2642}\u{fe0f}", "man tipping hand", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fb}", "person tipping hand: light skin tone", 24, (1, 0)),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman tipping hand: light skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man tipping hand: light skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fc}", "person tipping hand: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman tipping hand: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man tipping hand: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fd}", "person tipping hand: medium skin tone", 24, (1, 0)),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman tipping hand: medium skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man tipping hand: medium skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fe}", "person tipping hand: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman tipping hand: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man tipping hand: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3ff}", "person tipping hand: dark skin tone", 24, (1, 0)),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman tipping hand: dark skin tone", 24, (4, 0)),
+    ("\u{1f481}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man tipping hand: dark skin tone", 24, (4, 0)),
+    ("\u{1f482}", "guard", 25, (0, 6)),
+    ("\u{1f482}\u{200d}\u{2640}\u{fe0f}", "woman guard", 25, (4, 0)),
+    ("\u{1f482}\u{200d}\u{2642}\u{fe0f}", "man guard", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fb}", "guard: light skin tone", 25, (1, 0)),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman guard: light skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man guard: light skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fc}", "guard: medium-light skin tone", 25, (1, 0)),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman guard: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man guard: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fd}", "guard: medium skin tone", 25, (1, 0)),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman guard: medium skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man guard: medium skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fe}", "guard: medium-dark skin tone", 25, (1, 0)),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman guard: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man guard: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3ff}", "guard: dark skin tone", 25, (1, 0)),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman guard: dark skin tone", 25, (4, 0)),
+    ("\u{1f482}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man guard: dark skin tone", 25, (4, 0)),
+    ("\u{1f483}", "woman dancing", 27, (0, 6)),
+    ("\u{1f483}\u{1f3fb}", "woman dancing: light skin tone", 27, (1, 0)),
+    ("\u{1f483}\u{1f3fc}", "woman dancing: medium-light skin tone", 27, (1, 0)),
+    ("\u{1f483}\u{1f3fd}", "woman dancing: medium skin tone", 27, (1, 0)),
+    ("\u{1f483}\u{1f3fe}", "woman dancing: medium-dark skin tone", 27, (1, 0)),
+    ("\u{1f483}\u{1f3ff}", "woman dancing: dark skin tone", 27, (1, 0)),
+    ("\u{1f484}", "lipstick", 65, (0, 6)),
+    ("\u{1f485}", "nail polish", 21, (0, 6)),
+    ("\u{1f485}\u{1f3fb}", "nail polish: light skin tone", 21, (1, 0)),
+    ("\u{1f485}\u{1f3fc}", "nail polish: medium-light skin tone", 21, (1, 0)),
+    ("\u{1f485}\u{1f3fd}", "nail polish: medium skin tone", 21, (1, 0)),
+    ("\u{1f485}\u{1f3fe}", "nail polish: medium-dark skin tone", 21, (1, 0)),
+    ("\u{1f485}\u{1f3ff}", "nail polish: dark skin tone", 21, (1, 0)),
+    ("\u{1f486}", "person getting massage", 27, (0, 6)),
+    ("\u{1f486}\u{200d}\u{2640}\u{fe0f}", "woman getting massage", 27, (4, 0)),
+    ("\u{1f486}\u{200d}\u{2642}\u{fe0f}", "man getting massage", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fb}", "person getting massage: light skin tone", 27, (1, 0)),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman getting massage: light skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man getting massage: light skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fc}", "person getting massage: medium-light skin tone", 27, (1, 0)),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman getting massage: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man getting massage: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fd}", "person getting massage: medium skin tone", 27, (1, 0)),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman getting massage: medium skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man getting massage: medium skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fe}", "person getting massage: medium-dark skin tone", 27, (1, 0)),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman getting massage: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man getting massage: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3ff}", "person getting massage: dark skin tone", 27, (1, 0)),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman getting massage: dark skin tone", 27, (4, 0)),
+    ("\u{1f486}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man getting massage: dark skin tone", 27, (4, 0)),
+    ("\u{1f487}", "person getting haircut", 27, (0, 6)),
+    ("\u{1f487}\u{200d}\u{2640}\u{fe0f}", "woman getting haircut", 27, (4, 0)),
+    ("\u{1f487}\u{200d}\u{2642}\u{fe0f}", "man getting haircut", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fb}", "person getting haircut: light skin tone", 27, (1, 0)),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman getting haircut: light skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man getting haircut: light skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fc}", "person getting haircut: medium-light skin tone", 27, (1, 0)),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman getting haircut: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man getting haircut: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fd}", "person getting haircut: medium skin tone", 27, (1, 0)),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman getting haircut: medium skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man getting haircut: medium skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fe}", "person getting haircut: medium-dark skin tone", 27, (1, 0)),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman getting haircut: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man getting haircut: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3ff}", "person getting haircut: dark skin tone", 27, (1, 0)),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman getting haircut: dark skin tone", 27, (4, 0)),
+    ("\u{1f487}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man getting haircut: dark skin tone", 27, (4, 0)),
+    ("\u{1f488}", "barber pole", 53, (0, 6)),
+    ("\u{1f489}", "syringe", 80, (0, 6)),
+    ("\u{1f48a}", "pill", 80, (0, 6)),
+    ("\u{1f48b}", "kiss mark", 15, (0, 6)),
+    ("\u{1f48c}", "love letter", 14, (0, 6)),
+    ("\u{1f48d}", "ring", 65, (0, 6)),
+    ("\u{1f48e}", "gem stone", 65, (0, 6)),
+    ("\u{1f48f}", "kiss", 30, (0, 6)),
+    ("\u{1f48f}\u{1f3fb}", "kiss: light skin tone", 30, (13, 1)),
+    ("\u{1f48f}\u{1f3fc}", "kiss: medium-light skin tone", 30, (13, 1)),
+    ("\u{1f48f}\u{1f3fd}", "kiss: medium skin tone", 30, (13, 1)),
+    ("\u{1f48f}\u{1f3fe}", "kiss: medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f48f}\u{1f3ff}", "kiss: dark skin tone", 30, (13, 1)),
+    ("\u{1f490}", "bouquet", 40, (0, 6)),
+    ("\u{1f491}", "couple with heart", 30, (0, 6)),
+    ("\u{1f491}\u{1f3fb}", "couple with heart: light skin tone", 30, (13, 1)),
+    ("\u{1f491}\u{1f3fc}", "couple with heart: medium-light skin tone", 30, (13, 1)),
+    ("\u{1f491}\u{1f3fd}", "couple with heart: medium skin tone", 30, (13, 1)),
+    ("\u{1f491}\u{1f3fe}", "couple with heart: medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f491}\u{1f3ff}", "couple with heart: dark skin tone", 30, (13, 1)),
+    ("\u{1f492}", "wedding", 51, (0, 6)),
+    ("\u{1f493}", "beating heart", 14, (0, 6)),
+    ("\u{1f494}", "broken heart", 14, (0, 6)),
+    ("\u{1f495}", "two hearts", 14, (0, 6)),
+    ("\u{1f496}", "sparkling heart", 14, (0, 6)),
+    ("\u{1f497}", "growing heart", 14, (0, 6)),
+    ("\u{1f498}", "heart with arrow", 14, (0, 6)),
+    ("\u{1f499}", "blue heart", 14, (0, 6)),
+    ("\u{1f49a}", "green heart", 14, (0, 6)),
+    ("\u{1f49b}", "yellow heart", 14, (0, 6)),
+    ("\u{1f49c}", "purple heart", 14, (0, 6)),
+    ("\u{1f49d}", "heart with ribbon", 14, (0, 6)),
+    ("\u{1f49e}", "revolving hearts", 14, (0, 6)),
+    ("\u{1f49f}", "heart decoration", 14, (0, 6)),
+    ("\u{1f4a0}", "diamond with a dot", 96, (0, 6)),
+    ("\u{1f4a1}", "light bulb", 71, (0, 6)),
+    ("\u{1f4a2}", "anger symbol", 15, (0, 6)),
+    ("\u{1f4a3}", "bomb", 78, (0, 6)),
+    ("\u{1f4a4}", "ZZZ", 15, (0, 6)),
+    ("\u{1f4a5}", "collision", 15, (0, 6)),
+    ("\u{1f4a6}", "sweat droplets", 15, (0, 6)),
+    ("\u{1f4a7}", "droplet", 59, (0, 6)),
+    ("\u{1f4a8}", "dashing away", 15, (0, 6)),
+    ("\u{1f4a9}", "pile of poo", 11, (0, 6)),
+    ("\u{1f4aa}", "flexed biceps", 22, (0, 6)),
+    ("\u{1f4aa}\u{1f3fb}", "flexed biceps: light skin tone", 22, (1, 0)),
+    ("\u{1f4aa}\u{1f3fc}", "flexed biceps: medium-light skin tone", 22, (1, 0)),
+    ("\u{1f4aa}\u{1f3fd}", "flexed biceps: medium skin tone", 22, (1, 0)),
+    ("\u{1f4aa}\u{1f3fe}", "flexed biceps: medium-dark skin tone", 22, (1, 0)),
+    ("\u{1f4aa}\u{1f3ff}", "flexed biceps: dark skin tone", 22, (1, 0)),
+    ("\u{1f4ab}", "dizzy", 15, (0, 6)),
+    ("\u{1f4ac}", "speech balloon", 15, (0, 6)),
+    ("\u{1f4ad}", "thought balloon", 15, (1, 0)),
+    ("\u{1f4ae}", "white flower", 40, (0, 6)),
+    ("\u{1f4af}", "hundred points", 15, (0, 6)),
+    ("\u{1f4b0}", "money bag", 73, (0, 6)),
+    ("\u{1f4b1}", "currency exchange", 92, (0, 6)),
+    ("\u{1f4b2}", "heavy dollar sign", 92, (0, 6)),
+    ("\u{1f4b3}", "credit card", 73, (0, 6)),
+    ("\u{1f4b4}", "yen banknote", 73, (0, 6)),
+    ("\u{1f4b5}", "dollar banknote", 73, (0, 6)),
+    ("\u{1f4b6}", "euro banknote", 73, (1, 0)),
+    ("\u{1f4b7}", "pound banknote", 73, (1, 0)),
+    ("\u{1f4b8}", "money with wings", 73, (0, 6)),
+    ("\u{1f4b9}", "chart increasing with yen", 73, (0, 6)),
+    ("\u{1f4ba}", "seat", 56, (0, 6)),
+    ("\u{1f4bb}", "laptop", 70, (0, 6)),
+    ("\u{1f4bc}", "briefcase", 76, (0, 6)),
+    ("\u{1f4bd}", "computer disk", 70, (0, 6)),
+    ("\u{1f4be}", "floppy disk", 70, (0, 6)),
+    ("\u{1f4bf}", "optical disk", 70, (0, 6)),
+    ("\u{1f4c0}", "dvd", 70, (0, 6)),
+    ("\u{1f4c1}", "file folder", 76, (0, 6)),
+    ("\u{1f4c2}", "open file folder", 76, (0, 6)),
+    ("\u{1f4c3}", "page with curl", 72, (0, 6)),
+    ("\u{1f4c4}", "page facing up", 72, (0, 6)),
+    ("\u{1f4c5}", "calendar", 76, (0, 6)),
+    ("\u{1f4c6}", "tear-off calendar", 76, (0, 6)),
+    ("\u{1f4c7}", "card index", 76, (0, 6)),
+    ("\u{1f4c8}", "chart increasing", 76, (0, 6)),
+    ("\u{1f4c9}", "chart decreasing", 76, (0, 6)),
+    ("\u{1f4ca}", "bar chart", 76, (0, 6)),
+    ("\u{1f4cb}", "clipboard", 76, (0, 6)),
+    ("\u{1f4cc}", "pushpin", 76, (0, 6)),
+    ("\u{1f4cd}", "round pushpin", 76, (0, 6)),
+    ("\u{1f4ce}", "paperclip", 76, (0, 6)),
+    ("\u{1f4cf}", "straight ruler", 76, (0, 6)),
+    ("\u{1f4d0}", "triangular ruler", 76, (0, 6)),
+    ("\u{1f4d1}", "bookmark tabs", 72, (0, 6)),
+    ("\u{1f4d2}", "ledger", 72, (0, 6)),
+    ("\u{1f4d3}", "notebook", 72, (0, 6)),
+    ("\u{1f4d4}", "notebook with decorative cover", 72, (0, 6)),
+    ("\u{1f4d5}", "closed book", 72, (0, 6)),
+    ("\u{1f4d6}", "open book", 72, (0, 6)),
+    ("\u{1f4d7}", "green book", 72, (0, 6)),
+    ("\u{1f4d8}", "blue book", 72, (0, 6)),
+    ("\u{1f4d9}", "orange book", 72, (0, 6)),
+    ("\u{1f4da}", "books", 72, (0, 6)),
+    ("\u{1f4db}", "name badge", 93, (0, 6)),
+    ("\u{1f4dc}", "scroll", 72, (0, 6)),
+    ("\u{1f4dd}", "memo", 75, (0, 6)),
+    ("\u{1f4de}", "telephone receiver", 69, (0, 6)),
+    ("\u{1f4df}", "pager", 69, (0, 6)),
+    ("\u{1f4e0}", "fax machine", 69, (0, 6)),
+    ("\u{1f4e1}", "satellite antenna", 79, (0, 6)),
+    ("\u{1f4e2}", "loudspeaker", 66, (0, 6)),
+    ("\u{1f4e3}", "megaphone", 66, (0, 6)),
+    ("\u{1f4e4}", "outbox tray", 74, (0, 6)),
+    ("\u{1f4e5}", "inbox tray", 74, (0, 6)),
+    ("\u{1f4e6}", "package", 74, (0, 6)),
+    ("\u{1f4e7}", "e-mail", 74, (0, 6)),
+    ("\u{1f4e8}", "incoming envelope", 74, (0, 6)),
+    ("\u{1f4e9}", "envelope with arrow", 74, (0, 6)),
+    ("\u{1f4ea}", "closed mailbox with lowered flag", 74, (0, 6)),
+    ("\u{1f4eb}", "closed mailbox with raised flag", 74, (0, 6)),
+    ("\u{1f4ec}", "open mailbox with raised flag", 74, (0, 7)),
+    ("\u{1f4ed}", "open mailbox with lowered flag", 74, (0, 7)),
+    ("\u{1f4ee}", "postbox", 74, (0, 6)),
+    ("\u{1f4ef}", "postal horn", 66, (1, 0)),
+    ("\u{1f4f0}", "newspaper", 72, (0, 6)),
+    ("\u{1f4f1}", "mobile phone", 69, (0, 6)),
+    ("\u{1f4f2}", "mobile phone with arrow", 69, (0, 6)),
+    ("\u{1f4f3}", "vibration mode", 88, (0, 6)),
+    ("\u{1f4f4}", "mobile phone off", 88, (0, 6)),
+    ("\u{1f4f5}", "no mobile phones", 84, (1, 0)),
+    ("\u{1f4f6}", "antenna bars", 88, (0, 6)),
+    ("\u{1f4f7}", "camera", 71, (0, 6)),
+    ("\u{1f4f8}", "camera with flash", 71, (1, 0)),
+    ("\u{1f4f9}", "video camera", 71, (0, 6)),
+    ("\u{1f4fa}", "television", 71, (0, 6)),
+    ("\u{1f4fb}", "radio", 67, (0, 6)),
+    ("\u{1f4fc}", "videocassette", 71, (0, 6)),
+    ("\u{1f4fd}\u{fe0f}", "film projector", 71, (0, 7)),
+    ("\u{1f4ff}", "prayer beads", 65, (1, 0)),
+    ("\u{1f500}", "shuffle tracks button", 88, (1, 0)),
+    ("\u{1f501}", "repeat button", 88, (1, 0)),
+    ("\u{1f502}", "repeat single button", 88, (1, 0)),
+    ("\u{1f503}", "clockwise vertical arrows", 85, (0, 6)),
+    ("\u{1f504}", "counterclockwise arrows button", 85, (1, 0)),
+    ("\u{1f505}", "dim button", 88, (1, 0)),
+    ("\u{1f506}", "bright button", 88, (1, 0)),
+    ("\u{1f507}", "muted speaker", 66, (1, 0)),
+    ("\u{1f508}", "speaker low volume", 66, (0, 7)),
+    ("\u{1f509}", "speaker medium volume", 66, (1, 0)),
+    ("\u{1f50a}", "speaker high volume", 66, (0, 6)),
+    ("\u{1f50b}", "battery", 70, (0, 6)),
+    ("\u{1f50c}", "electric plug", 70, (0, 6)),
+    ("\u{1f50d}", "magnifying glass tilted left", 71, (0, 6)),
+    ("\u{1f50e}", "magnifying glass tilted right", 71, (0, 6)),
+    ("\u{1f50f}", "locked with pen", 77, (0, 6)),
+    ("\u{1f510}", "locked with key", 77, (0, 6)),
+    ("\u{1f511}", "key", 77, (0, 6)),
+    ("\u{1f512}", "locked", 77, (0, 6)),
+    ("\u{1f513}", "unlocked", 77, (0, 6)),
+    ("\u{1f514}", "bell", 66, (0, 6)),
+    ("\u{1f515}", "bell with slash", 66, (1, 0)),
+    ("\u{1f516}", "bookmark", 72, (0, 6)),
+    ("\u{1f517}", "link", 78, (0, 6)),
+    ("\u{1f518}", "radio button", 96, (0, 6)),
+    ("\u{1f519}", "BACK arrow", 85, (0, 6)),
+    ("\u{1f51a}", "END arrow", 85, (0, 6)),
+    ("\u{1f51b}", "ON! arrow", 85, (0, 6)),
+    ("\u{1f51c}", "SOON arrow", 85, (0, 6)),
+    ("\u{1f51d}", "TOP arrow", 85, (0, 6)),
+    ("\u{1f51e}", "no one under eighteen", 84, (0, 6)),
+    ("\u{1f51f}", "keycap: 10", 94, (0, 6)),
+    ("\u{1f520}", "input latin uppercase", 95, (0, 6)),
+    ("\u{1f521}", "input latin lowercase", 95, (0, 6)),
+    ("\u{1f522}", "input numbers", 95, (0, 6)),
+    ("\u{1f523}", "input symbols", 95, (0, 6)),
+    ("\u{1f524}", "input latin letters", 95, (0, 6)),
+    ("\u{1f525}", "fire", 59, (0, 6)),
+    ("\u{1f526}", "flashlight", 71, (0, 6)),
+    ("\u{1f527}", "wrench", 78, (0, 6)),
+    ("\u{1f528}", "hammer", 78, (0, 6)),
+    ("\u{1f529}", "nut and bolt", 78, (0, 6)),
+    ("\u{1f52a}", "kitchen knife", 48, (0, 6)),
+    ("\u{1f52b}", "water pistol", 63, (0, 6)),
+    ("\u{1f52c}", "microscope", 79, (1, 0)),
+    ("\u{1f52d}", "telescope", 79, (1, 0)),
+    ("\u{1f52e}", "crystal ball", 63, (0, 6)),
+    ("\u{1f52f}", "dotted six-pointed star", 86, (0, 6)),
+    ("\u{1f530}", "Japanese symbol for beginner", 93, (0, 6)),
+    ("\u{1f531}", "trident emblem", 93, (0, 6)),
+    ("\u{1f532}", "black square button", 96, (0, 6)),
+    ("\u{1f533}", "white square button", 96, (0, 6)),
+    ("\u{1f534}", "red circle", 96, (0, 6)),
+    ("\u{1f535}", "blue circle", 96, (0, 6)),
+    ("\u{1f536}", "large orange diamond", 96, (0, 6)),
+    ("\u{1f537}", "large blue diamond", 96, (0, 6)),
+    ("\u{1f538}", "small orange diamond", 96, (0, 6)),
+    ("\u{1f539}", "small blue diamond", 96, (0, 6)),
+    ("\u{1f53a}", "red triangle pointed up", 96, (0, 6)),
+    ("\u{1f53b}", "red triangle pointed down", 96, (0, 6)),
+    ("\u{1f53c}", "upwards button", 88, (0, 6)),
+    ("\u{1f53d}", "downwards button", 88, (0, 6)),
+    ("\u{1f549}\u{fe0f}", "om", 86, (0, 7)),
+    ("\u{1f54a}\u{fe0f}", "dove", 35, (0, 7)),
+    ("\u{1f54b}", "kaaba", 52, (1, 0)),
+    ("\u{1f54c}", "mosque", 52, (1, 0)),
+    ("\u{1f54d}", "synagogue", 52, (1, 0)),
+    ("\u{1f54e}", "menorah", 86, (1, 0)),
+    ("\u{1f550}", "one o’clock", 58, (0, 6)),
+    ("\u{1f551}", "two o’clock", 58, (0, 6)),
+    ("\u{1f552}", "three o’clock", 58, (0, 6)),
+    ("\u{1f553}", "four o’clock", 58, (0, 6)),
+    ("\u{1f554}", "five o’clock", 58, (0, 6)),
+    ("\u{1f555}", "six o’clock", 58, (0, 6)),
+    ("\u{1f556}", "seven o’clock", 58, (0, 6)),
+    ("\u{1f557}", "eight o’clock", 58, (0, 6)),
+    ("\u{1f558}", "nine o’clock", 58, (0, 6)),
+    ("\u{1f559}", "ten o’clock", 58, (0, 6)),
+    ("\u{1f55a}", "eleven o’clock", 58, (0, 6)),
+    ("\u{1f55b}", "twelve o’clock", 58, (0, 6)),
+    ("\u{1f55c}", "one-thirty", 58, (0, 7)),
+    ("\u{1f55d}", "two-thirty", 58, (0, 7)),
+    ("\u{1f55e}", "three-thirty", 58, (0, 7)),
+    ("\u{1f55f}", "four-thirty", 58, (0, 7)),
+    ("\u{1f560}", "five-thirty", 58, (0, 7)),
+    ("\u{1f561}", "six-thirty", 58, (0, 7)),
+    ("\u{1f562}", "seven-thirty", 58, (0, 7)),
+    ("\u{1f563}", "eight-thirty", 58, (0, 7)),
+    ("\u{1f564}", "nine-thirty", 58, (0, 7)),
+    ("\u{1f565}", "ten-thirty", 58, (0, 7)),
+    ("\u{1f566}", "eleven-thirty", 58, (0, 7)),
+    ("\u{1f567}", "twelve-thirty", 58, (0, 7)),
+    ("\u{1f56f}\u{fe0f}", "candle", 71, (0, 7)),
+    ("\u{1f570}\u{fe0f}", "mantelpiece clock", 58, (0, 7)),
+    ("\u{1f573}\u{fe0f}", "hole", 15, (0, 7)),
+    ("\u{1f574}\u{fe0f}", "person in suit levitating", 27, (0, 7)),
+    ("\u{1f574}\u{1f3fb}", "person in suit levitating: light skin tone", 27, (4, 0)),
+    ("\u{1f574}\u{1f3fc}", "person in suit levitating: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f574}\u{1f3fd}", "person in suit levitating: medium skin tone", 27, (4, 0)),
+    ("\u{1f574}\u{1f3fe}", "person in suit levitating: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f574}\u{1f3ff}", "person in suit levitating: dark skin tone", 27, (4, 0)),
+    ("\u{1f575}\u{fe0f}", "detective", 25, (0, 7)),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2640}\u{fe0f}", "woman detective", 25, (4, 0)),
+    ("\u{1f575}\u{fe0f}\u{200d}\u{2642}\u{fe0f}", "man detective", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fb}", "detective: light skin tone", 25, (2, 0)),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman detective: light skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man detective: light skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fc}", "detective: medium-light skin tone", 25, (2, 0)),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman detective: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man detective: medium-light skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fd}", "detective: medium skin tone", 25, (2, 0)),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman detective: medium skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man detective: medium skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fe}", "detective: medium-dark skin tone", 25, (2, 0)),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman detective: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man detective: medium-dark skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3ff}", "detective: dark skin tone", 25, (2, 0)),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman detective: dark skin tone", 25, (4, 0)),
+    ("\u{1f575}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man detective: dark skin tone", 25, (4, 0)),
+    ("\u{1f576}\u{fe0f}", "sunglasses", 65, (0, 7)),
+    ("\u{1f577}\u{fe0f}", "spider", 39, (0, 7)),
+    ("\u{1f578}\u{fe0f}", "spider web", 39, (0, 7)),
+    ("\u{1f579}\u{fe0f}", "joystick", 63, (0, 7)),
+    ("\u{1f57a}", "man dancing", 27, (3, 0)),
+    ("\u{1f57a}\u{1f3fb}", "man dancing: light skin tone", 27, (3, 0)),
+    ("\u{1f57a}\u{1f3fc}", "man dancing: medium-light skin tone", 27, (3, 0)),
+    ("\u{1f57a}\u{1f3fd}", "man dancing: medium skin tone", 27, (3, 0)),
+    ("\u{1f57a}\u{1f3fe}", "man dancing: medium-dark skin tone", 27, (3, 0)),
+    ("\u{1f57a}\u{1f3ff}", "man dancing: dark skin tone", 27, (3, 0)),
+    ("\u{1f587}\u{fe0f}", "linked paperclips", 76, (0, 7)),
+    ("\u{1f58a}\u{fe0f}", "pen", 75, (0, 7)),
+    ("\u{1f58b}\u{fe0f}", "fountain pen", 75, (0, 7)),
+    ("\u{1f58c}\u{fe0f}", "paintbrush", 75, (0, 7)),
+    ("\u{1f58d}\u{fe0f}", "crayon", 75, (0, 7)),
+    ("\u{1f590}\u{fe0f}", "hand with fingers splayed", 16, (0, 7)),
+    ("\u{1f590}\u{1f3fb}", "hand with fingers splayed: light skin tone", 16, (1, 0)),
+    ("\u{1f590}\u{1f3fc}", "hand with fingers splayed: medium-light skin tone", 16, (1, 0)),
+    ("\u{1f590}\u{1f3fd}", "hand with fingers splayed: medium skin tone", 16, (1, 0)),
+    ("\u{1f590}\u{1f3fe}", "hand with fingers splayed: medium-dark skin tone", 16, (1, 0)),
+    ("\u{1f590}\u{1f3ff}", "hand with fingers splayed: dark skin tone", 16, (1, 0)),
+    ("\u{1f595}", "middle finger", 18, (1, 0)),
+    ("\u{1f595}\u{1f3fb}", "middle finger: light skin tone", 18, (1, 0)),
+    ("\u{1f595}\u{1f3fc}", "middle finger: medium-light skin tone", 18, (1, 0)),
+    ("\u{1f595}\u{1f3fd}", "middle finger: medium skin tone", 18, (1, 0)),
+    ("\u{1f595}\u{1f3fe}", "middle finger: medium-dark skin tone", 18, (1, 0)),
+    ("\u{1f595}\u{1f3ff}", "middle finger: dark skin tone", 18, (1, 0)),
+    ("\u{1f596}", "vulcan salute", 16, (1, 0)),
+    ("\u{1f596}\u{1f3fb}", "vulcan salute: light skin tone", 16, (1, 0)),
+    ("\u{1f596}\u{1f3fc}", "vulcan salute: medium-light skin tone", 16, (1, 0)),
+    ("\u{1f596}\u{1f3fd}", "vulcan salute: medium skin tone", 16, (1, 0)),
+    ("\u{1f596}\u{1f3fe}", "vulcan salute: medium-dark skin tone", 16, (1, 0)),
+    ("\u{1f596}\u{1f3ff}", "vulcan salute: dark skin tone", 16, (1, 0)),
+    ("\u{1f5a4}", "black heart", 14, (3, 0)),
+    ("\u{1f5a5}\u{fe0f}", "desktop computer", 70, (0, 7)),
+    ("\u{1f5a8}\u{fe0f}", "printer", 70, (0, 7)),
+    ("\u{1f5b1}\u{fe0f}", "computer mouse", 70, (0, 7)),
+    ("\u{1f5b2}\u{fe0f}", "trackball", 70, (0, 7)),
+    ("\u{1f5bc}\u{fe0f}", "framed picture", 64, (0, 7)),
+    ("\u{1f5c2}\u{fe0f}", "card index dividers", 76, (0, 7)),
+    ("\u{1f5c3}\u{fe0f}", "card file box", 76, (0, 7)),
+    ("\u{1f5c4}\u{fe0f}", "file cabinet", 76, (0, 7)),
+    ("\u{1f5d1}\u{fe0f}", "wastebasket", 76, (0, 7)),
+    ("\u{1f5d2}\u{fe0f}", "spiral notepad", 76, (0, 7)),
+    ("\u{1f5d3}\u{fe0f}", "spiral calendar", 76, (0, 7)),
+    ("\u{1f5dc}\u{fe0f}", "clamp", 78, (0, 7)),
+    ("\u{1f5dd}\u{fe0f}", "old key", 77, (0, 7)),
+    ("\u{1f5de}\u{fe0f}", "rolled-up newspaper", 72, (0, 7)),
+    ("\u{1f5e1}\u{fe0f}", "dagger", 78, (0, 7)),
+    ("\u{1f5e3}\u{fe0f}", "speaking head", 31, (0, 7)),
+    ("\u{1f5e8}\u{fe0f}", "left speech bubble", 15, (2, 0)),
+    ("\u{1f5ef}\u{fe0f}", "right anger bubble", 15, (0, 7)),
+    ("\u{1f5f3}\u{fe0f}", "ballot box with ballot", 74, (0, 7)),
+    ("\u{1f5fa}\u{fe0f}", "world map", 49, (0, 7)),
+    ("\u{1f5fb}", "mount fuji", 50, (0, 6)),
+    ("\u{1f5fc}", "Tokyo tower", 51, (0, 6)),
+    ("\u{1f5fd}", "Statue of Liberty", 51, (0, 6)),
+    ("\u{1f5fe}", "map of Japan", 49, (0, 6)),
+    ("\u{1f5ff}", "moai", 82, (0, 6)),
+    ("\u{1f600}", "grinning face", 0, (1, 0)),
+    ("\u{1f601}", "beaming face with smiling eyes", 0, (0, 6)),
+    ("\u{1f602}", "face with tears of joy", 0, (0, 6)),
+    ("\u{1f603}", "grinning face with big eyes", 0, (0, 6)),
+    ("\u{1f604}", "grinning face with smiling eyes", 0, (0, 6)),
+    ("\u{1f605}", "grinning face with sweat", 0, (0, 6)),
+    ("\u{1f606}", "grinning squinting face", 0, (0, 6)),
+    ("\u{1f607}", "smiling face with halo", 0, (1, 0)),
+    ("\u{1f608}", "smiling face with horns", 10, (1, 0)),
+    ("\u{1f609}", "winking face", 0, (0, 6)),
+    ("\u{1f60a}", "smiling face with smiling eyes", 0, (0, 6)),
+    ("\u{1f60b}", "face savoring food", 2, (0, 6)),
+    ("\u{1f60c}", "relieved face", 5, (0, 6)),
+    ("\u{1f60d}", "smiling face with heart-eyes", 1, (0, 6)),
+    ("\u{1f60e}", "smiling face with sunglasses", 8, (1, 0)),
+    ("\u{1f60f}", "smirking face", 4, (0, 6)),
+    ("\u{1f610}", "neutral face", 4, (0, 7)),
+    ("\u{1f611}", "expressionless face", 4, (1, 0)),
+    ("\u{1f612}", "unamused face", 4, (0, 6)),
+    ("\u{1f613}", "downcast face with sweat", 9, (0, 6)),
+    ("\u{1f614}", "pensive face", 5, (0, 6)),
+    ("\u{1f615}", "confused face", 9, (1, 0)),
+    ("\u{1f616}", "confounded face", 9, (0, 6)),
+    ("\u{1f617}", "kissing face", 1, (1, 0)),
+    ("\u{1f618}", "face blowing a kiss", 1, (0, 6)),
+    ("\u{1f619}", "kissing face with smiling eyes", 1, (1, 0)),
+    ("\u{1f61a}", "kissing face with closed eyes", 1, (0, 6)),
+    ("\u{1f61b}", "face with tongue", 2, (1, 0)),
+    ("\u{1f61c}", "winking face with tongue", 2, (0, 6)),
+    ("\u{1f61d}", "squinting face with tongue", 2, (0, 6)),
+    ("\u{1f61e}", "disappointed face", 9, (0, 6)),
+    ("\u{1f61f}", "worried face", 9, (1, 0)),
+    ("\u{1f620}", "angry face", 10, (0, 6)),
+    ("\u{1f621}", "enraged face", 10, (0, 6)),
+    ("\u{1f622}", "crying face", 9, (0, 6)),
+    ("\u{1f623}", "persevering face", 9, (0, 6)),
+    ("\u{1f624}", "face with steam from nose", 10, (0, 6)),
+    ("\u{1f625}", "sad but relieved face", 9, (0, 6)),
+    ("\u{1f626}", "frowning face with open mouth", 9, (1, 0)),
+    ("\u{1f627}", "anguished face", 9, (1, 0)),
+    ("\u{1f628}", "fearful face", 9, (0, 6)),
+    ("\u{1f629}", "weary face", 9, (0, 6)),
+    ("\u{1f62a}", "sleepy face", 5, (0, 6)),
+    ("\u{1f62b}", "tired face", 9, (0, 6)),
+    ("\u{1f62c}", "grimacing face", 4, (1, 0)),
+    ("\u{1f62d}", "loudly crying face", 9, (0, 6)),
+    ("\u{1f62e}", "face with open mouth", 9, (1, 0)),
+    ("\u{1f62e}\u{200d}\u{1f4a8}", "face exhaling", 4, (13, 1)),
+    ("\u{1f62f}", "hushed face", 9, (1, 0)),
+    ("\u{1f630}", "anxious face with sweat", 9, (0, 6)),
+    ("\u{1f631}", "face screaming in fear", 9, (0, 6)),
+    ("\u{1f632}", "astonished face", 9, (0, 6)),
+    ("\u{1f633}", "flushed face", 9, (0, 6)),
+    ("\u{1f634}", "sleeping face", 5, (1, 0)),
+    ("\u{1f635}", "face with crossed-out eyes", 6, (0, 6)),
+    ("\u{1f635}\u{200d}\u{1f4ab}", "face with spiral eyes", 6, (13, 1)),
+    ("\u{1f636}", "face without mouth", 4, (1, 0)),
+    ("\u{1f636}\u{200d}\u{1f32b}\u{fe0f}", "face in clouds", 4, (13, 1)),
+    ("\u{1f637}", "face with medical mask", 6, (0, 6)),
+    ("\u{1f638}", "grinning cat with smiling eyes", 12, (0, 6)),
+    ("\u{1f639}", "cat with tears of joy", 12, (0, 6)),
+    ("\u{1f63a}", "grinning cat", 12, (0, 6)),
+    ("\u{1f63b}", "smiling cat with heart-eyes", 12, (0, 6)),
+    ("\u{1f63c}", "cat with wry smile", 12, (0, 6)),
+    ("\u{1f63d}", "kissing cat", 12, (0, 6)),
+    ("\u{1f63e}", "pouting cat", 12, (0, 6)),
+    ("\u{1f63f}", "crying cat", 12, (0, 6)),
+    ("\u{1f640}", "weary cat", 12, (0, 6)),
+    ("\u{1f641}", "slightly frowning face", 9, (1, 0)),
+    ("\u{1f642}", "slightly smiling face", 0, (1, 0)),
+    ("\u{1f642}\u{200d}\u{2194}\u{fe0f}", "head shaking horizontally", 4, (15, 1)),
+    ("\u{1f642}\u{200d}\u{2195}\u{fe0f}", "head shaking vertically", 4, (15, 1)),
+    ("\u{1f643}", "upside-down face", 0, (1, 0)),
+    ("\u{1f644}", "face with rolling eyes", 4, (1, 0)),
+    ("\u{1f645}", "person gesturing NO", 24, (0, 6)),
+    ("\u{1f645}\u{200d}\u{2640}\u{fe0f}", "woman gesturing NO", 24, (4, 0)),
+    ("\u{1f645}\u{200d}\u{2642}\u{fe0f}", "man gesturing NO", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fb}", "person gesturing NO: light skin tone", 24, (1, 0)),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman gesturing NO: light skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man gesturing NO: light skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fc}", "person gesturing NO: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman gesturing NO: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man gesturing NO: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fd}", "person gesturing NO: medium skin tone", 24, (1, 0)),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman gesturing NO: medium skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man gesturing NO: medium skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fe}", "person gesturing NO: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman gesturing NO: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man gesturing NO: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3ff}", "person gesturing NO: dark skin tone", 24, (1, 0)),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman gesturing NO: dark skin tone", 24, (4, 0)),
+    ("\u{1f645}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man gesturing NO: dark skin tone", 24, (4, 0)),
+    ("\u{1f646}", "person gesturing OK", 24, (0, 6)),
+    ("\u{1f646}\u{200d}\u{2640}\u{fe0f}", "woman gesturing OK", 24, (4, 0)),
+    ("\u{1f646}\u{200d}\u{2642}\u{fe0f}", "man gesturing OK", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fb}", "person gesturing OK: light skin tone", 24, (1, 0)),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman gesturing OK: light skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man gesturing OK: light skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fc}", "person gesturing OK: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman gesturing OK: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man gesturing OK: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fd}", "person gesturing OK: medium skin tone", 24, (1, 0)),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman gesturing OK: medium skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man gesturing OK: medium skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fe}", "person gesturing OK: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman gesturing OK: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man gesturing OK: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3ff}", "person gesturing OK: dark skin tone", 24, (1, 0)),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman gesturing OK: dark skin tone", 24, (4, 0)),
+    ("\u{1f646}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man gesturing OK: dark skin tone", 24, (4, 0)),
+    ("\u{1f647}", "person bowing", 24, (0, 6)),
+    ("\u{1f647}\u{200d}\u{2640}\u{fe0f}", "woman bowing", 24, (4, 0)),
+    ("\u{1f647}\u{200d}\u{2642}\u{fe0f}", "man bowing", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fb}", "person bowing: light skin tone", 24, (1, 0)),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman bowing: light skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man bowing: light skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fc}", "person bowing: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman bowing: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man bowing: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fd}", "person bowing: medium skin tone", 24, (1, 0)),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman bowing: medium skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man bowing: medium skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fe}", "person bowing: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman bowing: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man bowing: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3ff}", "person bowing: dark skin tone", 24, (1, 0)),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman bowing: dark skin tone", 24, (4, 0)),
+    ("\u{1f647}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man bowing: dark skin tone", 24, (4, 0)),
+    ("\u{1f648}", "see-no-evil monkey", 13, (0, 6)),
+    ("\u{1f649}", "hear-no-evil monkey", 13, (0, 6)),
+    ("\u{1f64a}", "speak-no-evil monkey", 13, (0, 6)),
+    ("\u{1f64b}", "person raising hand", 24, (0, 6)),
+    ("\u{1f64b}\u{200d}\u{2640}\u{fe0f}", "woman raising hand", 24, (4, 0)),
+    ("\u{1f64b}\u{200d}\u{2642}\u{fe0f}", "man raising hand", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fb}", "person raising hand: light skin tone", 24, (1, 0)),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman raising hand: light skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man raising hand: light skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fc}", "person raising hand: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman raising hand: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man raising hand: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fd}", "person raising hand: medium skin tone", 24, (1, 0)),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman raising hand: medium skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man raising hand: medium skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fe}", "person raising hand: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman raising hand: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man raising hand: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3ff}", "person raising hand: dark skin tone", 24, (1, 0)),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman raising hand: dark skin tone", 24, (4, 0)),
+    ("\u{1f64b}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man raising hand: dark skin tone", 24, (4, 0)),
+    ("\u{1f64c}", "raising hands", 20, (0, 6)),
+    ("\u{1f64c}\u{1f3fb}", "raising hands: light skin tone", 20, (1, 0)),
+    ("\u{1f64c}\u{1f3fc}", "raising hands: medium-light skin tone", 20, (1, 0)),
+    ("\u{1f64c}\u{1f3fd}", "raising hands: medium skin tone", 20, (1, 0)),
+    ("\u{1f64c}\u{1f3fe}", "raising hands: medium-dark skin tone", 20, (1, 0)),
+    ("\u{1f64c}\u{1f3ff}", "raising hands: dark skin tone", 20, (1, 0)),
+    ("\u{1f64d}", "person frowning", 24, (0, 6)),
+    ("\u{1f64d}\u{200d}\u{2640}\u{fe0f}", "woman frowning", 24, (4, 0)),
+    ("\u{1f64d}\u{200d}\u{2642}\u{fe0f}", "man frowning", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fb}", "person frowning: light skin tone", 24, (1, 0)),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman frowning: light skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man frowning: light skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fc}", "person frowning: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman frowning: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man frowning: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fd}", "person frowning: medium skin tone", 24, (1, 0)),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman frowning: medium skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man frowning: medium skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fe}", "person frowning: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman frowning: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man frowning: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3ff}", "person frowning: dark skin tone", 24, (1, 0)),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman frowning: dark skin tone", 24, (4, 0)),
+    ("\u{1f64d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man frowning: dark skin tone", 24, (4, 0)),
+    ("\u{1f64e}", "person pouting", 24, (0, 6)),
+    ("\u{1f64e}\u{200d}\u{2640}\u{fe0f}", "woman pouting", 24, (4, 0)),
+    ("\u{1f64e}\u{200d}\u{2642}\u{fe0f}", "man pouting", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fb}", "person pouting: light skin tone", 24, (1, 0)),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman pouting: light skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man pouting: light skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fc}", "person pouting: medium-light skin tone", 24, (1, 0)),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman pouting: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man pouting: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fd}", "person pouting: medium skin tone", 24, (1, 0)),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman pouting: medium skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man pouting: medium skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fe}", "person pouting: medium-dark skin tone", 24, (1, 0)),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman pouting: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man pouting: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3ff}", "person pouting: dark skin tone", 24, (1, 0)),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman pouting: dark skin tone", 24, (4, 0)),
+    ("\u{1f64e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man pouting: dark skin tone", 24, (4, 0)),
+    ("\u{1f64f}", "folded hands", 20, (0, 6)),
+    ("\u{1f64f}\u{1f3fb}", "folded hands: light skin tone", 20, (1, 0)),
+    ("\u{1f64f}\u{1f3fc}", "folded hands: medium-light skin tone", 20, (1, 0)),
+    ("\u{1f64f}\u{1f3fd}", "folded hands: medium skin tone", 20, (1, 0)),
+    ("\u{1f64f}\u{1f3fe}", "folded hands: medium-dark skin tone", 20, (1, 0)),
+    ("\u{1f64f}\u{1f3ff}", "folded hands: dark skin tone", 20, (1, 0)),
+    ("\u{1f680}", "rocket", 56, (0, 6)),
+    ("\u{1f681}", "helicopter", 56, (1, 0)),
+    ("\u{1f682}", "locomotive", 54, (1, 0)),
+    ("\u{1f683}", "railway car", 54, (0, 6)),
+    ("\u{1f684}", "high-speed train", 54, (0, 6)),
+    ("\u{1f685}", "bullet train", 54, (0, 6)),
+    ("\u{1f686}", "train", 54, (1, 0)),
+    ("\u{1f687}", "metro", 54, (0, 6)),
+    ("\u{1f688}", "light rail", 54, (1, 0)),
+    ("\u{1f689}", "station", 54, (0, 6)),
+    ("\u{1f68a}", "tram", 54, (1, 0)),
+    ("\u{1f68b}", "tram car", 54, (1, 0)),
+    ("\u{1f68c}", "bus", 54, (0, 6)),
+    ("\u{1f68d}", "oncoming bus", 54, (0, 7)),
+    ("\u{1f68e}", "trolleybus", 54, (1, 0)),
+    ("\u{1f68f}", "bus stop", 54, (0, 6)),
+    ("\u{1f690}", "minibus", 54, (1, 0)),
+    ("\u{1f691}", "ambulance", 54, (0, 6)),
+    ("\u{1f692}", "fire engine", 54, (0, 6)),
+    ("\u{1f693}", "police car", 54, (0, 6)),
+    ("\u{1f694}", "oncoming police car", 54, (0, 7)),
+    ("\u{1f695}", "taxi", 54, (0, 6)),
+    ("\u{1f696}", "oncoming taxi", 54, (1, 0)),
+    ("\u{1f697}", "automobile", 54, (0, 6)),
+    ("\u{1f698}", "oncoming automobile", 54, (0, 7)),
+    ("\u{1f699}", "sport utility vehicle", 54, (0, 6)),
+    ("\u{1f69a}", "delivery truck", 54, (0, 6)),
+    ("\u{1f69b}", "articulated lorry", 54, (1, 0)),
+    ("\u{1f69c}", "tractor", 54, (1, 0)),
+    ("\u{1f69d}", "monorail", 54, (1, 0)),
+    ("\u{1f69e}", "mountain railway", 54, (1, 0)),
+    ("\u{1f69f}", "suspension railway", 56, (1, 0)),
+    ("\u{1f6a0}", "mountain cableway", 56, (1, 0)),
+    ("\u{1f6a1}", "aerial tramway", 56, (1, 0)),
+    ("\u{1f6a2}", "ship", 55, (0, 6)),
+    ("\u{1f6a3}", "person rowing boat", 28, (1, 0)),
+    ("\u{1f6a3}\u{200d}\u{2640}\u{fe0f}", "woman rowing boat", 28, (4, 0)),
+    ("\u{1f6a3}\u{200d}\u{2642}\u{fe0f}", "man rowing boat", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fb}", "person rowing boat: light skin tone", 28, (1, 0)),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman rowing boat: light skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man rowing boat: light skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fc}", "person rowing boat: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman rowing boat: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man rowing boat: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fd}", "person rowing boat: medium skin tone", 28, (1, 0)),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman rowing boat: medium skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man rowing boat: medium skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fe}", "person rowing boat: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman rowing boat: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man rowing boat: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3ff}", "person rowing boat: dark skin tone", 28, (1, 0)),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman rowing boat: dark skin tone", 28, (4, 0)),
+    ("\u{1f6a3}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man rowing boat: dark skin tone", 28, (4, 0)),
+    ("\u{1f6a4}", "speedboat", 55, (0, 6)),
+    ("\u{1f6a5}", "horizontal traffic light", 54, (0, 6)),
+    ("\u{1f6a6}", "vertical traffic light", 54, (1, 0)),
+    ("\u{1f6a7}", "construction", 54, (0, 6)),
+    ("\u{1f6a8}", "police car light", 54, (0, 6)),
+    ("\u{1f6a9}", "triangular flag", 97, (0, 6)),
+    ("\u{1f6aa}", "door", 81, (0, 6)),
+    ("\u{1f6ab}", "prohibited", 84, (0, 6)),
+    ("\u{1f6ac}", "cigarette", 82, (0, 6)),
+    ("\u{1f6ad}", "no smoking", 84, (0, 6)),
+    ("\u{1f6ae}", "litter in bin sign", 83, (1, 0)),
+    ("\u{1f6af}", "no littering", 84, (1, 0)),
+    ("\u{1f6b0}", "potable water", 83, (1, 0)),
+    ("\u{1f6b1}", "non-potable water", 84, (1, 0)),
+    ("\u{1f6b2}", "bicycle", 54, (0, 6)),
+    ("\u{1f6b3}", "no bicycles", 84, (1, 0)),
+    ("\u{1f6b4}", "person biking", 28, (1, 0)),
+    ("\u{1f6b4}\u{200d}\u{2640}\u{fe0f}", "woman biking", 28, (4, 0)),
+    ("\u{1f6b4}\u{200d}\u{2642}\u{fe0f}", "man biking", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fb}", "person biking: light skin tone", 28, (1, 0)),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman biking: light skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man biking: light skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fc}", "person biking: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman biking: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man biking: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fd}", "person biking: medium skin tone", 28, (1, 0)),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman biking: medium skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man biking: medium skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fe}", "person biking: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman biking: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man biking: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3ff}", "person biking: dark skin tone", 28, (1, 0)),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman biking: dark skin tone", 28, (4, 0)),
+    ("\u{1f6b4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man biking: dark skin tone", 28, (4, 0)),
+    ("\u{1f6b5}", "person mountain biking", 28, (1, 0)),
+    ("\u{1f6b5}\u{200d}\u{2640}\u{fe0f}", "woman mountain biking", 28, (4, 0)),
+    ("\u{1f6b5}\u{200d}\u{2642}\u{fe0f}", "man mountain biking", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fb}", "person mountain biking: light skin tone", 28, (1, 0)),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman mountain biking: light skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man mountain biking: light skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fc}", "person mountain biking: medium-light skin tone", 28, (1, 0)),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman mountain biking: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man mountain biking: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fd}", "person mountain biking: medium skin tone", 28, (1, 0)),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman mountain biking: medium skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man mountain biking: medium skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fe}", "person mountain biking: medium-dark skin tone", 28, (1, 0)),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman mountain biking: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man mountain biking: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3ff}", "person mountain biking: dark skin tone", 28, (1, 0)),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman mountain biking: dark skin tone", 28, (4, 0)),
+    ("\u{1f6b5}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man mountain biking: dark skin tone", 28, (4, 0)),
+    ("\u{1f6b6}", "person walking", 27, (0, 6)),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}", "woman walking", 27, (4, 0)),
+    ("\u{1f6b6}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman walking facing right", 27, (15, 1)),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}", "man walking", 27, (4, 0)),
+    ("\u{1f6b6}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man walking facing right", 27, (15, 1)),
+    ("\u{1f6b6}\u{200d}\u{27a1}\u{fe0f}", "person walking facing right", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fb}", "person walking: light skin tone", 27, (1, 0)),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman walking: light skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman walking facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man walking: light skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man walking facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", "person walking facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fc}", "person walking: medium-light skin tone", 27, (1, 0)),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman walking: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman walking facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man walking: medium-light skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man walking facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", "person walking facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fd}", "person walking: medium skin tone", 27, (1, 0)),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman walking: medium skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman walking facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man walking: medium skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man walking facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", "person walking facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fe}", "person walking: medium-dark skin tone", 27, (1, 0)),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman walking: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman walking facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man walking: medium-dark skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man walking facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", "person walking facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3ff}", "person walking: dark skin tone", 27, (1, 0)),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman walking: dark skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman walking facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man walking: dark skin tone", 27, (4, 0)),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man walking facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f6b6}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", "person walking facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f6b7}", "no pedestrians", 84, (1, 0)),
+    ("\u{1f6b8}", "children crossing", 84, (1, 0)),
+    ("\u{1f6b9}", "men’s room", 83, (0, 6)),
+    ("\u{1f6ba}", "women’s room", 83, (0, 6)),
+    ("\u{1f6bb}", "restroom", 83, (0, 6)),
+    ("\u{1f6bc}", "baby symbol", 83, (0, 6)),
+    ("\u{1f6bd}", "toilet", 81, (0, 6)),
+    ("\u{1f6be}", "water closet", 83, (0, 6)),
+    ("\u{1f6bf}", "shower", 81, (1, 0)),
+    ("\u{1f6c0}", "person taking bath", 29, (0, 6)),
+    ("\u{1f6c0}\u{1f3fb}", "person taking bath: light skin tone", 29, (1, 0)),
+    ("\u{1f6c0}\u{1f3fc}", "person taking bath: medium-light skin tone", 29, (1, 0)),
+    ("\u{1f6c0}\u{1f3fd}", "person taking bath: medium skin tone", 29, (1, 0)),
+    ("\u{1f6c0}\u{1f3fe}", "person taking bath: medium-dark skin tone", 29, (1, 0)),
+    ("\u{1f6c0}\u{1f3ff}", "person taking bath: dark skin tone", 29, (1, 0)),
+    ("\u{1f6c1}", "bathtub", 81, (1, 0)),
+    ("\u{1f6c2}", "passport control", 83, (1, 0)),
+    ("\u{1f6c3}", "customs", 83, (1, 0)),
+    ("\u{1f6c4}", "baggage claim", 83, (1, 0)),
+    ("\u{1f6c5}", "left luggage", 83, (1, 0)),
+    ("\u{1f6cb}\u{fe0f}", "couch and lamp", 81, (0, 7)),
+    ("\u{1f6cc}", "person in bed", 29, (1, 0)),
+    ("\u{1f6cc}\u{1f3fb}", "person in bed: light skin tone", 29, (4, 0)),
+    ("\u{1f6cc}\u{1f3fc}", "person in bed: medium-light skin tone", 29, (4, 0)),
+    ("\u{1f6cc}\u{1f3fd}", "person in bed: medium skin tone", 29, (4, 0)),
+    ("\u{1f6cc}\u{1f3fe}", "person in bed: medium-dark skin tone", 29, (4, 0)),
+    ("\u{1f6cc}\u{1f3ff}", "person in bed: dark skin tone", 29, (4, 0)),
+    ("\u{1f6cd}\u{fe0f}", "shopping bags", 65, (0, 7)),
+    ("\u{1f6ce}\u{fe0f}", "bellhop bell", 57, (0, 7)),
+    ("\u{1f6cf}\u{fe0f}", "bed", 81, (0, 7)),
+    ("\u{1f6d0}", "place of worship", 86, (1, 0)),
+    ("\u{1f6d1}", "stop sign", 54, (3, 0)),
+    ("\u{1f6d2}", "shopping cart", 81, (3, 0)),
+    ("\u{1f6d5}", "hindu temple", 52, (12, 0)),
+    ("\u{1f6d6}", "hut", 51, (13, 0)),
+    ("\u{1f6d7}", "elevator", 81, (13, 0)),
+    ("\u{1f6dc}", "wireless", 88, (15, 0)),
+    ("\u{1f6dd}", "playground slide", 53, (14, 0)),
+    ("\u{1f6de}", "wheel", 54, (14, 0)),
+    ("\u{1f6df}", "ring buoy", 55, (14, 0)),
+    ("\u{1f6e0}\u{fe0f}", "hammer and wrench", 78, (0, 7)),
+    ("\u{1f6e1}\u{fe0f}", "shield", 78, (0, 7)),
+    ("\u{1f6e2}\u{fe0f}", "oil drum", 54, (0, 7)),
+    ("\u{1f6e3}\u{fe0f}", "motorway", 54, (0, 7)),
+    ("\u{1f6e4}\u{fe0f}", "railway track", 54, (0, 7)),
+    ("\u{1f6e5}\u{fe0f}", "motor boat", 55, (0, 7)),
+    ("\u{1f6e9}\u{fe0f}", "small airplane", 56, (0, 7)),
+    ("\u{1f6eb}", "airplane departure", 56, (1, 0)),
+    ("\u{1f6ec}", "airplane arrival", 56, (1, 0)),
+    ("\u{1f6f0}\u{fe0f}", "satellite", 56, (0, 7)),
+    ("\u{1f6f3}\u{fe0f}", "passenger ship", 55, (0, 7)),
+    ("\u{1f6f4}", "kick scooter", 54, (3, 0)),
+    ("\u{1f6f5}", "motor scooter", 54, (3, 0)),
+    ("\u{1f6f6}", "canoe", 55, (3, 0)),
+    ("\u{1f6f7}", "sled", 62, (5, 0)),
+    ("\u{1f6f8}", "flying saucer", 56, (5, 0)),
+    ("\u{1f6f9}", "skateboard", 54, (11, 0)),
+    ("\u{1f6fa}", "auto rickshaw", 54, (12, 0)),
+    ("\u{1f6fb}", "pickup truck", 54, (13, 0)),
+    ("\u{1f6fc}", "roller skate", 54, (13, 0)),
+    ("\u{1f7e0}", "orange circle", 96, (12, 0)),
+    ("\u{1f7e1}", "yellow circle", 96, (12, 0)),
+    ("\u{1f7e2}", "green circle", 96, (12, 0)),
+    ("\u{1f7e3}", "purple circle", 96, (12, 0)),
+    ("\u{1f7e4}", "brown circle", 96, (12, 0)),
+    ("\u{1f7e5}", "red square", 96, (12, 0)),
+    ("\u{1f7e6}", "blue square", 96, (12, 0)),
+    ("\u{1f7e7}", "orange square", 96, (12, 0)),
+    ("\u{1f7e8}", "yellow square", 96, (12, 0)),
+    ("\u{1f7e9}", "green square", 96, (12, 0)),
+    ("\u{1f7ea}", "purple square", 96, (12, 0)),
+    ("\u{1f7eb}", "brown square", 96, (12, 0)),
+    ("\u{1f7f0}", "heavy equals sign", 90, (14, 0)),
+    ("\u{1f90c}", "pinched fingers", 17, (13, 0)),
+    ("\u{1f90c}\u{1f3fb}", "pinched fingers: light skin tone", 17, (13, 0)),
+    ("\u{1f90c}\u{1f3fc}", "pinched fingers: medium-light skin tone", 17, (13, 0)),
+    ("\u{1f90c}\u{1f3fd}", "pinched fingers: medium skin tone", 17, (13, 0)),
+    ("\u{1f90c}\u{1f3fe}", "pinched fingers: medium-dark skin tone", 17, (13, 0)),
+    ("\u{1f90c}\u{1f3ff}", "pinched fingers: dark skin tone", 17, (13, 0)),
+    ("\u{1f90d}", "white heart", 14, (12, 0)),
+    ("\u{1f90e}", "brown heart", 14, (12, 0)),
+    ("\u{1f90f}", "pinching hand", 17, (12, 0)),
+    ("\u{1f90f}\u{1f3fb}", "pinching hand: light skin tone", 17, (12, 0)),
+    ("\u{1f90f}\u{1f3fc}", "pinching hand: medium-light skin tone", 17, (12, 0)),
+    ("\u{1f90f}\u{1f3fd}", "pinching hand: medium skin tone", 17, (12, 0)),
+    ("\u{1f90f}\u{1f3fe}", "pinching hand: medium-dark skin tone", 17, (12, 0)),
+    ("\u{1f90f}\u{1f3ff}", "pinching hand: dark skin tone", 17, (12, 0)),
+    ("\u{1f910}", "zipper-mouth face", 4, (1, 0)),
+    ("\u{1f911}", "money-mouth face", 2, (1, 0)),
+    ("\u{1f912}", "face with thermometer", 6, (1, 0)),
+    ("\u{1f913}", "nerd face", 8, (1, 0)),
+    ("\u{1f914}", "thinking face", 3, (1, 0)),
+    ("\u{1f915}", "face with head-bandage", 6, (1, 0)),
+    ("\u{1f916}", "robot", 11, (1, 0)),
+    ("\u{1f917}", "smiling face with open hands", 3, (1, 0)),
+    ("\u{1f918}", "sign of the horns", 17, (1, 0)),
+    ("\u{1f918}\u{1f3fb}", "sign of the horns: light skin tone", 17, (1, 0)),
+    ("\u{1f918}\u{1f3fc}", "sign of the horns: medium-light skin tone", 17, (1, 0)),
+    ("\u{1f918}\u{1f3fd}", "sign of the horns: medium skin tone", 17, (1, 0)),
+    ("\u{1f918}\u{1f3fe}", "sign of the horns: medium-dark skin tone", 17, (1, 0)),
+    ("\u{1f918}\u{1f3ff}", "sign of the horns: dark skin tone", 17, (1, 0)),
+    ("\u{1f919}", "call me hand", 17, (3, 0)),
+    ("\u{1f919}\u{1f3fb}", "call me hand: light skin tone", 17, (3, 0)),
+    ("\u{1f919}\u{1f3fc}", "call me hand: medium-light skin tone", 17, (3, 0)),
+    ("\u{1f919}\u{1f3fd}", "call me hand: medium skin tone", 17, (3, 0)),
+    ("\u{1f919}\u{1f3fe}", "call me hand: medium-dark skin tone", 17, (3, 0)),
+    ("\u{1f919}\u{1f3ff}", "call me hand: dark skin tone", 17, (3, 0)),
+    ("\u{1f91a}", "raised back of hand", 16, (3, 0)),
+    ("\u{1f91a}\u{1f3fb}", "raised back of hand: light skin tone", 16, (3, 0)),
+    ("\u{1f91a}\u{1f3fc}", "raised back of hand: medium-light skin tone", 16, (3, 0)),
+    ("\u{1f91a}\u{1f3fd}", "raised back of hand: medium skin tone", 16, (3, 0)),
+    ("\u{1f91a}\u{1f3fe}", "raised back of hand: medium-dark skin tone", 16, (3, 0)),
+    ("\u{1f91a}\u{1f3ff}", "raised back of hand: dark skin tone", 16, (3, 0)),
+    ("\u{1f91b}", "left-facing fist", 19, (3, 0)),
+    ("\u{1f91b}\u{1f3fb}", "left-facing fist: light skin tone", 19, (3, 0)),
+    ("\u{1f91b}\u{1f3fc}", "left-facing fist: medium-light skin tone", 19, (3, 0)),
+    ("\u{1f91b}\u{1f3fd}", "left-facing fist: medium skin tone", 19, (3, 0)),
+    ("\u{1f91b}\u{1f3fe}", "left-facing fist: medium-dark skin tone", 19, (3, 0)),
+    ("\u{1f91b}\u{1f3ff}", "left-facing fist: dark skin tone", 19, (3, 0)),
+    ("\u{1f91c}", "right-facing fist", 19, (3, 0)),
+    ("\u{1f91c}\u{1f3fb}", "right-facing fist: light skin tone", 19, (3, 0)),
+    ("\u{1f91c}\u{1f3fc}", "right-facing fist: medium-light skin tone", 19, (3, 0)),
+    ("\u{1f91c}\u{1f3fd}", "right-facing fist: medium skin tone", 19, (3, 0)),
+    ("\u{1f91c}\u{1f3fe}", "right-facing fist: medium-dark skin tone", 19, (3, 0)),
+    ("\u{1f91c}\u{1f3ff}", "right-facing fist: dark skin tone", 19, (3, 0)),
+    ("\u{1f91d}", "handshake", 20, (3, 0)),
+    ("\u{1f91d}\u{1f3fb}", "handshake: light skin tone", 20, (14, 0)),
+    ("\u{1f91d}\u{1f3fc}", "handshake: medium-light skin tone", 20, (14, 0)),
+    ("\u{1f91d}\u{1f3fd}", "handshake: medium skin tone", 20, (14, 0)),
+    ("\u{1f91d}\u{1f3fe}", "handshake: medium-dark skin tone", 20, (14, 0)),
+    ("\u{1f91d}\u{1f3ff}", "handshake: dark skin tone", 20, (14, 0)),
+    ("\u{1f91e}", "crossed fingers", 17, (3, 0)),
+    ("\u{1f91e}\u{1f3fb}", "crossed fingers: light skin tone", 17, (3, 0)),
+    ("\u{1f91e}\u{1f3fc}", "crossed fingers: medium-light skin tone", 17, (3, 0)),
+    ("\u{1f91e}\u{1f3fd}", "crossed fingers: medium skin tone", 17, (3, 0)),
+    ("\u{1f91e}\u{1f3fe}", "crossed fingers: medium-dark skin tone", 17, (3, 0)),
+    ("\u{1f91e}\u{1f3ff}", "crossed fingers: dark skin tone", 17, (3, 0)),
+    ("\u{1f91f}", "love-you gesture", 17, (5, 0)),
+    ("\u{1f91f}\u{1f3fb}", "love-you gesture: light skin tone", 17, (5, 0)),
+    ("\u{1f91f}\u{1f3fc}", "love-you gesture: medium-light skin tone", 17, (5, 0)),
+    ("\u{1f91f}\u{1f3fd}", "love-you gesture: medium skin tone", 17, (5, 0)),
+    ("\u{1f91f}\u{1f3fe}", "love-you gesture: medium-dark skin tone", 17, (5, 0)),
+    ("\u{1f91f}\u{1f3ff}", "love-you gesture: dark skin tone", 17, (5, 0)),
+    ("\u{1f920}", "cowboy hat face", 7, (3, 0)),
+    ("\u{1f921}", "clown face", 11, (3, 0)),
+    ("\u{1f922}", "nauseated face", 6, (3, 0)),
+    ("\u{1f923}", "rolling on the floor laughing", 0, (3, 0)),
+    ("\u{1f924}", "drooling face", 5, (3, 0)),
+    ("\u{1f925}", "lying face", 4, (3, 0)),
+    ("\u{1f926}", "person facepalming", 24, (3, 0)),
+    ("\u{1f926}\u{200d}\u{2640}\u{fe0f}", "woman facepalming", 24, (4, 0)),
+    ("\u{1f926}\u{200d}\u{2642}\u{fe0f}", "man facepalming", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fb}", "person facepalming: light skin tone", 24, (3, 0)),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman facepalming: light skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man facepalming: light skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fc}", "person facepalming: medium-light skin tone", 24, (3, 0)),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman facepalming: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man facepalming: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fd}", "person facepalming: medium skin tone", 24, (3, 0)),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman facepalming: medium skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man facepalming: medium skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fe}", "person facepalming: medium-dark skin tone", 24, (3, 0)),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman facepalming: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man facepalming: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3ff}", "person facepalming: dark skin tone", 24, (3, 0)),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman facepalming: dark skin tone", 24, (4, 0)),
+    ("\u{1f926}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man facepalming: dark skin tone", 24, (4, 0)),
+    ("\u{1f927}", "sneezing face", 6, (3, 0)),
+    ("\u{1f928}", "face with raised eyebrow", 4, (5, 0)),
+    ("\u{1f929}", "star-struck", 1, (5, 0)),
+    ("\u{1f92a}", "zany face", 2, (5, 0)),
+    ("\u{1f92b}", "shushing face", 3, (5, 0)),
+    ("\u{1f92c}", "face with symbols on mouth", 10, (5, 0)),
+    ("\u{1f92d}", "face with hand over mouth", 3, (5, 0)),
+    ("\u{1f92e}", "face vomiting", 6, (5, 0)),
+    ("\u{1f92f}", "exploding head", 6, (5, 0)),
+    ("\u{1f930}", "pregnant woman", 25, (3, 0)),
+    ("\u{1f930}\u{1f3fb}", "pregnant woman: light skin tone", 25, (3, 0)),
+    ("\u{1f930}\u{1f3fc}", "pregnant woman: medium-light skin tone", 25, (3, 0)),
+    ("\u{1f930}\u{1f3fd}", "pregnant woman: medium skin tone", 25, (3, 0)),
+    ("\u{1f930}\u{1f3fe}", "pregnant woman: medium-dark skin tone", 25, (3, 0)),
+    ("\u{1f930}\u{1f3ff}", "pregnant woman: dark skin tone", 25, (3, 0)),
+    ("\u{1f931}", "breast-feeding", 25, (5, 0)),
+    ("\u{1f931}\u{1f3fb}", "breast-feeding: light skin tone", 25, (5, 0)),
+    ("\u{1f931}\u{1f3fc}", "breast-feeding: medium-light skin tone", 25, (5, 0)),
+    ("\u{1f931}\u{1f3fd}", "breast-feeding: medium skin tone", 25, (5, 0)),
+    ("\u{1f931}\u{1f3fe}", "breast-feeding: medium-dark skin tone", 25, (5, 0)),
+    ("\u{1f931}\u{1f3ff}", "breast-feeding: dark skin tone", 25, (5, 0)),
+    ("\u{1f932}", "palms up together", 20, (5, 0)),
+    ("\u{1f932}\u{1f3fb}", "palms up together: light skin tone", 20, (5, 0)),
+    ("\u{1f932}\u{1f3fc}", "palms up together: medium-light skin tone", 20, (5, 0)),
+    ("\u{1f932}\u{1f3fd}", "palms up together: medium skin tone", 20, (5, 0)),
+    ("\u{1f932}\u{1f3fe}", "palms up together: medium-dark skin tone", 20, (5, 0)),
+    ("\u{1f932}\u{1f3ff}", "palms up together: dark skin tone", 20, (5, 0)),
+    ("\u{1f933}", "selfie", 21, (3, 0)),
+    ("\u{1f933}\u{1f3fb}", "selfie: light skin tone", 21, (3, 0)),
+    ("\u{1f933}\u{1f3fc}", "selfie: medium-light skin tone", 21, (3, 0)),
+    ("\u{1f933}\u{1f3fd}", "selfie: medium skin tone", 21, (3, 0)),
+    ("\u{1f933}\u{1f3fe}", "selfie: medium-dark skin tone", 21, (3, 0)),
+    ("\u{1f933}\u{1f3ff}", "selfie: dark skin tone", 21, (3, 0)),
+    ("\u{1f934}", "prince", 25, (3, 0)),
+    ("\u{1f934}\u{1f3fb}", "prince: light skin tone", 25, (3, 0)),
+    ("\u{1f934}\u{1f3fc}", "prince: medium-light skin tone", 25, (3, 0)),
+    ("\u{1f934}\u{1f3fd}", "prince: medium skin tone", 25, (3, 0)),
+    ("\u{1f934}\u{1f3fe}", "prince: medium-dark skin tone", 25, (3, 0)),
+    ("\u{1f934}\u{1f3ff}", "prince: dark skin tone", 25, (3, 0)),
+    ("\u{1f935}", "person in tuxedo", 25, (3, 0)),
+    ("\u{1f935}\u{200d}\u{2640}\u{fe0f}", "woman in tuxedo", 25, (13, 0)),
+    ("\u{1f935}\u{200d}\u{2642}\u{fe0f}", "man in tuxedo", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fb}", "person in tuxedo: light skin tone", 25, (3, 0)),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman in tuxedo: light skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man in tuxedo: light skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fc}", "person in tuxedo: medium-light skin tone", 25, (3, 0)),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman in tuxedo: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man in tuxedo: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fd}", "person in tuxedo: medium skin tone", 25, (3, 0)),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman in tuxedo: medium skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man in tuxedo: medium skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fe}", "person in tuxedo: medium-dark skin tone", 25, (3, 0)),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman in tuxedo: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man in tuxedo: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3ff}", "person in tuxedo: dark skin tone", 25, (3, 0)),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman in tuxedo: dark skin tone", 25, (13, 0)),
+    ("\u{1f935}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man in tuxedo: dark skin tone", 25, (13, 0)),
+    ("\u{1f936}", "Mrs. Claus", 26, (3, 0)),
+    ("\u{1f936}\u{1f3fb}", "Mrs. Claus: light skin tone", 26, (3, 0)),
+    ("\u{1f936}\u{1f3fc}", "Mrs. Claus: medium-light skin tone", 26, (3, 0)),
+    ("\u{1f936}\u{1f3fd}", "Mrs. Claus: medium skin tone", 26, (3, 0)),
+    ("\u{1f936}\u{1f3fe}", "Mrs. Claus: medium-dark skin tone", 26, (3, 0)),
+    ("\u{1f936}\u{1f3ff}", "Mrs. Claus: dark skin tone", 26, (3, 0)),
+    ("\u{1f937}", "person shrugging", 24, (3, 0)),
+    ("\u{1f937}\u{200d}\u{2640}\u{fe0f}", "woman shrugging", 24, (4, 0)),
+    ("\u{1f937}\u{200d}\u{2642}\u{fe0f}", "man shrugging", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fb}", "person shrugging: light skin tone", 24, (3, 0)),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman shrugging: light skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man shrugging: light skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fc}", "person shrugging: medium-light skin tone", 24, (3, 0)),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman shrugging: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man shrugging: medium-light skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fd}", "person shrugging: medium skin tone", 24, (3, 0)),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman shrugging: medium skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man shrugging: medium skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fe}", "person shrugging: medium-dark skin tone", 24, (3, 0)),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman shrugging: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man shrugging: medium-dark skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3ff}", "person shrugging: dark skin tone", 24, (3, 0)),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman shrugging: dark skin tone", 24, (4, 0)),
+    ("\u{1f937}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man shrugging: dark skin tone", 24, (4, 0)),
+    ("\u{1f938}", "person cartwheeling", 28, (3, 0)),
+    ("\u{1f938}\u{200d}\u{2640}\u{fe0f}", "woman cartwheeling", 28, (4, 0)),
+    ("\u{1f938}\u{200d}\u{2642}\u{fe0f}", "man cartwheeling", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fb}", "person cartwheeling: light skin tone", 28, (3, 0)),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman cartwheeling: light skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man cartwheeling: light skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fc}", "person cartwheeling: medium-light skin tone", 28, (3, 0)),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman cartwheeling: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man cartwheeling: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fd}", "person cartwheeling: medium skin tone", 28, (3, 0)),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman cartwheeling: medium skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man cartwheeling: medium skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fe}", "person cartwheeling: medium-dark skin tone", 28, (3, 0)),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman cartwheeling: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man cartwheeling: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3ff}", "person cartwheeling: dark skin tone", 28, (3, 0)),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman cartwheeling: dark skin tone", 28, (4, 0)),
+    ("\u{1f938}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man cartwheeling: dark skin tone", 28, (4, 0)),
+    ("\u{1f939}", "person juggling", 28, (3, 0)),
+    ("\u{1f939}\u{200d}\u{2640}\u{fe0f}", "woman juggling", 28, (4, 0)),
+    ("\u{1f939}\u{200d}\u{2642}\u{fe0f}", "man juggling", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fb}", "person juggling: light skin tone", 28, (3, 0)),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman juggling: light skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man juggling: light skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fc}", "person juggling: medium-light skin tone", 28, (3, 0)),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman juggling: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man juggling: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fd}", "person juggling: medium skin tone", 28, (3, 0)),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman juggling: medium skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man juggling: medium skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fe}", "person juggling: medium-dark skin tone", 28, (3, 0)),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman juggling: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man juggling: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3ff}", "person juggling: dark skin tone", 28, (3, 0)),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman juggling: dark skin tone", 28, (4, 0)),
+    ("\u{1f939}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man juggling: dark skin tone", 28, (4, 0)),
+    ("\u{1f93a}", "person fencing", 28, (3, 0)),
+    ("\u{1f93c}", "people wrestling", 28, (3, 0)),
+    ("\u{1f93c}\u{200d}\u{2640}\u{fe0f}", "women wrestling", 28, (4, 0)),
+    ("\u{1f93c}\u{200d}\u{2642}\u{fe0f}", "men wrestling", 28, (4, 0)),
+    ("\u{1f93d}", "person playing water polo", 28, (3, 0)),
+    ("\u{1f93d}\u{200d}\u{2640}\u{fe0f}", "woman playing water polo", 28, (4, 0)),
+    ("\u{1f93d}\u{200d}\u{2642}\u{fe0f}", "man playing water polo", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fb}", "person playing water polo: light skin tone", 28, (3, 0)),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman playing water polo: light skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man playing water polo: light skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fc}", "person playing water polo: medium-light skin tone", 28, (3, 0)),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman playing water polo: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man playing water polo: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fd}", "person playing water polo: medium skin tone", 28, (3, 0)),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman playing water polo: medium skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man playing water polo: medium skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fe}", "person playing water polo: medium-dark skin tone", 28, (3, 0)),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman playing water polo: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man playing water polo: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3ff}", "person playing water polo: dark skin tone", 28, (3, 0)),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman playing water polo: dark skin tone", 28, (4, 0)),
+    ("\u{1f93d}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man playing water polo: dark skin tone", 28, (4, 0)),
+    ("\u{1f93e}", "person playing handball", 28, (3, 0)),
+    ("\u{1f93e}\u{200d}\u{2640}\u{fe0f}", "woman playing handball", 28, (4, 0)),
+    ("\u{1f93e}\u{200d}\u{2642}\u{fe0f}", "man playing handball", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fb}", "person playing handball: light skin tone", 28, (3, 0)),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman playing handball: light skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man playing handball: light skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fc}", "person playing handball: medium-light skin tone", 28, (3, 0)),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman playing handball: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man playing handball: medium-light skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fd}", "person playing handball: medium skin tone", 28, (3, 0)),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman playing handball: medium skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man playing handball: medium skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fe}", "person playing handball: medium-dark skin tone", 28, (3, 0)),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman playing handball: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man playing handball: medium-dark skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3ff}", "person playing handball: dark skin tone", 28, (3, 0)),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman playing handball: dark skin tone", 28, (4, 0)),
+    ("\u{1f93e}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man playing handball: dark skin tone", 28, (4, 0)),
+    ("\u{1f93f}", "diving mask", 62, (12, 0)),
+    ("\u{1f940}", "wilted flower", 40, (3, 0)),
+    ("\u{1f941}", "drum", 68, (3, 0)),
+    ("\u{1f942}", "clinking glasses", 47, (3, 0)),
+    ("\u{1f943}", "tumbler glass", 47, (3, 0)),
+    ("\u{1f944}", "spoon", 48, (3, 0)),
+    ("\u{1f945}", "goal net", 62, (3, 0)),
+    ("\u{1f947}", "1st place medal", 61, (3, 0)),
+    ("\u{1f948}", "2nd place medal", 61, (3, 0)),
+    ("\u{1f949}", "3rd place medal", 61, (3, 0)),
+    ("\u{1f94a}", "boxing glove", 62, (3, 0)),
+    ("\u{1f94b}", "martial arts uniform", 62, (3, 0)),
+    ("\u{1f94c}", "curling stone", 62, (5, 0)),
+    ("\u{1f94d}", "lacrosse", 62, (11, 0)),
+    ("\u{1f94e}", "softball", 62, (11, 0)),
+    ("\u{1f94f}", "flying disc", 62, (11, 0)),
+    ("\u{1f950}", "croissant", 44, (3, 0)),
+    ("\u{1f951}", "avocado", 43, (3, 0)),
+    ("\u{1f952}", "cucumber", 43, (3, 0)),
+    ("\u{1f953}", "bacon", 44, (3, 0)),
+    ("\u{1f954}", "potato", 43, (3, 0)),
+    ("\u{1f955}", "carrot", 43, (3, 0)),
+    ("\u{1f956}", "baguette bread", 44, (3, 0)),
+    ("\u{1f957}", "green salad", 44, (3, 0)),
+    ("\u{1f958}", "shallow pan of food", 44, (3, 0)),
+    ("\u{1f959}", "stuffed flatbread", 44, (3, 0)),
+    ("\u{1f95a}", "egg", 44, (3, 0)),
+    ("\u{1f95b}", "glass of milk", 47, (3, 0)),
+    ("\u{1f95c}", "peanuts", 43, (3, 0)),
+    ("\u{1f95d}", "kiwi fruit", 42, (3, 0)),
+    ("\u{1f95e}", "pancakes", 44, (3, 0)),
+    ("\u{1f95f}", "dumpling", 45, (5, 0)),
+    ("\u{1f960}", "fortune cookie", 45, (5, 0)),
+    ("\u{1f961}", "takeout box", 45, (5, 0)),
+    ("\u{1f962}", "chopsticks", 48, (5, 0)),
+    ("\u{1f963}", "bowl with spoon", 44, (5, 0)),
+    ("\u{1f964}", "cup with straw", 47, (5, 0)),
+    ("\u{1f965}", "coconut", 42, (5, 0)),
+    ("\u{1f966}", "broccoli", 43, (5, 0)),
+    ("\u{1f967}", "pie", 46, (5, 0)),
+    ("\u{1f968}", "pretzel", 44, (5, 0)),
+    ("\u{1f969}", "cut of meat", 44, (5, 0)),
+    ("\u{1f96a}", "sandwich", 44, (5, 0)),
+    ("\u{1f96b}", "canned food", 44, (5, 0)),
+    ("\u{1f96c}", "leafy green", 43, (11, 0)),
+    ("\u{1f96d}", "mango", 42, (11, 0)),
+    ("\u{1f96e}", "moon cake", 45, (11, 0)),
+    ("\u{1f96f}", "bagel", 44, (11, 0)),
+    ("\u{1f970}", "smiling face with hearts", 1, (11, 0)),
+    ("\u{1f971}", "yawning face", 9, (12, 0)),
+    ("\u{1f972}", "smiling face with tear", 1, (13, 0)),
+    ("\u{1f973}", "partying face", 7, (11, 0)),
+    ("\u{1f974}", "woozy face", 6, (11, 0)),
+    ("\u{1f975}", "hot face", 6, (11, 0)),
+    ("\u{1f976}", "cold face", 6, (11, 0)),
+    ("\u{1f977}", "ninja", 25, (13, 0)),
+    ("\u{1f977}\u{1f3fb}", "ninja: light skin tone", 25, (13, 0)),
+    ("\u{1f977}\u{1f3fc}", "ninja: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f977}\u{1f3fd}", "ninja: medium skin tone", 25, (13, 0)),
+    ("\u{1f977}\u{1f3fe}", "ninja: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f977}\u{1f3ff}", "ninja: dark skin tone", 25, (13, 0)),
+    ("\u{1f978}", "disguised face", 7, (13, 0)),
+    ("\u{1f979}", "face holding back tears", 9, (14, 0)),
+    ("\u{1f97a}", "pleading face", 9, (11, 0)),
+    ("\u{1f97b}", "sari", 65, (12, 0)),
+    ("\u{1f97c}", "lab coat", 65, (11, 0)),
+    ("\u{1f97d}", "goggles", 65, (11, 0)),
+    ("\u{1f97e}", "hiking boot", 65, (11, 0)),
+    ("\u{1f97f}", "flat shoe", 65, (11, 0)),
+    ("\u{1f980}", "crab", 38, (1, 0)),
+    ("\u{1f981}", "lion", 34, (1, 0)),
+    ("\u{1f982}", "scorpion", 39, (1, 0)),
+    ("\u{1f983}", "turkey", 35, (1, 0)),
+    ("\u{1f984}", "unicorn", 34, (1, 0)),
+    ("\u{1f985}", "eagle", 35, (3, 0)),
+    ("\u{1f986}", "duck", 35, (3, 0)),
+    ("\u{1f987}", "bat", 34, (3, 0)),
+    ("\u{1f988}", "shark", 38, (3, 0)),
+    ("\u{1f989}", "owl", 35, (3, 0)),
+    ("\u{1f98a}", "fox", 34, (3, 0)),
+    ("\u{1f98b}", "butterfly", 39, (3, 0)),
+    ("\u{1f98c}", "deer", 34, (3, 0)),
+    ("\u{1f98d}", "gorilla", 34, (3, 0)),
+    ("\u{1f98e}", "lizard", 37, (3, 0)),
+    ("\u{1f98f}", "rhinoceros", 34, (3, 0)),
+    ("\u{1f990}", "shrimp", 38, (3, 0)),
+    ("\u{1f991}", "squid", 38, (3, 0)),
+    ("\u{1f992}", "giraffe", 34, (5, 0)),
+    ("\u{1f993}", "zebra", 34, (5, 0)),
+    ("\u{1f994}", "hedgehog", 34, (5, 0)),
+    ("\u{1f995}", "sauropod", 37, (5, 0)),
+    ("\u{1f996}", "T-Rex", 37, (5, 0)),
+    ("\u{1f997}", "cricket", 39, (5, 0)),
+    ("\u{1f998}", "kangaroo", 34, (11, 0)),
+    ("\u{1f999}", "llama", 34, (11, 0)),
+    ("\u{1f99a}", "peacock", 35, (11, 0)),
+    ("\u{1f99b}", "hippopotamus", 34, (11, 0)),
+    ("\u{1f99c}", "parrot", 35, (11, 0)),
+    ("\u{1f99d}", "raccoon", 34, (11, 0)),
+    ("\u{1f99e}", "lobster", 38, (11, 0)),
+    ("\u{1f99f}", "mosquito", 39, (11, 0)),
+    ("\u{1f9a0}", "microbe", 39, (11, 0)),
+    ("\u{1f9a1}", "badger", 34, (11, 0)),
+    ("\u{1f9a2}", "swan", 35, (11, 0)),
+    ("\u{1f9a3}", "mammoth", 34, (13, 0)),
+    ("\u{1f9a4}", "dodo", 35, (13, 0)),
+    ("\u{1f9a5}", "sloth", 34, (12, 0)),
+    ("\u{1f9a6}", "otter", 34, (12, 0)),
+    ("\u{1f9a7}", "orangutan", 34, (12, 0)),
+    ("\u{1f9a8}", "skunk", 34, (12, 0)),
+    ("\u{1f9a9}", "flamingo", 35, (12, 0)),
+    ("\u{1f9aa}", "oyster", 38, (12, 0)),
+    ("\u{1f9ab}", "beaver", 34, (13, 0)),
+    ("\u{1f9ac}", "bison", 34, (13, 0)),
+    ("\u{1f9ad}", "seal", 38, (13, 0)),
+    ("\u{1f9ae}", "guide dog", 34, (12, 0)),
+    ("\u{1f9af}", "white cane", 78, (12, 0)),
+    ("\u{1f9b0}", "red hair", 33, (11, 0)),
+    ("\u{1f9b1}", "curly hair", 33, (11, 0)),
+    ("\u{1f9b2}", "bald", 33, (11, 0)),
+    ("\u{1f9b3}", "white hair", 33, (11, 0)),
+    ("\u{1f9b4}", "bone", 22, (11, 0)),
+    ("\u{1f9b5}", "leg", 22, (11, 0)),
+    ("\u{1f9b5}\u{1f3fb}", "leg: light skin tone", 22, (11, 0)),
+    ("\u{1f9b5}\u{1f3fc}", "leg: medium-light skin tone", 22, (11, 0)),
+    ("\u{1f9b5}\u{1f3fd}", "leg: medium skin tone", 22, (11, 0)),
+    ("\u{1f9b5}\u{1f3fe}", "leg: medium-dark skin tone", 22, (11, 0)),
+    ("\u{1f9b5}\u{1f3ff}", "leg: dark skin tone", 22, (11, 0)),
+    ("\u{1f9b6}", "foot", 22, (11, 0)),
+    ("\u{1f9b6}\u{1f3fb}", "foot: light skin tone", 22, (11, 0)),
+    ("\u{1f9b6}\u{1f3fc}", "foot: medium-light skin tone", 22, (11, 0)),
+    ("\u{1f9b6}\u{1f3fd}", "foot: medium skin tone", 22, (11, 0)),
+    ("\u{1f9b6}\u{1f3fe}", "foot: medium-dark skin tone", 22, (11, 0)),
+    ("\u{1f9b6}\u{1f3ff}", "foot: dark skin tone", 22, (11, 0)),
+    ("\u{1f9b7}", "tooth", 22, (11, 0)),
+    ("\u{1f9b8}", "superhero", 26, (11, 0)),
+    ("\u{1f9b8}\u{200d}\u{2640}\u{fe0f}", "woman superhero", 26, (11, 0)),
+    ("\u{1f9b8}\u{200d}\u{2642}\u{fe0f}", "man superhero", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fb}", "superhero: light skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman superhero: light skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man superhero: light skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fc}", "superhero: medium-light skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman superhero: medium-light skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man superhero: medium-light skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fd}", "superhero: medium skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman superhero: medium skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man superhero: medium skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fe}", "superhero: medium-dark skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman superhero: medium-dark skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man superhero: medium-dark skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3ff}", "superhero: dark skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman superhero: dark skin tone", 26, (11, 0)),
+    ("\u{1f9b8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man superhero: dark skin tone", 26, (11, 0)),
+    ("\u{1f9b9}", "supervillain", 26, (11, 0)),
+    ("\u{1f9b9}\u{200d}\u{2640}\u{fe0f}", "woman supervillain", 26, (11, 0)),
+    ("\u{1f9b9}\u{200d}\u{2642}\u{fe0f}", "man supervillain", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fb}", "supervillain: light skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman supervillain: light skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man supervillain: light skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fc}", "supervillain: medium-light skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman supervillain: medium-light skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man supervillain: medium-light skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fd}", "supervillain: medium skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman supervillain: medium skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man supervillain: medium skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fe}", "supervillain: medium-dark skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman supervillain: medium-dark skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man supervillain: medium-dark skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3ff}", "supervillain: dark skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman supervillain: dark skin tone", 26, (11, 0)),
+    ("\u{1f9b9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man supervillain: dark skin tone", 26, (11, 0)),
+    ("\u{1f9ba}", "safety vest", 65, (12, 0)),
+    ("\u{1f9bb}", "ear with hearing aid", 22, (12, 0)),
+    ("\u{1f9bb}\u{1f3fb}", "ear with hearing aid: light skin tone", 22, (12, 0)),
+    ("\u{1f9bb}\u{1f3fc}", "ear with hearing aid: medium-light skin tone", 22, (12, 0)),
+    ("\u{1f9bb}\u{1f3fd}", "ear with hearing aid: medium skin tone", 22, (12, 0)),
+    ("\u{1f9bb}\u{1f3fe}", "ear with hearing aid: medium-dark skin tone", 22, (12, 0)),
+    ("\u{1f9bb}\u{1f3ff}", "ear with hearing aid: dark skin tone", 22, (12, 0)),
+    ("\u{1f9bc}", "motorized wheelchair", 54, (12, 0)),
+    ("\u{1f9bd}", "manual wheelchair", 54, (12, 0)),
+    ("\u{1f9be}", "mechanical arm", 22, (12, 0)),
+    ("\u{1f9bf}", "mechanical leg", 22, (12, 0)),
+    ("\u{1f9c0}", "cheese wedge", 44, (1, 0)),
+    ("\u{1f9c1}", "cupcake", 46, (11, 0)),
+    ("\u{1f9c2}", "salt", 44, (11, 0)),
+    ("\u{1f9c3}", "beverage box", 47, (12, 0)),
+    ("\u{1f9c4}", "garlic", 43, (12, 0)),
+    ("\u{1f9c5}", "onion", 43, (12, 0)),
+    ("\u{1f9c6}", "falafel", 44, (12, 0)),
+    ("\u{1f9c7}", "waffle", 44, (12, 0)),
+    ("\u{1f9c8}", "butter", 44, (12, 0)),
+    ("\u{1f9c9}", "mate", 47, (12, 0)),
+    ("\u{1f9ca}", "ice", 47, (12, 0)),
+    ("\u{1f9cb}", "bubble tea", 47, (13, 0)),
+    ("\u{1f9cc}", "troll", 26, (14, 0)),
+    ("\u{1f9cd}", "person standing", 27, (12, 0)),
+    ("\u{1f9cd}\u{200d}\u{2640}\u{fe0f}", "woman standing", 27, (12, 0)),
+    ("\u{1f9cd}\u{200d}\u{2642}\u{fe0f}", "man standing", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fb}", "person standing: light skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman standing: light skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man standing: light skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fc}", "person standing: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman standing: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man standing: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fd}", "person standing: medium skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman standing: medium skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man standing: medium skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fe}", "person standing: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman standing: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man standing: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3ff}", "person standing: dark skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman standing: dark skin tone", 27, (12, 0)),
+    ("\u{1f9cd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man standing: dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}", "person kneeling", 27, (12, 0)),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}", "woman kneeling", 27, (12, 0)),
+    ("\u{1f9ce}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman kneeling facing right", 27, (15, 1)),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}", "man kneeling", 27, (12, 0)),
+    ("\u{1f9ce}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man kneeling facing right", 27, (15, 1)),
+    ("\u{1f9ce}\u{200d}\u{27a1}\u{fe0f}", "person kneeling facing right", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fb}", "person kneeling: light skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman kneeling: light skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman kneeling facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man kneeling: light skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man kneeling facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fb}\u{200d}\u{27a1}\u{fe0f}", "person kneeling facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fc}", "person kneeling: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman kneeling: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman kneeling facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man kneeling: medium-light skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man kneeling facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fc}\u{200d}\u{27a1}\u{fe0f}", "person kneeling facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fd}", "person kneeling: medium skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman kneeling: medium skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman kneeling facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man kneeling: medium skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man kneeling facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fd}\u{200d}\u{27a1}\u{fe0f}", "person kneeling facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fe}", "person kneeling: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman kneeling: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman kneeling facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man kneeling: medium-dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man kneeling facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3fe}\u{200d}\u{27a1}\u{fe0f}", "person kneeling facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3ff}", "person kneeling: dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman kneeling: dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "woman kneeling facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man kneeling: dark skin tone", 27, (12, 0)),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}", "man kneeling facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f9ce}\u{1f3ff}\u{200d}\u{27a1}\u{fe0f}", "person kneeling facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f9cf}", "deaf person", 24, (12, 0)),
+    ("\u{1f9cf}\u{200d}\u{2640}\u{fe0f}", "deaf woman", 24, (12, 0)),
+    ("\u{1f9cf}\u{200d}\u{2642}\u{fe0f}", "deaf man", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fb}", "deaf person: light skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "deaf woman: light skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "deaf man: light skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fc}", "deaf person: medium-light skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "deaf woman: medium-light skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "deaf man: medium-light skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fd}", "deaf person: medium skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "deaf woman: medium skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "deaf man: medium skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fe}", "deaf person: medium-dark skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "deaf woman: medium-dark skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "deaf man: medium-dark skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3ff}", "deaf person: dark skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "deaf woman: dark skin tone", 24, (12, 0)),
+    ("\u{1f9cf}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "deaf man: dark skin tone", 24, (12, 0)),
+    ("\u{1f9d0}", "face with monocle", 8, (5, 0)),
+    ("\u{1f9d1}", "person", 23, (5, 0)),
+    ("\u{1f9d1}\u{200d}\u{2695}\u{fe0f}", "health worker", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{2696}\u{fe0f}", "judge", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{2708}\u{fe0f}", "pilot", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f33e}", "farmer", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f373}", "cook", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f37c}", "person feeding baby", 25, (13, 0)),
+    ("\u{1f9d1}\u{200d}\u{1f384}", "Mx Claus", 26, (13, 0)),
+    ("\u{1f9d1}\u{200d}\u{1f393}", "student", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f3a4}", "singer", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f3a8}", "artist", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f3eb}", "teacher", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f3ed}", "factory worker", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f4bb}", "technologist", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f4bc}", "office worker", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f527}", "mechanic", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f52c}", "scientist", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f680}", "astronaut", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f692}", "firefighter", 25, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}", "people holding hands", 30, (12, 0)),
+    ("\u{1f9d1}\u{200d}\u{1f9af}", "person with white cane", 27, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "person with white cane facing right", 27, (15, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9b0}", "person: red hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9b1}", "person: curly hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9b2}", "person: bald", 23, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9b3}", "person: white hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}", "person in motorized wheelchair", 27, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "person in motorized wheelchair facing right", 27, (15, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}", "person in manual wheelchair", 27, (12, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "person in manual wheelchair facing right", 27, (15, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}", "family: adult, adult, child", 31, (15, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}", "family: adult, adult, child, child", 31, (15, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9d2}", "family: adult, child", 31, (15, 1)),
+    ("\u{1f9d1}\u{200d}\u{1f9d2}\u{200d}\u{1f9d2}", "family: adult, child, child", 31, (15, 1)),
+    ("\u{1f9d1}\u{1f3fb}", "person: light skin tone", 23, (5, 0)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2695}\u{fe0f}", "health worker: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2696}\u{fe0f}", "judge: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2708}\u{fe0f}", "pilot: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "kiss: person, person, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "kiss: person, person, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "kiss: person, person, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "kiss: person, person, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", "couple with heart: person, person, light skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", "couple with heart: person, person, light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", "couple with heart: person, person, light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", "couple with heart: person, person, light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f33e}", "farmer: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f373}", "cook: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f37c}", "person feeding baby: light skin tone", 25, (13, 0)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f384}", "Mx Claus: light skin tone", 26, (13, 0)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f393}", "student: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a4}", "singer: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3a8}", "artist: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3eb}", "teacher: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f3ed}", "factory worker: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bb}", "technologist: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f4bc}", "office worker: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f527}", "mechanic: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f52c}", "scientist: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f680}", "astronaut: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f692}", "firefighter: light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", "people holding hands: light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", "people holding hands: light skin tone, medium-light skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", "people holding hands: light skin tone, medium skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", "people holding hands: light skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", "people holding hands: light skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}", "person with white cane: light skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "person with white cane facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b0}", "person: light skin tone, red hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b1}", "person: light skin tone, curly hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b2}", "person: light skin tone, bald", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9b3}", "person: light skin tone, white hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}", "person in motorized wheelchair: light skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "person in motorized wheelchair facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}", "person in manual wheelchair: light skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fb}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "person in manual wheelchair facing right: light skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fc}", "person: medium-light skin tone", 23, (5, 0)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2695}\u{fe0f}", "health worker: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2696}\u{fe0f}", "judge: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2708}\u{fe0f}", "pilot: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "kiss: person, person, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "kiss: person, person, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "kiss: person, person, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "kiss: person, person, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", "couple with heart: person, person, medium-light skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", "couple with heart: person, person, medium-light skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", "couple with heart: person, person, medium-light skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", "couple with heart: person, person, medium-light skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f33e}", "farmer: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f373}", "cook: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f37c}", "person feeding baby: medium-light skin tone", 25, (13, 0)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f384}", "Mx Claus: medium-light skin tone", 26, (13, 0)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f393}", "student: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a4}", "singer: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3a8}", "artist: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3eb}", "teacher: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f3ed}", "factory worker: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bb}", "technologist: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f4bc}", "office worker: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f527}", "mechanic: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f52c}", "scientist: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f680}", "astronaut: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f692}", "firefighter: medium-light skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", "people holding hands: medium-light skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", "people holding hands: medium-light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", "people holding hands: medium-light skin tone, medium skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", "people holding hands: medium-light skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", "people holding hands: medium-light skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}", "person with white cane: medium-light skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "person with white cane facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b0}", "person: medium-light skin tone, red hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b1}", "person: medium-light skin tone, curly hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b2}", "person: medium-light skin tone, bald", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9b3}", "person: medium-light skin tone, white hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}", "person in motorized wheelchair: medium-light skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "person in motorized wheelchair facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}", "person in manual wheelchair: medium-light skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fc}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "person in manual wheelchair facing right: medium-light skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fd}", "person: medium skin tone", 23, (5, 0)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2695}\u{fe0f}", "health worker: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2696}\u{fe0f}", "judge: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2708}\u{fe0f}", "pilot: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "kiss: person, person, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "kiss: person, person, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "kiss: person, person, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "kiss: person, person, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", "couple with heart: person, person, medium skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", "couple with heart: person, person, medium skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", "couple with heart: person, person, medium skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", "couple with heart: person, person, medium skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f33e}", "farmer: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f373}", "cook: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f37c}", "person feeding baby: medium skin tone", 25, (13, 0)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f384}", "Mx Claus: medium skin tone", 26, (13, 0)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f393}", "student: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a4}", "singer: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3a8}", "artist: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3eb}", "teacher: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f3ed}", "factory worker: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bb}", "technologist: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f4bc}", "office worker: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f527}", "mechanic: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f52c}", "scientist: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f680}", "astronaut: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f692}", "firefighter: medium skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", "people holding hands: medium skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", "people holding hands: medium skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", "people holding hands: medium skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", "people holding hands: medium skin tone, medium-dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", "people holding hands: medium skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}", "person with white cane: medium skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "person with white cane facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b0}", "person: medium skin tone, red hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b1}", "person: medium skin tone, curly hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b2}", "person: medium skin tone, bald", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9b3}", "person: medium skin tone, white hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}", "person in motorized wheelchair: medium skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "person in motorized wheelchair facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}", "person in manual wheelchair: medium skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fd}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "person in manual wheelchair facing right: medium skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fe}", "person: medium-dark skin tone", 23, (5, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}", "health worker: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2696}\u{fe0f}", "judge: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2708}\u{fe0f}", "pilot: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "kiss: person, person, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "kiss: person, person, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "kiss: person, person, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3ff}", "kiss: person, person, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", "couple with heart: person, person, medium-dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", "couple with heart: person, person, medium-dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", "couple with heart: person, person, medium-dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3ff}", "couple with heart: person, person, medium-dark skin tone, dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f33e}", "farmer: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f373}", "cook: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f37c}", "person feeding baby: medium-dark skin tone", 25, (13, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f384}", "Mx Claus: medium-dark skin tone", 26, (13, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f393}", "student: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a4}", "singer: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3a8}", "artist: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3eb}", "teacher: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f3ed}", "factory worker: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bb}", "technologist: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f4bc}", "office worker: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f527}", "mechanic: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f52c}", "scientist: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f680}", "astronaut: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f692}", "firefighter: medium-dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", "people holding hands: medium-dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", "people holding hands: medium-dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", "people holding hands: medium-dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", "people holding hands: medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", "people holding hands: medium-dark skin tone, dark skin tone", 30, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}", "person with white cane: medium-dark skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "person with white cane facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b0}", "person: medium-dark skin tone, red hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b1}", "person: medium-dark skin tone, curly hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b2}", "person: medium-dark skin tone, bald", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9b3}", "person: medium-dark skin tone, white hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}", "person in motorized wheelchair: medium-dark skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "person in motorized wheelchair facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}", "person in manual wheelchair: medium-dark skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3fe}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "person in manual wheelchair facing right: medium-dark skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3ff}", "person: dark skin tone", 23, (5, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2695}\u{fe0f}", "health worker: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2696}\u{fe0f}", "judge: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2708}\u{fe0f}", "pilot: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fb}", "kiss: person, person, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fc}", "kiss: person, person, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fd}", "kiss: person, person, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f48b}\u{200d}\u{1f9d1}\u{1f3fe}", "kiss: person, person, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fb}", "couple with heart: person, person, dark skin tone, light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fc}", "couple with heart: person, person, dark skin tone, medium-light skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fd}", "couple with heart: person, person, dark skin tone, medium skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{2764}\u{fe0f}\u{200d}\u{1f9d1}\u{1f3fe}", "couple with heart: person, person, dark skin tone, medium-dark skin tone", 30, (13, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f33e}", "farmer: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f373}", "cook: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f37c}", "person feeding baby: dark skin tone", 25, (13, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f384}", "Mx Claus: dark skin tone", 26, (13, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f393}", "student: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a4}", "singer: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3a8}", "artist: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3eb}", "teacher: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f3ed}", "factory worker: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bb}", "technologist: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f4bc}", "office worker: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f527}", "mechanic: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f52c}", "scientist: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f680}", "astronaut: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f692}", "firefighter: dark skin tone", 25, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fb}", "people holding hands: dark skin tone, light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fc}", "people holding hands: dark skin tone, medium-light skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fd}", "people holding hands: dark skin tone, medium skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3fe}", "people holding hands: dark skin tone, medium-dark skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f91d}\u{200d}\u{1f9d1}\u{1f3ff}", "people holding hands: dark skin tone", 30, (12, 0)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}", "person with white cane: dark skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9af}\u{200d}\u{27a1}\u{fe0f}", "person with white cane facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b0}", "person: dark skin tone, red hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b1}", "person: dark skin tone, curly hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b2}", "person: dark skin tone, bald", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9b3}", "person: dark skin tone, white hair", 23, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}", "person in motorized wheelchair: dark skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bc}\u{200d}\u{27a1}\u{fe0f}", "person in motorized wheelchair facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}", "person in manual wheelchair: dark skin tone", 27, (12, 1)),
+    ("\u{1f9d1}\u{1f3ff}\u{200d}\u{1f9bd}\u{200d}\u{27a1}\u{fe0f}", "person in manual wheelchair facing right: dark skin tone", 27, (15, 1)),
+    ("\u{1f9d2}", "child", 23, (5, 0)),
+    ("\u{1f9d2}\u{1f3fb}", "child: light skin tone", 23, (5, 0)),
+    ("\u{1f9d2}\u{1f3fc}", "child: medium-light skin tone", 23, (5, 0)),
+    ("\u{1f9d2}\u{1f3fd}", "child: medium skin tone", 23, (5, 0)),
+    ("\u{1f9d2}\u{1f3fe}", "child: medium-dark skin tone", 23, (5, 0)),
+    ("\u{1f9d2}\u{1f3ff}", "child: dark skin tone", 23, (5, 0)),
+    ("\u{1f9d3}", "older person", 23, (5, 0)),
+    ("\u{1f9d3}\u{1f3fb}", "older person: light skin tone", 23, (5, 0)),
+    ("\u{1f9d3}\u{1f3fc}", "older person: medium-light skin tone", 23, (5, 0)),
+    ("\u{1f9d3}\u{1f3fd}", "older person: medium skin tone", 23, (5, 0)),
+    ("\u{1f9d3}\u{1f3fe}", "older person: medium-dark skin tone", 23, (5, 0)),
+    ("\u{1f9d3}\u{1f3ff}", "older person: dark skin tone", 23, (5, 0)),
+    ("\u{1f9d4}", "person: beard", 23, (5, 0)),
+    ("\u{1f9d4}\u{200d}\u{2640}\u{fe0f}", "woman: beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{200d}\u{2642}\u{fe0f}", "man: beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fb}", "person: light skin tone, beard", 23, (5, 0)),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman: light skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man: light skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fc}", "person: medium-light skin tone, beard", 23, (5, 0)),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman: medium-light skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man: medium-light skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fd}", "person: medium skin tone, beard", 23, (5, 0)),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman: medium skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man: medium skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fe}", "person: medium-dark skin tone, beard", 23, (5, 0)),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman: medium-dark skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man: medium-dark skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3ff}", "person: dark skin tone, beard", 23, (5, 0)),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman: dark skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d4}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man: dark skin tone, beard", 23, (13, 1)),
+    ("\u{1f9d5}", "woman with headscarf", 25, (5, 0)),
+    ("\u{1f9d5}\u{1f3fb}", "woman with headscarf: light skin tone", 25, (5, 0)),
+    ("\u{1f9d5}\u{1f3fc}", "woman with headscarf: medium-light skin tone", 25, (5, 0)),
+    ("\u{1f9d5}\u{1f3fd}", "woman with headscarf: medium skin tone", 25, (5, 0)),
+    ("\u{1f9d5}\u{1f3fe}", "woman with headscarf: medium-dark skin tone", 25, (5, 0)),
+    ("\u{1f9d5}\u{1f3ff}", "woman with headscarf: dark skin tone", 25, (5, 0)),
+    ("\u{1f9d6}", "person in steamy room", 27, (5, 0)),
+    ("\u{1f9d6}\u{200d}\u{2640}\u{fe0f}", "woman in steamy room", 27, (5, 0)),
+    ("\u{1f9d6}\u{200d}\u{2642}\u{fe0f}", "man in steamy room", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fb}", "person in steamy room: light skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman in steamy room: light skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man in steamy room: light skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fc}", "person in steamy room: medium-light skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman in steamy room: medium-light skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man in steamy room: medium-light skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fd}", "person in steamy room: medium skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman in steamy room: medium skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man in steamy room: medium skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fe}", "person in steamy room: medium-dark skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman in steamy room: medium-dark skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man in steamy room: medium-dark skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3ff}", "person in steamy room: dark skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman in steamy room: dark skin tone", 27, (5, 0)),
+    ("\u{1f9d6}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man in steamy room: dark skin tone", 27, (5, 0)),
+    ("\u{1f9d7}", "person climbing", 27, (5, 0)),
+    ("\u{1f9d7}\u{200d}\u{2640}\u{fe0f}", "woman climbing", 27, (5, 0)),
+    ("\u{1f9d7}\u{200d}\u{2642}\u{fe0f}", "man climbing", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fb}", "person climbing: light skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman climbing: light skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man climbing: light skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fc}", "person climbing: medium-light skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman climbing: medium-light skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man climbing: medium-light skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fd}", "person climbing: medium skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman climbing: medium skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man climbing: medium skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fe}", "person climbing: medium-dark skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman climbing: medium-dark skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man climbing: medium-dark skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3ff}", "person climbing: dark skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman climbing: dark skin tone", 27, (5, 0)),
+    ("\u{1f9d7}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man climbing: dark skin tone", 27, (5, 0)),
+    ("\u{1f9d8}", "person in lotus position", 29, (5, 0)),
+    ("\u{1f9d8}\u{200d}\u{2640}\u{fe0f}", "woman in lotus position", 29, (5, 0)),
+    ("\u{1f9d8}\u{200d}\u{2642}\u{fe0f}", "man in lotus position", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fb}", "person in lotus position: light skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman in lotus position: light skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man in lotus position: light skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fc}", "person in lotus position: medium-light skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman in lotus position: medium-light skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man in lotus position: medium-light skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fd}", "person in lotus position: medium skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman in lotus position: medium skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man in lotus position: medium skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fe}", "person in lotus position: medium-dark skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman in lotus position: medium-dark skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man in lotus position: medium-dark skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3ff}", "person in lotus position: dark skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman in lotus position: dark skin tone", 29, (5, 0)),
+    ("\u{1f9d8}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man in lotus position: dark skin tone", 29, (5, 0)),
+    ("\u{1f9d9}", "mage", 26, (5, 0)),
+    ("\u{1f9d9}\u{200d}\u{2640}\u{fe0f}", "woman mage", 26, (5, 0)),
+    ("\u{1f9d9}\u{200d}\u{2642}\u{fe0f}", "man mage", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fb}", "mage: light skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman mage: light skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man mage: light skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fc}", "mage: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman mage: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man mage: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fd}", "mage: medium skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman mage: medium skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man mage: medium skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fe}", "mage: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman mage: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man mage: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3ff}", "mage: dark skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman mage: dark skin tone", 26, (5, 0)),
+    ("\u{1f9d9}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man mage: dark skin tone", 26, (5, 0)),
+    ("\u{1f9da}", "fairy", 26, (5, 0)),
+    ("\u{1f9da}\u{200d}\u{2640}\u{fe0f}", "woman fairy", 26, (5, 0)),
+    ("\u{1f9da}\u{200d}\u{2642}\u{fe0f}", "man fairy", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fb}", "fairy: light skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman fairy: light skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man fairy: light skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fc}", "fairy: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman fairy: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man fairy: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fd}", "fairy: medium skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman fairy: medium skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man fairy: medium skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fe}", "fairy: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman fairy: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man fairy: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3ff}", "fairy: dark skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman fairy: dark skin tone", 26, (5, 0)),
+    ("\u{1f9da}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man fairy: dark skin tone", 26, (5, 0)),
+    ("\u{1f9db}", "vampire", 26, (5, 0)),
+    ("\u{1f9db}\u{200d}\u{2640}\u{fe0f}", "woman vampire", 26, (5, 0)),
+    ("\u{1f9db}\u{200d}\u{2642}\u{fe0f}", "man vampire", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fb}", "vampire: light skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman vampire: light skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man vampire: light skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fc}", "vampire: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman vampire: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man vampire: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fd}", "vampire: medium skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman vampire: medium skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man vampire: medium skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fe}", "vampire: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman vampire: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man vampire: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3ff}", "vampire: dark skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman vampire: dark skin tone", 26, (5, 0)),
+    ("\u{1f9db}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man vampire: dark skin tone", 26, (5, 0)),
+    ("\u{1f9dc}", "merperson", 26, (5, 0)),
+    ("\u{1f9dc}\u{200d}\u{2640}\u{fe0f}", "mermaid", 26, (5, 0)),
+    ("\u{1f9dc}\u{200d}\u{2642}\u{fe0f}", "merman", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fb}", "merperson: light skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "mermaid: light skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "merman: light skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fc}", "merperson: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "mermaid: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "merman: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fd}", "merperson: medium skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "mermaid: medium skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "merman: medium skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fe}", "merperson: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "mermaid: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "merman: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3ff}", "merperson: dark skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "mermaid: dark skin tone", 26, (5, 0)),
+    ("\u{1f9dc}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "merman: dark skin tone", 26, (5, 0)),
+    ("\u{1f9dd}", "elf", 26, (5, 0)),
+    ("\u{1f9dd}\u{200d}\u{2640}\u{fe0f}", "woman elf", 26, (5, 0)),
+    ("\u{1f9dd}\u{200d}\u{2642}\u{fe0f}", "man elf", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fb}", "elf: light skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2640}\u{fe0f}", "woman elf: light skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fb}\u{200d}\u{2642}\u{fe0f}", "man elf: light skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fc}", "elf: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2640}\u{fe0f}", "woman elf: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fc}\u{200d}\u{2642}\u{fe0f}", "man elf: medium-light skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fd}", "elf: medium skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2640}\u{fe0f}", "woman elf: medium skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fd}\u{200d}\u{2642}\u{fe0f}", "man elf: medium skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fe}", "elf: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2640}\u{fe0f}", "woman elf: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3fe}\u{200d}\u{2642}\u{fe0f}", "man elf: medium-dark skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3ff}", "elf: dark skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2640}\u{fe0f}", "woman elf: dark skin tone", 26, (5, 0)),
+    ("\u{1f9dd}\u{1f3ff}\u{200d}\u{2642}\u{fe0f}", "man elf: dark skin tone", 26, (5, 0)),
+    ("\u{1f9de}", "genie", 26, (5, 0)),
+    ("\u{1f9de}\u{200d}\u{2640}\u{fe0f}", "woman genie", 26, (5, 0)),
+    ("\u{1f9de}\u{200d}\u{2642}\u{fe0f}", "man genie", 26, (5, 0)),
+    ("\u{1f9df}", "zombie", 26, (5, 0)),
+    ("\u{1f9df}\u{200d}\u{2640}\u{fe0f}", "woman zombie", 26, (5, 0)),
+    ("\u{1f9df}\u{200d}\u{2642}\u{fe0f}", "man zombie", 26, (5, 0)),
+    ("\u{1f9e0}", "brain", 22, (5, 0)),
+    ("\u{1f9e1}", "orange heart", 14, (5, 0)),
+    ("\u{1f9e2}", "billed cap", 65, (5, 0)),
+    ("\u{1f9e3}", "scarf", 65, (5, 0)),
+    ("\u{1f9e4}", "gloves", 65, (5, 0)),
+    ("\u{1f9e5}", "coat", 65, (5, 0)),
+    ("\u{1f9e6}", "socks", 65, (5, 0)),
+    ("\u{1f9e7}", "red envelope", 60, (11, 0)),
+    ("\u{1f9e8}", "firecracker", 60, (11, 0)),
+    ("\u{1f9e9}", "puzzle piece", 63, (11, 0)),
+    ("\u{1f9ea}", "test tube", 79, (11, 0)),
+    ("\u{1f9eb}", "petri dish", 79, (11, 0)),
+    ("\u{1f9ec}", "dna", 79, (11, 0)),
+    ("\u{1f9ed}", "compass", 49, (11, 0)),
+    ("\u{1f9ee}", "abacus", 70, (11, 0)),
+    ("\u{1f9ef}", "fire extinguisher", 81, (11, 0)),
+    ("\u{1f9f0}", "toolbox", 78, (11, 0)),
+    ("\u{1f9f1}", "brick", 51, (11, 0)),
+    ("\u{1f9f2}", "magnet", 78, (11, 0)),
+    ("\u{1f9f3}", "luggage", 57, (11, 0)),
+    ("\u{1f9f4}", "lotion bottle", 81, (11, 0)),
+    ("\u{1f9f5}", "thread", 64, (11, 0)),
+    ("\u{1f9f6}", "yarn", 64, (11, 0)),
+    ("\u{1f9f7}", "safety pin", 81, (11, 0)),
+    ("\u{1f9f8}", "teddy bear", 63, (11, 0)),
+    ("\u{1f9f9}", "broom", 81, (11, 0)),
+    ("\u{1f9fa}", "basket", 81, (11, 0)),
+    ("\u{1f9fb}", "roll of paper", 81, (11, 0)),
+    ("\u{1f9fc}", "soap", 81, (11, 0)),
+    ("\u{1f9fd}", "sponge", 81, (11, 0)),
+    ("\u{1f9fe}", "receipt", 73, (11, 0)),
+    ("\u{1f9ff}", "nazar amulet", 82, (11, 0)),
+    ("\u{1fa70}", "ballet shoes", 65, (12, 0)),
+    ("\u{1fa71}", "one-piece swimsuit", 65, (12, 0)),
+    ("\u{1fa72}", "briefs", 65, (12, 0)),
+    ("\u{1fa73}", "shorts", 65, (12, 0)),
+    ("\u{1fa74}", "thong sandal", 65, (13, 0)),
+    ("\u{1fa75}", "light blue heart", 14, (15, 0)),
+    ("\u{1fa76}", "grey heart", 14, (15, 0)),
+    ("\u{1fa77}", "pink heart", 14, (15, 0)),
+    ("\u{1fa78}", "drop of blood", 80, (12, 0)),
+    ("\u{1fa79}", "adhesive bandage", 80, (12, 0)),
+    ("\u{1fa7a}", "stethoscope", 80, (12, 0)),
+    ("\u{1fa7b}", "x-ray", 80, (14, 0)),
+    ("\u{1fa7c}", "crutch", 80, (14, 0)),
+    ("\u{1fa80}", "yo-yo", 63, (12, 0)),
+    ("\u{1fa81}", "kite", 63, (12, 0)),
+    ("\u{1fa82}", "parachute", 56, (12, 0)),
+    ("\u{1fa83}", "boomerang", 78, (13, 0)),
+    ("\u{1fa84}", "magic wand", 63, (13, 0)),
+    ("\u{1fa85}", "piñata", 63, (13, 0)),
+    ("\u{1fa86}", "nesting dolls", 63, (13, 0)),
+    ("\u{1fa87}", "maracas", 68, (15, 0)),
+    ("\u{1fa88}", "flute", 68, (15, 0)),
+    ("\u{1fa89}", "harp", 68, (16, 0)),
+    ("\u{1fa8f}", "shovel", 78, (16, 0)),
+    ("\u{1fa90}", "ringed planet", 59, (12, 0)),
+    ("\u{1fa91}", "chair", 81, (12, 0)),
+    ("\u{1fa92}", "razor", 81, (12, 0)),
+    ("\u{1fa93}", "axe", 78, (12, 0)),
+    ("\u{1fa94}", "diya lamp", 71, (12, 0)),
+    ("\u{1fa95}", "banjo", 68, (12, 0)),
+    ("\u{1fa96}", "military helmet", 65, (13, 0)),
+    ("\u{1fa97}", "accordion", 68, (13, 0)),
+    ("\u{1fa98}", "long drum", 68, (13, 0)),
+    ("\u{1fa99}", "coin", 73, (13, 0)),
+    ("\u{1fa9a}", "carpentry saw", 78, (13, 0)),
+    ("\u{1fa9b}", "screwdriver", 78, (13, 0)),
+    ("\u{1fa9c}", "ladder", 78, (13, 0)),
+    ("\u{1fa9d}", "hook", 78, (13, 0)),
+    ("\u{1fa9e}", "mirror", 81, (13, 0)),
+    ("\u{1fa9f}", "window", 81, (13, 0)),
+    ("\u{1faa0}", "plunger", 81, (13, 0)),
+    ("\u{1faa1}", "sewing needle", 64, (13, 0)),
+    ("\u{1faa2}", "knot", 64, (13, 0)),
+    ("\u{1faa3}", "bucket", 81, (13, 0)),
+    ("\u{1faa4}", "mouse trap", 81, (13, 0)),
+    ("\u{1faa5}", "toothbrush", 81, (13, 0)),
+    ("\u{1faa6}", "headstone", 82, (13, 0)),
+    ("\u{1faa7}", "placard", 82, (13, 0)),
+    ("\u{1faa8}", "rock", 51, (13, 0)),
+    ("\u{1faa9}", "mirror ball", 63, (14, 0)),
+    ("\u{1faaa}", "identification card", 82, (14, 0)),
+    ("\u{1faab}", "low battery", 70, (14, 0)),
+    ("\u{1faac}", "hamsa", 82, (14, 0)),
+    ("\u{1faad}", "folding hand fan", 65, (15, 0)),
+    ("\u{1faae}", "hair pick", 65, (15, 0)),
+    ("\u{1faaf}", "khanda", 86, (15, 0)),
+    ("\u{1fab0}", "fly", 39, (13, 0)),
+    ("\u{1fab1}", "worm", 39, (13, 0)),
+    ("\u{1fab2}", "beetle", 39, (13, 0)),
+    ("\u{1fab3}", "cockroach", 39, (13, 0)),
+    ("\u{1fab4}", "potted plant", 41, (13, 0)),
+    ("\u{1fab5}", "wood", 51, (13, 0)),
+    ("\u{1fab6}", "feather", 35, (13, 0)),
+    ("\u{1fab7}", "lotus", 40, (14, 0)),
+    ("\u{1fab8}", "coral", 38, (14, 0)),
+    ("\u{1fab9}", "empty nest", 41, (14, 0)),
+    ("\u{1faba}", "nest with eggs", 41, (14, 0)),
+    ("\u{1fabb}", "hyacinth", 40, (15, 0)),
+    ("\u{1fabc}", "jellyfish", 38, (15, 0)),
+    ("\u{1fabd}", "wing", 35, (15, 0)),
+    ("\u{1fabe}", "leafless tree", 41, (16, 0)),
+    ("\u{1fabf}", "goose", 35, (15, 0)),
+    ("\u{1fac0}", "anatomical heart", 22, (13, 0)),
+    ("\u{1fac1}", "lungs", 22, (13, 0)),
+    ("\u{1fac2}", "people hugging", 31, (13, 0)),
+    ("\u{1fac3}", "pregnant man", 25, (14, 0)),
+    ("\u{1fac3}\u{1f3fb}", "pregnant man: light skin tone", 25, (14, 0)),
+    ("\u{1fac3}\u{1f3fc}", "pregnant man: medium-light skin tone", 25, (14, 0)),
+    ("\u{1fac3}\u{1f3fd}", "pregnant man: medium skin tone", 25, (14, 0)),
+    ("\u{1fac3}\u{1f3fe}", "pregnant man: medium-dark skin tone", 25, (14, 0)),
+    ("\u{1fac3}\u{1f3ff}", "pregnant man: dark skin tone", 25, (14, 0)),
+    ("\u{1fac4}", "pregnant person", 25, (14, 0)),
+    ("\u{1fac4}\u{1f3fb}", "pregnant person: light skin tone", 25, (14, 0)),
+    ("\u{1fac4}\u{1f3fc}", "pregnant person: medium-light skin tone", 25, (14, 0)),
+    ("\u{1fac4}\u{1f3fd}", "pregnant person: medium skin tone", 25, (14, 0)),
+    ("\u{1fac4}\u{1f3fe}", "pregnant person: medium-dark skin tone", 25, (14, 0)),
+    ("\u{1fac4}\u{1f3ff}", "pregnant person: dark skin tone", 25, (14, 0)),
+    ("\u{1fac5}", "person with crown", 25, (14, 0)),
+    ("\u{1fac5}\u{1f3fb}", "person with crown: light skin tone", 25, (14, 0)),
+    ("\u{1fac5}\u{1f3fc}", "person with crown: medium-light skin tone", 25, (14, 0)),
+    ("\u{1fac5}\u{1f3fd}", "person with crown: medium skin tone", 25, (14, 0)),
+    ("\u{1fac5}\u{1f3fe}", "person with crown: medium-dark skin tone", 25, (14, 0)),
+    ("\u{1fac5}\u{1f3ff}", "person with crown: dark skin tone", 25, (14, 0)),
+    ("\u{1fac6}", "fingerprint", 31, (16, 0)),
+    ("\u{1face}", "moose", 34, (15, 0)),
+    ("\u{1facf}", "donkey", 34, (15, 0)),
+    ("\u{1fad0}", "blueberries", 42, (13, 0)),
+    ("\u{1fad1}", "bell pepper", 43, (13, 0)),
+    ("\u{1fad2}", "olive", 42, (13, 0)),
+    ("\u{1fad3}", "flatbread", 44, (13, 0)),
+    ("\u{1fad4}", "tamale", 44, (13, 0)),
+    ("\u{1fad5}", "fondue", 44, (13, 0)),
+    ("\u{1fad6}", "teapot", 47, (13, 0)),
+    ("\u{1fad7}", "pouring liquid", 47, (14, 0)),
+    ("\u{1fad8}", "beans", 43, (14, 0)),
+    ("\u{1fad9}", "jar", 48, (14, 0)),
+    ("\u{1fada}", "ginger root", 43, (15, 0)),
+    ("\u{1fadb}", "pea pod", 43, (15, 0)),
+    ("\u{1fadc}", "root vegetable", 43, (16, 0)),
+    ("\u{1fadf}", "splatter", 93, (16, 0)),
+    ("\u{1fae0}", "melting face", 0, (14, 0)),
+    ("\u{1fae1}", "saluting face", 3, (14, 0)),
+    ("\u{1fae2}", "face with open eyes and hand over mouth", 3, (14, 0)),
+    ("\u{1fae3}", "face with peeking eye", 3, (14, 0)),
+    ("\u{1fae4}", "face with diagonal mouth", 9, (14, 0)),
+    ("\u{1fae5}", "dotted line face", 4, (14, 0)),
+    ("\u{1fae6}", "biting lip", 22, (14, 0)),
+    ("\u{1fae7}", "bubbles", 81, (14, 0)),
+    ("\u{1fae8}", "shaking face", 4, (15, 0)),
+    ("\u{1fae9}", "face with bags under eyes", 5, (16, 0)),
+    ("\u{1faf0}", "hand with index finger and thumb crossed", 17, (14, 0)),
+    ("\u{1faf0}\u{1f3fb}", "hand with index finger and thumb crossed: light skin tone", 17, (14, 0)),
+    ("\u{1faf0}\u{1f3fc}", "hand with index finger and thumb crossed: medium-light skin tone", 17, (14, 0)),
+    ("\u{1faf0}\u{1f3fd}", "hand with index finger and thumb crossed: medium skin tone", 17, (14, 0)),
+    ("\u{1faf0}\u{1f3fe}", "hand with index finger and thumb crossed: medium-dark skin tone", 17, (14, 0)),
+    ("\u{1faf0}\u{1f3ff}", "hand with index finger and thumb crossed: dark skin tone", 17, (14, 0)),
+    ("\u{1faf1}", "rightwards hand", 16, (14, 0)),
+    ("\u{1faf1}\u{1f3fb}", "rightwards hand: light skin tone", 16, (14, 0)),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fc}", "handshake: light skin tone, medium-light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fd}", "handshake: light skin tone, medium skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3fe}", "handshake: light skin tone, medium-dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fb}\u{200d}\u{1faf2}\u{1f3ff}", "handshake: light skin tone, dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fc}", "rightwards hand: medium-light skin tone", 16, (14, 0)),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fb}", "handshake: medium-light skin tone, light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fd}", "handshake: medium-light skin tone, medium skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3fe}", "handshake: medium-light skin tone, medium-dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fc}\u{200d}\u{1faf2}\u{1f3ff}", "handshake: medium-light skin tone, dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fd}", "rightwards hand: medium skin tone", 16, (14, 0)),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fb}", "handshake: medium skin tone, light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fc}", "handshake: medium skin tone, medium-light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3fe}", "handshake: medium skin tone, medium-dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fd}\u{200d}\u{1faf2}\u{1f3ff}", "handshake: medium skin tone, dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fe}", "rightwards hand: medium-dark skin tone", 16, (14, 0)),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fb}", "handshake: medium-dark skin tone, light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fc}", "handshake: medium-dark skin tone, medium-light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3fd}", "handshake: medium-dark skin tone, medium skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3fe}\u{200d}\u{1faf2}\u{1f3ff}", "handshake: medium-dark skin tone, dark skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3ff}", "rightwards hand: dark skin tone", 16, (14, 0)),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fb}", "handshake: dark skin tone, light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fc}", "handshake: dark skin tone, medium-light skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fd}", "handshake: dark skin tone, medium skin tone", 20, (14, 0)),
+    ("\u{1faf1}\u{1f3ff}\u{200d}\u{1faf2}\u{1f3fe}", "handshake: dark skin tone, medium-dark skin tone", 20, (14, 0)),
+    ("\u{1faf2}", "leftwards hand", 16, (14, 0)),
+    ("\u{1faf2}\u{1f3fb}", "leftwards hand: light skin tone", 16, (14, 0)),
+    ("\u{1faf2}\u{1f3fc}", "leftwards hand: medium-light skin tone", 16, (14, 0)),
+    ("\u{1faf2}\u{1f3fd}", "leftwards hand: medium skin tone", 16, (14, 0)),
+    ("\u{1faf2}\u{1f3fe}", "leftwards hand: medium-dark skin tone", 16, (14, 0)),
+    ("\u{1faf2}\u{1f3ff}", "leftwards hand: dark skin tone", 16, (14, 0)),
+    ("\u{1faf3}", "palm down hand", 16, (14, 0)),
+    ("\u{1faf3}\u{1f3fb}", "palm down hand: light skin tone", 16, (14, 0)),
+    ("\u{1faf3}\u{1f3fc}", "palm down hand: medium-light skin tone", 16, (14, 0)),
+    ("\u{1faf3}\u{1f3fd}", "palm down hand: medium skin tone", 16, (14, 0)),
+    ("\u{1faf3}\u{1f3fe}", "palm down hand: medium-dark skin tone", 16, (14, 0)),
+    ("\u{1faf3}\u{1f3ff}", "palm down hand: dark skin tone", 16, (14, 0)),
+    ("\u{1faf4}", "palm up hand", 16, (14, 0)),
+    ("\u{1faf4}\u{1f3fb}", "palm up hand: light skin tone", 16, (14, 0)),
+    ("\u{1faf4}\u{1f3fc}", "palm up hand: medium-light skin tone", 16, (14, 0)),
+    ("\u{1faf4}\u{1f3fd}", "palm up hand: medium skin tone", 16, (14, 0)),
+    ("\u{1faf4}\u{1f3fe}", "palm up hand: medium-dark skin tone", 16, (14, 0)),
+    ("\u{1faf4}\u{1f3ff}", "palm up hand: dark skin tone", 16, (14, 0)),
+    ("\u{1faf5}", "index pointing at the viewer", 18, (14, 0)),
+    ("\u{1faf5}\u{1f3fb}", "index pointing at the viewer: light skin tone", 18, (14, 0)),
+    ("\u{1faf5}\u{1f3fc}", "index pointing at the viewer: medium-light skin tone", 18, (14, 0)),
+    ("\u{1faf5}\u{1f3fd}", "index pointing at the viewer: medium skin tone", 18, (14, 0)),
+    ("\u{1faf5}\u{1f3fe}", "index pointing at the viewer: medium-dark skin tone", 18, (14, 0)),
+    ("\u{1faf5}\u{1f3ff}", "index pointing at the viewer: dark skin tone", 18, (14, 0)),
+    ("\u{1faf6}", "heart hands", 20, (14, 0)),
+    ("\u{1faf6}\u{1f3fb}", "heart hands: light skin tone", 20, (14, 0)),
+    ("\u{1faf6}\u{1f3fc}", "heart hands: medium-light skin tone", 20, (14, 0)),
+    ("\u{1faf6}\u{1f3fd}", "heart hands: medium skin tone", 20, (14, 0)),
+    ("\u{1faf6}\u{1f3fe}", "heart hands: medium-dark skin tone", 20, (14, 0)),
+    ("\u{1faf6}\u{1f3ff}", "heart hands: dark skin tone", 20, (14, 0)),
+    ("\u{1faf7}", "leftwards pushing hand", 16, (15, 0)),
+    ("\u{1faf7}\u{1f3fb}", "leftwards pushing hand: light skin tone", 16, (15, 0)),
+    ("\u{1faf7}\u{1f3fc}", "leftwards pushing hand: medium-light skin tone", 16, (15, 0)),
+    ("\u{1faf7}\u{1f3fd}", "leftwards pushing hand: medium skin tone", 16, (15, 0)),
+    ("\u{1faf7}\u{1f3fe}", "leftwards pushing hand: medium-dark skin tone", 16, (15, 0)),
+    ("\u{1faf7}\u{1f3ff}", "leftwards pushing hand: dark skin tone", 16, (15, 0)),
+    ("\u{1faf8}", "rightwards pushing hand", 16, (15, 0)),
+    ("\u{1faf8}\u{1f3fb}", "rightwards pushing hand: light skin tone", 16, (15, 0)),
+    ("\u{1faf8}\u{1f3fc}", "rightwards pushing hand: medium-light skin tone", 16, (15, 0)),
+    ("\u{1faf8}\u{1f3fd}", "rightwards pushing hand: medium skin tone", 16, (15, 0)),
+    ("\u{1faf8}\u{1f3fe}", "rightwards pushing hand: medium-dark skin tone", 16, (15, 0)),
+    ("\u{1faf8}\u{1f3ff}", "rightwards pushing hand: dark skin tone", 16, (15, 0)),
+];
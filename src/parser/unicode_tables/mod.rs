@@ -0,0 +1,16 @@
+//! Character tables, generated from the data in `unicode/`
+//!
+// DO NOT EDIT, generated by `cargo xtask generate-unicode-tables`.
+
+#[rustfmt::skip]
+pub(crate) mod emoji;
+#[cfg(feature = "emoji-metadata")]
+#[rustfmt::skip]
+pub(crate) mod emoji_metadata;
+#[rustfmt::skip]
+pub(crate) mod hashtag;
+#[rustfmt::skip]
+pub(crate) mod rfc3987;
+
+/// The Unicode version that hashtag and emoji detection are based on
+pub const UNICODE_VERSION: (u8, u8, u8) = (16, 0, 0);
@@ -0,0 +1,70 @@
+//! A top level wrapper for the JSON of the AST that contains the version of its format, so
+//! consumers in other languages can detect a format they don't understand.
+//!
+//! The format is described by the JSON Schema in `schema/ast.v<version>.schema.json`, one file per
+//! [AST_SCHEMA_VERSION], it is generated with `cargo xtask json-schema`.
+//!
+//! ```
+//! use deltachat_message_parser::parser::{
+//!     parse_markdown_text, versioned::VersionedAst, OwnedElement, AST_SCHEMA_VERSION,
+//! };
+//!
+//! let ast = VersionedAst::new(parse_markdown_text("**Hello**"));
+//! assert_eq!(ast.schema_version, AST_SCHEMA_VERSION);
+//! ```
+
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// Version of the JSON format of the AST, it is increased with every change that can break
+/// consumers, like a new element variant or a renamed field
+///
+/// Its schema is in `schema/ast.v<version>.schema.json`, a test fails when the JSON format
+/// differs from it without a new version.
+pub const AST_SCHEMA_VERSION: u32 = 1;
+
+/// The elements of a message together with the version of their JSON format
+///
+/// `E` is [Element](super::Element) or [OwnedElement](super::OwnedElement), both serialize to
+/// the same JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct VersionedAst<E> {
+    /// always [AST_SCHEMA_VERSION], deserializing fails for other versions
+    #[serde(deserialize_with = "deserialize_schema_version")]
+    #[cfg_attr(
+        feature = "json-schema",
+        schemars(schema_with = "schema_version_schema")
+    )]
+    pub schema_version: u32,
+    pub elements: Vec<E>,
+}
+
+impl<E> VersionedAst<E> {
+    pub fn new(elements: Vec<E>) -> Self {
+        VersionedAst {
+            schema_version: AST_SCHEMA_VERSION,
+            elements,
+        }
+    }
+}
+
+fn deserialize_schema_version<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let version = u32::deserialize(deserializer)?;
+    if version == AST_SCHEMA_VERSION {
+        Ok(version)
+    } else {
+        Err(D::Error::custom(format!(
+            "unsupported AST schema version {version}, expected {AST_SCHEMA_VERSION}"
+        )))
+    }
+}
+
+#[cfg(feature = "json-schema")]
+fn schema_version_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::Integer.into()),
+        const_value: Some(AST_SCHEMA_VERSION.into()),
+        ..Default::default()
+    }
+    .into()
+}
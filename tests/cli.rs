@@ -0,0 +1,208 @@
+//! Runs the command line tool of `src/main.rs`
+
+#![cfg(feature = "cli")]
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_deltachat_message_parser"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    assert!(output.status.success(), "{:?}", output);
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn parse_stdin() {
+    assert_eq!(
+        stdout(&run(&["--format", "json"], "**hi**")),
+        r#"[
+  {
+    "t": "Bold",
+    "c": [
+      {
+        "t": "Text",
+        "c": "hi"
+      }
+    ]
+  }
+]
+"#
+    );
+    assert_eq!(
+        stdout(&run(&["--mode", "text", "--format", "tree"], "**hi** #tag")),
+        "Text \"**hi** \"\nTag \"#tag\"\n"
+    );
+    assert_eq!(
+        stdout(&run(
+            &["--mode", "desktop", "--format", "markdown"],
+            "[**a**](https://delta.chat)"
+        )),
+        "[**a**](https://delta.chat)\n"
+    );
+}
+
+#[test]
+fn parse_files() {
+    let dir = std::env::temp_dir().join(format!("message_parser_cli_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("message.txt");
+    std::fs::write(&file, "#tag").unwrap();
+    let file = file.to_str().unwrap();
+    assert_eq!(
+        stdout(&run(&["--format", "tree", file, "-"], "_x_")),
+        format!("==> {file} <==\nTag \"#tag\"\n==> - <==\nItalics\n  Text \"x\"\n")
+    );
+
+    let output = run(&[&format!("{file}.missing")], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: reading "));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn jsonl_batch() {
+    let input = "\"**a**\"\n\n{\"id\": 2, \"text\": \"#b\"}\nnot json\n";
+    let output = run(&["--jsonl", "--format", "json"], input);
+    // one output line for every input line
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        r##"[{"t":"Bold","c":[{"t":"Text","c":"a"}]}]
+null
+[{"t":"Tag","c":"#b"}]
+null
+"##
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("-:4: "));
+
+    assert_eq!(
+        stdout(&run(&["--jsonl", "--format", "tree"], "\"a\"\n\"#b\"")),
+        "==> -:1 <==\nText \"a\"\n==> -:2 <==\nTag \"#b\"\n"
+    );
+}
+
+#[test]
+fn emoji_and_punycode() {
+    // the `emoji-metadata` feature adds the names of the emoji
+    let name = if cfg!(feature = "emoji-metadata") {
+        r#" "waving hand: medium skin tone""#
+    } else {
+        ""
+    };
+    assert_eq!(
+        stdout(&run(&["emoji", "👋🏽 hi"], "")),
+        format!(
+            r#"text: "👋🏽 hi"
+first emoji: Some("👋🏽")
+emoji count if only emoji: None
+emoji and whitespace only: false, emoji: 1, graphemes: 4, non-RGI sequences: false
+segments:
+  0..8 Emoji "👋🏽" FullyQualified base "👋"{name}
+  8..11 Text " hi"
+"#
+        )
+    );
+    assert_eq!(
+        stdout(&run(&["punycode", "münchen.de", "delta.chat"], "")),
+        "host: münchen.de
+needs punycode: true
+encoded: xn--mnchen-3ya.de
+decoded: münchen.de
+
+host: delta.chat
+needs punycode: false
+encoded: delta.chat
+decoded: delta.chat
+"
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    let output = run(&["--format", "yaml"], "");
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: unknown format Some(\"yaml\")\n"
+    );
+}
+
+#[test]
+fn diff_configurations() {
+    let corpus = "\"#tag\"\n\"**bold** text\"\n\n{\"text\": \"[a](https://delta.chat)\"}\n";
+    let output = run(&["diff", "--a", "desktop", "--b", "markdown"], corpus);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        r#"{"file":"-","line":2,"text":"**bold** text","diffs":[{"path":[0],"a":[{"t":"Text","c":"**bold** text"}],"b":[{"t":"Bold","c":[{"t":"Text","c":"bold"}]},{"t":"Text","c":" text"}]}]}
+"#
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "1 of 3 messages are parsed differently\n"
+    );
+
+    let output = run(&["diff", "--a", "markdown", "--b", "markdown"], corpus);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn diff_baseline() {
+    let corpus = "\"_a_ 👋\"\n\n\"b\"\n";
+    // the output of an older version or another configuration
+    let baseline = stdout(&run(
+        &["--jsonl", "--format", "json", "--mode", "text"],
+        corpus,
+    ))
+    .to_owned();
+    let dir = std::env::temp_dir().join(format!("message_parser_diff_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let baseline_file = dir.join("baseline.jsonl");
+    std::fs::write(&baseline_file, baseline).unwrap();
+    let baseline_file = baseline_file.to_str().unwrap();
+
+    let output = run(
+        &["diff", "--baseline", baseline_file, "--b", "text"],
+        corpus,
+    );
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+
+    let output = run(
+        &["diff", "--baseline", baseline_file, "--b", "markdown+emoji"],
+        corpus,
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        r#"{"file":"-","line":1,"text":"_a_ 👋","diffs":[{"path":[0],"a":[{"t":"Text","c":"_a_ 👋"}],"b":[{"t":"Italics","c":[{"t":"Text","c":"a"}]},{"t":"Text","c":" "},{"t":"Emoji","c":"👋"}]}]}
+"#
+    );
+
+    // the baseline needs a line for every line of the corpus
+    let output = run(
+        &["diff", "--baseline", baseline_file],
+        "\"a\"\n\"b\"\n\"c\"\n\"d\"\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("fewer lines"));
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -1,6 +1,7 @@
 use deltachat_message_parser::parser::is_emoji::{
-    count_emojis_if_only_contains_emoji, emoji, get_first_emoji,
+    count_emoji_elements_if_only_emoji, count_emojis_if_only_contains_emoji, emoji, get_first_emoji,
 };
+use deltachat_message_parser::parser::{parse_only_text_with_options, ParserOptions};
 
 #[test]
 fn test_all_desktop_emoji_picker_emojis() {
@@ -53,3 +54,32 @@ fn test_all_desktop_emoji_picker_emojis_are_single_emojis() {
         "out of {total} cases {failed_count} failed"
     );
 }
+
+#[test]
+fn count_emojis_from_emoji_elements() {
+    let options = ParserOptions {
+        emoji_elements: true,
+        ..Default::default()
+    };
+    let testcases = include_str!("./all_desktop_emojis.txt")
+        .split(',')
+        .chain(vec![
+            "",
+            "hi",
+            "😀😀😀",
+            "😀 😀",
+            "😀\n",
+            "🇦🇨🇦",
+            "👍🏽‍🔥",
+            "1️⃣2️⃣",
+            "#️⃣#️⃣",
+            "😀hi",
+        ]);
+    for case in testcases {
+        assert_eq!(
+            count_emoji_elements_if_only_emoji(&parse_only_text_with_options(case, &options)),
+            count_emojis_if_only_contains_emoji(case),
+            "{case}"
+        );
+    }
+}
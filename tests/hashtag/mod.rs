@@ -0,0 +1,56 @@
+use deltachat_message_parser::parser::{
+    hashtag::{get_hashtags, normalize_hashtag},
+    parse_desktop_set, parse_markdown_text, parse_only_text,
+};
+
+#[test]
+fn same_hashtag_in_different_forms() {
+    let normalized: Vec<String> = ["#foo", "#Foo", "#FOO", "#ｆｏｏ"]
+        .iter()
+        .map(|tag| normalize_hashtag(tag))
+        .collect();
+    assert_eq!(normalized, vec!["foo", "foo", "foo", "foo"]);
+}
+
+#[test]
+fn unique_hashtags_of_a_message() {
+    let input = "#Delta meets #chat, #delta again and #CHAT #ｃｈａｔ";
+    assert_eq!(get_hashtags(&parse_only_text(input)), vec!["delta", "chat"]);
+    assert_eq!(
+        get_hashtags(&parse_desktop_set(input)),
+        vec!["delta", "chat"]
+    );
+    assert_eq!(
+        get_hashtags(&parse_markdown_text(input)),
+        vec!["delta", "chat"]
+    );
+}
+
+#[test]
+fn nested_hashtags_in_markdown() {
+    let input = "**#bold** and _#italic_ and ~~#gone~~ but not `#code`";
+    assert_eq!(
+        get_hashtags(&parse_markdown_text(input)),
+        vec!["bold", "italic", "gone"]
+    );
+}
+
+#[test]
+fn no_hashtags() {
+    assert!(get_hashtags(&parse_markdown_text(
+        "no tags here https://delta.chat/#anchor"
+    ))
+    .is_empty());
+}
+
+#[test]
+fn many_hashtags() {
+    let mut input = String::new();
+    for i in 0..5000 {
+        input.push_str(&format!("#tag{} #TAG{} ", i, i % 10));
+    }
+    let hashtags = get_hashtags(&parse_only_text(&input));
+    assert_eq!(hashtags.len(), 5000);
+    assert_eq!(hashtags[..3], ["tag0", "tag1", "tag2"]);
+    assert_eq!(hashtags[4999], "tag4999");
+}
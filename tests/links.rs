@@ -1,5 +1,10 @@
 #![allow(clippy::unwrap_used)]
-use deltachat_message_parser::parser::{link_url::PunycodeWarning, LinkDestination};
+use deltachat_message_parser::parser::{
+    link_url::{
+        AddressChecksum, ExactTopic, MagnetLink, PaymentCurrency, PaymentRequest, PunycodeWarning,
+    },
+    parse_markdown_text, parse_only_text, Element, LinkDestination,
+};
 
 #[test]
 fn basic_parsing() {
@@ -60,6 +65,8 @@ fn multiple_hashes() {
             target: "https://matrix.to/#/#deltachat:matrix.org",
             scheme: Some("https"),
             punycode: None,
+            payment_request: None,
+            magnet: None,
         }
     );
 }
@@ -98,6 +105,8 @@ fn punycode_detection() {
                 ascii_hostname: "xn--mnchen-3ya.de".to_owned(),
                 punycode_encoded_url: "http://xn--mnchen-3ya.de".to_owned(),
             }),
+            payment_request: None,
+            magnet: None,
         }
     );
 
@@ -108,6 +117,8 @@ fn punycode_detection() {
             target: "http://muenchen.de",
             scheme: Some("http"),
             punycode: None,
+            payment_request: None,
+            magnet: None,
         }
     );
 }
@@ -123,6 +134,8 @@ fn common_schemes() {
                 target: "http://delta.chat",
                 scheme: Some("http"),
                 punycode: None,
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -135,6 +148,8 @@ fn common_schemes() {
                 target: "https://far.chickenkiller.com",
                 scheme: Some("https"),
                 punycode: None,
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -149,7 +164,9 @@ fn generic_schemes() {
                 hostname: None,
                 scheme: Some("mailto"),
                 punycode: None,
-                target: "mailto:someone@example.com"
+                target: "mailto:someone@example.com",
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -162,6 +179,18 @@ fn generic_schemes() {
             scheme: Some("bitcoin"),
             target: "bitcoin:bc1qt3xhfvwmdqvxkk089tllvvtzqs8ts06u3u6qka",
             punycode: None,
+            payment_request: Some(PaymentRequest {
+                currency: PaymentCurrency::Bitcoin,
+                address: "bc1qt3xhfvwmdqvxkk089tllvvtzqs8ts06u3u6qka".to_owned(),
+                address_checksum: AddressChecksum::Valid,
+                amount: None,
+                label: None,
+                message: None,
+                chain_id: None,
+                function_name: None,
+                parameters: vec![],
+            }),
+            magnet: None,
         }
     );
     assert_eq!(
@@ -172,7 +201,9 @@ fn generic_schemes() {
             scheme: Some("geo"),
             punycode: None,
             target: "geo:37.786971,-122.399677",
-            hostname: None
+            hostname: None,
+            payment_request: None,
+            magnet: None,
         }
     );
 }
@@ -187,7 +218,9 @@ fn no_scheme_simple() {
                 hostname: Some("example.com"),
                 scheme: None,
                 punycode: None,
-                target: "example.com"
+                target: "example.com",
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -204,7 +237,9 @@ fn no_scheme_with_chat() {
                 hostname: Some("delta.chat"),
                 scheme: None,
                 punycode: None,
-                target: "delta.chat"
+                target: "delta.chat",
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -221,7 +256,9 @@ fn no_scheme_full_iri_segments() {
                 hostname: Some("delta.chat"),
                 scheme: None,
                 punycode: None,
-                target: "delta.chat/path/with/segments?query=params#fragment"
+                target: "delta.chat/path/with/segments?query=params#fragment",
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -242,7 +279,9 @@ fn no_scheme_punycode() {
                     ascii_hostname: "xn--mnchen-3ya.com".to_owned(),
                     punycode_encoded_url: "xn--mnchen-3ya.com".to_owned()
                 }),
-                target: "münchen.com"
+                target: "münchen.com",
+                payment_request: None,
+                magnet: None,
             }
         )
     );
@@ -268,3 +307,161 @@ fn no_scheme_disallow_port() {
     let result = LinkDestination::parse("delta.chat:8080/api");
     assert!(result.is_ok());
 }
+
+#[test]
+fn payment_requests() {
+    let (rest, link) = LinkDestination::parse(
+        "bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=0.001&label=Delta%20Chat and more",
+    )
+    .unwrap();
+    assert_eq!(rest, " and more");
+    assert_eq!(
+        link.payment_request,
+        Some(PaymentRequest {
+            currency: PaymentCurrency::Bitcoin,
+            address: "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_owned(),
+            address_checksum: AddressChecksum::Valid,
+            amount: Some("0.001".to_owned()),
+            label: Some("Delta Chat".to_owned()),
+            message: None,
+            chain_id: None,
+            function_name: None,
+            parameters: vec![],
+        })
+    );
+
+    let link =
+        LinkDestination::parse("ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed@1?value=1e18")
+            .unwrap()
+            .1;
+    let payment_request = link.payment_request.unwrap();
+    assert_eq!(payment_request.currency, PaymentCurrency::Ethereum);
+    assert_eq!(payment_request.address_checksum, AddressChecksum::Valid);
+    assert_eq!(payment_request.chain_id, Some(1));
+    assert_eq!(payment_request.amount, Some("1e18".to_owned()));
+
+    // tampered address is still a link, but the checksum shows the problem
+    let link = LinkDestination::parse("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6c")
+        .unwrap()
+        .1;
+    assert_eq!(
+        link.payment_request.map(|p| p.address_checksum),
+        Some(AddressChecksum::Invalid)
+    );
+
+    // other schemes have no payment request
+    assert_eq!(
+        LinkDestination::parse("https://delta.chat/?amount=5")
+            .unwrap()
+            .1
+            .payment_request,
+        None
+    );
+}
+
+#[test]
+fn magnet_links() {
+    let (rest, link) = LinkDestination::parse(
+        "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&dn=ubuntu.iso&xl=6114656256&tr=udp%3A%2F%2Ftracker.example.org%3A1337 is the link",
+    )
+    .unwrap();
+    assert_eq!(rest, " is the link");
+    assert_eq!(link.payment_request, None);
+    assert_eq!(
+        link.magnet,
+        Some(MagnetLink {
+            exact_topics: vec![ExactTopic::BitTorrentInfoHash(
+                "c12fe1c06bba254a9dc9f519b335aa7c1367a88a".to_owned()
+            )],
+            display_name: Some("ubuntu.iso".to_owned()),
+            exact_length: Some(6_114_656_256),
+            trackers: vec!["udp://tracker.example.org:1337".to_owned()],
+            web_seeds: vec![],
+        })
+    );
+
+    // other schemes have no magnet data, even if they look like one
+    assert_eq!(
+        LinkDestination::parse(
+            "https://delta.chat/?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a"
+        )
+        .unwrap()
+        .1
+        .magnet,
+        None
+    );
+}
+
+#[test]
+fn empty_port_at_the_end() {
+    let (_, link) = LinkDestination::parse("https://delta.chat: see").unwrap();
+    assert_eq!(link.target, "https://delta.chat");
+    assert_eq!(link.hostname, Some("delta.chat"));
+
+    // used to panic, because the host was sliced from the rest of the input
+    // `a.chat` is a scheme without a link after it, so the link starts at the dot
+    assert_eq!(
+        parse_markdown_text("a.chat:|x*️⃣"),
+        vec![
+            Element::Text("a"),
+            Element::Link {
+                destination: LinkDestination {
+                    target: ".chat",
+                    hostname: Some(".chat"),
+                    punycode: None,
+                    scheme: None,
+                    payment_request: None,
+                    magnet: None,
+                }
+            },
+            Element::Text(":|x*️⃣"),
+        ]
+    );
+    // `delta.chat` is the scheme and the emoji the host
+    assert_eq!(
+        parse_only_text("see delta.chat:🍏 there"),
+        vec![
+            Element::Text("see "),
+            Element::Link {
+                destination: LinkDestination {
+                    target: "delta.chat:🍏",
+                    hostname: Some("🍏"),
+                    punycode: Some(PunycodeWarning {
+                        original_hostname: "🍏".to_owned(),
+                        ascii_hostname: "xn--pi8h".to_owned(),
+                        punycode_encoded_url: "delta.chat:xn--pi8h".to_owned(),
+                    }),
+                    scheme: Some("delta.chat"),
+                    payment_request: None,
+                    magnet: None,
+                }
+            },
+            Element::Text(" there"),
+        ]
+    );
+}
+
+#[test]
+fn scheme_starts_with_ascii_letter() {
+    // the length of the first letter was counted as one byte, which cut the scheme and the link
+    let (_, link) = LinkDestination::parse("xä://c.de").unwrap();
+    assert_eq!(link.target, "xä://c.de");
+    assert_eq!(link.scheme, Some("xä"));
+    assert!(LinkDestination::parse("äb://c.de").is_err());
+    assert_eq!(
+        parse_only_text("äb://c.de"),
+        vec![
+            Element::Text("ä"),
+            Element::Link {
+                destination: LinkDestination {
+                    target: "b://c.de",
+                    hostname: Some("c.de"),
+                    punycode: None,
+                    scheme: Some("b"),
+                    payment_request: None,
+                    magnet: None,
+                }
+            }
+        ]
+    );
+}
@@ -1,4 +1,5 @@
 mod based_on_issue;
 mod emoji;
+mod hashtag;
 mod links;
 mod text_to_ast;
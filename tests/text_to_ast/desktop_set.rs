@@ -41,14 +41,14 @@ fn command_suggestions() {
     assert_eq!(
         parse_desktop_set(input),
         vec![
-            BotCommandSuggestion("/yes"),
+            bot_command("/yes"),
             Linebreak,
-            BotCommandSuggestion("/move_a5_a6"),
+            bot_command("/move_a5_a6"),
             Text(" "),
             Linebreak,
-            BotCommandSuggestion("/answer2_gameid"),
+            bot_command("/answer2_gameid"),
             Text(" or "),
-            BotCommandSuggestion("/answer__no")
+            bot_command("/answer__no")
         ]
     );
 }
@@ -62,7 +62,7 @@ fn invalid_command_suggestions() {
             Text("/1"),
             Linebreak,
             Text(" "),
-            BotCommandSuggestion("/hello"),
+            bot_command("/hello"),
             Text(" world")
         ]
     );
@@ -463,7 +463,9 @@ fn labeled_link_with_special_char_in_domain() {
                     ascii_hostname: "xn--mnchen-3ya.de".to_string(),
                     punycode_encoded_url: "https://xn--mnchen-3ya.de".to_string()
                 }),
-                scheme: Some("https")
+                scheme: Some("https"),
+                payment_request: None,
+                magnet: None,
             },
         }]
     );
@@ -509,3 +511,47 @@ fn labeled_link_should_not_allow_codeblock() {
         ]
     );
 }
+
+#[test]
+fn emoji_elements() {
+    use deltachat_message_parser::parser::{parse_desktop_set_with_options, ParserOptions};
+    let options = ParserOptions {
+        emoji_elements: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_desktop_set_with_options("🔥 [fire 🔥](https://delta.chat) **🔥**", &options),
+        vec![
+            Emoji("🔥"),
+            Text(" "),
+            LabeledLink {
+                label: vec![Text("fire 🔥")],
+                destination: https_link_no_puny("https://delta.chat", "delta.chat"),
+            },
+            Text(" **"),
+            Emoji("🔥"),
+            Text("**"),
+        ]
+    );
+}
+
+#[test]
+fn disabled_labeled_links() {
+    use deltachat_message_parser::parser::{
+        parse_desktop_set_with_options, ElementKind, ParserOptions,
+    };
+    let options = ParserOptions {
+        enabled_elements: Some(vec![ElementKind::Link]),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_desktop_set_with_options("[label](https://delta.chat)", &options),
+        vec![
+            Text("[label]("),
+            Link {
+                destination: https_link_no_puny("https://delta.chat", "delta.chat")
+            },
+            Text(")"),
+        ]
+    );
+}
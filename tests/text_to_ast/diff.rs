@@ -0,0 +1,81 @@
+use deltachat_message_parser::parser::{
+    diff::{diff, ElementDiff},
+    parse_desktop_set, parse_markdown_text, parse_only_text, OwnedElement,
+};
+
+fn text(text: &str) -> OwnedElement {
+    OwnedElement::Text(text.to_owned())
+}
+
+#[test]
+fn same_elements_have_no_diff() {
+    let input = "**Hello** [world](https://delta.chat) #tag";
+    assert_eq!(
+        diff(&parse_markdown_text(input), &parse_markdown_text(input)),
+        vec![]
+    );
+    assert_eq!(diff(&[], &[]), vec![]);
+}
+
+#[test]
+fn common_start_and_end_are_skipped() {
+    let input = "#a **b** c #d";
+    assert_eq!(
+        diff(&parse_only_text(input), &parse_markdown_text(input)),
+        vec![ElementDiff {
+            path: vec![1],
+            a: vec![text(" **b** c ")],
+            b: vec![text(" "), OwnedElement::Bold(vec![text("b")]), text(" c ")],
+        }]
+    );
+
+    // only additional elements
+    assert_eq!(
+        diff(&parse_only_text("#a"), &parse_only_text("#a #b")),
+        vec![ElementDiff {
+            path: vec![1],
+            a: vec![],
+            b: vec![text(" "), OwnedElement::Tag("#b".to_owned())],
+        }]
+    );
+}
+
+#[test]
+fn children_of_the_same_markdown_element_are_compared() {
+    let a = parse_markdown_text("x **a _b_ c** y");
+    let b = parse_markdown_text("x **a _B_ c** y");
+    assert_eq!(
+        diff(&a, &b),
+        vec![ElementDiff {
+            path: vec![1, 1, 0],
+            a: vec![text("b")],
+            b: vec![text("B")],
+        }]
+    );
+
+    // the children of labeled links with the same destination are the label
+    let a = parse_desktop_set("[a](https://delta.chat)");
+    let b = parse_desktop_set("[b](https://delta.chat)");
+    assert_eq!(diff(&a, &b)[0].path, vec![0, 0]);
+
+    // different kinds of elements or destinations are compared as a whole
+    let a = parse_markdown_text("**a**");
+    let b = parse_markdown_text("_a_");
+    assert_eq!(diff(&a, &b)[0].path, vec![0]);
+    let a = parse_desktop_set("[a](https://delta.chat)");
+    let b = parse_desktop_set("[a](https://delta.chat/)");
+    assert_eq!(diff(&a, &b)[0].path, vec![0]);
+}
+
+#[test]
+fn diffs_serialize_to_json() {
+    let a = parse_only_text("_a_");
+    let b = parse_markdown_text("_a_");
+    let json = serde_json::to_string(&diff(&a, &b)).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"path":[0],"a":[{"t":"Text","c":"_a_"}],"b":[{"t":"Italics","c":[{"t":"Text","c":"a"}]}]}]"#
+    );
+    let loaded: Vec<ElementDiff> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, diff(&a, &b));
+}
@@ -6,7 +6,7 @@ fn bold_capitalized_command_suggestion() {
     let input = "**/TELL** world";
     assert_eq!(
         parse_markdown_text(input),
-        vec![Bold(vec![BotCommandSuggestion("/TELL")]), Text(" world")]
+        vec![Bold(vec![bot_command("/TELL")]), Text(" world")]
     );
 }
 
@@ -16,7 +16,7 @@ fn bold_command_suggestion() {
     assert_eq!(
         parse_markdown_text(input),
         vec![
-            Bold(vec![BotCommandSuggestion("/yes")]),
+            Bold(vec![bot_command("/yes")]),
             Text(" - write yes to the bot")
         ]
     );
@@ -28,14 +28,14 @@ fn command_suggestions() {
     assert_eq!(
         parse_markdown_text(input),
         vec![
-            BotCommandSuggestion("/yes"),
+            bot_command("/yes"),
             Linebreak,
-            BotCommandSuggestion("/move_a5_a6"),
+            bot_command("/move_a5_a6"),
             Text(" "),
             Linebreak,
-            BotCommandSuggestion("/answer2_gameid"),
+            bot_command("/answer2_gameid"),
             Text(" or "),
-            BotCommandSuggestion("/answer__no")
+            bot_command("/answer__no")
         ]
     );
 }
@@ -49,7 +49,7 @@ fn invalid_command_suggestions() {
             Text("/1"),
             Linebreak,
             Text(" "),
-            BotCommandSuggestion("/hello"),
+            bot_command("/hello"),
             Text(" world")
         ]
     );
@@ -890,3 +890,207 @@ fn labeled_link_should_not_allow_codeblock() {
         ]
     );
 }
+
+#[test]
+fn emoji_elements() {
+    use deltachat_message_parser::parser::{parse_markdown_text_with_options, ParserOptions};
+    let options = ParserOptions {
+        emoji_elements: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_markdown_text_with_options(
+            "**great 👍**, [🔗 link](https://delta.chat) `🙈`",
+            &options
+        ),
+        vec![
+            Bold(vec![Text("great "), Emoji("👍")]),
+            Text(", "),
+            LabeledLink {
+                label: vec![Emoji("🔗"), Text(" link")],
+                destination: https_link_no_puny("https://delta.chat", "delta.chat"),
+            },
+            Text(" "),
+            InlineCode { content: "🙈" },
+        ]
+    );
+}
+
+#[test]
+fn many_characters_that_do_not_start_an_element() {
+    for pattern in ["[a ", "<a ", "a_ ", "* ", "a.a"] {
+        let input = pattern.repeat(2000);
+        assert_eq!(parse_markdown_text(&input), vec![Text(&input)]);
+    }
+    let input = format!("{}<https://delta.chat>", "<a ".repeat(2000));
+    let elements = parse_markdown_text(&input);
+    assert_eq!(elements.len(), 2);
+    assert!(matches!(elements.get(1), Some(Link { .. })));
+}
+
+#[test]
+fn max_nesting_depth() {
+    use deltachat_message_parser::parser::{parse_markdown_text_with_report, ParserOptions};
+    let input = "**bold _italic ~~strike~~_** [**label**](https://delta.chat)";
+    let options = ParserOptions {
+        max_nesting_depth: Some(3),
+        ..Default::default()
+    };
+    let (elements, report) = parse_markdown_text_with_report(input, &options);
+    assert_eq!(elements, parse_markdown_text(input));
+    assert!(!report.any());
+
+    let options = ParserOptions {
+        max_nesting_depth: Some(1),
+        ..Default::default()
+    };
+    let (elements, report) = parse_markdown_text_with_report(input, &options);
+    assert_eq!(
+        elements,
+        vec![
+            Bold(vec![Text("bold _italic ~~strike~~_")]),
+            Text(" "),
+            LabeledLink {
+                label: vec![Text("**label**")],
+                destination: https_link_no_puny("https://delta.chat", "delta.chat"),
+            },
+        ]
+    );
+    assert!(report.max_nesting_depth_exceeded);
+    assert!(!report.max_elements_exceeded);
+
+    let options = ParserOptions {
+        max_nesting_depth: Some(0),
+        ..Default::default()
+    };
+    let (elements, report) = parse_markdown_text_with_report("**bold** `code`", &options);
+    assert_eq!(
+        elements,
+        vec![Text("**bold** "), InlineCode { content: "code" }]
+    );
+    assert!(report.max_nesting_depth_exceeded);
+}
+
+#[test]
+fn max_elements() {
+    use deltachat_message_parser::parser::{parse_markdown_text_with_report, ParserOptions};
+    let options = ParserOptions {
+        max_elements: Some(4),
+        ..Default::default()
+    };
+    let input = "**a** #b\n**c** #d";
+    let (elements, report) = parse_markdown_text_with_report(input, &options);
+    assert_eq!(
+        elements,
+        vec![
+            Bold(vec![Text("a")]),
+            Text(" "),
+            Tag("#b"),
+            Text("\n**c** #d")
+        ]
+    );
+    assert!(report.max_elements_exceeded);
+
+    let (elements, report) = parse_markdown_text_with_report("**a** #b", &options);
+    assert_eq!(elements, parse_markdown_text("**a** #b"));
+    assert!(!report.any());
+
+    // the limit is also checked for the children of a large element
+    let input = format!("x **{}_a_**", "_a_ ".repeat(1000));
+    let (elements, report) = parse_markdown_text_with_report(&input, &options);
+    assert_eq!(elements, vec![Text("x "), Text(&input[2..])]);
+    assert!(report.max_elements_exceeded);
+}
+
+#[test]
+fn max_markdown_bytes() {
+    use deltachat_message_parser::parser::{parse_markdown_text_with_report, ParserOptions};
+    let options = ParserOptions {
+        max_markdown_bytes: Some(10),
+        ..Default::default()
+    };
+    let (elements, report) = parse_markdown_text_with_report("**bold** #tag", &options);
+    assert_eq!(elements, vec![Text("**bold** "), Tag("#tag")]);
+    assert!(report.max_markdown_bytes_exceeded);
+
+    let (elements, report) = parse_markdown_text_with_report("**bold**", &options);
+    assert_eq!(elements, vec![Bold(vec![Text("bold")])]);
+    assert!(!report.any());
+}
+
+#[test]
+fn enabled_elements() {
+    use deltachat_message_parser::parser::{
+        parse_markdown_text_with_options, ElementKind, ParserOptions,
+    };
+    let input = "**bold https://delta.chat** #tag `code` [label](https://delta.chat) <a@b.de>";
+    let options = ParserOptions {
+        enabled_elements: Some(vec![ElementKind::Link, ElementKind::Italics]),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_markdown_text_with_options(input, &options),
+        vec![
+            Text("*"),
+            Italics(vec![
+                Text("bold "),
+                Link {
+                    destination: https_link_no_puny("https://delta.chat", "delta.chat")
+                }
+            ]),
+            Text("* "),
+            Text("#tag"),
+            Text(" "),
+            Text("`code`"),
+            Text(" [label]("),
+            Link {
+                destination: https_link_no_puny("https://delta.chat", "delta.chat")
+            },
+            Text(") "),
+            Text("<a@b.de>"),
+        ]
+    );
+
+    let options = ParserOptions {
+        enabled_elements: Some(vec![]),
+        ..Default::default()
+    };
+    let elements = parse_markdown_text_with_options(input, &options);
+    assert!(elements.iter().all(|element| matches!(element, Text(_))));
+}
+
+#[test]
+fn allowed_schemes() {
+    use deltachat_message_parser::parser::{parse_markdown_text_with_options, ParserOptions};
+    let options = ParserOptions {
+        allowed_schemes: Some(vec!["HTTPS".to_owned()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_markdown_text_with_options(
+            "[a](https://delta.chat) [b](ftp://delta.chat) delta.chat <ftp://delta.chat>",
+            &options
+        ),
+        vec![
+            LabeledLink {
+                label: vec![Text("a")],
+                destination: https_link_no_puny("https://delta.chat", "delta.chat"),
+            },
+            Text(" [b]("),
+            Text("ftp://delta.chat"),
+            Text(") "),
+            Link {
+                destination: LinkDestination {
+                    target: "delta.chat",
+                    hostname: Some("delta.chat"),
+                    scheme: None,
+                    punycode: None,
+                    payment_request: None,
+                    magnet: None,
+                }
+            },
+            Text(" "),
+            Text("<ftp://delta.chat>"),
+        ]
+    );
+}
@@ -1,5 +1,5 @@
 use deltachat_message_parser::parser::Element::*;
-use deltachat_message_parser::parser::LinkDestination;
+use deltachat_message_parser::parser::{Element, LinkDestination};
 
 pub(crate) fn gopher_link_no_puny<'a>(target: &'a str, hostname: &'a str) -> LinkDestination<'a> {
     LinkDestination {
@@ -7,6 +7,8 @@ pub(crate) fn gopher_link_no_puny<'a>(target: &'a str, hostname: &'a str) -> Lin
         hostname: Some(hostname),
         scheme: Some("gopher"),
         punycode: None,
+        payment_request: None,
+        magnet: None,
     }
 }
 
@@ -16,6 +18,8 @@ pub(crate) fn http_link_no_puny<'a>(target: &'a str, hostname: &'a str) -> LinkD
         hostname: Some(hostname),
         scheme: Some("http"),
         punycode: None,
+        payment_request: None,
+        magnet: None,
     }
 }
 
@@ -25,6 +29,8 @@ pub(crate) fn ftp_link_no_puny<'a>(target: &'a str, hostname: &'a str) -> LinkDe
         hostname: Some(hostname),
         scheme: Some("ftp"),
         punycode: None,
+        payment_request: None,
+        magnet: None,
     }
 }
 
@@ -34,6 +40,8 @@ pub(crate) fn https_link_no_puny<'a>(target: &'a str, hostname: &'a str) -> Link
         hostname: Some(hostname),
         scheme: Some("https"),
         punycode: None,
+        payment_request: None,
+        magnet: None,
     }
 }
 
@@ -43,9 +51,29 @@ pub(crate) fn mailto_link_no_puny(target: &str) -> LinkDestination<'_> {
         hostname: None,
         scheme: Some("mailto"),
         punycode: None,
+        payment_request: None,
+        magnet: None,
+    }
+}
+
+/// a bot command suggestion without arguments
+pub(crate) fn bot_command(content: &str) -> Element<'_> {
+    let (command, target) = match content.trim_start_matches('/').split_once('@') {
+        Some((command, target)) => (command, Some(target)),
+        None => (content.trim_start_matches('/'), None),
+    };
+    BotCommandSuggestion {
+        content,
+        command,
+        target,
+        args: None,
     }
 }
 
 mod desktop_set;
+mod diff;
 mod markdown;
+mod owned;
+mod sources;
 mod text_only;
+mod versioned;
@@ -0,0 +1,74 @@
+use deltachat_message_parser::parser::{
+    parse_markdown_text, parse_markdown_text_with_options, Element, OwnedElement, ParserOptions,
+};
+
+const TEXTS: [&str; 6] = [
+    "**Hello** _World_ ~~strike~~ `code` #tag /help@bot.example args\n```js\nlet a;\n```",
+    "[label **bold**](https://delta.chat) <hello@delta.chat> 👋🏽",
+    "https://münchen.de bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=1",
+    "magnet:?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a&dn=file",
+    include_str!("../../benches/testdata.md"),
+    include_str!("../../benches/moar_links.txt"),
+];
+
+fn options() -> ParserOptions {
+    ParserOptions {
+        emoji_elements: true,
+        bot_command_args: true,
+        ..Default::default()
+    }
+}
+
+fn owned(input: &str) -> Vec<OwnedElement> {
+    parse_markdown_text_with_options(input, &options())
+        .into_iter()
+        .map(Element::into_owned)
+        .collect()
+}
+
+#[test]
+fn owned_elements_borrow_as_the_same_elements() {
+    for input in TEXTS {
+        let elements = parse_markdown_text_with_options(input, &options());
+        let owned = owned(input);
+        let borrowed: Vec<Element> = owned.iter().map(OwnedElement::as_element).collect();
+        assert_eq!(borrowed, elements);
+    }
+}
+
+#[test]
+fn owned_elements_have_the_same_json() {
+    for input in TEXTS {
+        let elements = parse_markdown_text_with_options(input, &options());
+        let json = serde_json::to_string(&elements).unwrap();
+        let owned = owned(input);
+        assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+        // stored results can be loaded again
+        let loaded: Vec<OwnedElement> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, owned);
+    }
+}
+
+#[test]
+fn owned_elements_outlive_the_input() {
+    let handle = {
+        let input = String::from("**Hello** https://delta.chat");
+        let owned: Vec<OwnedElement> = parse_markdown_text(&input)
+            .into_iter()
+            .map(OwnedElement::from)
+            .collect();
+        std::thread::spawn(move || owned)
+    };
+    let elements = handle.join().unwrap();
+    assert_eq!(
+        elements[0],
+        OwnedElement::Bold(vec![OwnedElement::Text("Hello".to_owned())])
+    );
+    match &elements[2] {
+        OwnedElement::Link { destination } => {
+            assert_eq!(destination.target, "https://delta.chat");
+            assert_eq!(destination.hostname.as_deref(), Some("delta.chat"));
+        }
+        element => panic!("expected a link, got {:?}", element),
+    }
+}
@@ -0,0 +1,186 @@
+use deltachat_message_parser::parser::{
+    parse_desktop_set_with_options, parse_desktop_set_with_sources,
+    parse_markdown_text_with_options, parse_markdown_text_with_sources,
+    parse_only_text_with_options, parse_only_text_with_sources,
+    source::{verify_coverage, CoverageError, ElementSource},
+    Element, ParserOptions,
+};
+
+const TEXTS: [&str; 12] = [
+    "",
+    "Hello **World**, how are _you_?\nsee ~~https://delta.chat~~ <https://delta.chat>",
+    "[the *label* with `code`](https://delta.chat) and [desktop](https://delta.chat)",
+    "```rust\n  fn main() {}  \n```\nand ``` \nno language\n```",
+    "mail me at <hello@delta.chat> or hello@delta.chat, #tag /command@bot@example.org",
+    "**bold _italics ~~strike **bold**~~_** __x__",
+    "a link (https://en.wikipedia.org/wiki/Bracket_(disambiguation)), mailto://x.",
+    "emoji 👋🏽 and 1️⃣ #️⃣\r\n\n\ttabs",
+    "unclosed **bold and _italics and [label](",
+    include_str!("../../benches/testdata.md"),
+    include_str!("../../benches/moar_links.txt"),
+    include_str!("../../benches/r10s_update_message.txt"),
+];
+
+fn options() -> [ParserOptions; 4] {
+    [
+        ParserOptions::default(),
+        ParserOptions {
+            emoji_elements: true,
+            bot_command_args: true,
+            ..Default::default()
+        },
+        ParserOptions {
+            max_nesting_depth: Some(1),
+            max_elements: Some(20),
+            ..Default::default()
+        },
+        ParserOptions {
+            max_elements: Some(3),
+            ..Default::default()
+        },
+    ]
+}
+
+/// every element has a source and the children of nested elements have one, too
+fn assert_same_shape(elements: &[Element], sources: &[ElementSource]) {
+    assert_eq!(elements.len(), sources.len());
+    for (element, source) in elements.iter().zip(sources) {
+        match element {
+            Element::Bold(children)
+            | Element::Italics(children)
+            | Element::StrikeThrough(children)
+            | Element::LabeledLink {
+                label: children, ..
+            } => assert_same_shape(children, &source.children),
+            _ => assert!(source.children.is_empty()),
+        }
+    }
+}
+
+fn concatenated(sources: &[ElementSource]) -> String {
+    sources.iter().map(|source| source.text).collect()
+}
+
+#[test]
+fn sources_cover_input_markdown() {
+    for options in &options() {
+        for input in TEXTS {
+            let (elements, sources) = parse_markdown_text_with_sources(input, options);
+            assert_eq!(verify_coverage(input, &sources), Ok(()), "{input:?}");
+            assert_eq!(concatenated(&sources), input);
+            assert_same_shape(&elements, &sources);
+        }
+    }
+}
+
+#[test]
+fn sources_cover_input_text_only() {
+    for options in &options() {
+        for input in TEXTS {
+            let (elements, sources) = parse_only_text_with_sources(input, options);
+            assert_eq!(verify_coverage(input, &sources), Ok(()), "{input:?}");
+            assert_eq!(concatenated(&sources), input);
+            assert_same_shape(&elements, &sources);
+        }
+    }
+}
+
+#[test]
+fn sources_cover_input_desktop_set() {
+    for options in &options() {
+        for input in TEXTS {
+            let (elements, sources) = parse_desktop_set_with_sources(input, options);
+            assert_eq!(verify_coverage(input, &sources), Ok(()), "{input:?}");
+            assert_eq!(concatenated(&sources), input);
+            assert_same_shape(&elements, &sources);
+        }
+    }
+}
+
+#[test]
+fn sources_dont_change_the_elements() {
+    for options in &options() {
+        for input in TEXTS {
+            assert_eq!(
+                parse_markdown_text_with_sources(input, options).0,
+                parse_markdown_text_with_options(input, options)
+            );
+            assert_eq!(
+                parse_only_text_with_sources(input, options).0,
+                parse_only_text_with_options(input, options)
+            );
+            assert_eq!(
+                parse_desktop_set_with_sources(input, options).0,
+                parse_desktop_set_with_options(input, options)
+            );
+        }
+    }
+}
+
+#[test]
+fn sources_contain_delimiters() {
+    let input = "[the *label*](https://delta.chat) ```js\n code \n```";
+    let (_, sources) = parse_markdown_text_with_sources(input, &ParserOptions::default());
+    let texts: Vec<&str> = sources.iter().map(|source| source.text).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "[the *label*](https://delta.chat)",
+            " ",
+            "```js\n code \n```"
+        ]
+    );
+    assert_eq!(sources[0].range, 0..33);
+    let label = &sources[0].children;
+    assert_eq!(label[0].text, "the ");
+    assert_eq!(label[0].range, 1..5);
+    assert_eq!(label[1].text, "*label*");
+    assert_eq!(label[1].children[0].text, "label");
+    assert_eq!(label[1].children[0].range, 6..11);
+}
+
+#[test]
+fn desktop_set_label_source() {
+    let input = "[the *label*](https://delta.chat)";
+    let (_, sources) = parse_desktop_set_with_sources(input, &ParserOptions::default());
+    assert_eq!(sources[0].text, input);
+    assert_eq!(sources[0].children[0].text, "the *label*");
+}
+
+#[test]
+fn verify_coverage_errors() {
+    let input = "abc";
+    let source = |range: std::ops::Range<usize>| ElementSource {
+        text: input.get(range.clone()).unwrap_or_default(),
+        range,
+        children: Vec::new(),
+    };
+    assert_eq!(
+        verify_coverage(input, &[source(0..1)]),
+        Err(CoverageError::Uncovered(1..3))
+    );
+    assert_eq!(
+        verify_coverage(input, &[source(0..1), source(2..3)]),
+        Err(CoverageError::Uncovered(1..2))
+    );
+    assert_eq!(
+        verify_coverage(input, &[source(0..2), source(1..3)]),
+        Err(CoverageError::Overlap(1..3))
+    );
+    let wrong_text = ElementSource {
+        text: "x",
+        ..source(0..3)
+    };
+    assert_eq!(
+        verify_coverage(input, &[wrong_text]),
+        Err(CoverageError::TextMismatch(0..3))
+    );
+    let outside = ElementSource {
+        children: vec![source(1..3)],
+        ..source(0..2)
+    };
+    assert_eq!(
+        verify_coverage(input, &[outside, source(2..3)]),
+        Err(CoverageError::ChildOutsideParent(1..3))
+    );
+}
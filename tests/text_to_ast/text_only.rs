@@ -1,5 +1,7 @@
 use super::*;
-use deltachat_message_parser::parser::parse_only_text;
+use deltachat_message_parser::parser::{
+    parse_only_text, parse_only_text_with_options, ParserOptions,
+};
 
 #[test]
 fn do_not_parse_markdown_elements() {
@@ -41,14 +43,14 @@ fn command_suggestions() {
     assert_eq!(
         parse_only_text(input),
         vec![
-            BotCommandSuggestion("/yes"),
+            bot_command("/yes"),
             Linebreak,
-            BotCommandSuggestion("/move_a5_a6"),
+            bot_command("/move_a5_a6"),
             Text(" "),
             Linebreak,
-            BotCommandSuggestion("/answer2_gameid"),
+            bot_command("/answer2_gameid"),
             Text(" or "),
-            BotCommandSuggestion("/answer__no")
+            bot_command("/answer__no")
         ]
     );
 }
@@ -68,7 +70,7 @@ fn invalid_command_suggestions() {
             Text("/1"),
             Linebreak,
             Text(" "),
-            BotCommandSuggestion("/hello"),
+            bot_command("/hello"),
             Text(" world")
         ]
     );
@@ -567,11 +569,11 @@ fn link_in_parenthesis2() {
 fn bot_suggestion_is_no_email() {
     assert_eq!(
         parse_only_text("/command@bot@addr.com"),
-        vec![BotCommandSuggestion("/command@bot@addr.com"),]
+        vec![bot_command("/command@bot@addr.com"),]
     );
     assert_eq!(
         parse_only_text("\n/command@bot@addr.com"),
-        vec![Linebreak, BotCommandSuggestion("/command@bot@addr.com"),]
+        vec![Linebreak, bot_command("/command@bot@addr.com"),]
     );
 
     assert_eq!(
@@ -579,12 +581,120 @@ fn bot_suggestion_is_no_email() {
         vec![
             Text("Bots that can be selected "),
             Linebreak,
-            BotCommandSuggestion("/command@bot@addr.com"),
+            bot_command("/command@bot@addr.com"),
             Text(" BOT"),
         ]
     );
 }
 
+#[test]
+fn bot_command_target() {
+    assert_eq!(
+        parse_only_text("/command@bot@addr.com"),
+        vec![BotCommandSuggestion {
+            content: "/command@bot@addr.com",
+            command: "command",
+            target: Some("bot@addr.com"),
+            args: None,
+        }]
+    );
+    assert_eq!(
+        parse_only_text("/help@"),
+        vec![BotCommandSuggestion {
+            content: "/help@",
+            command: "help",
+            target: None,
+            args: None,
+        }]
+    );
+}
+
+#[test]
+fn bot_command_args() {
+    let options = ParserOptions {
+        bot_command_args: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_only_text_with_options("/move@chess a5 a6 \nnext line", &options),
+        vec![
+            BotCommandSuggestion {
+                content: "/move@chess",
+                command: "move",
+                target: Some("chess"),
+                args: Some("a5 a6"),
+            },
+            Text(" "),
+            Linebreak,
+            Text("next line"),
+        ]
+    );
+    // no arguments
+    assert_eq!(
+        parse_only_text_with_options("/start  \n/stop", &options),
+        vec![
+            bot_command("/start"),
+            Text("  "),
+            Linebreak,
+            bot_command("/stop"),
+        ]
+    );
+    // arguments are only parsed with the option
+    assert_eq!(
+        parse_only_text("/move a5 a6"),
+        vec![bot_command("/move"), Text(" a5 a6")]
+    );
+}
+
+#[test]
+fn known_bot_commands() {
+    let options = ParserOptions {
+        known_bot_commands: Some(vec!["help".to_owned(), "/move".to_owned()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_only_text_with_options("/help or /move_a5_a6, not /s /dev", &options),
+        vec![
+            bot_command("/help"),
+            Text(" or "),
+            bot_command("/move_a5_a6"),
+            Text(", not "),
+            Text("/s"),
+            Text(" "),
+            Text("/dev"),
+        ]
+    );
+    assert_eq!(
+        parse_only_text_with_options("/help@bot@example.org", &options),
+        vec![bot_command("/help@bot@example.org")]
+    );
+    // the target of an unknown command is no email address
+    assert_eq!(
+        parse_only_text_with_options("/start@bot@example.org", &options),
+        vec![Text("/start@bot@example.org")]
+    );
+    // commands that only start with a known command are unknown
+    assert_eq!(
+        parse_only_text_with_options("/helpful /helpdesk@bot /movement", &options),
+        vec![
+            Text("/helpful"),
+            Text(" "),
+            Text("/helpdesk@bot"),
+            Text(" "),
+            Text("/movement"),
+        ]
+    );
+    // no known commands
+    let options = ParserOptions {
+        known_bot_commands: Some(vec![]),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_only_text_with_options("/help", &options),
+        vec![Text("/help")]
+    );
+}
+
 #[test]
 fn link_with_delimiter_and_space() {
     assert_eq!(
@@ -612,3 +722,121 @@ fn link_with_domain_and_multiple_delimiters() {
         ]
     );
 }
+
+#[test]
+fn emoji_elements() {
+    let options = ParserOptions {
+        emoji_elements: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_only_text_with_options("Hi 👋🏽, #️⃣ #tag❤️ 😀\n🇩🇪!", &options),
+        vec![
+            Text("Hi "),
+            Emoji("👋🏽"),
+            Text(", "),
+            Emoji("#️⃣"),
+            Text(" "),
+            Tag("#tag❤️"),
+            Text(" "),
+            Emoji("😀"),
+            Linebreak,
+            Emoji("🇩🇪"),
+            Text("!"),
+        ]
+    );
+    // default options keep emojis in the text
+    assert_eq!(
+        parse_only_text_with_options("Hi 👋🏽", &ParserOptions::default()),
+        vec![Text("Hi 👋🏽")]
+    );
+}
+
+#[test]
+fn max_elements() {
+    use deltachat_message_parser::parser::parse_only_text_with_report;
+    let options = ParserOptions {
+        max_elements: Some(2),
+        ..Default::default()
+    };
+    let (elements, report) = parse_only_text_with_report("#a #b #c", &options);
+    assert_eq!(elements, vec![Tag("#a"), Text(" "), Text("#b #c")]);
+    assert!(report.max_elements_exceeded);
+}
+
+#[test]
+fn parenthesis_in_links_after_multibyte_characters() {
+    assert_eq!(
+        parse_only_text("(https://de.wikipedia.org/wiki/Käse)"),
+        vec![
+            Text("("),
+            Link {
+                destination: https_link_no_puny(
+                    "https://de.wikipedia.org/wiki/Käse",
+                    "de.wikipedia.org"
+                )
+            },
+            Text(")")
+        ]
+    );
+}
+
+#[test]
+fn generic_scheme_with_slashes() {
+    assert_eq!(
+        parse_only_text("write to mailto://x now"),
+        vec![
+            Text("write to "),
+            Link {
+                destination: mailto_link_no_puny("mailto://x")
+            },
+            Text(" now")
+        ]
+    );
+}
+
+#[test]
+fn disabled_elements_are_kept_as_text() {
+    use deltachat_message_parser::parser::{
+        parse_only_text_with_options, ElementKind, ParserOptions,
+    };
+    let options = ParserOptions {
+        enabled_elements: Some(vec![ElementKind::Tag, ElementKind::EmailAddress]),
+        ..Default::default()
+    };
+    // the hashtag inside of the link is not parsed, the link is kept as one text
+    assert_eq!(
+        parse_only_text_with_options("see https://delta.chat/#tag or #tag /help a@b.de", &options),
+        vec![
+            Text("see "),
+            Text("https://delta.chat/#tag"),
+            Text(" or "),
+            Tag("#tag"),
+            Text(" "),
+            Text("/help"),
+            Text(" "),
+            EmailAddress("a@b.de"),
+        ]
+    );
+}
+
+#[test]
+fn allowed_schemes_in_text() {
+    use deltachat_message_parser::parser::{parse_only_text_with_options, ParserOptions};
+    let options = ParserOptions {
+        allowed_schemes: Some(vec!["mailto".to_owned()]),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_only_text_with_options("mailto:a@b.de https://delta.chat delta.chat", &options),
+        vec![
+            Link {
+                destination: mailto_link_no_puny("mailto:a@b.de")
+            },
+            Text(" "),
+            Text("https://delta.chat"),
+            Text(" "),
+            Text("delta.chat"),
+        ]
+    );
+}
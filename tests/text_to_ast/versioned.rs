@@ -0,0 +1,72 @@
+use deltachat_message_parser::parser::{
+    parse_markdown_text, parse_markdown_text_with_options, versioned::VersionedAst, Element,
+    OwnedElement, ParserOptions, AST_SCHEMA_VERSION,
+};
+
+fn options() -> ParserOptions {
+    ParserOptions {
+        emoji_elements: true,
+        bot_command_args: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn borrowed_elements_deserialize_from_json_without_escapes() {
+    let input = "**Hello** _World_ [label](https://münchen.de) #tag /help@bot.example args 👋🏽 \
+        bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=1 `code`";
+    let elements = parse_markdown_text_with_options(input, &options());
+    let json = serde_json::to_string(&elements).unwrap();
+    let loaded: Vec<Element> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, elements);
+}
+
+#[test]
+fn escaped_json_needs_owned_elements() {
+    let input = "```js\nlet a = \"b\";\n```";
+    let json = serde_json::to_string(&parse_markdown_text(input)).unwrap();
+    assert!(serde_json::from_str::<Vec<Element>>(&json).is_err());
+    let loaded: Vec<OwnedElement> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        loaded,
+        vec![OwnedElement::CodeBlock {
+            language: Some("js".to_owned()),
+            content: "let a = \"b\";".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn versioned_ast_json() {
+    let ast = VersionedAst::new(parse_markdown_text("**Hello**"));
+    assert_eq!(
+        serde_json::to_string(&ast).unwrap(),
+        format!(
+            r#"{{"schema_version":{AST_SCHEMA_VERSION},"elements":[{{"t":"Bold","c":[{{"t":"Text","c":"Hello"}}]}}]}}"#
+        )
+    );
+    let json = serde_json::to_string(&ast).unwrap();
+    let loaded: VersionedAst<Element> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, ast);
+    let owned: VersionedAst<OwnedElement> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        owned.elements,
+        vec![OwnedElement::Bold(vec![OwnedElement::Text(
+            "Hello".to_owned()
+        )])]
+    );
+}
+
+#[test]
+fn versioned_ast_rejects_other_versions() {
+    let json = format!(
+        r#"{{"schema_version":{},"elements":[]}}"#,
+        AST_SCHEMA_VERSION + 1
+    );
+    let error = serde_json::from_str::<VersionedAst<OwnedElement>>(&json).unwrap_err();
+    assert!(
+        error.to_string().contains("unsupported AST schema version"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,56 @@
+//! Generates the C header of the FFI crate in `message_parser_ffi/include/message_parser.h`
+//! from its Rust source with cbindgen
+
+use std::{fs, path::Path};
+
+use cbindgen::{Builder, Config, DocumentationStyle, Language, Style};
+
+pub(crate) const HEADER_PATH: &str = "message_parser_ffi/include/message_parser.h";
+const SOURCE_PATH: &str = "message_parser_ffi/src/lib.rs";
+
+/// The content of [HEADER_PATH]
+pub(crate) fn generate(root: &Path) -> Result<String, String> {
+    let mut config = Config::default();
+    config.header = Some(
+        "/* DO NOT EDIT, generated by `cargo xtask ffi-header` from message_parser_ffi/src/lib.rs. */"
+            .to_owned(),
+    );
+    config.include_guard = Some("MESSAGE_PARSER_H".to_owned());
+    config.language = Language::C;
+    config.cpp_compat = true;
+    config.style = Style::Type;
+    config.documentation_style = DocumentationStyle::Doxy;
+    config.usize_is_size_t = true;
+    let mut header = Vec::new();
+    Builder::new()
+        .with_config(config)
+        .with_src(root.join(SOURCE_PATH))
+        .generate()
+        .map_err(|error| format!("generating the header: {error}"))?
+        .write(&mut header);
+    String::from_utf8(header).map_err(|error| format!("the header is not UTF-8: {error}"))
+}
+
+/// Regenerates [HEADER_PATH]
+pub(crate) fn write(root: &Path) -> Result<(), String> {
+    fs::write(root.join(HEADER_PATH), generate(root)?)
+        .map_err(|error| format!("writing {HEADER_PATH}: {error}"))?;
+    println!("generated {HEADER_PATH}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, HEADER_PATH};
+    use crate::project_root;
+
+    #[test]
+    fn checked_in_header_is_up_to_date() {
+        let checked_in = std::fs::read_to_string(project_root().join(HEADER_PATH)).unwrap();
+        assert!(
+            checked_in == generate(&project_root()).unwrap(),
+            "{} is outdated, run `cargo xtask ffi-header`",
+            HEADER_PATH
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Writes the seed corpus for the fuzz targets in `fuzz/`
+//!
+//! The seeds are the string literals of the tests in `tests/` and the lines and whole texts of the
+//! test data in `tests/` and `benches/`, so the fuzzer starts from inputs that reach most parsers.
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const FUZZ_TARGETS: [&str; 6] = [
+    "parse_markdown_text",
+    "parse_only_text",
+    "parse_desktop_set",
+    "link_destination",
+    "punycode",
+    "emoji",
+];
+
+const SOURCE_DIRS: [&str; 2] = ["tests", "benches"];
+
+/// Collects the seeds from the files in [SOURCE_DIRS]
+pub(crate) fn seeds(root: &Path) -> Result<BTreeSet<String>, String> {
+    let mut seeds = BTreeSet::new();
+    for dir in SOURCE_DIRS {
+        for path in files(&root.join(dir))? {
+            let content = fs::read_to_string(&path)
+                .map_err(|error| format!("reading {}: {error}", path.display()))?;
+            match path.extension().and_then(|extension| extension.to_str()) {
+                Some("rs") => seeds.extend(string_literals(&content)),
+                Some("txt") | Some("md") => {
+                    seeds.extend(content.lines().map(str::to_owned));
+                    seeds.insert(content);
+                }
+                _ => {}
+            }
+        }
+    }
+    seeds.remove("");
+    Ok(seeds)
+}
+
+/// Writes the seeds to `fuzz/corpus/<target>/`, the default corpus directory of `cargo fuzz`
+pub(crate) fn write(root: &Path) -> Result<(), String> {
+    let seeds = seeds(root)?;
+    for target in FUZZ_TARGETS {
+        let dir = root.join("fuzz/corpus").join(target);
+        fs::create_dir_all(&dir).map_err(|error| format!("creating {}: {error}", dir.display()))?;
+        for seed in &seeds {
+            let path = dir.join(format!("seed-{:016x}", fnv1a(seed)));
+            fs::write(&path, seed)
+                .map_err(|error| format!("writing {}: {error}", path.display()))?;
+        }
+    }
+    println!(
+        "wrote {} seeds for each of {} fuzz targets to fuzz/corpus/",
+        seeds.len(),
+        FUZZ_TARGETS.len()
+    );
+    Ok(())
+}
+
+fn files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut paths = Vec::new();
+    let entries =
+        fs::read_dir(dir).map_err(|error| format!("reading {}: {error}", dir.display()))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|error| format!("reading {}: {error}", dir.display()))?
+            .path();
+        if path.is_dir() {
+            paths.extend(files(&path)?);
+        } else {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Stable file names, so running the task again doesn't add duplicates
+fn fnv1a(seed: &str) -> u64 {
+    seed.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The values of the string literals in Rust source code, including raw strings
+fn string_literals(source: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                // comments can contain unbalanced quotes
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '\'' => {
+                // char literals like '"', lifetimes have no closing quote
+                let mut lookahead = chars.clone();
+                match lookahead.next() {
+                    Some('\\') => {
+                        for c in chars.by_ref().skip(1) {
+                            if c == '\'' {
+                                break;
+                            }
+                        }
+                    }
+                    Some(_) if lookahead.next() == Some('\'') => {
+                        chars.nth(1);
+                    }
+                    _ => {}
+                }
+            }
+            'r' if matches!(chars.peek(), Some('"') | Some('#')) => {
+                let mut hashes = 0;
+                while chars.peek() == Some(&'#') {
+                    chars.next();
+                    hashes += 1;
+                }
+                if chars.next() != Some('"') {
+                    continue;
+                }
+                let terminator = format!("\"{}", "#".repeat(hashes));
+                let mut literal = String::new();
+                for c in chars.by_ref() {
+                    literal.push(c);
+                    if literal.ends_with(&terminator) {
+                        literal.truncate(literal.len() - terminator.len());
+                        break;
+                    }
+                }
+                literals.push(literal);
+            }
+            '"' => {
+                let mut literal = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some('n') => literal.push('\n'),
+                            Some('r') => literal.push('\r'),
+                            Some('t') => literal.push('\t'),
+                            Some('0') => literal.push('\0'),
+                            Some('u') => {
+                                let code: String =
+                                    chars.by_ref().skip(1).take_while(|&c| c != '}').collect();
+                                if let Some(c) =
+                                    u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                                {
+                                    literal.push(c);
+                                }
+                            }
+                            Some('\n') => {
+                                while chars.peek().map_or(false, |c| c.is_whitespace()) {
+                                    chars.next();
+                                }
+                            }
+                            Some(c) => literal.push(c),
+                            None => {}
+                        },
+                        c => literal.push(c),
+                    }
+                }
+                literals.push(literal);
+            }
+            // identifiers ending with r, like `for"`, don't start a raw string
+            c if c.is_alphanumeric() || c == '_' => {
+                while chars
+                    .peek()
+                    .map_or(false, |&c| c.is_alphanumeric() || c == '_')
+                {
+                    chars.next();
+                }
+            }
+            _ => {}
+        }
+    }
+    literals
+}
+
+#[cfg(test)]
+mod test {
+    use super::string_literals;
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            string_literals(
+                "// \"comment\"\nlet c = '\"'; fn a<'a>(x: &'a str) { \"a\\n\\\"b\\u{1F34F}\"; r#\"raw \"quote\"\"#; '\\''; \"c\\\n    d\" }"
+            ),
+            vec!["a\n\"b🍏", "raw \"quote\"", "cd"]
+        );
+    }
+}
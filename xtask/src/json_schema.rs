@@ -0,0 +1,76 @@
+//! Generates the JSON Schema of the AST in `schema/ast.v<version>.schema.json` from the Rust types
+//!
+//! Every [AST_SCHEMA_VERSION] has its own schema file, which is never changed after it was
+//! written. A change of the JSON format changes the generated schema, so the test below fails
+//! until AST_SCHEMA_VERSION is increased and the schema of the new version is generated.
+
+use std::{fs, io::ErrorKind, path::Path};
+
+use deltachat_message_parser::parser::{versioned::VersionedAst, Element, AST_SCHEMA_VERSION};
+
+/// Path of the schema of the current [AST_SCHEMA_VERSION], relative to the project root
+pub(crate) fn schema_path() -> String {
+    format!("schema/ast.v{AST_SCHEMA_VERSION}.schema.json")
+}
+
+/// The schema as pretty printed JSON
+pub(crate) fn generate() -> Result<String, String> {
+    let mut schema = schemars::schema_for!(VersionedAst<Element<'static>>);
+    let metadata = schema.schema.metadata();
+    metadata.title = Some("deltachat message parser AST".to_owned());
+    metadata.description = Some(
+        "The elements of a message, generated by `cargo xtask json-schema`, do not edit".to_owned(),
+    );
+    let mut json = serde_json::to_string_pretty(&schema)
+        .map_err(|error| format!("serializing the schema: {error}"))?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Writes the schema file of the current version, an existing file of the version is not changed
+pub(crate) fn write(root: &Path) -> Result<(), String> {
+    let schema_path = schema_path();
+    let path = root.join(&schema_path);
+    let schema = generate()?;
+    match fs::read_to_string(&path) {
+        Ok(checked_in) if checked_in == schema => {
+            println!("{schema_path} is up to date");
+            return Ok(());
+        }
+        Ok(_) => {
+            return Err(format!(
+                "the JSON format changed, but {schema_path} is the schema of version \
+                {AST_SCHEMA_VERSION}: increase AST_SCHEMA_VERSION and run `cargo xtask json-schema` again"
+            ))
+        }
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => return Err(format!("reading {schema_path}: {error}")),
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| format!("creating {}: {error}", dir.display()))?;
+    }
+    fs::write(&path, schema).map_err(|error| format!("writing {schema_path}: {error}"))?;
+    println!("generated {schema_path}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, schema_path};
+    use crate::project_root;
+
+    #[test]
+    fn schema_of_the_current_version_is_checked_in() {
+        let schema_path = schema_path();
+        let checked_in =
+            std::fs::read_to_string(project_root().join(&schema_path)).unwrap_or_else(|_| {
+                panic!("{} is missing, run `cargo xtask json-schema`", schema_path)
+            });
+        assert!(
+            checked_in == generate().unwrap(),
+            "the JSON format differs from {}, increase AST_SCHEMA_VERSION \
+            and run `cargo xtask json-schema` to write the schema of the new version",
+            schema_path
+        );
+    }
+}
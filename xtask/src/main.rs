@@ -0,0 +1,52 @@
+//! Development tasks for the message parser, run them with `cargo xtask <task>`
+
+mod ffi_header;
+mod fuzz_corpus;
+mod json_schema;
+mod typescript;
+mod unicode_tables;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const HELP: &str = "\
+usage: cargo xtask <task>
+
+tasks:
+  generate-unicode-tables    regenerate src/parser/unicode_tables/ from the data in unicode/
+  fuzz-corpus                write the seed corpus for the fuzz targets to fuzz/corpus/
+  json-schema                write schema/ast.v<version>.schema.json of AST_SCHEMA_VERSION from the AST types
+  typescript                 regenerate the TypeScript types of the wasm package from the Rust types
+  ffi-header                 regenerate the C header of message_parser_ffi from its source
+";
+
+fn main() -> ExitCode {
+    let result = match env::args().nth(1).as_deref() {
+        Some("generate-unicode-tables") => unicode_tables::write(&project_root()),
+        Some("fuzz-corpus") => fuzz_corpus::write(&project_root()),
+        Some("json-schema") => json_schema::write(&project_root()),
+        Some("typescript") => typescript::write(&project_root()),
+        Some("ffi-header") => ffi_header::write(&project_root()),
+        _ => {
+            eprint!("{HELP}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
@@ -0,0 +1,285 @@
+//! Generates the TypeScript types of the wasm package in `message_parser_wasm/src/typings.rs`
+//!
+//! The types are converted from the JSON Schema of the Rust types (see `json_schema.rs`), so they
+//! describe the JSON that serde produces. Only the parts of JSON Schema that `schemars` uses for
+//! our types are supported.
+
+use std::{fmt::Write, fs, path::Path};
+
+use deltachat_message_parser::parser::{
+    is_emoji::{JumboEmojiClassification, SegmentKind},
+    versioned::VersionedAst,
+    Element, ElementKind, LimitsReport, ParseMode,
+};
+use schemars::{
+    gen::SchemaSettings,
+    schema::{InstanceType, Schema, SchemaObject, SingleOrVec},
+    Map,
+};
+
+pub(crate) const TYPINGS_PATH: &str = "message_parser_wasm/src/typings.rs";
+
+/// TypeScript names of the types whose Rust name is not used
+const RENAMED: [(&str, &str); 2] = [
+    ("Element", "ParsedElement"),
+    ("VersionedAst_for_Element", "VersionedAst"),
+];
+
+/// The content of [TYPINGS_PATH]
+pub(crate) fn generate() -> Result<String, String> {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    generator.subschema_for::<VersionedAst<Element>>();
+    generator.subschema_for::<JumboEmojiClassification>();
+    generator.subschema_for::<SegmentKind>();
+    generator.subschema_for::<ParseMode>();
+    generator.subschema_for::<ElementKind>();
+    generator.subschema_for::<LimitsReport>();
+    let typescript = typescript(&generator.take_definitions())?;
+    Ok(format!(
+        "// DO NOT EDIT, generated by `cargo xtask typescript` from the Rust types.
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_APPEND_CONTENT: &'static str = r#\"
+{typescript}\"#;
+"
+    ))
+}
+
+/// Regenerates [TYPINGS_PATH]
+pub(crate) fn write(root: &Path) -> Result<(), String> {
+    fs::write(root.join(TYPINGS_PATH), generate()?)
+        .map_err(|error| format!("writing {TYPINGS_PATH}: {error}"))?;
+    println!("generated {TYPINGS_PATH}");
+    Ok(())
+}
+
+/// One `export type` for each definition, in the order of the definitions
+fn typescript(definitions: &Map<String, Schema>) -> Result<String, String> {
+    let mut typescript = String::new();
+    for (name, schema) in definitions {
+        let schema = object(schema)?;
+        let name = type_name(name)?;
+        let definition = match variants(schema) {
+            Some(variants) => variants.iter().try_fold(String::new(), |union, variant| {
+                Ok::<_, String>(format!("{union}\n  | {}", ts_type(variant)?))
+            })?,
+            None if is_object(schema) => format!(" {}", object_type(schema, true)?),
+            // long lists of strings get one line per string, like the variants above
+            None => match &schema.enum_values {
+                Some(values)
+                    if values
+                        .iter()
+                        .map(|value| value.to_string().len())
+                        .sum::<usize>()
+                        > 60 =>
+                {
+                    values.iter().fold(String::new(), |union, value| {
+                        format!("{union}\n  | {value}")
+                    })
+                }
+                _ => format!(" {}", ts_type(&Schema::Object(schema.clone()))?),
+            },
+        };
+        let _ = writeln!(typescript, "export type {name} ={definition};");
+    }
+    Ok(typescript)
+}
+
+fn object(schema: &Schema) -> Result<&SchemaObject, String> {
+    match schema {
+        Schema::Object(object) => Ok(object),
+        Schema::Bool(_) => Err("boolean schemas are not supported".to_owned()),
+    }
+}
+
+fn type_name(definition: &str) -> Result<&str, String> {
+    let name = RENAMED
+        .iter()
+        .find(|(rust, _)| *rust == definition)
+        .map_or(definition, |(_, typescript)| typescript);
+    if name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(name)
+    } else {
+        Err(format!("{definition} needs a TypeScript name in RENAMED"))
+    }
+}
+
+/// the variants of enums, which become a union
+fn variants(schema: &SchemaObject) -> Option<&Vec<Schema>> {
+    let subschemas = schema.subschemas.as_ref()?;
+    subschemas.one_of.as_ref().or(subschemas.any_of.as_ref())
+}
+
+fn is_object(schema: &SchemaObject) -> bool {
+    schema.instance_type == Some(SingleOrVec::Single(Box::new(InstanceType::Object)))
+}
+
+fn ts_type(schema: &Schema) -> Result<String, String> {
+    let schema = object(schema)?;
+    if let Some(reference) = &schema.reference {
+        let definition = reference
+            .strip_prefix("#/definitions/")
+            .ok_or_else(|| format!("unsupported reference {reference}"))?;
+        return type_name(definition).map(str::to_owned);
+    }
+    if let Some(value) = &schema.const_value {
+        return Ok(value.to_string());
+    }
+    if let Some(values) = &schema.enum_values {
+        return Ok(values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(" | "));
+    }
+    if let Some(variants) = variants(schema) {
+        return union(variants.iter().map(ts_type).collect::<Result<_, _>>()?);
+    }
+    let types = match &schema.instance_type {
+        Some(SingleOrVec::Single(instance_type)) => vec![**instance_type],
+        Some(SingleOrVec::Vec(instance_types)) => instance_types.clone(),
+        None => return Err(format!("unsupported schema {schema:?}")),
+    };
+    union(
+        types
+            .into_iter()
+            .map(|instance_type| match instance_type {
+                InstanceType::Null => Ok("null".to_owned()),
+                InstanceType::Boolean => Ok("boolean".to_owned()),
+                InstanceType::Integer | InstanceType::Number => Ok("number".to_owned()),
+                InstanceType::String => Ok("string".to_owned()),
+                InstanceType::Array => array_type(schema),
+                InstanceType::Object => object_type(schema, false),
+            })
+            .collect::<Result<_, _>>()?,
+    )
+}
+
+/// `null` goes first, like in `null | string`
+fn union(mut types: Vec<String>) -> Result<String, String> {
+    types.sort_by_key(|ts_type| ts_type != "null");
+    Ok(types.join(" | "))
+}
+
+fn array_type(schema: &SchemaObject) -> Result<String, String> {
+    let items = schema
+        .array
+        .as_ref()
+        .and_then(|array| array.items.as_ref())
+        .ok_or("arrays need items")?;
+    Ok(match items {
+        SingleOrVec::Single(item) => {
+            let item = ts_type(item)?;
+            if item.contains(" | ") {
+                format!("({item})[]")
+            } else {
+                format!("{item}[]")
+            }
+        }
+        SingleOrVec::Vec(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(ts_type)
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ")
+        ),
+    })
+}
+
+/// Objects of definitions are written over multiple lines with the doc comments of the fields,
+/// nested objects on one line.
+///
+/// All fields are required, because serde also writes the fields that are `None` (as `null`).
+fn object_type(schema: &SchemaObject, multiline: bool) -> Result<String, String> {
+    let properties = match &schema.object {
+        Some(object) => &object.properties,
+        None => return Ok("{}".to_owned()),
+    };
+    let mut fields = Vec::new();
+    for (name, property) in properties {
+        let mut field = String::new();
+        let description = object(property)?
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.description.as_ref());
+        if let (true, Some(description)) = (multiline, description) {
+            push_doc_comment(&mut field, description);
+        }
+        let _ = write!(field, "{name}: {}", ts_type(property)?);
+        fields.push(field);
+    }
+    if !multiline {
+        return Ok(format!("{{ {} }}", fields.join("; ")));
+    }
+    let mut object = "{\n".to_owned();
+    for field in fields {
+        let _ = writeln!(object, "  {field};");
+    }
+    object.push('}');
+    Ok(object)
+}
+
+fn push_doc_comment(field: &mut String, description: &str) {
+    if !description.contains('\n') {
+        let _ = write!(field, "/** {description} */\n  ");
+        return;
+    }
+    field.push_str("/**\n");
+    for line in description.lines() {
+        let _ = writeln!(
+            field,
+            "   *{}{line}",
+            if line.is_empty() { "" } else { " " }
+        );
+    }
+    field.push_str("   */\n  ");
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, typescript, TYPINGS_PATH};
+    use crate::project_root;
+    use schemars::{gen::SchemaSettings, JsonSchema};
+
+    #[test]
+    fn checked_in_typings_are_up_to_date() {
+        let checked_in = std::fs::read_to_string(project_root().join(TYPINGS_PATH)).unwrap();
+        assert!(
+            checked_in == generate().unwrap(),
+            "{} is outdated, run `cargo xtask typescript`",
+            TYPINGS_PATH
+        );
+    }
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    #[serde(tag = "t", content = "c")]
+    enum Example {
+        Unit,
+        Text(String),
+        Fields {
+            /// not shown for nested objects
+            optional: Option<u32>,
+            pairs: Vec<(String, bool)>,
+        },
+        Nested(Option<Box<Example>>),
+    }
+
+    #[test]
+    fn converts_schemars_schemas() {
+        let mut generator = SchemaSettings::draft07().into_generator();
+        generator.subschema_for::<Example>();
+        assert_eq!(
+            typescript(&generator.take_definitions()).unwrap(),
+            r#"export type Example =
+  | { t: "Unit" }
+  | { t: "Text"; c: string }
+  | { t: "Fields"; c: { optional: null | number; pairs: [string, boolean][] } }
+  | { t: "Nested"; c: null | Example };
+"#
+        );
+    }
+}
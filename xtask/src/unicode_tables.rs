@@ -0,0 +1,687 @@
+//! Generates the character tables in `src/parser/unicode_tables/`
+//!
+//! Sources:
+//! - `unicode/emoji-test.txt` for the emoji sequences, its fully-qualified entries are the RGI
+//!   sequences of `emoji-sequences.txt` and `emoji-zwj-sequences.txt`
+//! - `unicode/rfc3987.abnf` for the character ranges of IRIs
+//! - `unicode/hashtag-properties.txt` for the character properties of hashtags (`XID_Continue`,
+//!   `Emoji`, `Extended_Pictographic`), derived data that `unicode/README.md` explains
+//!
+//! All of them have to be of the same Unicode version, see [UNICODE_VERSION]. Only the version of
+//! emoji-test.txt is checked, the derived hashtag properties have no version header.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    fs,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+/// Unicode version of the data in `unicode/`
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (16, 0, 0);
+
+const OUTPUT_DIR: &str = "src/parser/unicode_tables";
+
+const HEADER: &str = "// DO NOT EDIT, generated by `cargo xtask generate-unicode-tables`";
+
+pub(crate) struct GeneratedFile {
+    pub(crate) path: PathBuf,
+    pub(crate) content: String,
+}
+
+/// Generates the content of all table files, the paths are relative to the project root
+pub(crate) fn generate(root: &Path) -> Result<Vec<GeneratedFile>, String> {
+    let emoji_test = read(root, "unicode/emoji-test.txt")?;
+    let hashtag_properties = read(root, "unicode/hashtag-properties.txt")?;
+    let rfc3987 = read(root, "unicode/rfc3987.abnf")?;
+    check_emoji_test_version(&emoji_test)?;
+
+    let emoji_entries = parse_emoji_test(&emoji_test)?;
+    check_status_counts(&emoji_test, &emoji_entries)?;
+    let rfc3987_rules = parse_abnf_ranges(&rfc3987)?;
+    let properties = parse_properties(&hashtag_properties)?;
+
+    let mut files = vec![GeneratedFile {
+        path: Path::new(OUTPUT_DIR).join("mod.rs"),
+        content: mod_rs(),
+    }];
+
+    let mut hashtag = format!("{HEADER} from unicode/hashtag-properties.txt.\n\n");
+    hashtag.push_str("use std::ops::RangeInclusive;\n\n");
+    push_ranges(
+        &mut hashtag,
+        "Characters that are `XID_Continue`, `Emoji` or `Extended_Pictographic`",
+        "HASHTAG_CONTENT_CHAR_RANGES",
+        &property_ranges(
+            &properties,
+            &["XID_Continue", "Emoji", "Extended_Pictographic"],
+        )?,
+    );
+    files.push(GeneratedFile {
+        path: Path::new(OUTPUT_DIR).join("hashtag.rs"),
+        content: hashtag,
+    });
+
+    files.push(GeneratedFile {
+        path: Path::new(OUTPUT_DIR).join("emoji.rs"),
+        content: emoji_rs(&emoji_entries)?,
+    });
+    files.push(GeneratedFile {
+        path: Path::new(OUTPUT_DIR).join("emoji_metadata.rs"),
+        content: emoji_metadata_rs(&emoji_entries)?,
+    });
+
+    let mut iri = format!("{HEADER} from unicode/rfc3987.abnf.\n\n");
+    iri.push_str("use std::ops::RangeInclusive;\n");
+    for (rule, constant) in [
+        ("ucschar", "UCSCHAR_RANGES"),
+        ("iprivate", "IPRIVATE_RANGES"),
+    ] {
+        let ranges = rfc3987_rules
+            .iter()
+            .find(|(name, _)| name == rule)
+            .map(|(_, ranges)| ranges)
+            .ok_or_else(|| format!("rule {rule} is missing in unicode/rfc3987.abnf"))?;
+        iri.push('\n');
+        push_ranges(&mut iri, &format!("`{rule}` of RFC 3987"), constant, ranges);
+    }
+    files.push(GeneratedFile {
+        path: Path::new(OUTPUT_DIR).join("rfc3987.rs"),
+        content: iri,
+    });
+
+    Ok(files)
+}
+
+/// Regenerates the table files
+pub(crate) fn write(root: &Path) -> Result<(), String> {
+    for file in generate(root)? {
+        let path = root.join(&file.path);
+        fs::write(&path, file.content)
+            .map_err(|error| format!("writing {}: {error}", path.display()))?;
+        println!("generated {}", file.path.display());
+    }
+    Ok(())
+}
+
+fn read(root: &Path, path: &str) -> Result<String, String> {
+    fs::read_to_string(root.join(path)).map_err(|error| format!("reading {path}: {error}"))
+}
+
+fn mod_rs() -> String {
+    let (major, minor, patch) = UNICODE_VERSION;
+    format!(
+        "//! Character tables, generated from the data in `unicode/`
+//!
+{HEADER}.
+
+#[rustfmt::skip]
+pub(crate) mod emoji;
+#[cfg(feature = \"emoji-metadata\")]
+#[rustfmt::skip]
+pub(crate) mod emoji_metadata;
+#[rustfmt::skip]
+pub(crate) mod hashtag;
+#[rustfmt::skip]
+pub(crate) mod rfc3987;
+
+/// The Unicode version that hashtag and emoji detection are based on
+pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {patch});
+"
+    )
+}
+
+fn push_ranges(output: &mut String, doc: &str, name: &str, ranges: &[RangeInclusive<u32>]) {
+    let _ = writeln!(output, "/// {doc}");
+    let _ = writeln!(
+        output,
+        "pub(crate) const {name}: &[RangeInclusive<u32>] = &["
+    );
+    for range in ranges {
+        let _ = writeln!(output, "    {:#x}..={:#x},", range.start(), range.end());
+    }
+    output.push_str("];\n");
+}
+
+/// Sorts the code points and merges them into ranges
+fn to_ranges(mut code_points: Vec<u32>) -> Vec<RangeInclusive<u32>> {
+    code_points.sort_unstable();
+    code_points.dedup();
+    let mut ranges: Vec<RangeInclusive<u32>> = Vec::new();
+    for code_point in code_points {
+        match ranges.last_mut() {
+            Some(last) if *last.end() + 1 == code_point => *last = *last.start()..=code_point,
+            _ => ranges.push(code_point..=code_point),
+        }
+    }
+    ranges
+}
+
+/// Code point ranges of properties, by property name
+type Properties<'a> = HashMap<&'a str, Vec<RangeInclusive<u32>>>;
+
+/// Parses `code points ; property # comment` lines, the format of the Unicode Character Database
+/// files like DerivedCoreProperties.txt, the code points are a single one or a range like `0030..0039`
+fn parse_properties(data: &str) -> Result<Properties<'_>, String> {
+    let mut properties = Properties::new();
+    for line in data.lines() {
+        let data = line.split('#').next().unwrap_or_default().trim();
+        if data.is_empty() {
+            continue;
+        }
+        let invalid_line = || format!("invalid property line: {line}");
+        // some properties have a value after another `;`, like `InCB; Linker`
+        let mut fields = data.split(';').map(str::trim);
+        let code_points = fields.next().ok_or_else(invalid_line)?;
+        let property = fields.next().ok_or_else(invalid_line)?;
+        let parse = |hex: &str| u32::from_str_radix(hex, 16).map_err(|_| invalid_line());
+        let range = match code_points.split_once("..") {
+            Some((start, end)) => parse(start)?..=parse(end)?,
+            None => parse(code_points)?..=parse(code_points)?,
+        };
+        properties.entry(property).or_default().push(range);
+    }
+    Ok(properties)
+}
+
+/// The code points that have one of the `names` properties, as sorted ranges
+fn property_ranges(
+    properties: &Properties,
+    names: &[&str],
+) -> Result<Vec<RangeInclusive<u32>>, String> {
+    let mut code_points = Vec::new();
+    for name in names {
+        let ranges = properties
+            .get(name)
+            .ok_or_else(|| format!("property {name} is missing in the unicode data"))?;
+        code_points.extend(ranges.iter().cloned().flatten());
+    }
+    Ok(to_ranges(code_points))
+}
+
+fn check_emoji_test_version(emoji_test: &str) -> Result<(), String> {
+    let (major, minor, _) = UNICODE_VERSION;
+    let version = emoji_test
+        .lines()
+        .find_map(|line| line.strip_prefix("# Version: "))
+        .ok_or("unicode/emoji-test.txt has no version header")?;
+    if version.trim() != format!("{major}.{minor}") {
+        return Err(format!(
+            "unicode/emoji-test.txt is version {version}, expected {major}.{minor}"
+        ));
+    }
+    Ok(())
+}
+
+struct EmojiTestEntry {
+    code_points: Vec<u32>,
+    /// `component`, `fully-qualified`, `minimally-qualified` or `unqualified`
+    status: String,
+    /// the emoji version in which the emoji was introduced, like `(0, 6)` for `E0.6`
+    version: (u8, u8),
+    /// the CLDR short name, like `thumbs up: medium skin tone`
+    name: String,
+    group: String,
+    subgroup: String,
+}
+
+/// Parses the `code points ; status # comment` lines of emoji-test.txt
+fn parse_emoji_test(emoji_test: &str) -> Result<Vec<EmojiTestEntry>, String> {
+    let mut entries = Vec::new();
+    let mut group = "";
+    let mut subgroup = "";
+    for line in emoji_test.lines() {
+        if let Some(name) = line.strip_prefix("# group:") {
+            group = name.trim();
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("# subgroup:") {
+            subgroup = name.trim();
+            continue;
+        }
+        let (data, comment) = line.split_once('#').unwrap_or((line, ""));
+        let data = data.trim();
+        if data.is_empty() {
+            continue;
+        }
+        let invalid_line = || format!("invalid line in emoji-test.txt: {line}");
+        let (code_points, status) = data.split_once(';').ok_or_else(invalid_line)?;
+        let code_points = code_points
+            .split_whitespace()
+            .map(|code_point| u32::from_str_radix(code_point, 16))
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|error| format!("invalid code point in line {line}: {error}"))?;
+        // the comment is `emoji E<version> name`
+        let mut comment = comment.trim().splitn(3, ' ');
+        let version = comment
+            .nth(1)
+            .and_then(|version| version.strip_prefix('E'))
+            .and_then(|version| version.split_once('.'))
+            .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+            .ok_or_else(invalid_line)?;
+        let name = comment.next().ok_or_else(invalid_line)?;
+        entries.push(EmojiTestEntry {
+            code_points,
+            status: status.trim().to_owned(),
+            version,
+            name: name.to_owned(),
+            group: group.to_owned(),
+            subgroup: subgroup.to_owned(),
+        });
+    }
+    Ok(entries)
+}
+
+/// Checks the number of entries of each status against the `# Status Counts` at the end of
+/// emoji-test.txt, so no RGI sequence is missing
+fn check_status_counts(emoji_test: &str, entries: &[EmojiTestEntry]) -> Result<(), String> {
+    let counts = emoji_test
+        .lines()
+        .skip_while(|line| line.trim() != "# Status Counts")
+        .skip(1)
+        .map_while(|line| line.strip_prefix('#')?.split_once(':'));
+    let mut checked = 0;
+    for (status, count) in counts {
+        let status = status.trim();
+        let count: usize = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid status count of {status} in emoji-test.txt"))?;
+        let parsed = entries
+            .iter()
+            .filter(|entry| entry.status == status)
+            .count();
+        if parsed != count {
+            return Err(format!(
+                "emoji-test.txt lists {count} {status} emoji, but {parsed} were parsed"
+            ));
+        }
+        checked += count;
+    }
+    if checked != entries.len() {
+        return Err("the status counts of emoji-test.txt don't cover all entries".to_owned());
+    }
+    Ok(())
+}
+
+/// Names, groups and versions of the fully-qualified emoji and the components
+fn emoji_metadata_rs(entries: &[EmojiTestEntry]) -> Result<String, String> {
+    let mut output = format!("{HEADER} from unicode/emoji-test.txt.\n");
+
+    let mut groups: Vec<&str> = Vec::new();
+    let mut subgroups: Vec<(&str, usize)> = Vec::new();
+    let mut metadata: BTreeMap<String, (&str, usize, (u8, u8))> = BTreeMap::new();
+    for entry in entries {
+        if entry.status != "fully-qualified" && entry.status != "component" {
+            continue;
+        }
+        if groups.last() != Some(&entry.group.as_str()) {
+            groups.push(&entry.group);
+        }
+        let group = groups.len() - 1;
+        if subgroups.last() != Some(&(entry.subgroup.as_str(), group)) {
+            subgroups.push((&entry.subgroup, group));
+        }
+        let emoji: String = entry
+            .code_points
+            .iter()
+            .filter_map(|c| char::from_u32(*c))
+            .collect();
+        let previous = metadata.insert(emoji, (&entry.name, subgroups.len() - 1, entry.version));
+        if previous.is_some() {
+            return Err(format!("{} is listed twice", entry.name));
+        }
+    }
+    if subgroups.len() > usize::from(u8::MAX) {
+        return Err("too many subgroups for an u8 index".to_owned());
+    }
+
+    output.push_str("\n/// Emoji groups in the order of emoji-test.txt\n");
+    output.push_str("pub(crate) const EMOJI_GROUPS: &[&str] = &[\n");
+    for group in &groups {
+        let _ = writeln!(output, "    {group:?},");
+    }
+    output.push_str("];\n");
+
+    output.push_str(
+        "\n/// Emoji subgroups in the order of emoji-test.txt, with the index of their group in [EMOJI_GROUPS]\n",
+    );
+    output.push_str("pub(crate) const EMOJI_SUBGROUPS: &[(&str, u8)] = &[\n");
+    for (subgroup, group) in &subgroups {
+        let _ = writeln!(output, "    ({subgroup:?}, {group}),");
+    }
+    output.push_str("];\n");
+
+    output.push_str(
+        "\n/// Emoji with their name, the index of their subgroup in [EMOJI_SUBGROUPS]\n\
+         /// and the emoji version they were introduced in, sorted for binary search\n",
+    );
+    output.push_str("pub(crate) const EMOJI_METADATA: &[(&str, &str, u8, (u8, u8))] = &[\n");
+    for (emoji, (name, subgroup, (major, minor))) in metadata {
+        let emoji: Vec<u32> = emoji.chars().map(u32::from).collect();
+        let _ = writeln!(
+            output,
+            "    (\"{}\", {name:?}, {subgroup}, ({major}, {minor})),",
+            escape(&emoji)
+        );
+    }
+    output.push_str("];\n");
+    Ok(output)
+}
+
+fn emoji_rs(entries: &[EmojiTestEntry]) -> Result<String, String> {
+    let mut output = format!("{HEADER} from unicode/emoji-test.txt.\n\n");
+    output.push_str("use std::ops::RangeInclusive;\n\n");
+    output.push_str("use crate::parser::is_emoji::EmojiQualification::{self, *};\n\n");
+
+    push_ranges(
+        &mut output,
+        "First characters of the sequences in [EMOJI_SEQUENCES]",
+        "EMOJI_START_RANGES",
+        &to_ranges(
+            entries
+                .iter()
+                .filter_map(|entry| entry.code_points.first().copied())
+                .collect(),
+        ),
+    );
+
+    let max_length = entries
+        .iter()
+        .map(|entry| entry.code_points.len())
+        .max()
+        .unwrap_or_default();
+    let _ = write!(
+        output,
+        "\n/// Maximum number of characters of the sequences in [EMOJI_SEQUENCES]\n\
+         pub(crate) const MAX_EMOJI_SEQUENCE_LENGTH: usize = {max_length};\n\n"
+    );
+
+    let mut sequences = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let qualification = match entry.status.as_str() {
+            "fully-qualified" => "FullyQualified",
+            "minimally-qualified" => "MinimallyQualified",
+            "unqualified" => "Unqualified",
+            "component" => "Component",
+            status => return Err(format!("unknown status {status} in emoji-test.txt")),
+        };
+        let sequence = entry
+            .code_points
+            .iter()
+            .map(|&code_point| {
+                char::from_u32(code_point).ok_or(format!("invalid code point {code_point:x}"))
+            })
+            .collect::<Result<String, String>>()?;
+        sequences.push((sequence, entry.code_points.clone(), qualification));
+    }
+    // sorted by their UTF-8 bytes, like `str::cmp`, for binary search
+    sequences.sort();
+
+    output.push_str("/// All sequences of emoji-test.txt, sorted for binary search\n");
+    output.push_str("pub(crate) const EMOJI_SEQUENCES: &[(&str, EmojiQualification)] = &[\n");
+    for (_, code_points, qualification) in &sequences {
+        let _ = writeln!(
+            output,
+            "    (\"{}\", {qualification}),",
+            escape(code_points)
+        );
+    }
+    output.push_str("];\n");
+
+    // fully-qualified emoji that contain emoji presentation selectors, by their form without them
+    let mut qualified_forms: Vec<(String, &[u32])> = sequences
+        .iter()
+        .filter(|(_, code_points, qualification)| {
+            *qualification == "FullyQualified" && code_points.contains(&EMOJI_PRESENTATION_SELECTOR)
+        })
+        .map(|(_, code_points, _)| {
+            let stripped: String = code_points
+                .iter()
+                .filter(|c| **c != EMOJI_PRESENTATION_SELECTOR)
+                .filter_map(|c| char::from_u32(*c))
+                .collect();
+            (stripped, code_points.as_slice())
+        })
+        .collect();
+    qualified_forms.sort();
+
+    output.push_str(
+        "\n/// Fully-qualified emoji with emoji presentation selectors (`U+FE0F`), by their form without\n\
+         /// them, sorted for binary search\n",
+    );
+    output.push_str("pub(crate) const FULLY_QUALIFIED_FORMS: &[(&str, &str)] = &[\n");
+    for (stripped, code_points) in qualified_forms {
+        let stripped: Vec<u32> = stripped.chars().map(u32::from).collect();
+        let _ = writeln!(
+            output,
+            "    (\"{}\", \"{}\"),",
+            escape(&stripped),
+            escape(code_points)
+        );
+    }
+    output.push_str("];\n");
+
+    push_skin_tone_tables(&mut output, entries)?;
+    Ok(output)
+}
+
+const EMOJI_PRESENTATION_SELECTOR: u32 = 0xfe0f;
+const SKIN_TONES: RangeInclusive<u32> = 0x1f3fb..=0x1f3ff;
+
+/// Tables to remove and apply skin tones
+///
+/// They rely on the order of emoji-test.txt, which lists the skin tone variants of an emoji
+/// directly after it.
+fn push_skin_tone_tables(output: &mut String, entries: &[EmojiTestEntry]) -> Result<(), String> {
+    let to_string = |code_points: &[u32]| -> String {
+        code_points
+            .iter()
+            .filter_map(|c| char::from_u32(*c))
+            .collect()
+    };
+    let fully_qualified: Vec<&[u32]> = entries
+        .iter()
+        .filter(|entry| entry.status == "fully-qualified")
+        .map(|entry| entry.code_points.as_slice())
+        .collect();
+    // fully-qualified emoji by their form without emoji presentation selectors
+    let qualified_forms: HashMap<Vec<u32>, &[u32]> = fully_qualified
+        .iter()
+        .map(|code_points| {
+            let stripped = code_points
+                .iter()
+                .filter(|c| **c != EMOJI_PRESENTATION_SELECTOR)
+                .copied()
+                .collect();
+            (stripped, *code_points)
+        })
+        .collect();
+
+    let mut bases: BTreeMap<String, &[u32]> = BTreeMap::new();
+    let mut variants: BTreeMap<String, [Option<&[u32]>; 5]> = BTreeMap::new();
+    let mut base: Option<&[u32]> = None;
+    for code_points in fully_qualified {
+        let tones: Vec<u32> = code_points
+            .iter()
+            .filter(|c| SKIN_TONES.contains(c))
+            .copied()
+            .collect();
+        let Some(&first_tone) = tones.first() else {
+            base = Some(code_points);
+            continue;
+        };
+        let base = base.ok_or_else(|| format!("no base emoji for {}", to_string(code_points)))?;
+
+        // only needed if the base can not be found by removing the skin tones,
+        // like for handshakes and couples with different skin tones
+        let toneless: Vec<u32> = code_points
+            .iter()
+            .filter(|c| **c != EMOJI_PRESENTATION_SELECTOR && !SKIN_TONES.contains(c))
+            .copied()
+            .collect();
+        if qualified_forms.get(&toneless) != Some(&base) {
+            let previous = bases.insert(to_string(&toneless), base);
+            if previous.is_some_and(|previous| previous != base) {
+                return Err(format!("{} has multiple bases", to_string(&toneless)));
+            }
+        }
+
+        if tones.iter().all(|tone| *tone == first_tone) {
+            let slot = &mut variants.entry(to_string(base)).or_default()
+                [(first_tone - SKIN_TONES.start()) as usize];
+            if slot.is_none() {
+                *slot = Some(code_points);
+            }
+        }
+    }
+
+    output.push_str(
+        "\n/// Emoji without skin tones (and without `U+FE0F`) whose base emoji is not the same emoji\n\
+         /// with skin tones, like a handshake with different skin tones, sorted for binary search\n",
+    );
+    output.push_str("pub(crate) const SKIN_TONE_BASES: &[(&str, &str)] = &[\n");
+    for (toneless, base) in bases {
+        let toneless: Vec<u32> = toneless.chars().map(u32::from).collect();
+        let _ = writeln!(
+            output,
+            "    (\"{}\", \"{}\"),",
+            escape(&toneless),
+            escape(base)
+        );
+    }
+    output.push_str("];\n");
+
+    output.push_str(
+        "\n/// Emoji that support skin tones with their light, medium-light, medium, medium-dark\n\
+         /// and dark variant, sorted for binary search\n",
+    );
+    output.push_str("pub(crate) const SKIN_TONE_VARIANTS: &[(&str, [&str; 5])] = &[\n");
+    for (base, tones) in variants {
+        let tones = tones
+            .iter()
+            .map(|variant| {
+                variant
+                    .map(|variant| format!("\"{}\"", escape(variant)))
+                    .ok_or_else(|| format!("{base} misses skin tone variants"))
+            })
+            .collect::<Result<Vec<String>, String>>()?;
+        let base: Vec<u32> = base.chars().map(u32::from).collect();
+        let _ = writeln!(
+            output,
+            "    (\"{}\", [{}]),",
+            escape(&base),
+            tones.join(", ")
+        );
+    }
+    output.push_str("];\n");
+    Ok(())
+}
+
+fn escape(code_points: &[u32]) -> String {
+    code_points
+        .iter()
+        .fold(String::new(), |mut escaped, code_point| {
+            let _ = write!(escaped, "\\u{{{code_point:x}}}");
+            escaped
+        })
+}
+
+/// Name and ranges of an ABNF rule
+type AbnfRule = (String, Vec<RangeInclusive<u32>>);
+
+/// Parses the `%xA0-D7FF / %xF900-FDCF` alternatives of ABNF rules, comments start with `;`
+fn parse_abnf_ranges(abnf: &str) -> Result<Vec<AbnfRule>, String> {
+    let mut rules: Vec<AbnfRule> = Vec::new();
+    for line in abnf.lines() {
+        let line = line.split(';').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let alternatives = match line.split_once('=') {
+            Some((name, alternatives)) => {
+                rules.push((name.trim().to_owned(), Vec::new()));
+                alternatives
+            }
+            None => line,
+        };
+        let (_, ranges) = rules
+            .last_mut()
+            .ok_or_else(|| format!("continuation line without rule: {line}"))?;
+        for alternative in alternatives.split('/').map(str::trim) {
+            if alternative.is_empty() {
+                continue;
+            }
+            let parse = |hex: &str| u32::from_str_radix(hex, 16);
+            let range = alternative
+                .strip_prefix("%x")
+                .and_then(|range| range.split_once('-'))
+                .and_then(|(start, end)| Some(parse(start).ok()?..=parse(end).ok()?))
+                .ok_or_else(|| format!("unsupported ABNF alternative {alternative}"))?;
+            ranges.push(range);
+        }
+    }
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, parse_abnf_ranges, parse_properties, property_ranges, to_ranges};
+    use crate::project_root;
+
+    #[test]
+    fn checked_in_tables_are_up_to_date() {
+        let root = project_root();
+        for file in generate(&root).unwrap() {
+            let checked_in = std::fs::read_to_string(root.join(&file.path)).unwrap();
+            assert!(
+                checked_in == file.content,
+                "{} is outdated, run `cargo xtask generate-unicode-tables`",
+                file.path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            to_ranges(vec![5, 1, 2, 3, 7, 6, 3, 10]),
+            vec![1..=3, 5..=7, 10..=10]
+        );
+    }
+
+    #[test]
+    fn properties() {
+        let properties = parse_properties(
+            "# comment\n\n0030..0039    ; XID_Continue # Nd  [10] DIGIT ZERO..DIGIT NINE\n\
+             0041          ; XID_Continue # L&       LATIN CAPITAL LETTER A\n\
+             094D          ; InCB; Linker # Mn       DEVANAGARI SIGN VIRAMA\n\
+             0023          ; Emoji        # E0.0   [1] (#️)       number sign\n",
+        )
+        .unwrap();
+        assert_eq!(properties["XID_Continue"], vec![0x30..=0x39, 0x41..=0x41]);
+        assert_eq!(properties["InCB"], vec![0x94d..=0x94d]);
+        assert_eq!(
+            property_ranges(&properties, &["XID_Continue", "Emoji"]).unwrap(),
+            vec![0x23..=0x23, 0x30..=0x39, 0x41..=0x41]
+        );
+        assert!(property_ranges(&properties, &["Extended_Pictographic"]).is_err());
+        assert!(parse_properties("00ZZ ; Emoji\n").is_err());
+    }
+
+    #[test]
+    fn abnf() {
+        let rules = parse_abnf_ranges(
+            "; comment\n  a = %x1-2 / %x4-5\n      / %x8-9\n  b = %xA-F ; comment\n",
+        )
+        .unwrap();
+        assert_eq!(
+            rules,
+            vec![
+                ("a".to_owned(), vec![1..=2, 4..=5, 8..=9]),
+                ("b".to_owned(), vec![10..=15]),
+            ]
+        );
+    }
+}